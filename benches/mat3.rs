@@ -0,0 +1,122 @@
+//! The flat Mat3 against the [[f64; 3]; 3] r-matrix functions:  a
+//! product of three rotations applied to a vector, as in the c2t and
+//! bias-precession-nutation chains, and pn00 itself.  The "old" cases
+//! are the r-matrix code as it was before Mat3, working through
+//! temporaries copied out with cr/cp.
+//!
+//!     cargo bench --bench mat3
+use std::hint::black_box;
+use std::time::Instant;
+
+use sofars::pnp::{bp00, numat, obl80, pn00, pn00_mat3, pr00};
+use sofars::vm::{ir, rx, rxp, rxr, rz, Mat3, RotMat};
+
+const N: u32 = 10_000_000;
+
+fn time(name: &str, n: u32, mut f: impl FnMut(f64) -> f64) {
+    let t = Instant::now();
+    let mut s = 0.0;
+    for i in 0..n {
+        s += f(black_box(i as f64 * 1e-7));
+    }
+    let ns = t.elapsed().as_secs_f64() * 1e9 / n as f64;
+    println!("{:10} {:8.2} ns/iter   ({:e})", name, ns, s);
+}
+
+/* The r-matrix functions before Mat3. */
+
+fn cr_old(r: &[[f64; 3]; 3], c: &mut [[f64; 3]; 3]) {
+    *c = *r;
+}
+
+fn rxr_old(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3], atb: &mut [[f64; 3]; 3]) {
+    let wm = &mut [[0.0; 3]; 3];
+    for i in 0..3 {
+        for j in 0..3 {
+            wm[i][j] = 0.0;
+            for k in 0..3 {
+                wm[i][j] += a[i][k] * b[k][j];
+            }
+        }
+    }
+    cr_old(wm, atb);
+}
+
+fn rxp_old(r: &[[f64; 3]; 3], p: &[f64; 3], rp: &mut [f64; 3]) {
+    let wrp = &mut [0.0; 3];
+    for j in 0..3 {
+        let mut w = 0.0;
+        for i in 0..3 {
+            w += r[j][i] * p[i];
+        }
+        wrp[j] = w;
+    }
+    *rp = *wrp;
+}
+
+/* pn00 before Mat3, the matrices going through temporaries. */
+fn pn00_old(date1: f64, date2: f64, dpsi: f64, deps: f64) -> [[[f64; 3]; 3]; 5] {
+    let (mut rb, mut rp, mut rbp, mut rn, mut rbpn) = Default::default();
+    let (rbpw, rnw) = (&mut [[0.0; 3]; 3], &mut [[0.0; 3]; 3]);
+    let (_, depspr) = pr00(date1, date2);
+    let epsa = obl80(date1, date2) + depspr;
+    let [b, p, bp] = bp00(date1, date2);
+    cr_old(&b, &mut rb);
+    cr_old(&p, &mut rp);
+    cr_old(&bp, rbpw);
+    cr_old(rbpw, &mut rbp);
+    cr_old(&numat(epsa, dpsi, deps), rnw);
+    cr_old(rnw, &mut rn);
+    rxr_old(rnw, rbpw, &mut rbpn);
+    [rb, rp, rbp, rn, rbpn]
+}
+
+fn main() {
+    let p = [0.3, -0.5, 0.8];
+
+    time("arrays old", N, |a| {
+        let mut r1 = [[0.0; 3]; 3];
+        ir(&mut r1);
+        rz(a, &mut r1);
+        let mut r2 = [[0.0; 3]; 3];
+        ir(&mut r2);
+        rx(0.4, &mut r2);
+        let (mut w, mut r, mut q) = ([[0.0; 3]; 3], [[0.0; 3]; 3], [0.0; 3]);
+        rxr_old(&r2, &r1, &mut w);
+        rxr_old(&r1, &w, &mut r);
+        rxp_old(&r, &p, &mut q);
+        q[2]
+    });
+
+    time("arrays", N, |a| {
+        let mut r1 = [[0.0; 3]; 3];
+        ir(&mut r1);
+        rz(a, &mut r1);
+        let mut r2 = [[0.0; 3]; 3];
+        ir(&mut r2);
+        rx(0.4, &mut r2);
+        let w = rxr(&r2, &r1);
+        let r = rxr(&r1, &w);
+        let q = rxp(&r, &p);
+        q[2]
+    });
+
+    time("Mat3", N, |a| {
+        let mut r1 = Mat3::IDENTITY;
+        r1.rotate_z(a);
+        let mut r2 = Mat3::IDENTITY;
+        r2.rotate_x(0.4);
+        r1.mul(&r2.mul(&r1)).mul_vec(&p)[2]
+    });
+
+    time("RotMat", N, |a| {
+        let r1 = RotMat::rz(a);
+        (r1 * RotMat::rx(0.4) * r1).apply(&p)[2]
+    });
+
+    /* pn00, as a whole and with the matrices left as Mat3. */
+    let (dpsi, deps) = (-0.9632552291149335877e-5, 0.4063197106621141414e-4);
+    time("pn00 old", N, |a| pn00_old(2400000.5, 53736.0 + a, dpsi, deps)[4][0][1]);
+    time("pn00", N, |a| pn00(2400000.5, 53736.0 + a, dpsi, deps).rbpn[0][1]);
+    time("pn00_mat3", N, |a| pn00_mat3(2400000.5, 53736.0 + a, dpsi, deps).rbpn[(0, 1)]);
+}
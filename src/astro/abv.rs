@@ -0,0 +1,130 @@
+use crate::consts::{CMPS, DAU, DAYSEC};
+use crate::coords::gd2gc;
+use crate::eph::epv00;
+use crate::frames::{itrs_to_gcrs_pv, EopProvider};
+use crate::ts::{Epoch, TimeScale};
+use crate::vm::{pdp, PvVec};
+
+use super::{ab, Site};
+
+///  Aberration for an arbitrary observer velocity.
+///
+///  Given:
+///  ```text
+///     pnat    double[3]   natural direction to the source (unit vector)
+///     v       double[3]   observer barycentric velocity (km/s)
+///     s       double      distance between the Sun and the observer (au)
+///  ```
+///  Returned (function value):
+///  ```text
+///             double[3]   proper direction to source (unit vector)
+///  ```
+///  Status values:
+///  ```text
+///     -1   |v| is not less than c
+///  ```
+///  Notes:
+///
+///  1) This is `ab` for callers who have a velocity in km/s rather than
+///     the c-scaled velocity and Lorentz factor of an IauAstrom, as in
+///     the spacecraft case.  The velocity is with respect to the BCRS
+///     (or, to the same accuracy, the GCRS axes).
+///
+///  2) s only scales the Sun's gravitational potential term (at most
+///     0.4 microarcsecond at 1 au);  use 1.0 if it is not known.
+pub fn abv(pnat: &[f64; 3], v: &[f64; 3], s: f64) -> Result<[f64; 3], i32> {
+    let vc = v.map(|x| x * 1e3 / CMPS);
+    let v2 = pdp(&vc, &vc);
+    if v2 >= 1.0 {
+        return Err(-1);
+    }
+    Ok(ab(pnat, &vc, s, (1.0 - v2).sqrt()))
+}
+
+///  Barycentric position and velocity of a ground site.
+///
+///  Given:
+///  ```text
+///     site     Site               geodetic position (WGS84)
+///     epoch    Epoch              UTC, TAI or TT
+///     eop      EopProvider        UT1-UTC, polar motion, dX,dY
+///  ```
+///  Returned (function value):
+///  ```text
+///              PvVec              BCRS position and velocity of the
+///                                 site (au, au/day)
+///              double[3]          heliocentric position of the site (au)
+///  ```
+///  Status values:
+///  ```text
+///      1   date outside 1900-2100 (from epv00)
+///     -2   illegal site coordinates (from gd2gc)
+///     other  from the time scale conversions
+///  ```
+///  Notes:
+///
+///  1) The Earth's motion is from `epv00` (TT used for TDB) and the
+///     site's diurnal motion from `itrs_to_gcrs_pv`.
+///
+///  2) The heliocentric distance is what `ab` needs for its
+///     gravitational term.
+pub fn site_pv_bary(
+    site: &Site,
+    epoch: &Epoch,
+    eop: &(impl EopProvider + ?Sized),
+) -> Result<(PvVec, [f64; 3]), i32> {
+    let eo = &eop.eop(epoch)?;
+    let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+
+    /* Earth, heliocentric and barycentric. */
+    let (pvh, pvb) = epv00(tt1, tt2).ok_or(1)?;
+
+    /* Site in the GCRS, metres. */
+    let mut xyz = [0.0; 3];
+    gd2gc(1, site.elong, site.phi, site.hm, &mut xyz)?;
+    let pvs = itrs_to_gcrs_pv(epoch, eo, &PvVec::new(xyz, [0.0; 3]))?;
+
+    /* To au, au/day. */
+    let pvs = pvs.scale2(1.0 / DAU, DAYSEC / DAU);
+    let bary = PvVec::from(pvb) + pvs;
+    let helio = [pvh[0][0] + pvs.p[0], pvh[0][1] + pvs.p[1], pvh[0][2] + pvs.p[2]];
+
+    Ok((bary, helio))
+}
+
+///  Annual plus diurnal aberration for a ground site.
+///
+///  Given:
+///  ```text
+///     pnat     double[3]          natural direction to the source
+///                                 (unit vector, BCRS/GCRS axes)
+///     site     Site               geodetic position (WGS84)
+///     epoch    Epoch              UTC, TAI or TT
+///     eop      EopProvider        UT1-UTC, polar motion, dX,dY
+///  ```
+///  Returned (function value):
+///  ```text
+///              double[3]          proper direction to source, as seen
+///                                 from the site (unit vector, GCRS
+///                                 axes)
+///  ```
+///  Status values are those of `site_pv_bary`.
+///
+///  Notes:
+///
+///  1) The observer velocity is the Earth's barycentric velocity plus
+///     the site's rotational velocity, so the result contains both the
+///     annual (up to 20.5 arcsec) and diurnal (up to 0.32 arcsec)
+///     aberration, without the caller assembling the velocity.
+///
+///  2) Accuracy is limited by `epv00`, a few microarcseconds.
+pub fn ab_site(
+    pnat: &[f64; 3],
+    site: &Site,
+    epoch: &Epoch,
+    eop: &(impl EopProvider + ?Sized),
+) -> Result<[f64; 3], i32> {
+    let (pv, ph) = site_pv_bary(site, epoch, eop)?;
+    let v = pv.v.map(|x| x * DAU / DAYSEC / 1e3);
+    abv(pnat, &v, pdp(&ph, &ph).sqrt())
+}
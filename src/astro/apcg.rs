@@ -21,9 +21,9 @@ use super::{apcs, IauAstrom};
 ///     ebpv   double[2][3] Earth barycentric pos/vel (au, au/day)
 ///     ehp    double[3]    Earth heliocentric position (au)
 ///  ```
-///  Returned:
+///  Returned (function value):
 ///  ```
-///     astrom iauASTROM*   star-independent astrometry parameters:
+///     astrom IauAstrom    star-independent astrometry parameters:
 ///      pmt    double       PM time interval (SSB, Julian years)
 ///      eb     double[3]    SSB to observer (vector, au)
 ///      eh     double[3]    Sun to observer (unit vector)
@@ -31,15 +31,15 @@ use super::{apcs, IauAstrom};
 ///      v      double[3]    barycentric observer velocity (vector, c)
 ///      bm1    double       sqrt(1-|v|^2): reciprocal of Lorenz factor
 ///      bpn    double[3][3] bias-precession-nutation matrix
-///      along  double       unchanged
-///      xpl    double       unchanged
-///      ypl    double       unchanged
-///      sphi   double       unchanged
-///      cphi   double       unchanged
-///      diurab double       unchanged
-///      eral   double       unchanged
-///      refa   double       unchanged
-///      refb   double       unchanged
+///      along  double       zero
+///      xpl    double       zero
+///      ypl    double       zero
+///      sphi   double       zero
+///      cphi   double       zero
+///      diurab double       zero
+///      eral   double       zero
+///      refa   double       zero
+///      refb   double       zero
 ///  ```
 ///  Notes:
 ///
@@ -103,10 +103,10 @@ use super::{apcs, IauAstrom};
 ///  ```
 ///     iauApcs      astrometry parameters, ICRS-GCRS, space observer
 ///  ```
-pub fn apcg(date1: f64, date2: f64, ebpv: &[[f64; 3]; 2], ehp: &[f64; 3], astrom: &mut IauAstrom) {
+pub fn apcg(date1: f64, date2: f64, ebpv: &[[f64; 3]; 2], ehp: &[f64; 3]) -> IauAstrom {
     /* Geocentric observer */
     let pv = [[0.0; 3]; 2];
 
     /* Compute the star-independent astrometry parameters. */
-    apcs(date1, date2, &pv, ebpv, ehp, astrom);
+    apcs(date1, date2, &pv, ebpv, ehp)
 }
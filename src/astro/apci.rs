@@ -27,9 +27,9 @@ use super::{apcg, IauAstrom};
 ///     x,y    double       CIP X,Y (components of unit vector)
 ///     s      double       the CIO locator s (radians)
 ///  ```
-///  Returned:
+///  Returned (function value):
 ///  ```
-///     astrom iauASTROM*   star-independent astrometry parameters:
+///     astrom IauAstrom    star-independent astrometry parameters:
 ///      pmt    double       PM time interval (SSB, Julian years)
 ///      eb     double[3]    SSB to observer (vector, au)
 ///      eh     double[3]    Sun to observer (unit vector)
@@ -37,15 +37,15 @@ use super::{apcg, IauAstrom};
 ///      v      double[3]    barycentric observer velocity (vector, c)
 ///      bm1    double       sqrt(1-|v|^2): reciprocal of Lorenz factor
 ///      bpn    double[3][3] bias-precession-nutation matrix
-///      along  double       unchanged
-///      xpl    double       unchanged
-///      ypl    double       unchanged
-///      sphi   double       unchanged
-///      cphi   double       unchanged
-///      diurab double       unchanged
-///      eral   double       unchanged
-///      refa   double       unchanged
-///      refb   double       unchanged
+///      along  double       zero
+///      xpl    double       zero
+///      ypl    double       zero
+///      sphi   double       zero
+///      cphi   double       zero
+///      diurab double       zero
+///      eral   double       zero
+///      refa   double       zero
+///      refb   double       zero
 ///  ```
 ///  Notes:
 ///
@@ -118,11 +118,13 @@ use super::{apcg, IauAstrom};
 ///  This revision:   2013 September 25
 pub fn apci(
     date1: f64, date2: f64, ebpv: &[[f64; 3]; 2], ehp: &[f64; 3], 
-    x: f64, y: f64, s: f64, astrom: &mut IauAstrom,) {
+    x: f64, y: f64, s: f64) -> IauAstrom {
     
     /* Star-independent astrometry parameters for geocenter. */
-    apcg(date1, date2, ebpv, ehp, astrom);
+    let mut astrom = apcg(date1, date2, ebpv, ehp);
     
     /* CIO based BPN matrix. */
-    c2ixys(x, y, s, &mut astrom.bpn);
+    astrom.bpn = c2ixys(x, y, s);
+
+    astrom
 }
\ No newline at end of file
@@ -22,9 +22,9 @@ use super::{apci, IauAstrom};
 ///     date1  double      TDB as a 2-part...
 ///     date2  double      ...Julian Date (Note 1)
 ///  ```
-///  Returned:
+///  Returned (function value):
 ///  ```
-///     astrom iauASTROM*  star-independent astrometry parameters:
+///     astrom IauAstrom   star-independent astrometry parameters:
 ///      pmt    double       PM time interval (SSB, Julian years)
 ///      eb     double[3]    SSB to observer (vector, au)
 ///      eh     double[3]    Sun to observer (unit vector)
@@ -32,16 +32,16 @@ use super::{apci, IauAstrom};
 ///      v      double[3]    barycentric observer velocity (vector, c)
 ///      bm1    double       sqrt(1-|v|^2): reciprocal of Lorenz factor
 ///      bpn    double[3][3] bias-precession-nutation matrix
-///      along  double       unchanged
-///      xpl    double       unchanged
-///      ypl    double       unchanged
-///      sphi   double       unchanged
-///      cphi   double       unchanged
-///      diurab double       unchanged
-///      eral   double       unchanged
-///      refa   double       unchanged
-///      refb   double       unchanged
-///     eo     double*     equation of the origins (ERA-GST, radians)
+///      along  double       zero
+///      xpl    double       zero
+///      ypl    double       zero
+///      sphi   double       zero
+///      cphi   double       zero
+///      diurab double       zero
+///      eral   double       zero
+///      refa   double       zero
+///      refb   double       zero
+///     eo     double      equation of the origins (ERA-GST, radians)
 ///  ```
 ///  Notes:
 ///
@@ -114,7 +114,7 @@ use super::{apci, IauAstrom};
 ///     iauApci      astrometry parameters, ICRS-CIRS
 ///     iauEors      equation of the origins, given NPB matrix and s
 ///  ```
-pub fn apci13(date1: f64, date2: f64, astrom: &mut IauAstrom, eo: &mut f64) {
+pub fn apci13(date1: f64, date2: f64) -> (IauAstrom, f64) {
     /* Earth barycentric & heliocentric position/velocity (au, au/d). */
     let (ehpv, ebpv) = epv00(date1, date2).unwrap();
 
@@ -128,8 +128,8 @@ pub fn apci13(date1: f64, date2: f64, astrom: &mut IauAstrom, eo: &mut f64) {
     let s = s06(date1, date2, x, y);
 
     /* Compute the star-independent astrometry parameters. */
-    apci(date1, date2, &ebpv, &ehpv[0], x, y, s, astrom);
+    let astrom = apci(date1, date2, &ebpv, &ehpv[0], x, y, s);
 
     /* Equation of the origins. */
-    *eo = eors(&r, s);
+    (astrom, eors(&r, s))
 }
\ No newline at end of file
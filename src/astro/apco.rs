@@ -1,6 +1,7 @@
 use super::{apcs, pvtob, IauAstrom};
 use crate::pnp::c2ixys;
-use crate::vm::{anpm, cr, ir, rx, ry, rz, trxpv};
+use crate::vm::{anpm, ir, rx, ry, rz, trxpv};
+use crate::math::{atan2, cos, sin};
 
 ///  Prepare for ICRS <−> observed, terrestrial, special 
 /// 
@@ -32,9 +33,9 @@ use crate::vm::{anpm, cr, ir, rx, ry, rz, trxpv};
 ///     refa   double       refraction constant A (radians, Note 5)
 ///     refb   double       refraction constant B (radians, Note 5)
 ///  ```
-///  Returned:
+///  Returned (function value):
 ///  ```
-///     astrom iauASTROM*   star-independent astrometry parameters:
+///     astrom IauAstrom    star-independent astrometry parameters:
 ///      pmt    double       PM time interval (SSB, Julian years)
 ///      eb     double[3]    SSB to observer (vector, au)
 ///      eh     double[3]    Sun to observer (unit vector)
@@ -157,7 +158,6 @@ use crate::vm::{anpm, cr, ir, rx, ry, rz, trxpv};
 ///     iauPvtob     position/velocity of terrestrial station
 ///     iauTrxpv     product of transpose of r-matrix and pv-vector
 ///     iauApcs      astrometry parameters, ICRS-GCRS, space observer
-///     iauCr        copy r-matrix
 ///  ```
 pub fn apco(
     date1: f64,
@@ -176,10 +176,10 @@ pub fn apco(
     sp: f64,
     refa: f64,
     refb: f64,
-    astrom: &mut IauAstrom,
-) {
+) -> IauAstrom {
     let r = &mut [[0.0; 3]; 3];
     let (mut a, mut b, eral, c);
+    let (xpl, ypl);
 
     // Form the rotation matrix, CIRS to apparent [HA,Dec].
     ir(r);
@@ -192,52 +192,55 @@ pub fn apco(
     a = r[0][0];
     b = r[0][1];
     eral = if a != 0.0 || b != 0.0 {
-        b.atan2(a)
+        atan2(b, a)
     } else {
         0.0
     };
-    astrom.eral = eral;
 
     // Solve for polar motion [X,Y] with respect to local meridian.
     a = r[0][0];
     c = r[0][2];
-    astrom.xpl = c.atan2((a * a + b * b).sqrt());
+    xpl = atan2(c, (a * a + b * b).sqrt());
     a = r[1][2];
     b = r[2][2];
-    astrom.ypl = if a != 0.0 || b != 0.0 {
-        -a.atan2(b)
+    ypl = if a != 0.0 || b != 0.0 {
+        -atan2(a, b)
     } else {
         0.0
     };
 
-    // Adjusted longitude.
-    astrom.along = anpm(eral - theta);
-
-    // Functions of latitude.
-    astrom.sphi = phi.sin();
-    astrom.cphi = phi.cos();
-
-    // Refraction constants.
-    astrom.refa = refa;
-    astrom.refb = refb;
-
-    // Disable the (redundant) diurnal aberration step.
-    astrom.diurab = 0.0;
-
     /* CIO based BPN matrix. */
-    c2ixys(x, y, s, r);
+    let bpn = c2ixys(x, y, s);
 
     /* Observer's geocentric position and velocity (m, m/s, CIRS). */
-    let pvc = &mut [[0.0; 3]; 2];
-    pvtob(elong, phi, hm, xp, yp, sp, theta, pvc);
+    let pvc = pvtob(elong, phi, hm, xp, yp, sp, theta);
 
     /* Rotate into GCRS. */
-    let pv = &mut [[0.0; 3]; 2];
-    trxpv(r, pvc, pv);
+    let pv = trxpv(&bpn, &pvc);
 
     /* ICRS <-> GCRS parameters. */
-    apcs(date1, date2, pv, &ebpv, &ehp, astrom);
+    IauAstrom {
+        eral,
+        xpl,
+        ypl,
+
+        // Adjusted longitude.
+        along: anpm(eral - theta),
+
+        // Functions of latitude.
+        sphi: sin(phi),
+        cphi: cos(phi),
+
+        // Refraction constants.
+        refa,
+        refb,
+
+        // Disable the (redundant) diurnal aberration step.
+        diurab: 0.0,
+
+        /* Store the CIO based BPN matrix. */
+        bpn,
 
-    /* Store the CIO based BPN matrix. */
-    cr(r, &mut astrom.bpn);
+        ..apcs(date1, date2, &pv, ebpv, ehp)
+    }
 }
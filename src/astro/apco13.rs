@@ -1,8 +1,8 @@
 use super::{apco, refco, IauAstrom};
-use crate::eph::epv00;
+use crate::eph::epv00_status;
 use crate::erst::era00;
 use crate::pnp::{bpn2xy, eors, pnm06a, s06, sp00};
-use crate::ts::{taitt, utctai, utcut1};
+use crate::ts::{taitt, utctai_status, utcut1_status};
 
 ///  Prepare for ICRS <−> observed, terrestrial
 /// 
@@ -35,9 +35,9 @@ use crate::ts::{taitt, utctai, utcut1};
 ///     rh     double     relative humidity at the observer (range 0-1)
 ///     wl     double     wavelength (micrometers, Note 7)
 ///  ```
-///  Returned:
+///  Returned (function value):
 ///  ```
-///     astrom iauASTROM* star-independent astrometry parameters:
+///     astrom IauAstrom  star-independent astrometry parameters:
 ///      pmt    double       PM time interval (SSB, Julian years)
 ///      eb     double[3]    SSB to observer (vector, au)
 ///      eh     double[3]    Sun to observer (unit vector)
@@ -54,9 +54,9 @@ use crate::ts::{taitt, utctai, utcut1};
 ///      eral   double       "local" Earth rotation angle (radians)
 ///      refa   double       refraction constant A (radians)
 ///      refb   double       refraction constant B (radians)
-///     eo     double*    equation of the origins (ERA-GST, radians)
+///     eo     double     equation of the origins (ERA-GST, radians)
 ///  ```
-///  Returned (function value):
+///  Status values:
 ///  ```
 ///            int        status: +1 = dubious year (Note 2)
 ///                                0 = OK
@@ -181,29 +181,36 @@ use crate::ts::{taitt, utctai, utcut1};
 ///  ```
 pub fn apco13(
     utc1: f64, utc2: f64, dut1: f64, elong: f64, phi: f64, hm: f64, xp: f64, yp: f64, 
-    phpa: f64, tc: f64, rh: f64, wl: f64, astrom: &mut IauAstrom, eo: &mut f64,
-) -> Result<i32, i32> {
+    phpa: f64, tc: f64, rh: f64, wl: f64,
+) -> Result<(IauAstrom, f64), i32> {
+    match apco13_status(utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl)? {
+        (r, 0) => Ok(r),
+        (_, j) => Err(j),
+    }
+}
+
+/* As apco13, with the warning status of the C function (+1 for a
+   dubious year) returned together with the results. */
+pub(crate) fn apco13_status(
+    utc1: f64, utc2: f64, dut1: f64, elong: f64, phi: f64, hm: f64, xp: f64, yp: f64,
+    phpa: f64, tc: f64, rh: f64, wl: f64,
+) -> Result<((IauAstrom, f64), i32), i32> {
     let s: f64;
     let theta: f64;
     let sp: f64;
 
     /* UTC to other time scales. */
-    let (tai1, tai2) = match utctai(utc1, utc2) {
-        Ok((tai1, tai2)) => (tai1, tai2),
-        Err(j) => return Err(j),
-    };
+    let ((tai1, tai2), _) = utctai_status(utc1, utc2)?;
 
     let (tt1, tt2) = match taitt(tai1, tai2) {
         Ok((tt1, tt2)) => (tt1, tt2),
         Err(j) => return Err(j),
     };
 
-    let (ut11, ut12) = match utcut1(utc1, utc2, dut1) {
-        Ok((ut11, ut12)) => (ut11, ut12),
-        Err(j) => return Err(j),
-    };
+    let ((ut11, ut12), j) = utcut1_status(utc1, utc2, dut1)?;
 
-    let (ehpv, ebpv) = epv00(tt1, tt2).unwrap();
+    /* Earth barycentric & heliocentric position/velocity (au, au/d). */
+    let (ehpv, ebpv, _) = epv00_status(tt1, tt2);
 
     /* Form the equinox based BPN matrix, IAU 2006/2000A. */
     let r = pnm06a(tt1, tt2);
@@ -224,12 +231,10 @@ pub fn apco13(
     let (refa, refb) = refco(phpa, tc, rh, wl);
 
     /* Compute the star-independent astrometry parameters. */
-    apco(
-        tt1, tt2, &ebpv, &ehpv[0], x, y, s, theta, elong, phi, hm, xp, yp, sp, refa, refb, astrom,
+    let astrom = apco(
+        tt1, tt2, &ebpv, &ehpv[0], x, y, s, theta, elong, phi, hm, xp, yp, sp, refa, refb,
     );
 
     /* Equation of the origins. */
-    *eo = eors(&r, s);
-
-    Ok(0)
+    Ok(((astrom, eors(&r, s)), j))
 }
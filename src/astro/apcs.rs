@@ -1,5 +1,5 @@
 use super::IauAstrom;
-use crate::vm::{cp, ir, pn};
+use crate::vm::{ir, pn};
 use crate::consts::{DAU, DAYSEC, DJ00, DJY, AULT};
 
 ///  Prepare for ICRS <−> CIRS, space, special
@@ -26,9 +26,9 @@ use crate::consts::{DAU, DAYSEC, DJ00, DJY, AULT};
 ///     ebpv   double[2][3] Earth barycentric PV (au, au/day)
 ///     ehp    double[3]    Earth heliocentric P (au)
 ///  ```
-///  Returned:
+///  Returned (function value):
 ///  ```
-///     astrom iauASTROM*   star-independent astrometry parameters:
+///     astrom IauAstrom    star-independent astrometry parameters:
 ///      pmt    double       PM time interval (SSB, Julian years)
 ///      eb     double[3]    SSB to observer (vector, au)
 ///      eh     double[3]    Sun to observer (unit vector)
@@ -36,15 +36,15 @@ use crate::consts::{DAU, DAYSEC, DJ00, DJY, AULT};
 ///      v      double[3]    barycentric observer velocity (vector, c)
 ///      bm1    double       sqrt(1-|v|^2): reciprocal of Lorenz factor
 ///      bpn    double[3][3] bias-precession-nutation matrix
-///      along  double       unchanged
-///      xpl    double       unchanged
-///      ypl    double       unchanged
-///      sphi   double       unchanged
-///      cphi   double       unchanged
-///      diurab double       unchanged
-///      eral   double       unchanged
-///      refa   double       unchanged
-///      refb   double       unchanged
+///      along  double       zero
+///      xpl    double       zero
+///      ypl    double       zero
+///      sphi   double       zero
+///      cphi   double       zero
+///      diurab double       zero
+///      eral   double       zero
+///      refa   double       zero
+///      refb   double       zero
 ///  ```
 ///  Notes:
 ///
@@ -119,10 +119,12 @@ use crate::consts::{DAU, DAYSEC, DJ00, DJY, AULT};
 ///  6) The context structure astrom produced by this function is used by
 ///     iauAtciq* and iauAticq*.
 ///
+///  7) Unlike iauApcs, which leaves the remaining fields of astrom as
+///     they were, a new structure is returned with those fields zero.
+///     The in-place form is astro::legacy::apcs.
+///
 ///  Called:
 ///  ```
-///     iauCp        copy p-vector
-///     iauPm        modulus of p-vector
 ///     iauPn        decompose p-vector into modulus and direction
 ///     iauIr        initialize r-matrix to identity
 ///  ```
@@ -132,8 +134,9 @@ pub fn apcs(
     pv: &[[f64; 3]; 2],
     ebpv: &[[f64; 3]; 2],
     ehp: &[f64; 3],
-    astrom: &mut IauAstrom,
-) {
+) -> IauAstrom {
+    let mut astrom = IauAstrom::default();
+
     // au/d to m/s
     let audms = DAU / DAYSEC;
 
@@ -157,7 +160,7 @@ pub fn apcs(
     }
 
     // Barycentric position of observer (au).
-    cp(&pb, &mut astrom.eb);
+    astrom.eb = pb;
 
     // Heliocentric direction and distance (unit vector and au).
     (astrom.em, astrom.eh) = pn(&ph);
@@ -173,4 +176,6 @@ pub fn apcs(
 
     // Reset the NPB matrix.
     ir(&mut astrom.bpn);
+
+    astrom
 }
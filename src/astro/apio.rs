@@ -1,6 +1,7 @@
 use super::{IauAstrom, pvtob};
 use crate::vm::{ir, rz, ry, rx, anpm};
 use crate::consts::CMPS;
+use crate::math::{atan2, cos, sin};
 
 ///  Prepare for CIRS <−> observed, terrestrial, special 
 /// 
@@ -25,16 +26,16 @@ use crate::consts::CMPS;
 ///     refa   double      refraction constant A (radians, Note 4)
 ///     refb   double      refraction constant B (radians, Note 4)
 ///  ```
-///  Returned:
+///  Returned (function value):
 ///  ```
-///     astrom iauASTROM*  star-independent astrometry parameters:
-///      pmt    double       unchanged
-///      eb     double[3]    unchanged
-///      eh     double[3]    unchanged
-///      em     double       unchanged
-///      v      double[3]    unchanged
-///      bm1    double       unchanged
-///      bpn    double[3][3] unchanged
+///     astrom IauAstrom   star-independent astrometry parameters:
+///      pmt    double       zero
+///      eb     double[3]    zero
+///      eh     double[3]    zero
+///      em     double       zero
+///      v      double[3]    zero
+///      bm1    double       zero
+///      bpn    double[3][3] zero
 ///      along  double       adjusted longitude (radians)
 ///      xpl    double       polar motion xp wrt local meridian (radians)
 ///      ypl    double       polar motion yp wrt local meridian (radians)
@@ -122,13 +123,13 @@ use crate::consts::CMPS;
 ///  ```
 pub fn apio(
     sp: f64, theta: f64, elong: f64, phi: f64, hm: f64, xp: f64, yp: f64, 
-    refa: f64, refb: f64, astrom: &mut IauAstrom) {
+    refa: f64, refb: f64) -> IauAstrom {
+    let mut astrom = IauAstrom::default();
     let r = &mut [[0.0; 3]; 3];
     let mut a;
     let mut b;
     let eral;
     let c;
-    let pv = &mut [[0.0; 3]; 2];
 
     // Form the rotation matrix, CIRS to apparent [HA,Dec].
     ir(r);
@@ -140,26 +141,26 @@ pub fn apio(
     // Solve for local Earth rotation angle.
     a = r[0][0];
     b = r[0][1];
-    eral = if a != 0.0 || b != 0.0 { b.atan2(a) } else { 0.0 };
+    eral = if a != 0.0 || b != 0.0 { atan2(b, a) } else { 0.0 };
     astrom.eral = eral;
 
     // Solve for polar motion [X,Y] with respect to local meridian.
     a = r[0][0];
     c = r[0][2];
-    astrom.xpl = c.atan2((a * a + b * b).sqrt());
+    astrom.xpl = atan2(c, (a * a + b * b).sqrt());
     a = r[1][2];
     b = r[2][2];
-    astrom.ypl = if a != 0.0 || b != 0.0 { -a.atan2(b) } else { 0.0 };
+    astrom.ypl = if a != 0.0 || b != 0.0 { -atan2(a, b) } else { 0.0 };
 
     // Adjusted longitude.
     astrom.along = anpm(eral - theta);
 
     // Functions of latitude.
-    astrom.sphi = phi.sin();
-    astrom.cphi = phi.cos();
+    astrom.sphi = sin(phi);
+    astrom.cphi = cos(phi);
 
     // Observer's geocentric position and velocity (m, m/s, CIRS).
-    pvtob(elong, phi, hm, xp, yp, sp, theta, pv);
+    let pv = pvtob(elong, phi, hm, xp, yp, sp, theta);
 
     // Magnitude of diurnal aberration vector.
     astrom.diurab = (pv[1][0] * pv[1][0] + pv[1][1] * pv[1][1]).sqrt() / CMPS;
@@ -167,4 +168,6 @@ pub fn apio(
     // Refraction constants.
     astrom.refa = refa;
     astrom.refb = refb;
+
+    astrom
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 use crate::erst::era00;
-use crate::ts::{taitt, utctai, utcut1};
+use crate::ts::{taitt, utctai_status, utcut1_status};
 use crate::pnp::sp00;
 
 use super::{IauAstrom, refco, apio};
@@ -31,16 +31,16 @@ use super::{IauAstrom, refco, apio};
 ///     rh     double      relative humidity at the observer (range 0-1)
 ///     wl     double      wavelength (micrometers, Note 7)
 ///  ```
-///  Returned:
+///  Returned (function value):
 ///  ```
-///     astrom iauASTROM*  star-independent astrometry parameters:
-///      pmt    double       unchanged
-///      eb     double[3]    unchanged
-///      eh     double[3]    unchanged
-///      em     double       unchanged
-///      v      double[3]    unchanged
-///      bm1    double       unchanged
-///      bpn    double[3][3] unchanged
+///     astrom IauAstrom   star-independent astrometry parameters:
+///      pmt    double       zero
+///      eb     double[3]    zero
+///      eh     double[3]    zero
+///      em     double       zero
+///      v      double[3]    zero
+///      bm1    double       zero
+///      bpn    double[3][3] zero
 ///      along  double       longitude + s' (radians)
 ///      xpl    double       polar motion xp wrt local meridian (radians)
 ///      ypl    double       polar motion yp wrt local meridian (radians)
@@ -51,7 +51,7 @@ use super::{IauAstrom, refco, apio};
 ///      refa   double       refraction constant A (radians)
 ///      refb   double       refraction constant B (radians)
 ///  ```
-///  Returned (function value):
+///  Status values:
 ///  ```
 ///            int         status: +1 = dubious year (Note 2)
 ///                                 0 = OK
@@ -172,22 +172,27 @@ use super::{IauAstrom, refco, apio};
 pub fn apio13(
     utc1: f64, utc2: f64, dut1: f64, elong: f64, phi: f64, 
     hm: f64, xp: f64, yp: f64,phpa: f64, tc: f64, rh: f64, wl: f64, 
-    astrom: &mut IauAstrom) -> Result<(), i32> {
+    ) -> Result<IauAstrom, i32> {
+    match apio13_status(utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl)? {
+        (astrom, 0) => Ok(astrom),
+        (_, j) => Err(j),
+    }
+}
 
-    let (tai1, tai2) = match utctai(utc1, utc2) {
-        Ok((t1, t2)) => (t1, t2),
-        Err(j) => return Err(j),
-    };
+/* As apio13, with the warning status of the C function (+1 for a
+   dubious year) returned together with the parameters. */
+pub(crate) fn apio13_status(
+    utc1: f64, utc2: f64, dut1: f64, elong: f64, phi: f64,
+    hm: f64, xp: f64, yp: f64, phpa: f64, tc: f64, rh: f64, wl: f64,
+) -> Result<(IauAstrom, i32), i32> {
+    let ((tai1, tai2), _) = utctai_status(utc1, utc2)?;
 
     let (tt1, tt2) = match taitt(tai1, tai2) {
         Ok(t) => t,
         Err(j) => return Err(j),
     };
 
-    let (ut11, ut12) = match utcut1(utc1, utc2, dut1) {
-        Ok(t) => t,
-        Err(j) => return Err(j),
-    };
+    let ((ut11, ut12), j) = utcut1_status(utc1, utc2, dut1)?;
 
     /* TIO locator s'. */
     let sp = sp00(tt1, tt2);
@@ -199,7 +204,5 @@ pub fn apio13(
     let (refa, refb) = refco(phpa, tc, rh, wl);
 
     /* CIRS <-> observed astrometry parameters. */
-    apio(sp, theta, elong, phi, hm, xp, yp, refa, refb, astrom);
-    
-    Ok(())
+    Ok((apio(sp, theta, elong, phi, hm, xp, yp, refa, refb), j))
 }
\ No newline at end of file
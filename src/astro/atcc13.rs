@@ -1,4 +1,4 @@
-use super::{apci13, atccq, IauAstrom};
+use super::{apci13, atccq};
 
 ///  Catalog -> astrometric.
 ///
@@ -67,14 +67,11 @@ pub fn atcc13(
     date1: f64, date2: f64
 ) -> (f64, f64) {
     /* Star-independent astrometry parameters */
-    let astrom = &mut IauAstrom::default();
-    let w = &mut 0.0;
-
     /* The transformation parameters. */
-    apci13(date1, date2, astrom, w);
+    let (astrom, _) = apci13(date1, date2);
 
     /* Catalog ICRS (epoch J2000.0) to astrometric. */
-    let (ra, da) = atccq(rc, dc, pr, pd, px, rv, astrom);
+    let (ra, da) = atccq(rc, dc, pr, pd, px, rv, &astrom);
 
     (ra, da)
 }
\ No newline at end of file
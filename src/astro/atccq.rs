@@ -72,7 +72,7 @@ pub fn atccq(
 ) -> (f64, f64) {
 
     /* Proper motion and parallax, giving BCRS coordinate direction. */
-    let p = pmpx(rc, dc, pr, pd, px, rv, astrom.pmt, astrom.eb);
+    let p = pmpx(rc, dc, pr, pd, px, rv, astrom.pmt, &astrom.eb);
 
     /* ICRS astrometric RA,Dec. */
     let (w, da) = c2s(&p);
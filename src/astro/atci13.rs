@@ -1,4 +1,4 @@
-use super::{apci13, atciq, IauAstrom};
+use super::{apci13, atciq};
 
 ///  Catalog −> CIRS
 ///
@@ -82,15 +82,11 @@ use super::{apci13, atciq, IauAstrom};
 pub fn atci13(
     rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64, 
                                 date1: f64, date2: f64) -> (f64, f64, f64) {
-    let eo = &mut 0.0;
     /* Star-independent astrometry parameters */
-    let astrom = &mut IauAstrom::default();
-    
-    /* The transformation parameters. */
-    apci13(date1, date2, astrom, eo);
+    let (astrom, eo) = apci13(date1, date2);
 
     /* ICRS (epoch J2000.0) to CIRS. */
-    let (ri, di)= atciq(rc, dc, pr, pd, px, rv, astrom);
+    let (ri, di)= atciq(rc, dc, pr, pd, px, rv, &astrom);
 
-    (ri, di, *eo)
+    (ri, di, eo)
 }
\ No newline at end of file
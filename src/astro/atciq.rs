@@ -65,13 +65,12 @@ use crate::vm::{rxp, c2s, anp};
 ///     iauC2s       p-vector to spherical
 ///  ```
 pub fn atciq(rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64, 
-                            astrom: &mut IauAstrom,) -> (f64, f64) {
-    let pco = pmpx(rc, dc, pr, pd, px, rv, astrom.pmt, astrom.eb);
-    let pnat = ldsun(pco, astrom.eh, astrom.em);
-    let mut ppr = ab(&pnat, &astrom.v, astrom.em, astrom.bm1);
-    let pi = &mut [0.0; 3];
-    rxp(&astrom.bpn, &mut ppr, pi);
-    let (w, di) = c2s(pi);
+                            astrom: &IauAstrom,) -> (f64, f64) {
+    let pco = pmpx(rc, dc, pr, pd, px, rv, astrom.pmt, &astrom.eb);
+    let pnat = ldsun(&pco, &astrom.eh, astrom.em);
+    let ppr = ab(&pnat, &astrom.v, astrom.em, astrom.bm1);
+    let pi = rxp(&astrom.bpn, &ppr);
+    let (w, di) = c2s(&pi);
     let ri = anp(w);
     (ri, di)
 }
\ No newline at end of file
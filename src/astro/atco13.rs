@@ -1,4 +1,8 @@
-use super::{apco13, atciq, atioq, IauAstrom};
+use super::{apco13_status, atciq, atioq};
+
+/* Observed azimuth, zenith distance, hour angle, declination and
+   right ascension, and the equation of the origins. */
+type Observed = (f64, f64, f64, f64, f64, f64);
 
 ///  ICRS −> observed
 /// 
@@ -157,22 +161,31 @@ pub fn atco13(
     elong: f64, phi: f64, hm: f64, xp: f64, yp: f64,
     phpa: f64, tc: f64, rh: f64, wl: f64,
 ) -> Result<(f64, f64, f64, f64, f64, f64), i32> {
-    let astrom = &mut IauAstrom::default();
-    let eo = &mut 0.0;
-
-    /* Star-independent astrometry parameters. */
-    let j = apco13(utc1, utc2, dut1, elong, phi, hm, xp, yp, 
-            phpa, tc, rh, wl, astrom, eo);
+    match atco13_status(rc, dc, pr, pd, px, rv, utc1, utc2, dut1, elong, phi, hm, xp, yp,
+                        phpa, tc, rh, wl)? {
+        (r, 0) => Ok(r),
+        (_, j) => Err(j),
+    }
+}
 
-    /* Abort if bad UTC. */
-    if j.is_err() { return Err(j.unwrap_err()) };
+/* As atco13, with the warning status of the C function (+1 for a
+   dubious year) returned together with the results. */
+pub(crate) fn atco13_status(
+    rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64,
+    utc1: f64, utc2: f64, dut1: f64,
+    elong: f64, phi: f64, hm: f64, xp: f64, yp: f64,
+    phpa: f64, tc: f64, rh: f64, wl: f64,
+) -> Result<(Observed, i32), i32> {
+    /* Star-independent astrometry parameters (abort if bad UTC). */
+    let ((astrom, eo), j) = apco13_status(utc1, utc2, dut1, elong, phi, hm, xp, yp,
+            phpa, tc, rh, wl)?;
 
     /* Transform ICRS to CIRS. */
-    let (ri, di) = atciq(rc, dc, pr, pd, px, rv, astrom);
+    let (ri, di) = atciq(rc, dc, pr, pd, px, rv, &astrom);
 
     /* Transform CIRS to observed. */
-    let (aob, zob, hob, dob, rob) = atioq(ri, di, astrom);
+    let (aob, zob, hob, dob, rob) = atioq(ri, di, &astrom);
 
     /* Return values */
-    Ok((aob, zob, hob, dob, rob, *eo))
+    Ok(((aob, zob, hob, dob, rob, eo), j))
 }
\ No newline at end of file
@@ -1,4 +1,8 @@
-use super::{apio13, atioq, IauAstrom};
+use super::{apio13_status, atioq};
+
+/* Observed azimuth, zenith distance, hour angle, declination and
+   right ascension. */
+type Observed = (f64, f64, f64, f64, f64);
 
 ///  CIRS −> observed
 ///
@@ -139,18 +143,25 @@ pub fn atio13(
     elong: f64, phi: f64, hm: f64, xp: f64, yp: f64,
     phpa: f64, tc: f64, rh: f64, wl: f64
 ) -> Result<(f64, f64, f64, f64, f64), i32> {
-    let astrom = &mut IauAstrom::default();
-    
-    /* Star-independent astrometry parameters for CIRS->observed. */
-    match apio13(utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl, astrom) {
-        Ok(_) => {
-            /* CIRS to observed. */
-        },
-        Err(j) => { return Err(j);},
+    match atio13_status(ri, di, utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl)? {
+        (r, 0) => Ok(r),
+        (_, j) => Err(j),
     }
+}
+
+/* As atio13, with the warning status of the C function (+1 for a
+   dubious year) returned together with the results. */
+pub(crate) fn atio13_status(
+    ri: f64, di: f64,
+    utc1: f64, utc2: f64, dut1: f64,
+    elong: f64, phi: f64, hm: f64, xp: f64, yp: f64,
+    phpa: f64, tc: f64, rh: f64, wl: f64
+) -> Result<(Observed, i32), i32> {
+    /* Star-independent astrometry parameters for CIRS->observed. */
+    let (astrom, j) = apio13_status(utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl)?;
 
     /* Transform CIRS to observed. */
-    let (aob, zob, hob, dob, rob) = atioq(ri, di, astrom);
+    let (aob, zob, hob, dob, rob) = atioq(ri, di, &astrom);
 
-    Ok((aob, zob, hob, dob, rob))
+    Ok(((aob, zob, hob, dob, rob), j))
 }
\ No newline at end of file
@@ -2,6 +2,7 @@
 use crate::vm::{anp, c2s, s2c};
 
 use super::IauAstrom;
+use crate::math::{atan2, cos, sin};
 
 ///  Quick CIRS −> observed
 /// 
@@ -111,10 +112,10 @@ pub fn atioq(ri: f64, di: f64, astrom: &IauAstrom) -> (f64, f64, f64, f64, f64)
     let [x, y, z] = v;
 
     /* Polar motion. */
-    let sx = astrom.xpl.sin();
-    let cx = astrom.xpl.cos();
-    let sy = astrom.ypl.sin();
-    let cy = astrom.ypl.cos();
+    let sx = sin(astrom.xpl);
+    let cx = cos(astrom.xpl);
+    let sy = sin(astrom.ypl);
+    let cy = cos(astrom.ypl);
     let xhd = cx * x + sx * z;
     let yhd = sx * sy * x + cy * y - cx * sy * z;
     let zhd = -sx * cy * x + sy * y + cx * cy * z;
@@ -132,7 +133,7 @@ pub fn atioq(ri: f64, di: f64, astrom: &IauAstrom) -> (f64, f64, f64, f64, f64)
 
     /* Azimuth (N=0,E=90). */
     let azobs = if xaet != 0.0 || yaet != 0.0 {
-        yaet.atan2(-xaet)
+        atan2(yaet, -xaet)
     } else {
         0.0
     };
@@ -159,7 +160,7 @@ pub fn atioq(ri: f64, di: f64, astrom: &IauAstrom) -> (f64, f64, f64, f64, f64)
     let zaeo = cosdel * zaet + del * r;
 
     /* Observed ZD. */
-    let zdobs = (xaeo * xaeo + yaeo * yaeo).sqrt().atan2(zaeo);
+    let zdobs = atan2((xaeo * xaeo + yaeo * yaeo).sqrt(), zaeo);
 
     /* Az/El vector to HA,Dec vector (both right-handed). */
     v[0] = astrom.sphi * xaeo + astrom.cphi * zaeo;
@@ -0,0 +1,87 @@
+use crate::consts::{CMPS, DAU, DAYSEC, GM_EARTH, SRS};
+use crate::coords::gd2gc;
+use crate::frames::EopProvider;
+use crate::ts::Epoch;
+use crate::vm::{pdp, pm, pn};
+
+use super::{site_pv_bary, Site};
+
+///  Barycentric radial-velocity correction
+///
+///  ```text
+///     zb         barycentric correction as a redshift
+///     velocity   c * zb (m/s)
+///  ```
+///  A measured redshift z_meas corrects to the barycentric
+///  1 + z = (1 + z_meas)(1 + zb), which `apply` evaluates.  For small
+///  velocities v = v_meas + velocity + v_meas*zb, the last term
+///  reaching 10 m/s for a star at 100 km/s.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Berv {
+    pub zb: f64,
+    pub velocity: f64,
+}
+
+impl Berv {
+    /// Barycentric redshift from the measured one.
+    pub fn apply(&self, z_meas: f64) -> f64 {
+        (1.0 + z_meas) * (1.0 + self.zb) - 1.0
+    }
+}
+
+///  Barycentric radial-velocity correction for a ground site
+///
+///  Given:
+///  ```text
+///     dir      double[3]          barycentric direction to the target
+///                                 (BCRS, not necessarily unit length)
+///     site     Site               geodetic position (WGS84)
+///     epoch    Epoch              UTC, TAI or TT of the observation
+///     eop      EopProvider        UT1-UTC, polar motion, dX,dY
+///  ```
+///  Returned (function value):
+///  ```text
+///              Berv               the correction
+///  ```
+///  Status values are those of `site_pv_bary`.
+///
+///  Notes:
+///
+///  1) 1 + zb = gamma (1 + beta.r) (1 + phi/c^2), with beta the
+///     observer's barycentric velocity in units of c (the Earth's
+///     orbital motion from `epv00` plus the site's rotation), gamma the
+///     Lorentz factor, r the unit vector towards the target and phi the
+///     gravitational potential of the Sun and the Earth at the site.
+///     See Wright & Eastman (2014), Eq. 28, for a target without
+///     space motion.
+///
+///  2) The direction is the geometric (catalog) one, with proper motion
+///     and parallax applied (see `pmpx`), not the observed place:
+///     aberration is part of the correction.
+///
+///  3) The gravitational terms are 3.0 m/s (Sun) and 0.2 m/s (Earth);
+///     the planets and Moon contribute less than a millimeter per
+///     second.  Accuracy is limited by `epv00` and by the epoch:  the
+///     Earth's rotation changes the correction by up to 3 cm/s per
+///     second of time, so for a long exposure use its flux-weighted
+///     midpoint.
+///
+///  Reference:
+///
+///     Wright, J.T. & Eastman, J.D., 2014, PASP 126, 838
+pub fn berv(dir: &[f64; 3], site: &Site, epoch: &Epoch, eop: &(impl EopProvider + ?Sized)) -> Result<Berv, i32> {
+    let (pv, ph) = site_pv_bary(site, epoch, eop)?;
+
+    /* Observer velocity, units of c, and Lorentz factor. */
+    let beta = pv.v.map(|x| x * DAU / DAYSEC / CMPS);
+    let gamma = 1.0 / (1.0 - pdp(&beta, &beta)).sqrt();
+
+    /* Potential of the Sun and the Earth at the site, units of c^2. */
+    let mut xyz = [0.0; 3];
+    gd2gc(1, site.elong, site.phi, site.hm, &mut xyz)?;
+    let phi = SRS / 2.0 / pm(&ph) + GM_EARTH / (CMPS * CMPS * pm(&xyz));
+
+    let (_, r) = pn(dir);
+    let zb = gamma * (1.0 + pdp(&beta, &r)) * (1.0 + phi) - 1.0;
+    Ok(Berv { zb, velocity: zb * CMPS })
+}
@@ -0,0 +1,81 @@
+use crate::consts::AULT;
+use crate::frames::EopProvider;
+use crate::ts::{Epoch, TimeScale};
+use crate::vm::{pdp, pn};
+
+use super::{site_pv_bary, Site};
+
+///  Barycentric Julian Date (TDB) of an observation
+///
+///  Given:
+///  ```text
+///     epoch    Epoch              time of the observation (UTC, TAI or
+///                                 TT)
+///     dir      double[3]          barycentric direction to the target
+///                                 (BCRS, not necessarily unit length)
+///     site     Site               geodetic position (WGS84)
+///     eop      EopProvider        UT1-UTC, polar motion, dX,dY
+///  ```
+///  Returned (function value):
+///  ```text
+///              Epoch              BJD_TDB, the time the wavefront
+///                                 passed the solar-system barycenter
+///  ```
+///  Status values are those of `site_pv_bary`.
+///
+///  Notes:
+///
+///  1) The observation time is taken to TDB for the site (see
+///     `Epoch::to_topocentric`), and the Roemer delay r.u/c added, with
+///     r the site's barycentric position and u the unit vector towards
+///     the target.  The target is treated as infinitely distant (plane
+///     wavefront).
+///
+///  2) The Shapiro delay (up to 0.1 ms, see `shapiro_sun`) is not
+///     applied;  nor are the target's space motion and parallax, which
+///     the caller supplies in the direction (see `pmpx`).  The position
+///     is from `epv00`, so accuracy is a few microseconds, much less
+///     than the 8 minutes of the correction itself.
+///
+///  Reference:
+///
+///     Eastman, J., Siverd, R. & Gaudi, B.S., 2010, PASP 122, 935
+pub fn bjd_tdb(epoch: &Epoch, dir: &[f64; 3], site: &Site, eop: &(impl EopProvider + ?Sized)) -> Result<Epoch, i32> {
+    let (pv, _) = site_pv_bary(site, epoch, eop)?;
+    let tdb = epoch.to_topocentric(TimeScale::TDB, site)?;
+    Ok(Epoch::new(TimeScale::TDB, tdb.jd.add_seconds(roemer(&pv.p, dir))))
+}
+
+///  Heliocentric Julian Date of an observation
+///
+///  Given:
+///  ```text
+///     epoch    Epoch              time of the observation
+///     dir      double[3]          direction to the target (BCRS)
+///     site     Site               geodetic position (WGS84)
+///     eop      EopProvider        UT1-UTC, polar motion, dX,dY
+///  ```
+///  Returned (function value):
+///  ```text
+///              Epoch              HJD, in the time scale of epoch
+///  ```
+///  Status values are those of `site_pv_bary`.
+///
+///  Notes:
+///
+///  1) As `bjd_tdb`, but the light time is to the center of the Sun,
+///     and the time scale is not changed:  HJD is usually quoted in
+///     UTC.  Apart from the time scale it differs from BJD by up to
+///     5 s, because the Sun moves about the barycenter, and is kept
+///     for comparison with older results;  BJD_TDB is the better
+///     standard.
+pub fn hjd(epoch: &Epoch, dir: &[f64; 3], site: &Site, eop: &(impl EopProvider + ?Sized)) -> Result<Epoch, i32> {
+    let (_, ph) = site_pv_bary(site, epoch, eop)?;
+    Ok(Epoch::new(epoch.scale, epoch.jd.add_seconds(roemer(&ph, dir))))
+}
+
+/* Light time (s) of the projection of p (au) on the direction u. */
+fn roemer(p: &[f64; 3], u: &[f64; 3]) -> f64 {
+    let (_, u) = pn(u);
+    pdp(p, &u) * AULT
+}
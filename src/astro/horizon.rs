@@ -0,0 +1,99 @@
+//! Horizon coordinates, parallactic angle and airmass for a site
+use crate::consts::{DD2R, DR2D};
+use crate::horeq;
+use crate::vm::anpm;
+
+use super::Site;
+use crate::math::{cos, powf, sin};
+
+/// Airmass formula
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AirmassModel {
+    /// plane-parallel atmosphere, X = sec z
+    SecZ,
+    /// Kasten & Young (1989), good to the horizon
+    KastenYoung,
+    /// Pickering (2002), for apparent altitude
+    Pickering,
+}
+
+/// Local hour angle of a target
+///
+///  Given the right ascension ra and the Greenwich sidereal time (or
+///  Earth rotation angle, for CIO-based ra) gst, both in radians,
+///  return the hour angle at the site in the range +/-pi.
+pub fn hour_angle(site: &Site, gst: f64, ra: f64) -> f64 {
+    anpm(gst + site.elong - ra)
+}
+
+/// (hour angle, declination) to (azimuth, altitude) at the site
+///
+///  Azimuth is N=0,E=90 in the range 0-2pi.  See `horeq::hd2ae`.
+pub fn hd2ae(site: &Site, ha: f64, dec: f64) -> (f64, f64) {
+    horeq::hd2ae(ha, dec, site.phi)
+}
+
+/// (azimuth, altitude) to (hour angle, declination) at the site
+///
+///  See `horeq::ae2hd`.
+pub fn ae2hd(site: &Site, az: f64, el: f64) -> (f64, f64) {
+    horeq::ae2hd(az, el, site.phi)
+}
+
+/// Parallactic angle at the site
+///
+///  See `horeq::hd2pa`.
+pub fn hd2pa(site: &Site, ha: f64, dec: f64) -> f64 {
+    horeq::hd2pa(ha, dec, site.phi)
+}
+
+/// Airmass for a given altitude
+///
+///  Given:
+///  ```text
+///     model   AirmassModel  formula to use
+///     el      double        altitude (radians, Note 1)
+///  ```
+///  Returned (function value):
+///  ```text
+///             double        relative airmass (1 at the zenith)
+///  ```
+///  Notes:
+///
+///  1) SecZ and KastenYoung expect the true (unrefracted) altitude,
+///     Pickering the apparent (observed) altitude.
+///
+///  2) Below the horizon (el < 0) all models return infinity.  Sec z
+///     grows without limit as el approaches zero; the other two stay
+///     near 38 at the horizon.
+///
+///  References:
+///  ```text
+///     Kasten, F. & Young, A.T., "Revised optical air mass tables and
+///     approximation formula", Applied Optics 28, 4735-4738 (1989).
+///
+///     Pickering, K.A., "The Southern Limits of the Ancient Star
+///     Catalog", DIO 12, 3-27 (2002).
+///  ```
+pub fn airmass(model: AirmassModel, el: f64) -> f64 {
+    if el < 0.0 {
+        return f64::INFINITY;
+    }
+    match model {
+        AirmassModel::SecZ => 1.0 / sin(el),
+        AirmassModel::KastenYoung => {
+            let zd = 90.0 - el * DR2D;
+            1.0 / (cos(zd * DD2R) + 0.50572 * powf(96.07995 - zd, -1.6364))
+        }
+        AirmassModel::Pickering => {
+            let h = el * DR2D;
+            1.0 / sin((h + 244.0 / (165.0 + 47.0 * powf(h, 1.1))) * DD2R)
+        }
+    }
+}
+
+/// Airmass of a target at the given hour angle and declination
+pub fn airmass_hd(site: &Site, model: AirmassModel, ha: f64, dec: f64) -> f64 {
+    let (_, el) = hd2ae(site, ha, dec);
+    airmass(model, el)
+}
@@ -0,0 +1,98 @@
+use crate::cal::{jd_forms, Jd};
+use crate::eph::OscElements;
+
+use super::*;
+
+jd_forms! {
+    value apcg_jd = apcg(ebpv: &[[f64; 3]; 2], ehp: &[f64; 3]) -> IauAstrom;
+    value apci_jd = apci(ebpv: &[[f64; 3]; 2], ehp: &[f64; 3], x: f64, y: f64, s: f64) -> IauAstrom;
+    value apci13_jd = apci13() -> (IauAstrom, f64);
+    value apcs_jd = apcs(pv: &[[f64; 3]; 2], ebpv: &[[f64; 3]; 2], ehp: &[f64; 3]) -> IauAstrom;
+    value equation_of_time_jd = equation_of_time() -> Result<f64, i32>;
+    value moon_phase_jd = moon_phase() -> Result<LunarPhase, i32>;
+    value sun_geocentric_jd = sun_geocentric() -> Result<ApparentPlace, i32>;
+}
+
+/// As `apco`, with the TDB date as a `Jd` (or a (date1, date2) pair).
+#[allow(clippy::too_many_arguments)]
+pub fn apco_jd(
+    date: impl Into<Jd>, ebpv: &[[f64; 3]; 2], ehp: &[f64; 3], x: f64, y: f64, s: f64,
+    theta: f64, elong: f64, phi: f64, hm: f64, xp: f64, yp: f64, sp: f64, refa: f64, refb: f64,
+) -> IauAstrom {
+    let Jd(d1, d2) = date.into();
+    apco(d1, d2, ebpv, ehp, x, y, s, theta, elong, phi, hm, xp, yp, sp, refa, refb)
+}
+
+/// As `apco13`, with the UTC date as a `Jd` (or a (date1, date2) pair).
+#[allow(clippy::too_many_arguments)]
+pub fn apco13_jd(
+    utc: impl Into<Jd>, dut1: f64, elong: f64, phi: f64, hm: f64, xp: f64, yp: f64,
+    phpa: f64, tc: f64, rh: f64, wl: f64,
+) -> Result<(IauAstrom, f64), i32> {
+    let Jd(utc1, utc2) = utc.into();
+    apco13(utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl)
+}
+
+/// As `apio13`, with the UTC date as a `Jd` (or a (date1, date2) pair).
+#[allow(clippy::too_many_arguments)]
+pub fn apio13_jd(
+    utc: impl Into<Jd>, dut1: f64, elong: f64, phi: f64, hm: f64, xp: f64, yp: f64,
+    phpa: f64, tc: f64, rh: f64, wl: f64,
+) -> Result<IauAstrom, i32> {
+    let Jd(utc1, utc2) = utc.into();
+    apio13(utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl)
+}
+
+/// As `atcc13`, with the TDB date as a `Jd` (or a (date1, date2) pair).
+pub fn atcc13_jd(rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64, date: impl Into<Jd>) -> (f64, f64) {
+    let Jd(d1, d2) = date.into();
+    atcc13(rc, dc, pr, pd, px, rv, d1, d2)
+}
+
+/// As `atci13`, with the TDB date as a `Jd` (or a (date1, date2) pair).
+pub fn atci13_jd(rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64, date: impl Into<Jd>) -> (f64, f64, f64) {
+    let Jd(d1, d2) = date.into();
+    atci13(rc, dc, pr, pd, px, rv, d1, d2)
+}
+
+/// As `atco13`, with the UTC date as a `Jd` (or a (date1, date2) pair).
+#[allow(clippy::too_many_arguments)]
+pub fn atco13_jd(
+    rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64,
+    utc: impl Into<Jd>, dut1: f64,
+    elong: f64, phi: f64, hm: f64, xp: f64, yp: f64,
+    phpa: f64, tc: f64, rh: f64, wl: f64,
+) -> Result<(f64, f64, f64, f64, f64, f64), i32> {
+    let Jd(utc1, utc2) = utc.into();
+    atco13(rc, dc, pr, pd, px, rv, utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl)
+}
+
+/// As `atio13`, with the UTC date as a `Jd` (or a (date1, date2) pair).
+#[allow(clippy::too_many_arguments)]
+pub fn atio13_jd(
+    ri: f64, di: f64,
+    utc: impl Into<Jd>, dut1: f64,
+    elong: f64, phi: f64, hm: f64, xp: f64, yp: f64,
+    phpa: f64, tc: f64, rh: f64, wl: f64,
+) -> Result<(f64, f64, f64, f64, f64), i32> {
+    let Jd(utc1, utc2) = utc.into();
+    atio13(ri, di, utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl)
+}
+
+/// As `oscplace`, with the TT date as a `Jd` (or a (date1, date2) pair).
+pub fn oscplace_jd(el: &OscElements, date: impl Into<Jd>) -> Result<ApparentPlace, i32> {
+    let Jd(d1, d2) = date.into();
+    oscplace(el, d1, d2)
+}
+
+/// As `niell_hydro`, with the date as a `Jd` (or a (date1, date2) pair).
+pub fn niell_hydro_jd(site: &Site, date: impl Into<Jd>, el: f64) -> f64 {
+    let Jd(d1, d2) = date.into();
+    niell_hydro(site, d1, d2, el)
+}
+
+/// As `tropo_delay`, with the date as a `Jd` (or a (date1, date2) pair).
+pub fn tropo_delay_jd(site: &Site, phpa: f64, tc: f64, rh: f64, date: impl Into<Jd>, el: f64) -> TropoDelay {
+    let Jd(d1, d2) = date.into();
+    tropo_delay(site, phpa, tc, rh, d1, d2, el)
+}
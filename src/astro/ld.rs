@@ -31,7 +31,8 @@ use crate::{consts::SRS, vm::{pdp, pxp}};
 ///     precision.
 ///
 ///  2) The mass parameter bm can, as required, be adjusted in order to
-///     allow for such effects as quadrupole field.
+///     allow for such effects as quadrupole field.  ld_j2 adds the
+///     quadrupole term of an oblate body explicitly.
 ///
 ///  3) The barycentric position of the deflecting body should ideally
 ///     correspond to the time of closest approach of the light ray to
@@ -55,7 +56,7 @@ use crate::{consts::SRS, vm::{pdp, pxp}};
 ///
 ///  8) For efficiency, validation is omitted.  The supplied vectors must
 ///     be of unit magnitude, and the deflection limiter non-zero and
-///     positive.
+///     positive.  validate::ld_checked verifies them first.
 ///
 ///  References:
 ///
@@ -71,18 +72,18 @@ use crate::{consts::SRS, vm::{pdp, pxp}};
 ///     iauPdp       scalar product of two p-vectors
 ///     iauPxp       vector product of two p-vectors
 ///  ```
-pub fn ld(bm: f64, p: [f64; 3], q: [f64; 3], e: [f64; 3], 
+pub fn ld(bm: f64, p: &[f64; 3], q: &[f64; 3], e: &[f64; 3], 
                         em: f64, dlim: f64,) -> [f64; 3] {
     let mut qpe = [0.0; 3];
     for i in 0..3 {
         qpe[i] = q[i] + e[i];
     }
-    let qdqpe = pdp(&q, &qpe);
+    let qdqpe = pdp(q, &qpe);
 
     let w = bm * SRS / em / qdqpe.max(dlim);
 
-    let eq = pxp(&e, &q);
-    let peq = pxp(&p, &eq);
+    let eq = pxp(e, q);
+    let peq = pxp(p, &eq);
 
     let mut p1 = [0.0; 3];
     for i in 0..3 {
@@ -0,0 +1,79 @@
+use crate::consts::SRS;
+use crate::vm::pdp;
+
+use super::ld;
+
+///  Light deflection by a single solar-system body, including the
+///  quadrupole field of an oblate body
+///
+///  Given:
+///  ```text
+///     bm     double     mass of the gravitating body (solar masses)
+///     p      double[3]  direction from observer to source (unit vector)
+///     q      double[3]  direction from body to source (unit vector)
+///     e      double[3]  direction from body to observer (unit vector)
+///     em     double     distance from body to observer (au)
+///     dlim   double     deflection limiter (see ld)
+///     j2     double     second zonal harmonic J2 of the body
+///     re     double     reference (equatorial) radius for J2 (au)
+///     pole   double[3]  direction of the body's north pole (unit
+///                       vector, same frame as p)
+///  ```
+///  Returned (function value):
+///  ```text
+///            double[3]  observer to deflected source (unit vector)
+///  ```
+///  Notes:
+///
+///  1) The monopole deflection is that of `ld`, to which the quadrupole
+///     term is added.  At the limb of Jupiter the quadrupole deflection
+///     is up to 240 microarcsec (J2 = 0.0147) and of Saturn 95, falling
+///     off as the inverse cube of the impact parameter b.  Its sign
+///     depends on where the ray passes:  over the equator it adds to
+///     the monopole deflection, over a pole it subtracts.
+///
+///  2) The quadrupole deflection is that for a ray from a distant
+///     source, b being the distance of closest approach to the body's
+///     centre of the line of sight p (Klioner 1991).  For an observer at
+///     a finite distance it is scaled as the monopole deflection is,
+///     by (1 - e.p)/2, which is exact in the limits of the body in
+///     front of and behind the observer and in error by a fraction of
+///     order b/em otherwise.
+///
+///  3) Rays passing within the radius re are treated as at the limb,
+///     the term being unphysical there;  the limiter dlim applies only
+///     to the monopole term.
+///
+///  4) For Jupiter and Saturn, J2 = 0.014696 and 0.016291 for radii of
+///     71492 and 60330 km;  the pole follows from the IAU rotational
+///     elements (right ascension and declination of the pole) with
+///     s2c, rotated into the frame of p.
+///
+///  Reference:
+///
+///     Klioner, S.A., 1991, Sov.Astron. 35, 523
+#[allow(clippy::too_many_arguments)]
+pub fn ld_j2(bm: f64, p: &[f64; 3], q: &[f64; 3], e: &[f64; 3], em: f64, dlim: f64,
+             j2: f64, re: f64, pole: &[f64; 3]) -> [f64; 3] {
+    let p1 = ld(bm, p, q, e, em, dlim);
+
+    /* Impact vector:  body to the point of closest approach (au). */
+    let ep = pdp(e, p);
+    let bv: [f64; 3] = std::array::from_fn(|i| em * (e[i] - ep * p[i]));
+    let b = pdp(&bv, &bv).sqrt();
+    if b == 0.0 || j2 == 0.0 {
+        return p1;
+    }
+    let n = bv.map(|x| x / b);
+    let b = b.max(re);
+
+    /* The pole projected on the plane of the sky, and along n. */
+    let sp = pdp(pole, p);
+    let st: [f64; 3] = std::array::from_fn(|i| pole[i] - sp * p[i]);
+    let sn = pdp(&st, &n);
+    let st2 = pdp(&st, &st);
+
+    /* Quadrupole deflection, away from the body along n. */
+    let w = bm * SRS * (1.0 - ep) * j2 * re * re / (b * b * b);
+    std::array::from_fn(|i| p1[i] + w * (2.0 * sn * st[i] + (st2 - 4.0 * sn * sn) * n[i]))
+}
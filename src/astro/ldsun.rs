@@ -38,7 +38,7 @@ use super::ld;
 ///  ```
 ///     iauLd        light deflection by a solar-system body
 ///  ```
-pub fn ldsun(p: [f64; 3], e: [f64; 3], em: f64) -> [f64; 3] {
+pub fn ldsun(p: &[f64; 3], e: &[f64; 3], em: f64) -> [f64; 3] {
     /* Deflection limiter (smaller for distant observers). */
     let mut em2 = em * em;
     if em2 < 1.0 {
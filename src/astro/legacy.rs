@@ -0,0 +1,128 @@
+//! Out-parameter forms of the astrometry routines
+//!
+//! Before the API was made uniform (references in, values out) the
+//! context functions filled an `&mut IauAstrom`, `pvtob` wrote through
+//! an `&mut` pv-vector and `ld`, `ldsun` and `pmpx` took their vectors
+//! by value.  The old forms are kept here, deprecated.  The original
+//! paths now name the new forms, so code written against the old ones
+//! does not compile as it stands:  importing from here instead (`use
+//! sofars::astro::legacy::*;`) restores the old signatures while the
+//! calls are moved over:
+//! ```text
+//!     apci13(d1, d2, &mut astrom, &mut eo);  ->  let (astrom, eo) = apci13(d1, d2);
+//!     apio(..., refb, &mut astrom);          ->  let astrom = apio(..., refb);
+//! ```
+//! As in the C library, these update only the fields of astrom that
+//! the function is responsible for and leave the others as they were,
+//! whereas the new forms return a structure with those fields zero.
+
+use super::IauAstrom;
+
+/* Copy the ICRS <-> GCRS part of the context (as apcs sets it). */
+fn set_icrs(astrom: &mut IauAstrom, a: &IauAstrom) {
+    astrom.pmt = a.pmt;
+    astrom.eb = a.eb;
+    astrom.eh = a.eh;
+    astrom.em = a.em;
+    astrom.v = a.v;
+    astrom.bm1 = a.bm1;
+    astrom.bpn = a.bpn;
+}
+
+/* Copy the CIRS <-> observed part of the context (as apio sets it). */
+fn set_observed(astrom: &mut IauAstrom, a: &IauAstrom) {
+    astrom.along = a.along;
+    astrom.xpl = a.xpl;
+    astrom.ypl = a.ypl;
+    astrom.sphi = a.sphi;
+    astrom.cphi = a.cphi;
+    astrom.diurab = a.diurab;
+    astrom.eral = a.eral;
+    astrom.refa = a.refa;
+    astrom.refb = a.refb;
+}
+
+#[deprecated(note = "use astro::apcg, which returns the context")]
+pub fn apcg(date1: f64, date2: f64, ebpv: &[[f64; 3]; 2], ehp: &[f64; 3], astrom: &mut IauAstrom) {
+    set_icrs(astrom, &super::apcg(date1, date2, ebpv, ehp));
+}
+
+#[deprecated(note = "use astro::apci, which returns the context")]
+#[allow(clippy::too_many_arguments)]
+pub fn apci(date1: f64, date2: f64, ebpv: &[[f64; 3]; 2], ehp: &[f64; 3],
+            x: f64, y: f64, s: f64, astrom: &mut IauAstrom) {
+    set_icrs(astrom, &super::apci(date1, date2, ebpv, ehp, x, y, s));
+}
+
+#[deprecated(note = "use astro::apci13, which returns the context and eo")]
+pub fn apci13(date1: f64, date2: f64, astrom: &mut IauAstrom, eo: &mut f64) {
+    let (a, e) = super::apci13(date1, date2);
+    set_icrs(astrom, &a);
+    *eo = e;
+}
+
+#[deprecated(note = "use astro::apco, which returns the context")]
+#[allow(clippy::too_many_arguments)]
+pub fn apco(date1: f64, date2: f64, ebpv: &[[f64; 3]; 2], ehp: &[f64; 3],
+            x: f64, y: f64, s: f64, theta: f64, elong: f64, phi: f64, hm: f64,
+            xp: f64, yp: f64, sp: f64, refa: f64, refb: f64, astrom: &mut IauAstrom) {
+    *astrom = super::apco(date1, date2, ebpv, ehp, x, y, s, theta, elong, phi, hm,
+                          xp, yp, sp, refa, refb);
+}
+
+#[deprecated(note = "use astro::apco13, which returns the context and eo")]
+#[allow(clippy::too_many_arguments)]
+pub fn apco13(utc1: f64, utc2: f64, dut1: f64, elong: f64, phi: f64, hm: f64,
+              xp: f64, yp: f64, phpa: f64, tc: f64, rh: f64, wl: f64,
+              astrom: &mut IauAstrom, eo: &mut f64) -> Result<i32, i32> {
+    (*astrom, *eo) = super::apco13(utc1, utc2, dut1, elong, phi, hm, xp, yp,
+                                   phpa, tc, rh, wl)?;
+    Ok(0)
+}
+
+#[deprecated(note = "use astro::apcs, which returns the context")]
+pub fn apcs(date1: f64, date2: f64, pv: &[[f64; 3]; 2], ebpv: &[[f64; 3]; 2],
+            ehp: &[f64; 3], astrom: &mut IauAstrom) {
+    set_icrs(astrom, &super::apcs(date1, date2, pv, ebpv, ehp));
+}
+
+#[deprecated(note = "use astro::apio, which returns the context")]
+#[allow(clippy::too_many_arguments)]
+pub fn apio(sp: f64, theta: f64, elong: f64, phi: f64, hm: f64, xp: f64, yp: f64,
+            refa: f64, refb: f64, astrom: &mut IauAstrom) {
+    set_observed(astrom, &super::apio(sp, theta, elong, phi, hm, xp, yp, refa, refb));
+}
+
+#[deprecated(note = "use astro::apio13, which returns the context")]
+#[allow(clippy::too_many_arguments)]
+pub fn apio13(utc1: f64, utc2: f64, dut1: f64, elong: f64, phi: f64, hm: f64,
+              xp: f64, yp: f64, phpa: f64, tc: f64, rh: f64, wl: f64,
+              astrom: &mut IauAstrom) -> Result<(), i32> {
+    let a = super::apio13(utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl)?;
+    set_observed(astrom, &a);
+    Ok(())
+}
+
+#[deprecated(note = "use astro::ld, which takes references")]
+pub fn ld(bm: f64, p: [f64; 3], q: [f64; 3], e: [f64; 3], em: f64, dlim: f64) -> [f64; 3] {
+    super::ld(bm, &p, &q, &e, em, dlim)
+}
+
+#[deprecated(note = "use astro::ldsun, which takes references")]
+pub fn ldsun(p: [f64; 3], e: [f64; 3], em: f64) -> [f64; 3] {
+    super::ldsun(&p, &e, em)
+}
+
+#[deprecated(note = "use astro::pmpx, which takes pob by reference")]
+#[allow(clippy::too_many_arguments)]
+pub fn pmpx(rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64, pmt: f64,
+            pob: [f64; 3]) -> [f64; 3] {
+    super::pmpx(rc, dc, pr, pd, px, rv, pmt, &pob)
+}
+
+#[deprecated(note = "use astro::pvtob, which returns the pv-vector")]
+#[allow(clippy::too_many_arguments)]
+pub fn pvtob(elong: f64, phi: f64, hm: f64, xp: f64, yp: f64, sp: f64, theta: f64,
+             pv: &mut [[f64; 3]; 2]) {
+    *pv = super::pvtob(elong, phi, hm, xp, yp, sp, theta);
+}
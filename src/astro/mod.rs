@@ -1,6 +1,10 @@
 //! Astrometry
 
-// Star-independent astrometry parameters
+use crate::consts::{Body, ConstantSet};
+
+// Star-independent astrometry parameters (layout as SOFA iauASTROM)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct IauAstrom {
     pub pmt: f64,           // PM time interval (SSB, Julian years)
     pub eb: [f64; 3],       // SSB to observer (vector, au)
@@ -45,16 +49,44 @@ impl Default for IauAstrom {
     }
 }
 
+// Observing site (geodetic coordinates, WGS84)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Site {
+    pub elong: f64, // longitude (radians, east +ve)
+    pub phi: f64,   // geodetic latitude (radians)
+    pub hm: f64,    // height above ellipsoid (m, geodetic)
+}
+
+impl Site {
+    pub fn new(elong: f64, phi: f64, hm: f64) -> Self {
+        Site { elong, phi, hm }
+    }
+}
+
 // Body parameters for light deflection
+#[derive(Debug, Clone, Copy, Default)]
 pub struct IauLdBody {
     pub bm: f64,           // mass of the body (solar masses)
     pub dl: f64,           // deflection limiter (radians^2/2)
     pub pv: [[f64; 3]; 2], // barycentric PV of the body (au, au/day)
 }
 
+impl IauLdBody {
+    /// A deflecting body with its mass from a constant set (see iauLdn
+    /// for the choice of dl).
+    pub fn new(set: &ConstantSet, body: Body, pv: &[[f64; 3]; 2], dl: f64) -> Self {
+        IauLdBody { bm: set.mass_ratio(body), dl, pv: *pv }
+    }
+}
+
+pub mod horizon;
+
 mod ab;
 pub use ab::*;
 
+mod abv;
+pub use abv::*;
+
 mod apcg;
 pub use apcg::*;
 
@@ -73,6 +105,12 @@ pub use apco13::*;
 mod atco13;
 pub use atco13::*;
 
+mod berv;
+pub use berv::*;
+
+mod bjd;
+pub use bjd::*;
+
 mod apcs;
 pub use apcs::*;
 
@@ -100,6 +138,18 @@ pub use atio13::*;
 mod atioq;
 pub use atioq::*;
 
+mod moon;
+pub use moon::*;
+
+mod oscplace;
+pub use oscplace::*;
+
+mod planet;
+pub use planet::*;
+
+mod pipeline;
+pub use pipeline::*;
+
 mod pmpx;
 pub use pmpx::*;
 
@@ -109,8 +159,27 @@ pub use pvtob::*;
 mod refco;
 pub use refco::*;
 
+mod refract;
+pub use refract::*;
+
+mod tropo;
+pub use tropo::*;
+
+mod sun;
+pub use sun::*;
+
 mod ld;
 pub use ld::*;
 
+mod ld_j2;
+pub use ld_j2::*;
+
 mod ldsun;
-pub use ldsun::*;
\ No newline at end of file
+pub use ldsun::*;
+
+mod shapiro;
+pub use shapiro::*;
+
+mod jdforms;
+pub use jdforms::*;
+pub mod legacy;
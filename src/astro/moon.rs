@@ -0,0 +1,77 @@
+use crate::consts::{AULT, DAYSEC};
+use crate::eph::moon98;
+use crate::math::cos;
+use crate::pnp::pfw06;
+use crate::vm::{pdp, pm, pxp, sepp, RotMat};
+
+use super::sun_direction;
+
+///  Phase of the Moon
+///
+///  ```text
+///     phase_angle   Sun-Moon-Earth angle (radians, 0-pi)
+///     illuminated   illuminated fraction of the disk (0-1)
+///     elongation    Sun-Earth-Moon angle (radians, 0-pi)
+///     waxing        true from new Moon to full Moon
+///  ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LunarPhase {
+    pub phase_angle: f64,
+    pub illuminated: f64,
+    pub elongation: f64,
+    pub waxing: bool,
+}
+
+///  Phase angle, illuminated fraction and elongation of the Moon
+///
+///  Given:
+///  ```text
+///     date1,date2  double       TT as a 2-part Julian Date
+///  ```
+///  Returned (function value):
+///  ```text
+///                  LunarPhase   phase, seen from the geocentre
+///  ```
+///  Status values:
+///  ```text
+///      1   date outside 1900-2100 (from epv00)
+///  ```
+///  Notes:
+///
+///  1) The Moon is from `moon98` and the Sun from `epv00`, each at the
+///     time the light left it.  The illuminated fraction is
+///     (1 + cos i)/2, with i the phase angle.
+///
+///  2) The Moon is waxing when it is east of the Sun in ecliptic
+///     longitude, the ecliptic being the mean ecliptic of date.
+///
+///  3) The result is geocentric:  the topocentric phase angle differs
+///     by up to about 1 degree.  Accuracy is limited by `moon98`, a few
+///     arcseconds, giving errors of order 1e-5 in the illuminated
+///     fraction.
+pub fn moon_phase(date1: f64, date2: f64) -> Result<LunarPhase, i32> {
+    /* Sun, geocentric (au). */
+    let (pu, _, delta, _) = sun_direction(date1, date2, &Default::default())?;
+    let s = pu.map(|x| x * delta);
+
+    /* Moon, geocentric (au), at the time the light left it. */
+    let pv = moon98(date1, date2);
+    let tau = pm(&pv[0]) * AULT / DAYSEC;
+    let m = [0, 1, 2].map(|i| pv[0][i] - tau * pv[1][i]);
+
+    /* Sun-Moon-Earth and Sun-Earth-Moon angles. */
+    let ms = [0, 1, 2].map(|i| s[i] - m[i]);
+    let phase_angle = sepp(&ms, &m.map(|x| -x));
+    let elongation = sepp(&s, &m);
+
+    /* Pole of the mean ecliptic of date, GCRS. */
+    let (gamb, phib, _, _) = pfw06(date1, date2);
+    let n = RotMat::rz(-gamb) * RotMat::rx(-phib) * [0.0, 0.0, 1.0];
+
+    Ok(LunarPhase {
+        phase_angle,
+        illuminated: (1.0 + cos(phase_angle)) / 2.0,
+        elongation,
+        waxing: pdp(&pxp(&s, &m), &n) > 0.0,
+    })
+}
@@ -0,0 +1,101 @@
+use crate::consts::{AULT, DAYSEC, DC};
+use crate::eph::{epv00, oscpv, OscElements};
+use crate::pnp::pnm06a;
+use crate::vm::{anp, c2s, pdp, pn, rxp};
+
+use super::{ab, ld};
+
+///  Geocentric place of a solar-system body
+///
+///  ```text
+///     ra_astrom,dec_astrom   astrometric RA,Dec (ICRS, radians)
+///     ra,dec                 apparent RA,Dec (true equator and
+///                            equinox of date, radians)
+///     delta                  geocentric distance at the retarded
+///                            time (au)
+///     tau                    light time (days)
+///  ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ApparentPlace {
+    pub ra_astrom: f64,
+    pub dec_astrom: f64,
+    pub ra: f64,
+    pub dec: f64,
+    pub delta: f64,
+    pub tau: f64,
+}
+
+///  Astrometric and apparent place of a comet or asteroid from
+///  heliocentric osculating elements.
+///
+///  Given:
+///  ```text
+///     el          OscElements   cometary-form elements (see oscpv)
+///     date1,date2 double        TT as a 2-part Julian Date
+///  ```
+///  Returned (function value):
+///  ```text
+///                 ApparentPlace astrometric and apparent place
+///  ```
+///  Status values:
+///  ```text
+///      1   date outside 1900-2100 (from epv00)
+///     -1   illegal elements
+///     -2   Kepler's equation failed to converge
+///  ```
+///  Notes:
+///
+///  1) The steps are those of the classical apparent place of a planet.
+///     Kepler's equation is solved (`oscpv`) at the time the light left
+///     the body, found by iterating the light time from the Earth's
+///     position (`epv00`) at date1+date2.  The astrometric place is the
+///     direction from the Earth at the date to the body at that
+///     retarded time.  The apparent place adds light deflection by the
+///     Sun for a source at a finite distance (`ld`), aberration due to
+///     the Earth's barycentric velocity (`ab`), and precession-nutation
+///     (`pnm06a`).
+///
+///  2) The observer is at the geocentre.  TT is used for TDB.
+///
+///  3) Accuracy is limited by the two-body motion and by `epv00`;  for
+///     a main-belt asteroid near the osculation epoch the model terms
+///     are good to a few milliarcseconds.
+pub fn oscplace(el: &OscElements, date1: f64, date2: f64) -> Result<ApparentPlace, i32> {
+    /* Earth, heliocentric and barycentric. */
+    let (pvh, pvb) = epv00(date1, date2).ok_or(1)?;
+    let eh = pvh[0];
+
+    /* Light-time iteration. */
+    let mut tau = 0.0;
+    let mut p = [0.0; 3];
+    let mut q = [0.0; 3];
+    for _ in 0..3 {
+        let b = oscpv(el, date1, date2 - tau)?;
+        q = b.p;
+        p = [q[0] - eh[0], q[1] - eh[1], q[2] - eh[2]];
+        tau = pdp(&p, &p).sqrt() * AULT / DAYSEC;
+    }
+    let (delta, pu) = pn(&p);
+
+    /* Astrometric place. */
+    let (w, dec_astrom) = c2s(&pu);
+    let ra_astrom = anp(w);
+
+    /* Light deflection by the Sun. */
+    let (em, e) = pn(&eh);
+    let (_, qu) = pn(&q);
+    let dlim = 1e-6 / (em * em).max(1.0);
+    let pd = ld(1.0, &pu, &qu, &e, em, dlim);
+
+    /* Aberration. */
+    let v = pvb[1].map(|x| x / DC);
+    let bm1 = (1.0 - pdp(&v, &v)).sqrt();
+    let pa = ab(&pd, &v, em, bm1);
+
+    /* Precession-nutation. */
+    let rnpb = pnm06a(date1, date2);
+    let pt = rxp(&rnpb, &pa);
+    let (w, dec) = c2s(&pt);
+
+    Ok(ApparentPlace { ra_astrom, dec_astrom, ra: anp(w), dec, delta, tau })
+}
@@ -0,0 +1,324 @@
+use crate::consts::{AULT, DAU, DAYSEC, DJ00, DJY};
+use crate::coords::gd2gc;
+use crate::eph::epv00;
+use crate::erst::gst06;
+use crate::frames::{itrs_to_gcrs_pv, EopProvider};
+use crate::horeq::{ae2hd, hd2ae};
+use crate::pnp::{nut06a, pom00, sp00, FwAngles};
+use crate::star::Star;
+use crate::ts::{Epoch, TimeScale};
+use crate::vm::{anp, c2s, pdp, pn, s2c, PvVec, RotMat};
+
+use super::{ab, ld, ldsun, pmpx, refco, refract, IauLdBody, Site};
+
+/* Light time for 1 au, days. */
+const CR: f64 = AULT / DAYSEC;
+
+///  Observed place from an `AstroPipeline`
+///
+///  ```text
+///     az      azimuth (radians, N=0,E=90)
+///     zd      zenith distance (radians)
+///     ha      hour angle (radians)
+///     dec     declination (radians)
+///     ra      right ascension (radians, equinox based)
+///  ```
+///  As atco13's aob, zob, hob, dob and rob, except that ra is measured
+///  from the equinox (of the frame the enabled effects lead to) rather
+///  than from the CIO.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ObservedPlace {
+    pub az: f64,
+    pub zd: f64,
+    pub ha: f64,
+    pub dec: f64,
+    pub ra: f64,
+}
+
+///  Builder for an `AstroPipeline`
+///
+///  Every effect is enabled by default;  see `AstroPipeline`.
+#[derive(Debug, Clone)]
+pub struct PipelineBuilder {
+    frame_bias: bool,
+    precession: bool,
+    nutation: bool,
+    annual_aberration: bool,
+    diurnal_aberration: bool,
+    sun_deflection: bool,
+    planetary_deflection: bool,
+    refraction: bool,
+    weather: [f64; 4],
+    bodies: Vec<IauLdBody>,
+}
+
+impl Default for PipelineBuilder {
+    fn default() -> Self {
+        PipelineBuilder {
+            frame_bias: true,
+            precession: true,
+            nutation: true,
+            annual_aberration: true,
+            diurnal_aberration: true,
+            sun_deflection: true,
+            planetary_deflection: true,
+            refraction: true,
+            weather: [0.0; 4],
+            bodies: Vec::new(),
+        }
+    }
+}
+
+impl PipelineBuilder {
+    /// ICRS to mean J2000 frame bias.
+    pub fn frame_bias(mut self, on: bool) -> Self {
+        self.frame_bias = on;
+        self
+    }
+
+    /// IAU 2006 precession.
+    pub fn precession(mut self, on: bool) -> Self {
+        self.precession = on;
+        self
+    }
+
+    /// IAU 2000A nutation (and with it the equation of the equinoxes).
+    pub fn nutation(mut self, on: bool) -> Self {
+        self.nutation = on;
+        self
+    }
+
+    /// Aberration due to the Earth's barycentric velocity.
+    pub fn annual_aberration(mut self, on: bool) -> Self {
+        self.annual_aberration = on;
+        self
+    }
+
+    /// Aberration due to the site's rotational velocity.
+    pub fn diurnal_aberration(mut self, on: bool) -> Self {
+        self.diurnal_aberration = on;
+        self
+    }
+
+    /// Light deflection by the Sun.
+    pub fn sun_deflection(mut self, on: bool) -> Self {
+        self.sun_deflection = on;
+        self
+    }
+
+    /// Light deflection by the bodies given to `bodies`.
+    pub fn planetary_deflection(mut self, on: bool) -> Self {
+        self.planetary_deflection = on;
+        self
+    }
+
+    /// Atmospheric refraction, for the conditions given to `weather`.
+    pub fn refraction(mut self, on: bool) -> Self {
+        self.refraction = on;
+        self
+    }
+
+    /// Pressure (hPa), temperature (C), relative humidity (0-1) and
+    /// wavelength (micrometers), as for refco.  The default, zero
+    /// pressure, means no refraction.
+    pub fn weather(mut self, phpa: f64, tc: f64, rh: f64, wl: f64) -> Self {
+        self.weather = [phpa, tc, rh, wl];
+        self
+    }
+
+    /// Deflecting bodies, with BCRS pv at the epoch (see IauLdBody and
+    /// iauLdn).  Exclude the Sun, which is `sun_deflection`.
+    pub fn bodies(mut self, b: &[IauLdBody]) -> Self {
+        self.bodies = b.to_vec();
+        self
+    }
+
+    ///  Compute the star-independent parameters.
+    ///
+    ///  Given:
+    ///  ```text
+    ///     epoch    Epoch              UTC, TAI or TT
+    ///     site     Site               geodetic position (WGS84)
+    ///     eop      EopProvider        UT1-UTC and polar motion
+    ///  ```
+    ///  Status values:
+    ///  ```text
+    ///      1   date outside 1900-2100 (from epv00)
+    ///     -2   illegal site coordinates (from gd2gc)
+    ///     other  from the time scale conversions
+    ///  ```
+    pub fn build(self, epoch: &Epoch, site: &Site, eop: &(impl EopProvider + ?Sized)) -> Result<AstroPipeline, i32> {
+        let eo = &eop.eop(epoch)?;
+        let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+        let (ut1, ut2) = epoch.to_ut1(eo.ut1_utc)?.jd.into();
+
+        /* Earth and site, BCRS (au, au/day). */
+        let (pvh, pvb) = epv00(tt1, tt2).ok_or(1)?;
+        let mut xyz = [0.0; 3];
+        gd2gc(1, site.elong, site.phi, site.hm, &mut xyz)?;
+        let pvs = itrs_to_gcrs_pv(epoch, eo, &PvVec::new(xyz, [0.0; 3]))?.scale2(1.0 / DAU, DAYSEC / DAU);
+        let pob = (PvVec::from(pvb) + pvs).p;
+        let (em, eh) = pn(&(PvVec::new(pvh[0], [0.0; 3]) + pvs).p);
+
+        /* Observer velocity for aberration, units of c. */
+        let mut v = [0.0; 3];
+        for i in 0..3 {
+            if self.annual_aberration {
+                v[i] += pvb[1][i];
+            }
+            if self.diurnal_aberration {
+                v[i] += pvs.v[i];
+            }
+            v[i] *= CR;
+        }
+        let bm1 = (1.0 - pdp(&v, &v)).sqrt();
+
+        /* Bias, precession and nutation, as iauPn06. */
+        let rb = FwAngles::bias().matrix();
+        let fw = FwAngles::iau2006(tt1, tt2);
+        let rbp = fw.matrix();
+        let (dpsi, deps) = nut06a(tt1, tt2);
+        let rn = fw.with_nutation(dpsi, deps).matrix() * rbp.transpose();
+        let rp = rbp * rb.transpose();
+        let on = |b: bool, m: RotMat| if b { m } else { RotMat::identity() };
+        let rbpn = on(self.nutation, rn) * on(self.precession, rp) * on(self.frame_bias, rb);
+
+        /* Sidereal time for that frame, polar motion and longitude. */
+        let gst = gst06(ut1, ut2, tt1, tt2, &rbpn.to_array());
+        let rpom = RotMat::new(pom00(eo.xp, eo.yp, sp00(tt1, tt2)));
+        let rhd = RotMat::rz(site.elong) * rpom * RotMat::rz(gst);
+
+        let (refa, refb) = if self.refraction {
+            let [phpa, tc, rh, wl] = self.weather;
+            refco(phpa, tc, rh, wl)
+        } else {
+            (0.0, 0.0)
+        };
+
+        Ok(AstroPipeline {
+            pmt: ((tt1 - DJ00) + tt2) / DJY,
+            pob,
+            eh,
+            em,
+            v,
+            bm1,
+            aberration: self.annual_aberration || self.diurnal_aberration,
+            sun_deflection: self.sun_deflection,
+            bodies: if self.planetary_deflection { self.bodies } else { Vec::new() },
+            rbpn,
+            rhd,
+            phi: site.phi,
+            refa,
+            refb,
+        })
+    }
+}
+
+///  Configurable ICRS to observed place transformation
+///
+///  A pipeline is built for an epoch and site with any of the effects
+///  frame bias, precession, nutation, annual aberration, diurnal
+///  aberration, Sun deflection, planetary deflection and refraction
+///  switched off, and then run on as many targets as required:
+///  ```text
+///     let pl = AstroPipeline::builder()
+///         .nutation(false)
+///         .weather(952.0, 18.5, 0.83, 0.55)
+///         .build(&epoch, &site, &eo)?;
+///     let obs = pl.places(&stars);
+///  ```
+///  Notes:
+///
+///  1) The steps are those of atco13, but equinox based:  space motion
+///     and parallax (always applied), planetary deflection, Sun
+///     deflection, aberration, bias-precession-nutation (IAU 2006/2000A,
+///     as iauPn06), Earth rotation (gst06), polar motion, the site's
+///     hour angle and declination, azimuth and zenith distance, then
+///     refraction (refract).  With every effect enabled the result
+///     agrees with atco13 to better than a milliarcsecond in azimuth;
+///     the zenith distance differs by atco13's single Newton-Raphson
+///     refraction step (10 mas at ZD 80 deg), and ra here is corrected
+///     for polar motion, as rob is not.
+///
+///  2) Disabling frame bias, precession or nutation removes that factor
+///     from the rbpn matrix;  the sidereal time follows, so the Earth
+///     rotates from the equinox of the frame reached.  This is for
+///     study of the effects:  such a pipeline is not a valid model of
+///     the sky.
+///
+///  3) The observer's velocity, used for aberration, is the sum of the
+///     enabled annual and diurnal parts.  The site position is always
+///     used for parallax.
+///
+///  4) eo.dx and eo.dy are not used.  TT is used for TDB.
+#[derive(Debug, Clone)]
+pub struct AstroPipeline {
+    pmt: f64,
+    pob: [f64; 3],
+    eh: [f64; 3],
+    em: f64,
+    v: [f64; 3],
+    bm1: f64,
+    aberration: bool,
+    sun_deflection: bool,
+    bodies: Vec<IauLdBody>,
+    rbpn: RotMat,
+    rhd: RotMat,
+    phi: f64,
+    refa: f64,
+    refb: f64,
+}
+
+impl AstroPipeline {
+    /// A builder with every effect enabled.
+    pub fn builder() -> PipelineBuilder {
+        PipelineBuilder::default()
+    }
+
+    /// The bias-precession-nutation matrix, with the disabled parts
+    /// left out.
+    pub fn rbpn(&self) -> RotMat {
+        self.rbpn
+    }
+
+    /// Observed place of a catalog star.
+    pub fn place(&self, star: &Star) -> ObservedPlace {
+        /* Proper motion and parallax, giving BCRS coordinate direction. */
+        let mut p = pmpx(star.ra, star.dec, star.pmr, star.pmd, star.px, star.rv, self.pmt, &self.pob);
+
+        /* Planetary deflection, as iauLdn. */
+        for b in &self.bodies {
+            let v = [self.pob[0] - b.pv[0][0], self.pob[1] - b.pv[0][1], self.pob[2] - b.pv[0][2]];
+            let dt = (pdp(&p, &v) * CR).min(0.0);
+            let ev = [v[0] - dt * b.pv[1][0], v[1] - dt * b.pv[1][1], v[2] - dt * b.pv[1][2]];
+            let (em, e) = pn(&ev);
+            p = ld(b.bm, &p, &p, &e, em, b.dl);
+        }
+
+        /* Sun deflection and aberration. */
+        if self.sun_deflection {
+            p = ldsun(&p, &self.eh, self.em);
+        }
+        if self.aberration {
+            p = ab(&p, &self.v, self.em, self.bm1);
+        }
+
+        /* To the frame of date, then to (-HA, Dec). */
+        let ptrue = self.rbpn * p;
+        let (hm, dec) = c2s(&(self.rhd * ptrue));
+        let (az, el) = hd2ae(-hm, dec, self.phi);
+
+        /* Refraction. */
+        let zd = refract(self.refa, self.refb, std::f64::consts::FRAC_PI_2 - el);
+        let (ha, dec) = ae2hd(az, std::f64::consts::FRAC_PI_2 - zd, self.phi);
+
+        /* Observed RA. */
+        let (ra, _) = c2s(&self.rhd.apply_inverse(&s2c(-ha, dec)));
+        ObservedPlace { az, zd, ha, dec, ra: anp(ra) }
+    }
+
+    /// Observed places of many catalog stars.
+    pub fn places(&self, stars: &[Star]) -> Vec<ObservedPlace> {
+        stars.iter().map(|s| self.place(s)).collect()
+    }
+}
@@ -0,0 +1,135 @@
+use crate::consts::{AULT, DAYSEC, DC, DPI};
+use crate::eph::{epv00, planet_pv, Planet};
+use crate::frames::{gcrs_to_itrs, EopProvider};
+use crate::horeq::hd2ae;
+use crate::math::{cos, exp};
+use crate::ts::{Epoch, TimeScale};
+use crate::vm::{c2s, pdp, pn, sepp, PvVec, RotMat};
+
+use super::{ab, ld, refco, refract, site_pv, Site};
+
+/* Standard atmosphere for the refraction:  sea-level temperature (K),
+   temperature (C), relative humidity and wavelength (micrometers). */
+const TSL: f64 = 283.15;
+const TC: f64 = 10.0;
+const RH: f64 = 0.5;
+const WL: f64 = 0.55;
+
+///  Where a planet is in the sky
+///
+///  ```text
+///     az            azimuth (radians, N=0,E=90)
+///     alt           observed altitude, refraction included (radians)
+///     refraction    the refraction included in alt (radians)
+///     distance      distance from the observer (au, at the time the
+///                   light left the planet)
+///     phase_angle   Sun-planet-observer angle (radians, 0-pi)
+///     illuminated   illuminated fraction of the disk (0-1)
+///  ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PlanetAltAz {
+    pub az: f64,
+    pub alt: f64,
+    pub refraction: f64,
+    pub distance: f64,
+    pub phase_angle: f64,
+    pub illuminated: f64,
+}
+
+///  Azimuth, altitude, distance and phase of a planet
+///
+///  Given:
+///  ```text
+///     planet   Planet             the planet
+///     epoch    Epoch              UTC, TAI or TT
+///     site     Site               geodetic position (WGS84)
+///     eop      EopProvider        UT1-UTC, polar motion, dX,dY
+///  ```
+///  Returned (function value):
+///  ```text
+///              PlanetAltAz        apparent place on the local horizon
+///  ```
+///  Status values:
+///  ```text
+///      1   date outside 1900-2100 (from epv00)
+///     -2   illegal site coordinates (from gd2gc), or Kepler's
+///          equation failed to converge (from oscpv)
+///     other  from the time scale conversions
+///  ```
+///  Notes:
+///
+///  1) The planet is from `planet_pv`, at the time the light left it,
+///     and the observer at the site from `epv00` and the Earth
+///     orientation.  Light deflection by the Sun (`ld`) and aberration
+///     due to the observer's barycentric velocity (`ab`) give the
+///     apparent direction, which is rotated into the ITRS
+///     (`gcrs_to_itrs`) and then to the local horizon, as in
+///     `sun_altaz`.
+///
+///  2) Refraction (`refco`, `refract`) is for a standard atmosphere:
+///     10 C, 50% humidity, 0.55 micrometers and a pressure estimated
+///     from the height, 1013.25 exp(-hm/(29.3 x 283.15)) hPa.  For
+///     other conditions subtract the refraction field and apply
+///     `refract` with the observed weather.
+///
+///  3) The illuminated fraction is (1 + cos i)/2, with i the phase
+///     angle;  the disk is taken to be a sphere.
+///
+///  4) Accuracy is that of `planet_pv`, from under an arcminute for
+///     most planets to about 10 arcminutes for Saturn.  TT is used for
+///     TDB.
+pub fn planet_altaz(planet: Planet, epoch: &Epoch, site: &Site, eop: &(impl EopProvider + ?Sized))
+                    -> Result<PlanetAltAz, i32> {
+    let eo = &eop.eop(epoch)?;
+    let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+
+    /* Earth, heliocentric and barycentric, and the observer. */
+    let (pvh, pvb) = epv00(tt1, tt2).ok_or(1)?;
+    let obs = site_pv(site, epoch, eo)?;
+    let ob = PvVec::from(pvb) + obs;
+    let oh = [0, 1, 2].map(|i| pvh[0][i] + obs.p[i]);
+
+    /* Light-time iteration. */
+    let mut tau = 0.0;
+    let mut p = [0.0; 3];
+    let mut q = [0.0; 3];
+    for _ in 0..3 {
+        q = planet_pv(planet, tt1, tt2 - tau)?.p;
+        p = [0, 1, 2].map(|i| q[i] - oh[i]);
+        tau = pdp(&p, &p).sqrt() * AULT / DAYSEC;
+    }
+    let (distance, pu) = pn(&p);
+
+    /* Light deflection by the Sun. */
+    let (em, e) = pn(&oh);
+    let (_, qu) = pn(&q);
+    let dlim = 1e-6 / (em * em).max(1.0);
+    let pd = ld(1.0, &pu, &qu, &e, em, dlim);
+
+    /* Aberration. */
+    let v = ob.v.map(|x| x / DC);
+    let bm1 = (1.0 - pdp(&v, &v)).sqrt();
+    let pa = ab(&pd, &v, em, bm1);
+
+    /* To the local horizon. */
+    let r = RotMat::rz(site.elong) * gcrs_to_itrs(epoch, eo)?;
+    let (hm, dec) = c2s(&(r * pa));
+    let (az, el) = hd2ae(-hm, dec, site.phi);
+
+    /* Refraction. */
+    let phpa = 1013.25 * exp(-site.hm / (29.3 * TSL));
+    let (refa, refb) = refco(phpa, TC, RH, WL);
+    let alt = DPI / 2.0 - refract(refa, refb, DPI / 2.0 - el);
+
+    /* Phase. */
+    let phase_angle = sepp(&q, &p);
+
+    Ok(PlanetAltAz {
+        az,
+        alt,
+        refraction: alt - el,
+        distance,
+        phase_angle,
+        illuminated: (1.0 + cos(phase_angle)) / 2.0,
+    })
+}
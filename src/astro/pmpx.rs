@@ -1,4 +1,5 @@
 use crate::{consts::{AULT, DAU, DAYSEC, DJM, DJY}, vm::{pdp, pn}};
+use crate::math::{cos, sin};
 
 ///  Apply proper motion and parallax
 /// 
@@ -49,17 +50,17 @@ use crate::{consts::{AULT, DAU, DAYSEC, DJM, DJY}, vm::{pdp, pn}};
 ///     iauPn        decompose p-vector into modulus and direction
 ///  ```
 pub fn pmpx(rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64, pmt: f64, 
-                                                pob: [f64; 3],) -> [f64; 3] {
+                                                pob: &[f64; 3],) -> [f64; 3] {
     /* Km/s to au/year */
     let vf = DAYSEC * DJM / DAU;
 
     /* Light time for 1 au, Julian years */
     let aulty = AULT / DAYSEC / DJY;
 
-    let sr = rc.sin();
-    let cr = rc.cos();
-    let sd = dc.sin();
-    let cd = dc.cos();
+    let sr = sin(rc);
+    let cr = cos(rc);
+    let sd = sin(dc);
+    let cd = cos(dc);
 
     let x = cr * cd;
     let y = sr * cd;
@@ -67,7 +68,7 @@ pub fn pmpx(rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64, pmt: f64,
     let mut p = [x, y, z];
 
     /* Proper motion time interval (y) including Roemer effect. */
-    let dt = pmt + pdp(&p, &pob) * aulty;
+    let dt = pmt + pdp(&p, pob) * aulty;
 
     /* Space motion (radians per year). */
     let pxr = px * crate::consts::DAS2R;
@@ -1,8 +1,8 @@
-#![allow(non_snake_case)]
 use crate::consts::{D2PI, DAYSEC};
 use crate::coords::gd2gc;
 use crate::pnp::pom00;
 use crate::vm::trxp;
+use crate::math::{cos, sin};
 
 ///  Observatory position and velocity
 ///
@@ -22,7 +22,7 @@ use crate::vm::trxp;
 ///     sp      double       the TIO locator s' (radians, Note 2)
 ///     theta   double       Earth rotation angle (radians, Note 3)
 ///  ```
-///  Returned:
+///  Returned (function value):
 ///  ```
 ///     pv      double[2][3] position/velocity vector (m, m/s, CIRS)
 ///  ```
@@ -75,42 +75,28 @@ pub fn pvtob(
     yp: f64,
     sp: f64,
     theta: f64,
-    pv: &mut [[f64; 3]; 2],
-) {
+) -> [[f64; 3]; 2] {
     /* Earth rotation rate in radians per UT1 second */
-    let OM = 1.00273781191135448 * D2PI / DAYSEC;
+    let om = 1.00273781191135448 * D2PI / DAYSEC;
 
     let xyzm = &mut [0.0; 3];
-    let rpm = &mut [[0.0; 3]; 3];
-    let xyz = &mut [0.0; 3];
-    let mut x = 0.0;
-    let mut y = 0.0;
-    let mut z = 0.0;
-    let mut s = 0.0;
-    let mut c = 0.0;
 
     /* Geodetic to geocentric transformation (WGS84). */
     #[allow(unused_must_use)]
     gd2gc(1, elong, phi, hm, xyzm);
 
     /* Polar motion and TIO position. */
-    pom00(xp, yp, sp, rpm);
-    trxp(rpm, xyzm, xyz);
-    x = xyz[0];
-    y = xyz[1];
-    z = xyz[2];
+    let rpm = pom00(xp, yp, sp);
+    let [x, y, z] = trxp(&rpm, xyzm);
 
     /* Functions of ERA. */
-    s = theta.sin();
-    c = theta.cos();
+    let s = sin(theta);
+    let c = cos(theta);
 
-    /* Position. */
-    pv[0][0] = c * x - s * y;
-    pv[0][1] = s * x + c * y;
-    pv[0][2] = z;
-
-    /* Velocity. */
-    pv[1][0] = OM * (-s * x - c * y);
-    pv[1][1] = OM * (c * x - s * y);
-    pv[1][2] = 0.0;
+    [
+        /* Position. */
+        [c * x - s * y, s * x + c * y, z],
+        /* Velocity. */
+        [om * (-s * x - c * y), om * (c * x - s * y), 0.0],
+    ]
 }
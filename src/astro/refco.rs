@@ -1,3 +1,5 @@
+use crate::math::powf;
+
 ///  Refraction constants
 /// 
 ///  Determine the constants A and B in the atmospheric refraction model
@@ -96,7 +98,8 @@
 ///
 ///  4) Outlandish input parameters are silently limited to
 ///     mathematically safe values.  Zero pressure is permissible, and
-///     causes zeroes to be returned.
+///     causes zeroes to be returned.  validate::refco_checked rejects
+///     such parameters instead.
 ///
 ///  5) The algorithm draws on several sources, as follows:
 ///
@@ -172,7 +175,7 @@ pub fn refco(phpa: f64, tc: f64, rh: f64, wl: f64) -> (f64, f64) {
 
     // Water vapour pressure at the observer.
     if p > 0.0 {
-        ps = 10.0_f64.powf((0.7859 + 0.03477 * t) / (1.0 + 0.00412 * t))
+        ps = powf(10.0_f64, (0.7859 + 0.03477 * t) / (1.0 + 0.00412 * t))
             * (1.0 + p * (4.5e-6 + 6e-10 * t * t));
         pw = r * ps / (1.0 - (1.0 - r) * ps / p);
     } else {
@@ -0,0 +1,115 @@
+use crate::math::{atan2, cos, sin, sin_cos};
+
+/* Smallest cosine of ZD used in tan Z, as in iauAtioq (about 87 deg). */
+const SELMIN: f64 = 0.05;
+
+/* dZ = A tan Z + B tan^3 Z and its derivative, with tan Z limited
+   near and below the horizon. */
+fn dz(refa: f64, refb: f64, zd: f64) -> (f64, f64) {
+    let (s, c) = sin_cos(zd);
+    let tz = s / c.max(SELMIN);
+    let dtz = if c > SELMIN { 1.0 + tz * tz } else { c / SELMIN };
+    let w = refb * tz * tz;
+    ((refa + w) * tz, (refa + 3.0 * w) * dtz)
+}
+
+///  Remove refraction from an observed zenith distance
+///
+///  Given:
+///  ```text
+///     refa    double    tan Z coefficient (radians, from refco)
+///     refb    double    tan^3 Z coefficient (radians, from refco)
+///     zdo     double    observed zenith distance (radians)
+///  ```
+///  Returned (function value):
+///  ```text
+///             double    topocentric zenith distance (radians)
+///  ```
+///  Notes:
+///
+///  1) This is the refco model applied directly, zdt = zdo + dZ(zdo),
+///     the refco Z being the observed zenith distance.
+///
+///  2) As in iauAtioq, tan Z is evaluated with cos Z no smaller than
+///     0.05, about 2.9 deg above the horizon, so that the result stays
+///     finite (and monotonic) down to and below the horizon.  Within
+///     a few degrees of the horizon the model itself is poor.
+pub fn unrefract(refa: f64, refb: f64, zdo: f64) -> f64 {
+    zdo + dz(refa, refb, zdo).0
+}
+
+///  Apply refraction to a topocentric zenith distance
+///
+///  Given:
+///  ```text
+///     refa    double    tan Z coefficient (radians, from refco)
+///     refb    double    tan^3 Z coefficient (radians, from refco)
+///     zdt     double    topocentric zenith distance (radians)
+///  ```
+///  Returned (function value):
+///  ```text
+///             double    observed zenith distance (radians)
+///  ```
+///  Notes:
+///
+///  1) The inverse of unrefract:  zdo + dZ(zdo) = zdt is solved for the
+///     observed zenith distance zdo by Newton-Raphson iteration, to
+///     convergence rather than the single step of iauAtioq.
+///
+///  2) The horizon precautions of unrefract apply (its Note 2), so
+///     refract(unrefract(z)) = z for all z in 0-pi.
+pub fn refract(refa: f64, refb: f64, zdt: f64) -> f64 {
+    let mut zdo = zdt;
+    for _ in 0..20 {
+        let (d, dd) = dz(refa, refb, zdo);
+        let step = (zdo + d - zdt) / (1.0 + dd);
+        zdo -= step;
+        if step.abs() < 1e-15 {
+            break;
+        }
+    }
+    zdo
+}
+
+/* Replace the ZD of a horizon-frame vector, keeping its azimuth and
+   modulus. */
+fn set_zd(v: &[f64; 3], f: impl Fn(f64) -> f64) -> [f64; 3] {
+    let r = (v[0] * v[0] + v[1] * v[1]).sqrt();
+    if r == 0.0 {
+        return *v;
+    }
+    let m = (r * r + v[2] * v[2]).sqrt();
+    let zd = f(atan2(r, v[2]));
+    let w = m * sin(zd) / r;
+    [v[0] * w, v[1] * w, m * cos(zd)]
+}
+
+///  Apply refraction to a topocentric direction vector
+///
+///  Given:
+///  ```text
+///     refa    double      tan Z coefficient (radians, from refco)
+///     refb    double      tan^3 Z coefficient (radians, from refco)
+///     v       double[3]   topocentric direction, z axis to the zenith
+///  ```
+///  Returned (function value):
+///  ```text
+///             double[3]   observed direction
+///  ```
+///  Notes:
+///
+///  1) The x and y axes may be any horizontal pair, for example the
+///     (az, el) vector of iauAtioq.  The azimuth and modulus of v are
+///     preserved, and a vector along the zenith is returned unchanged.
+///
+///  2) See refract.
+pub fn refract_vec(refa: f64, refb: f64, v: &[f64; 3]) -> [f64; 3] {
+    set_zd(v, |zd| refract(refa, refb, zd))
+}
+
+///  Remove refraction from an observed direction vector
+///
+///  The inverse of refract_vec;  see unrefract.
+pub fn unrefract_vec(refa: f64, refb: f64, v: &[f64; 3]) -> [f64; 3] {
+    set_zd(v, |zd| unrefract(refa, refb, zd))
+}
@@ -0,0 +1,105 @@
+use crate::consts::{AULT, SRS};
+use crate::coords::gd2gc;
+use crate::math::{hypot, ln};
+use crate::ts::{dtdb, Epoch, TimeScale};
+use crate::vm::pdp;
+
+use super::Site;
+
+///  Shapiro delay by a single solar-system body
+///
+///  Given:
+///  ```text
+///     bm     double     mass of the gravitating body (solar masses)
+///     p      double[3]  direction from observer to source (unit vector)
+///     e      double[3]  direction from body to observer (unit vector)
+///     em     double     distance from body to observer (au)
+///     dlim   double     delay limiter (Note 3)
+///  ```
+///  Returned (function value):
+///  ```text
+///            double     delay (s)
+///  ```
+///  Notes:
+///
+///  1) The delay is -2 GM/c^3 ln(em (1 + p.e)), the usual pulsar-timing
+///     form (Backer & Hellings 1986) for a source at infinity.  The
+///     constant part, which depends on the unit of em, is not
+///     observable;  2 GM/c^3 is bm times the light time of the
+///     Schwarzschild radius SRS, as in `ld`.
+///
+///  2) For the Sun the delay reaches 110 microseconds at the limb;  it
+///     is -7 microseconds for a source at opposition.  Jupiter
+///     contributes up to 0.2 microseconds.
+///
+///  3) 1 + p.e is not allowed below dlim, which keeps the delay finite
+///     for a source behind the body.  It corresponds to the parameter
+///     of `ld`:  phi^2/2, with phi the separation of source and body at
+///     which limiting begins.
+///
+///  4) The vectors and distance are as for `ld`:  for several bodies
+///     call the present function for each and sum the delays.
+///
+///  Reference:
+///
+///     Backer, D.C. & Hellings, R.W., 1986, ARA&A 24, 537
+pub fn shapiro(bm: f64, p: &[f64; 3], e: &[f64; 3], em: f64, dlim: f64) -> f64 {
+    -bm * SRS * AULT * ln(em * (1.0 + pdp(p, e)).max(dlim))
+}
+
+///  Shapiro delay by the Sun
+///
+///  Given:
+///  ```text
+///     p      double[3]  direction from observer to source (unit vector)
+///     e      double[3]  direction from Sun to observer (unit vector)
+///     em     double     distance from Sun to observer (au)
+///  ```
+///  Returned (function value):
+///  ```text
+///            double     delay (s)
+///  ```
+///  The limiter is that of `ldsun`, about 5 arcminutes for a
+///  terrestrial observer (see `shapiro`).
+pub fn shapiro_sun(p: &[f64; 3], e: &[f64; 3], em: f64) -> f64 {
+    let dlim = 1e-6 / (em * em).max(1.0);
+    shapiro(1.0, p, e, em, dlim)
+}
+
+///  Einstein delay at a ground site
+///
+///  Given:
+///  ```text
+///     epoch    Epoch     time of the observation (UTC, TAI or TT)
+///     site     Site      geodetic position (WGS84)
+///  ```
+///  Returned (function value):
+///  ```text
+///              double    TDB-TT at the site (s)
+///  ```
+///  Status values are those of `Epoch::to_topocentric`.
+///
+///  Notes:
+///
+///  1) The accumulated clock offset between the site and the
+///     barycenter:  the periodic terms of `dtdb` (1.7 ms annual) and
+///     the topocentric terms (up to 2 microseconds), the latter being
+///     the part that depends on the site.  The fraction of the day used
+///     as UT1 is that of TT, as in `Epoch::to_topocentric`.
+///
+///  2) With the Roemer delay (`bjd_tdb`) and the Shapiro delay
+///     (`shapiro_sun`) this makes the barycentric arrival time of
+///     pulsar timing, for which the same site should be used
+///     throughout.
+pub fn einstein_delay(epoch: &Epoch, site: &Site) -> Result<f64, i32> {
+    let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+
+    /* Distances from the Earth's spin axis and equatorial plane (km). */
+    let mut xyz = [0.0; 3];
+    gd2gc(1, site.elong, site.phi, site.hm, &mut xyz)?;
+    let u = hypot(xyz[0], xyz[1]) / 1e3;
+    let v = xyz[2] / 1e3;
+
+    let ut = (tt1.fract() + tt2.fract() + 0.5).rem_euclid(1.0);
+    Ok(dtdb(tt1, tt2, ut, site.elong, u, v))
+}
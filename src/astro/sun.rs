@@ -0,0 +1,196 @@
+use crate::consts::{AULT, DAU, DAYSEC, DC, DD2R, DJ00, DJM};
+use crate::coords::gd2gc;
+use crate::eph::epv00;
+use crate::frames::{gcrs_to_itrs, itrs_to_gcrs_pv, EarthOrientation, EopProvider};
+use crate::horeq::hd2ae;
+use crate::math::cos;
+use crate::pnp::{nut06a, obl06, pnm06a};
+use crate::ts::{Epoch, TimeScale};
+use crate::vm::{anp, anpm, c2s, pdp, pn, rxp, PvVec, RotMat};
+
+use super::{ab, ApparentPlace, Site};
+
+/* Direction of the Sun for an observer displaced by obs (GCRS, au and
+   au/day) from the geocentre:  astrometric and proper directions
+   (GCRS), distance (au) and light time (days). */
+pub(crate) fn sun_direction(date1: f64, date2: f64, obs: &PvVec)
+                            -> Result<([f64; 3], [f64; 3], f64, f64), i32> {
+    /* Earth, heliocentric and barycentric, and the observer. */
+    let (pvh, pvb) = epv00(date1, date2).ok_or(1)?;
+    let ob = PvVec::from(pvb) + *obs;
+    let oh = [pvh[0][0] + obs.p[0], pvh[0][1] + obs.p[1], pvh[0][2] + obs.p[2]];
+
+    /* Sun, barycentric, at the time the light left it. */
+    let mut tau = 0.0;
+    let mut p = [0.0; 3];
+    for _ in 0..2 {
+        for i in 0..3 {
+            let ps = pvb[0][i] - pvh[0][i];
+            let vs = pvb[1][i] - pvh[1][i];
+            p[i] = ps - tau * vs - ob.p[i];
+        }
+        tau = pdp(&p, &p).sqrt() * AULT / DAYSEC;
+    }
+    let (delta, pu) = pn(&p);
+
+    /* Aberration (no deflection of the Sun's own light). */
+    let v = ob.v.map(|x| x / DC);
+    let bm1 = (1.0 - pdp(&v, &v)).sqrt();
+    let pa = ab(&pu, &v, pdp(&oh, &oh).sqrt(), bm1);
+
+    Ok((pu, pa, delta, tau))
+}
+
+/* Place of the Sun for an observer displaced by obs from the geocentre. */
+fn place(date1: f64, date2: f64, obs: &PvVec) -> Result<ApparentPlace, i32> {
+    let (pu, pa, delta, tau) = sun_direction(date1, date2, obs)?;
+
+    /* Astrometric place. */
+    let (w, dec_astrom) = c2s(&pu);
+    let ra_astrom = anp(w);
+
+    /* Precession-nutation. */
+    let pt = rxp(&pnm06a(date1, date2), &pa);
+    let (w, dec) = c2s(&pt);
+
+    Ok(ApparentPlace { ra_astrom, dec_astrom, ra: anp(w), dec, delta, tau })
+}
+
+/* Geocentric pv of a site, GCRS, au and au/day. */
+pub(crate) fn site_pv(site: &Site, epoch: &Epoch, eo: &EarthOrientation) -> Result<PvVec, i32> {
+    let mut xyz = [0.0; 3];
+    gd2gc(1, site.elong, site.phi, site.hm, &mut xyz)?;
+    let pvs = itrs_to_gcrs_pv(epoch, eo, &PvVec::new(xyz, [0.0; 3]))?;
+    Ok(pvs.scale2(1.0 / DAU, DAYSEC / DAU))
+}
+
+///  Geocentric place of the Sun
+///
+///  Given:
+///  ```text
+///     date1,date2  double          TT as a 2-part Julian Date
+///  ```
+///  Returned (function value):
+///  ```text
+///                  ApparentPlace   astrometric and apparent place
+///  ```
+///  Status values:
+///  ```text
+///      1   date outside 1900-2100 (from epv00)
+///  ```
+///  Notes:
+///
+///  1) The Sun's barycentric position is the difference of the Earth's
+///     barycentric and heliocentric positions from `epv00`, taken at
+///     the time the light left the Sun.  The apparent place adds
+///     aberration due to the Earth's barycentric velocity (`ab`) and
+///     precession-nutation (`pnm06a`), giving RA,Dec with respect to
+///     the true equator and equinox of date.
+///
+///  2) TT is used for TDB.  Accuracy is that of `epv00`, a few
+///     milliarcseconds.
+pub fn sun_geocentric(date1: f64, date2: f64) -> Result<ApparentPlace, i32> {
+    place(date1, date2, &PvVec::default())
+}
+
+///  Topocentric place of the Sun
+///
+///  Given:
+///  ```text
+///     epoch    Epoch              UTC, TAI or TT
+///     site     Site               geodetic position (WGS84)
+///     eop      EopProvider        UT1-UTC, polar motion, dX,dY
+///  ```
+///  Returned (function value):
+///  ```text
+///              ApparentPlace      astrometric and apparent place seen
+///                                 from the site
+///  ```
+///  Status values:
+///  ```text
+///      1   date outside 1900-2100 (from epv00)
+///     -2   illegal site coordinates (from gd2gc)
+///     other  from the time scale conversions
+///  ```
+///  Notes:
+///
+///  1) As `sun_geocentric`, for an observer at the site:  the result
+///     includes diurnal parallax (up to 8.8 arcsec) and diurnal
+///     aberration (up to 0.3 arcsec).
+pub fn sun_topocentric(epoch: &Epoch, site: &Site, eop: &(impl EopProvider + ?Sized)) -> Result<ApparentPlace, i32> {
+    let eo = &eop.eop(epoch)?;
+    let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+    place(tt1, tt2, &site_pv(site, epoch, eo)?)
+}
+
+///  Azimuth and altitude of the Sun
+///
+///  Given:
+///  ```text
+///     epoch    Epoch              UTC, TAI or TT
+///     site     Site               geodetic position (WGS84)
+///     eop      EopProvider        UT1-UTC, polar motion, dX,dY
+///  ```
+///  Returned (function value):
+///  ```text
+///              double             azimuth (radians, N=0,E=90)
+///              double             altitude (radians)
+///  ```
+///  Status values are those of `sun_topocentric`.
+///
+///  Notes:
+///
+///  1) The topocentric apparent direction is rotated into the ITRS
+///     (`gcrs_to_itrs`) and then to the local horizon.
+///
+///  2) Refraction is not applied;  see `refco` and `refract`.  The
+///     altitude is that of the centre of the disk.
+pub fn sun_altaz(epoch: &Epoch, site: &Site, eop: &(impl EopProvider + ?Sized)) -> Result<(f64, f64), i32> {
+    let eo = &eop.eop(epoch)?;
+    let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+    let (_, pa, _, _) = sun_direction(tt1, tt2, &site_pv(site, epoch, eo)?)?;
+    let r = RotMat::rz(site.elong) * gcrs_to_itrs(epoch, eo)?;
+    let (hm, dec) = c2s(&(r * pa));
+    Ok(hd2ae(-hm, dec, site.phi))
+}
+
+///  Equation of time
+///
+///  Given:
+///  ```text
+///     date1,date2  double   TT as a 2-part Julian Date
+///  ```
+///  Returned (function value):
+///  ```text
+///                  double   apparent minus mean solar time (radians,
+///                           range +/-pi)
+///  ```
+///  Status values:
+///  ```text
+///      1   date outside 1900-2100 (from epv00)
+///  ```
+///  Notes:
+///
+///  1) E = L0 - 0.0057183 deg - RA + dpsi cos(eps), with L0 the Sun's
+///     geometric mean longitude (Meeus 1998, Eq. 28.1), RA the apparent
+///     right ascension from `sun_geocentric` and dpsi, eps the IAU
+///     2006/2000A nutation in longitude and true obliquity.
+///
+///  2) The result is positive when a sundial is ahead of the clock:
+///     about +16 minutes in early November, -14 minutes in February.
+///     Multiply by 86400/2pi for seconds of time.
+///
+///  Reference:
+///
+///     Meeus, J., Astronomical Algorithms, 2nd ed., Willmann-Bell
+///     (1998), Chapter 28.
+pub fn equation_of_time(date1: f64, date2: f64) -> Result<f64, i32> {
+    let t = ((date1 - DJ00) + date2) / DJM;
+    let l0 = (280.4664567
+        + t * (360007.6982779 + t * (0.03032028 + t * (1.0 / 49931.0 + t * (-1.0 / 15300.0 + t * (-1.0 / 2000000.0))))))
+        * DD2R;
+    let ra = sun_geocentric(date1, date2)?.ra;
+    let (dpsi, deps) = nut06a(date1, date2);
+    let eps = obl06(date1, date2) + deps;
+    Ok(anpm(l0 - 0.0057183 * DD2R - ra + dpsi * cos(eps)))
+}
@@ -0,0 +1,250 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::consts::{D2PI, DJM0};
+use crate::math::{cos, powf, sin};
+
+use super::{refco, refract, Site};
+
+/* Niell (1996) mapping function coefficients at latitudes 15, 30, 45,
+   60 and 75 deg:  hydrostatic average a,b,c, their seasonal amplitudes,
+   the height correction and the wet a,b,c. */
+const NMF_LAT: [f64; 5] = [15.0, 30.0, 45.0, 60.0, 75.0];
+
+const NMF_H_AVG: [[f64; 3]; 5] = [
+    [1.2769934e-3, 2.9153695e-3, 62.610505e-3],
+    [1.2683230e-3, 2.9152299e-3, 62.837393e-3],
+    [1.2465397e-3, 2.9288445e-3, 63.721774e-3],
+    [1.2196049e-3, 2.9022565e-3, 63.824265e-3],
+    [1.2045996e-3, 2.9024912e-3, 64.258455e-3],
+];
+
+const NMF_H_AMP: [[f64; 3]; 5] = [
+    [0.0, 0.0, 0.0],
+    [1.2709626e-5, 2.1414979e-5, 9.0128400e-5],
+    [2.6523662e-5, 3.0160779e-5, 4.3497037e-5],
+    [3.4000452e-5, 7.2562722e-5, 84.795348e-5],
+    [4.1202191e-5, 11.723375e-5, 170.37206e-5],
+];
+
+const NMF_H_HT: [f64; 3] = [2.53e-5, 5.49e-3, 1.14e-3];
+
+const NMF_W: [[f64; 3]; 5] = [
+    [5.8021897e-4, 1.4275268e-3, 4.3472961e-2],
+    [5.6794847e-4, 1.5138625e-3, 4.6729510e-2],
+    [5.8118019e-4, 1.4572752e-3, 4.3908931e-2],
+    [5.9727542e-4, 1.5007428e-3, 4.4626982e-2],
+    [6.1641693e-4, 1.7599082e-3, 5.4736038e-2],
+];
+
+/* Marini's continued fraction, normalized to 1 at the zenith. */
+fn marini(sine: f64, [a, b, c]: [f64; 3]) -> f64 {
+    (1.0 + a / (1.0 + b / (1.0 + c))) / (sine + a / (sine + b / (sine + c)))
+}
+
+/* Coefficients linearly interpolated in |latitude| (radians). */
+fn at_latitude(table: &[[f64; 3]; 5], phi: f64) -> [f64; 3] {
+    let d = phi.abs().to_degrees().clamp(NMF_LAT[0], NMF_LAT[4]);
+    let i = (((d - NMF_LAT[0]) / 15.0) as usize).min(3);
+    let f = (d - NMF_LAT[i]) / 15.0;
+    std::array::from_fn(|k| table[i][k] + f * (table[i + 1][k] - table[i][k]))
+}
+
+/* Partial pressure of water vapour (hPa), as in refco. */
+fn vapour_pressure(phpa: f64, tc: f64, rh: f64) -> f64 {
+    let (p, t, r) = (phpa.max(0.0), tc.clamp(-150.0, 200.0), rh.clamp(0.0, 1.0));
+    if p <= 0.0 {
+        return 0.0;
+    }
+    let ps = powf(10.0, (0.7859 + 0.03477 * t) / (1.0 + 0.00412 * t))
+        * (1.0 + p * (4.5e-6 + 6e-10 * t * t));
+    r * ps / (1.0 - (1.0 - r) * ps / p)
+}
+
+///  Hydrostatic zenith delay, Saastamoinen model
+///
+///  Given:
+///  ```text
+///     site     Site       geodetic latitude and height (WGS84)
+///     phpa     double     pressure at the site (hPa)
+///  ```
+///  Returned (function value):
+///  ```text
+///              double     zenith hydrostatic delay (m)
+///  ```
+///  Notes:
+///
+///  1) The formula of Davis et al. (1985), with the gravity at the
+///     centroid of the column from the latitude and height.  The delay
+///     is 2.3 m at sea level, and 2.3 mm per hPa of pressure, which is
+///     the main source of error.
+///
+///  2) Delays are the same at all radio frequencies (the troposphere
+///     is not dispersive there) and apply to GNSS and VLBI alike.
+///
+///  Reference:
+///
+///     Davis, J.L., Herring, T.A., Shapiro, I.I., Rogers, A.E.E. &
+///     Elgered, G., 1985, Radio Science 20, 1593
+pub fn saastamoinen_zhd(site: &Site, phpa: f64) -> f64 {
+    0.0022768 * phpa / (1.0 - 0.00266 * cos(2.0 * site.phi) - 0.28e-6 * site.hm)
+}
+
+///  Wet zenith delay, Saastamoinen model
+///
+///  Given:
+///  ```text
+///     phpa     double     pressure at the site (hPa)
+///     tc       double     temperature at the site (deg C)
+///     rh       double     relative humidity at the site (0-1)
+///  ```
+///  Returned (function value):
+///  ```text
+///              double     zenith wet delay (m)
+///  ```
+///  Notes:
+///
+///  1) The water vapour pressure is found from the weather as `refco`
+///     finds it, and the same parameters are limited to safe values.
+///
+///  2) Surface meteorology predicts the wet delay to only a few cm;  in
+///     precise work it is estimated from the data, this value serving
+///     as the a priori.
+///
+///  Reference:
+///
+///     Saastamoinen, J., 1972, "Atmospheric correction for the
+///     troposphere and stratosphere in radio ranging of satellites",
+///     Geophysical Monograph 15, AGU, 247
+pub fn saastamoinen_zwd(phpa: f64, tc: f64, rh: f64) -> f64 {
+    let tk = tc.clamp(-150.0, 200.0) + 273.15;
+    0.002277 * (1255.0 / tk + 0.05) * vapour_pressure(phpa, tc, rh)
+}
+
+///  Niell hydrostatic mapping function
+///
+///  Given:
+///  ```text
+///     site     Site       geodetic latitude and height (WGS84)
+///     date1    double     date as a 2-part Julian Date (Note 1)
+///     date2    double
+///     el       double     elevation (radians, in vacuo)
+///  ```
+///  Returned (function value):
+///  ```text
+///              double     ratio of slant to zenith hydrostatic delay
+///  ```
+///  Notes:
+///
+///  1) Any time scale will do:  the date gives only the season, which
+///     is shifted by half a year in the southern hemisphere.
+///
+///  2) The coefficients are tabulated at latitudes 15-75 deg and
+///     interpolated linearly;  outside that range the end values are
+///     used.  The height (nominally above sea level) enters through
+///     Niell's correction, 1/sin(el) minus a further Marini term.
+///
+///  3) The function is 1 at the zenith and about 10 at 5 deg
+///     elevation, where it is good to a few parts in 1000.  It is not
+///     intended for elevations below 3 deg.
+///
+///  Reference:
+///
+///     Niell, A.E., 1996, J. Geophys. Res. 101, 3227
+pub fn niell_hydro(site: &Site, date1: f64, date2: f64, el: f64) -> f64 {
+    /* Days from 28 January (1980), half a year later in the south. */
+    let mut doy = (date1 - DJM0) + date2 - 44239.0 + 1.0 - 28.0;
+    if site.phi < 0.0 {
+        doy += 365.25 / 2.0;
+    }
+    let c = cos(D2PI * doy / 365.25);
+
+    let avg = at_latitude(&NMF_H_AVG, site.phi);
+    let amp = at_latitude(&NMF_H_AMP, site.phi);
+    let abc = std::array::from_fn(|k| avg[k] - amp[k] * c);
+
+    let sine = sin(el);
+    let dm = (1.0 / sine - marini(sine, NMF_H_HT)) * site.hm / 1e3;
+    marini(sine, abc) + dm
+}
+
+///  Niell wet mapping function
+///
+///  Given:
+///  ```text
+///     site     Site       geodetic latitude
+///     el       double     elevation (radians, in vacuo)
+///  ```
+///  Returned (function value):
+///  ```text
+///              double     ratio of slant to zenith wet delay
+///  ```
+///  As `niell_hydro`, without seasonal or height dependence.
+pub fn niell_wet(site: &Site, el: f64) -> f64 {
+    marini(sin(el), at_latitude(&NMF_W, site.phi))
+}
+
+///  Tropospheric path delay and bending in a given direction
+///
+///  ```text
+///     zhd      hydrostatic zenith delay (m)
+///     zwd      wet zenith delay (m)
+///     mfh      hydrostatic mapping function
+///     mfw      wet mapping function
+///     delay    slant delay zhd*mfh + zwd*mfw (m)
+///     bend     bending, in vacuo minus observed zenith distance
+///              (radians)
+///  ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TropoDelay {
+    pub zhd: f64,
+    pub zwd: f64,
+    pub mfh: f64,
+    pub mfw: f64,
+    pub delay: f64,
+    pub bend: f64,
+}
+
+///  Radio tropospheric delay and refraction for a site and weather
+///
+///  Given:
+///  ```text
+///     site     Site       geodetic position (WGS84)
+///     phpa     double     pressure at the site (hPa)
+///     tc       double     temperature at the site (deg C)
+///     rh       double     relative humidity at the site (0-1)
+///     date1    double     date as a 2-part Julian Date
+///     date2    double
+///     el       double     elevation (radians, in vacuo)
+///  ```
+///  Returned (function value):
+///  ```text
+///              TropoDelay zenith and slant delays, mapping functions
+///                         and bending
+///  ```
+///  Notes:
+///
+///  1) The zenith delays are Saastamoinen's (`saastamoinen_zhd`,
+///     `saastamoinen_zwd`) and the mapping functions Niell's
+///     (`niell_hydro`, `niell_wet`).
+///
+///  2) The bending is that of the refco model in the radio case,
+///     applied by `refract`:  the same weather given to `refco` with a
+///     radio wavelength, or to `PipelineBuilder::weather`, gives the
+///     same elevation correction.
+///
+///  3) The delay is the excess path length of the signal, to be added
+///     to the geometric range (GNSS) or, divided by c, to the arrival
+///     time at the station (VLBI).
+pub fn tropo_delay(site: &Site, phpa: f64, tc: f64, rh: f64, date1: f64, date2: f64, el: f64)
+                   -> TropoDelay {
+    let zhd = saastamoinen_zhd(site, phpa);
+    let zwd = saastamoinen_zwd(phpa, tc, rh);
+    let mfh = niell_hydro(site, date1, date2, el);
+    let mfw = niell_wet(site, el);
+
+    let (refa, refb) = refco(phpa, tc, rh, 1e6);
+    let zdt = FRAC_PI_2 - el;
+    let bend = zdt - refract(refa, refb, zdt);
+
+    TropoDelay { zhd, zwd, mfh, mfw, delay: zhd * mfh + zwd * mfw, bend }
+}
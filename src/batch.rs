@@ -0,0 +1,100 @@
+//! Parallel catalog transformations
+//!
+//! Each function applies one of the "quick" astrometry transformations
+//! to every element of a slice, sharing one star-independent context
+//! (`IauAstrom`, from apci13, apco13 ...) across all of them and
+//! spreading the work over the rayon thread pool.  The results are
+//! identical to calling the scalar function in a loop, and come back
+//! in input order.
+//!
+//! The `_into` forms write to a caller-supplied slice, so that a very
+//! large catalog can be processed in chunks without reallocating;  they
+//! panic if the input and output slices differ in length.
+//!
+//! Requires the "rayon" feature.
+
+use rayon::prelude::*;
+
+use crate::astro::{atccq, atciq, atioq, IauAstrom};
+use crate::star::Star;
+use crate::vm::{anp, c2s, s2c, RotMat};
+
+/* Map f over inp into out, in parallel. */
+fn map_into<T: Sync, U: Send>(inp: &[T], out: &mut [U], f: impl Fn(&T) -> U + Sync, name: &str) {
+    assert_eq!(inp.len(), out.len(), "{}: length mismatch", name);
+    out.par_iter_mut().zip(inp.par_iter()).for_each(|(o, x)| *o = f(x));
+}
+
+///  Catalog ICRS (epoch J2000.0) to ICRS astrometric RA,Dec, for many
+///  stars (see `atccq`).
+///
+///  Returned:
+///  ```text
+///     (ra, da)  ICRS astrometric RA,Dec (radians), one per star
+///  ```
+pub fn atccq_par(stars: &[Star], astrom: &IauAstrom) -> Vec<(f64, f64)> {
+    let mut out = vec![(0.0, 0.0); stars.len()];
+    atccq_par_into(stars, astrom, &mut out);
+    out
+}
+
+///  As `atccq_par`, writing to out.
+pub fn atccq_par_into(stars: &[Star], astrom: &IauAstrom, out: &mut [(f64, f64)]) {
+    map_into(stars, out, |s| atccq(s.ra, s.dec, s.pmr, s.pmd, s.px, s.rv, astrom), "atccq_par");
+}
+
+///  Catalog ICRS (epoch J2000.0) to CIRS RA,Dec, for many stars (see
+///  `atciq`).
+///
+///  Returned:
+///  ```text
+///     (ri, di)  CIRS RA,Dec (radians), one per star
+///  ```
+pub fn atciq_par(stars: &[Star], astrom: &IauAstrom) -> Vec<(f64, f64)> {
+    let mut out = vec![(0.0, 0.0); stars.len()];
+    atciq_par_into(stars, astrom, &mut out);
+    out
+}
+
+///  As `atciq_par`, writing to out.
+pub fn atciq_par_into(stars: &[Star], astrom: &IauAstrom, out: &mut [(f64, f64)]) {
+    map_into(stars, out, |s| atciq(s.ra, s.dec, s.pmr, s.pmd, s.px, s.rv, astrom), "atciq_par");
+}
+
+///  CIRS RA,Dec to observed place, for many directions (see `atioq`).
+///
+///  Returned:
+///  ```text
+///     (aob, zob, hob, dob, rob)  observed azimuth, zenith distance,
+///                                hour angle, Dec and RA (radians)
+///  ```
+pub fn atioq_par(cirs: &[(f64, f64)], astrom: &IauAstrom) -> Vec<(f64, f64, f64, f64, f64)> {
+    let mut out = vec![(0.0, 0.0, 0.0, 0.0, 0.0); cirs.len()];
+    atioq_par_into(cirs, astrom, &mut out);
+    out
+}
+
+///  As `atioq_par`, writing to out.
+pub fn atioq_par_into(cirs: &[(f64, f64)], astrom: &IauAstrom,
+                      out: &mut [(f64, f64, f64, f64, f64)]) {
+    map_into(cirs, out, |&(ri, di)| atioq(ri, di, astrom), "atioq_par");
+}
+
+///  Rotate many (longitude, latitude) directions into another frame.
+///
+///  r is the r-matrix from the old frame to the new, for example
+///  `RotMat::from(bpn)` for GCRS to true equator and equinox.  The
+///  longitudes returned are in the range 0-2pi.
+pub fn rotate_par(r: &RotMat, dirs: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut out = vec![(0.0, 0.0); dirs.len()];
+    rotate_par_into(r, dirs, &mut out);
+    out
+}
+
+///  As `rotate_par`, writing to out.
+pub fn rotate_par_into(r: &RotMat, dirs: &[(f64, f64)], out: &mut [(f64, f64)]) {
+    map_into(dirs, out, |&(a, b)| {
+        let (w, lat) = c2s(&r.apply(&s2c(a, b)));
+        (anp(w), lat)
+    }, "rotate_par");
+}
@@ -36,16 +36,6 @@ pub fn epb2jd() {
     // ...
 }
 
-/// Julian Date to Julian Epoch
-pub fn epj() {
-    // ...
-}
-
-/// Julian Epoch to Julian Date
-pub fn epj2jd() {
-    // ...
-}
-
 /// Julian Date to Gregorian date for formatted output
 pub fn jdcalf() {
     // ...
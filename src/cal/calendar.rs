@@ -0,0 +1,225 @@
+use crate::consts::DJM0;
+
+/* Years accepted by the calendar-mode functions, and the corresponding
+   Julian Date limit (about +/- a million years). */
+const IYLIM: i32 = 1_000_000;
+const DJLIM: f64 = 3.7e8;
+
+/* MJD of 0h on 1 March of year 0 in each calendar, the origin of the
+   day counts below. */
+const MJD_GREG_MAR0: i64 = -678881;
+const MJD_JUL_MAR0: i64 = -678883;
+
+///  Which calendar dates are expressed in
+///
+///  ```text
+///     Gregorian         proleptic Gregorian, as cal2jd and jd2cal
+///     Julian            proleptic Julian
+///     Switchover(jd)    Julian before, and Gregorian from, the day
+///                       starting at Julian Date jd (0h)
+///  ```
+///  Notes:
+///
+///  1) `REFORM_1582` is the switch of the papal bull, Thursday 4 October
+///     1582 (Julian) being followed by Friday 15 October 1582
+///     (Gregorian);  `BRITAIN_1752` that of Great Britain and its
+///     colonies, 2 September 1752 followed by 14 September.  Other
+///     countries changed at other dates, given as the Julian Date of
+///     0h on the first Gregorian day.
+///
+///  2) Years are astronomical:  year 0 is 1 BC, year -1 is 2 BC and so
+///     on.  Both calendars are extended indefinitely, without regard to
+///     the irregular leap years of the early Julian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CalendarMode {
+    #[default]
+    Gregorian,
+    Julian,
+    Switchover(f64),
+}
+
+impl CalendarMode {
+    /// The Gregorian reform of 1582:  15 October 1582 follows 4 October.
+    pub const REFORM_1582: CalendarMode = CalendarMode::Switchover(2299160.5);
+
+    /// Great Britain, 1752:  14 September 1752 follows 2 September.
+    pub const BRITAIN_1752: CalendarMode = CalendarMode::Switchover(2361221.5);
+
+    /// Whether a year is a leap year.  With a switchover the rule is
+    /// that of the calendar in use on 1 March of the year.
+    pub fn is_leap(&self, iy: i32) -> bool {
+        let gregorian = match *self {
+            CalendarMode::Gregorian => true,
+            CalendarMode::Julian => false,
+            CalendarMode::Switchover(_) => self.is_gregorian(greg_day(iy as i64, 3, 1)),
+        };
+        iy % 4 == 0 && (!gregorian || iy % 100 != 0 || iy % 400 == 0)
+    }
+
+    /// Number of days in a month (im 1-12) of a year, before any days
+    /// dropped at a switchover.
+    pub fn days_in_month(&self, iy: i32, im: i32) -> i32 {
+        match im {
+            2 if self.is_leap(iy) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /* Whether a day (MJD number) is reckoned in the Gregorian calendar. */
+    fn is_gregorian(&self, mjd: i64) -> bool {
+        match *self {
+            CalendarMode::Gregorian => true,
+            CalendarMode::Julian => false,
+            CalendarMode::Switchover(jd) => mjd >= (jd - DJM0).floor() as i64,
+        }
+    }
+}
+
+/* Day counts (MJD) of a calendar date, and back, years starting on
+   1 March so that the leap day comes last (Hinnant 2013). */
+fn greg_day(iy: i64, im: i64, id: i64) -> i64 {
+    let y = if im <= 2 { iy - 1 } else { iy };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((im + 9) % 12) + 2) / 5 + id - 1;
+    era * 146097 + yoe * 365 + yoe / 4 - yoe / 100 + doy + MJD_GREG_MAR0
+}
+
+fn jul_day(iy: i64, im: i64, id: i64) -> i64 {
+    let y = if im <= 2 { iy - 1 } else { iy };
+    let era = y.div_euclid(4);
+    let yoe = y - era * 4;
+    let doy = (153 * ((im + 9) % 12) + 2) / 5 + id - 1;
+    era * 1461 + yoe * 365 + doy + MJD_JUL_MAR0
+}
+
+/* (year, month, day) from the day of a 1 March based year and that
+   year. */
+fn from_doy(y: i64, doy: i64) -> (i32, i32, i32) {
+    let mp = (5 * doy + 2) / 153;
+    let id = doy - (153 * mp + 2) / 5 + 1;
+    let im = if mp < 10 { mp + 3 } else { mp - 9 };
+    ((if im <= 2 { y + 1 } else { y }) as i32, im as i32, id as i32)
+}
+
+fn greg_date(mjd: i64) -> (i32, i32, i32) {
+    let z = mjd - MJD_GREG_MAR0;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    from_doy(era * 400 + yoe, doe - (365 * yoe + yoe / 4 - yoe / 100))
+}
+
+fn jul_date(mjd: i64) -> (i32, i32, i32) {
+    let z = mjd - MJD_JUL_MAR0;
+    let era = z.div_euclid(1461);
+    let doe = z - era * 1461;
+    let yoe = (doe - doe / 1460) / 365;
+    from_doy(era * 4 + yoe, doe - 365 * yoe)
+}
+
+///  Calendar date to Julian Date, in a given calendar
+///
+///  Given:
+///  ```text
+///     mode     CalendarMode   Gregorian, Julian or with a switchover
+///     iy,im,id int            year, month, day in that calendar
+///  ```
+///  Returned (function value):
+///  ```text
+///              double         MJD zero-point:  always 2400000.5
+///              double         Modified Julian Date for 0 hrs
+///  ```
+///  Status values:
+///  ```text
+///     -1   bad year (beyond +/- 1000000)
+///     -2   bad month
+///     -3   bad day
+///     -4   day dropped at the switchover
+///  ```
+///  Notes:
+///
+///  1) In Gregorian mode this is `cal2jd` without its lower limit of
+///     4800 BC;  the results are the same where both succeed.
+///
+///  2) Years are astronomical (see `CalendarMode`).  For example the
+///     Julian-calendar date of the Battle of Actium, 2 September 31 BC,
+///     is (Julian, -30, 9, 2).
+pub fn cal2jd_mode(mode: CalendarMode, iy: i32, im: i32, id: i32) -> Result<(f64, f64), i32> {
+    if !(-IYLIM..=IYLIM).contains(&iy) {
+        return Err(-1);
+    }
+    if !(1..=12).contains(&im) {
+        return Err(-2);
+    }
+    let (y, m, d) = (iy as i64, im as i64, id as i64);
+
+    /* The day in either calendar, then which applies. */
+    let g = greg_day(y, m, d);
+    let j = jul_day(y, m, d);
+    let mjd = if mode.is_gregorian(g) {
+        g
+    } else if !mode.is_gregorian(j) {
+        j
+    } else {
+        return Err(-4);
+    };
+
+    /* The calendar in force decides the month length. */
+    let cal = if mode.is_gregorian(mjd) { CalendarMode::Gregorian } else { CalendarMode::Julian };
+    if id < 1 || id > cal.days_in_month(iy, im) {
+        return Err(-3);
+    }
+    Ok((DJM0, mjd as f64))
+}
+
+///  Julian Date to calendar date, in a given calendar
+///
+///  Given:
+///  ```text
+///     mode     CalendarMode   Gregorian, Julian or with a switchover
+///     dj1,dj2  double         Julian Date (Note 1)
+///  ```
+///  Returned:
+///  ```text
+///     iy       int            year
+///     im       int            month
+///     id       int            day
+///     fd       double         fraction of day
+///  ```
+///  Status values:
+///  ```text
+///     -1   unacceptable date (beyond +/- 3.7e8 days, about a million
+///          years)
+///  ```
+///  Notes:
+///
+///  1) As for `jd2cal`, the date is dj1+dj2 apportioned in any
+///     convenient way.  In Gregorian mode the results are those of
+///     jd2cal, whose lower limit of 4900 BC does not apply.
+///
+///  2) With a switchover, dates from its Julian Date on are Gregorian
+///     and earlier ones Julian.
+pub fn jd2cal_mode(mode: CalendarMode, dj1: f64, dj2: f64) -> Result<(i32, i32, i32, f64), i32> {
+    let dj = dj1 + dj2;
+    if !(-DJLIM..=DJLIM).contains(&dj) {
+        return Err(-1);
+    }
+
+    /* Day (MJD) and fraction, the parts separated first for precision. */
+    let (d1, d2) = (dj1.round(), dj2.round());
+    let mut f = (dj1 - d1) + (dj2 - d2) + 0.5;
+    let mut mjd = d1 as i64 + d2 as i64 - 2400001;
+    let w = f.floor();
+    mjd += w as i64;
+    f -= w;
+    if f >= 1.0 {
+        mjd += 1;
+        f = 0.0;
+    }
+
+    let (iy, im, id) = if mode.is_gregorian(mjd) { greg_date(mjd) } else { jul_date(mjd) };
+    Ok((iy, im, id, f))
+}
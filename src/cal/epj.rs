@@ -0,0 +1,11 @@
+use crate::consts::{DJ00, DJM0, DJM00, DJY};
+
+/// Julian Date to Julian Epoch
+pub fn epj(dj1: f64, dj2: f64) -> f64 {
+    2000.0 + ((dj1 - DJ00) + dj2) / DJY
+}
+
+/// Julian Epoch to Julian Date, as (DJM0, MJD)
+pub fn epj2jd(epj: f64) -> (f64, f64) {
+    (DJM0, DJM00 + (epj - 2000.0) * DJY)
+}
@@ -0,0 +1,206 @@
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use crate::consts::{DAYSEC, DJ00, DJM0};
+
+///  Two-part Julian Date
+///
+///  The pair (date1, date2) taken by the SOFA functions, kept together
+///  so that arithmetic on it does not collapse the two parts into one
+///  f64.  A single f64 JD near 2.45e6 resolves only ~40 microseconds;
+///  the two-part form resolves well below a nanosecond.
+///
+///  Functions that take a date still accept `date1, date2`; pass
+///  `jd.0, jd.1`, destructure with `let (d1, d2) = jd.into()`, or hand
+///  the function itself to `eval`, e.g. `jd.eval(obl06)`.  Each of them
+///  (calendars, time scales, precession-nutation, sidereal time,
+///  astrometry, ephemerides, ecliptic coordinates and orbits) has a `_jd`
+///  form that takes the date as a Jd, or a (date1, date2) tuple, and
+///  returns dates as Jd:  `tttai_jd(jd)?`, `pnm06a_jd(jd)`,
+///  `atco13_jd(.., utc, ..)?`.
+///
+///  Arithmetic uses compensated (two-sum) addition, and intervals are
+///  in days unless stated otherwise.  `normalize` puts the date into
+///  the (midnight, fraction) convention, date1 = N + 0.5 and
+///  0 <= date2 < 1, which is also the form delivered by `dtf2d`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Jd(pub f64, pub f64);
+
+/// Error-free transformation of a sum (Knuth 1969): a + b = s + e.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    let e = (a - (s - bb)) + (b - bb);
+    (s, e)
+}
+
+impl Jd {
+    pub fn new(date1: f64, date2: f64) -> Self {
+        Jd(date1, date2)
+    }
+
+    /// J2000.0 as (2451545.0, 0.0).
+    pub fn j2000() -> Self {
+        Jd(DJ00, 0.0)
+    }
+
+    /// From a Modified Julian Date, as (2400000.5, mjd).
+    pub fn from_mjd(mjd: f64) -> Self {
+        Jd(DJM0, mjd)
+    }
+
+    /// The two parts, in order.
+    pub fn parts(&self) -> (f64, f64) {
+        (self.0, self.1)
+    }
+
+    /// Call a function taking a (date1, date2) pair with this date.
+    pub fn eval<T>(&self, f: impl FnOnce(f64, f64) -> T) -> T {
+        f(self.0, self.1)
+    }
+
+    /// The date as a single f64 (loses precision).
+    pub fn total(&self) -> f64 {
+        self.0 + self.1
+    }
+
+    /// Modified Julian Date as a single f64.
+    pub fn mjd(&self) -> f64 {
+        (self.0 - DJM0) + self.1
+    }
+
+    /// Days since J2000.0, with the subtraction done on the large part.
+    pub fn days_since_j2000(&self) -> f64 {
+        (self.0 - DJ00) + self.1
+    }
+
+    /// Rearrange into date1 = N + 0.5 (the preceding midnight) and
+    /// 0 <= date2 < 1, without loss of precision.
+    pub fn normalize(&self) -> Self {
+        /* Split each part into whole days and a non-negative fraction. */
+        let i1 = (self.0 - 0.5).floor() + 0.5;
+        let f1 = self.0 - i1;
+        let i2 = self.1.floor();
+        let f2 = self.1 - i2;
+
+        /* Combine, carrying any whole day out of the fraction. */
+        let mut day = i1 + i2;
+        let (s, e) = two_sum(f1, f2);
+        let mut f = s + e;
+        if f >= 1.0 {
+            day += 1.0;
+            f -= 1.0;
+        }
+        Jd(day, f)
+    }
+
+    /// Add an interval in SI seconds (86400 s per day).
+    pub fn add_seconds(&self, s: f64) -> Self {
+        *self + s / DAYSEC
+    }
+
+    /// Keep date2 within a day, moving whole days into date1 while
+    /// carrying the rounding error of that move back into date2.
+    fn renorm(date1: f64, date2: f64, err: f64) -> Self {
+        let k = date2.trunc();
+        if k == 0.0 {
+            return Jd(date1, date2 + err);
+        }
+        let (hi, lo) = two_sum(date1, k);
+        Jd(hi, (date2 - k) + (lo + err))
+    }
+}
+
+impl From<(f64, f64)> for Jd {
+    fn from(d: (f64, f64)) -> Self {
+        Jd(d.0, d.1)
+    }
+}
+
+impl From<Jd> for (f64, f64) {
+    fn from(jd: Jd) -> Self {
+        (jd.0, jd.1)
+    }
+}
+
+/// Jd + interval (days)
+impl Add<f64> for Jd {
+    type Output = Jd;
+
+    fn add(self, days: f64) -> Jd {
+        let (s, e) = two_sum(self.1, days);
+        Jd::renorm(self.0, s, e)
+    }
+}
+
+/// Jd - interval (days)
+impl Sub<f64> for Jd {
+    type Output = Jd;
+
+    fn sub(self, days: f64) -> Jd {
+        self + -days
+    }
+}
+
+/// Jd - Jd = interval (days)
+impl Sub<Jd> for Jd {
+    type Output = f64;
+
+    fn sub(self, other: Jd) -> f64 {
+        let (s1, e1) = two_sum(self.0, -other.0);
+        let (s2, e2) = two_sum(self.1, -other.1);
+        let (s, e) = two_sum(s1, s2);
+        s + (e + e1 + e2)
+    }
+}
+
+impl AddAssign<f64> for Jd {
+    fn add_assign(&mut self, days: f64) {
+        *self = *self + days;
+    }
+}
+
+impl SubAssign<f64> for Jd {
+    fn sub_assign(&mut self, days: f64) {
+        *self = *self - days;
+    }
+}
+
+/// Dates compare by value, however they are split.
+impl PartialEq for Jd {
+    fn eq(&self, other: &Jd) -> bool {
+        *self - *other == 0.0
+    }
+}
+
+impl PartialOrd for Jd {
+    fn partial_cmp(&self, other: &Jd) -> Option<Ordering> {
+        (*self - *other).partial_cmp(&0.0)
+    }
+}
+
+/* `_jd` forms of the functions of a two-part date:  the date comes
+   first as anything convertible to a Jd, the other arguments follow.
+   `date` forms return a date (Result<(f64, f64), i32> becoming
+   Result<Jd, i32>), `value` forms the function's own result. */
+macro_rules! jd_forms {
+    ($($kind:ident $name:ident = $f:ident($($a:ident: $t:ty),*) $(-> $r:ty)?;)*) => {$(
+        jd_forms!(@one $kind $name $f ($($a: $t),*) $($r)?);
+    )*};
+    (@one date $name:ident $f:ident ($($a:ident: $t:ty),*)) => {
+        #[doc = concat!("As `", stringify!($f), "`, with the date as a `Jd` (or a (date1, date2) pair) and returning a `Jd`.")]
+        pub fn $name(date: impl Into<$crate::cal::Jd>, $($a: $t),*)
+                     -> Result<$crate::cal::Jd, i32> {
+            let $crate::cal::Jd(d1, d2) = date.into();
+            $f(d1, d2, $($a),*).map($crate::cal::Jd::from)
+        }
+    };
+    (@one value $name:ident $f:ident ($($a:ident: $t:ty),*) $r:ty) => {
+        #[doc = concat!("As `", stringify!($f), "`, with the date as a `Jd` (or a (date1, date2) pair).")]
+        pub fn $name(date: impl Into<$crate::cal::Jd>, $($a: $t),*) -> $r {
+            let $crate::cal::Jd(d1, d2) = date.into();
+            $f(d1, d2, $($a),*)
+        }
+    };
+}
+pub(crate) use jd_forms;
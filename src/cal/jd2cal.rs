@@ -20,7 +20,7 @@ pub fn jd2cal(dj1: f64, dj2: f64) -> Result<(i32, i32, i32, f64), i32> {
 
     /* Verify date is acceptable. */
     dj = dj1 + dj2;
-    if dj < DJMIN || dj > DJMAX {
+    if !(DJMIN..=DJMAX).contains(&dj) {
         return Err(-1);
     }
 
@@ -0,0 +1,13 @@
+use super::*;
+
+jd_forms! {
+    value epb_jd = epb() -> f64;
+    value epj_jd = epj() -> f64;
+    value jd2cal_jd = jd2cal() -> Result<(i32, i32, i32, f64), i32>;
+}
+
+/// As `jd2cal_mode`, with the date as a `Jd` (or a (date1, date2) pair).
+pub fn jd2cal_mode_jd(mode: CalendarMode, date: impl Into<Jd>) -> Result<(i32, i32, i32, f64), i32> {
+    let Jd(dj1, dj2) = date.into();
+    jd2cal_mode(mode, dj1, dj2)
+}
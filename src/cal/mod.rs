@@ -7,5 +7,17 @@ pub use cal2jd::*;
 mod jd2cal;
 pub use jd2cal::*;
 
+mod calendar;
+pub use calendar::*;
+
 mod epb;
-pub use epb::*;
\ No newline at end of file
+pub use epb::*;
+
+mod epj;
+pub use epj::*;
+
+mod jd;
+pub use jd::*;
+
+mod jdforms;
+pub use jdforms::*;
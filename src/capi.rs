@@ -0,0 +1,572 @@
+//! C interface with the SOFA names and signatures
+//!
+//! Each function here is an `extern "C"` wrapper with the same name,
+//! argument order and types as the SOFA ANSI C routine, so that C or
+//! Fortran code written against sofa.h can link against sofars
+//! instead.  Build the library with
+//! ```text
+//!     cargo build --release --features capi
+//! ```
+//! which leaves the shared library (libsofars.so, sofars.dll, ...)
+//! in target/release, and compile the caller against the usual sofa.h;
+//! iauASTROM has the same layout as `IauAstrom`.
+//!
+//! # Safety
+//!
+//! Every pointer argument must be valid for the C type in sofa.h, as
+//! for the SOFA library itself;  the exception is that null output
+//! pointers are allowed and simply not written to.
+//!
+//! Requires the "capi" feature.
+#![allow(non_snake_case, clippy::missing_safety_doc)]
+
+use std::ffi::{c_char, c_int, CStr};
+
+use crate::astro::{
+    apci13, apco13_status, atcc13, atccq, atci13, atciq, atco13_status, atio13_status, atioq,
+    refco, IauAstrom,
+};
+use crate::cal::{cal2jd, jd2cal};
+use crate::coords::{gc2gd, gd2gc};
+use crate::eph::epv00_status;
+use crate::erst::{ee06a, era00, gmst06, gst06, gst06a};
+use crate::horeq::{ae2hd, hd2ae, hd2pa};
+use crate::pnp::{bp00, bpn2xy, c2i06a, c2t06a, nut00a, nut06a, pn00, pnm00a, pnm06a, pom00, s06, sp00};
+use crate::ts::{
+    d2dtf_status, dat_status, dtdb, dtf2d_status, taitt, taiut1, taiutc_status, tcbtdb, tcgtt,
+    tdbtcb, tdbtt, tttai, tttcg, tttdb, utctai_status, utcut1_status,
+};
+use crate::vm::{anp, anpm, c2s, rxp, rxr, s2c, tr};
+
+type Mat = [[f64; 3]; 3];
+type Pv = [[f64; 3]; 2];
+
+/* Write v through p unless p is null. */
+unsafe fn put<T>(p: *mut T, v: T) {
+    if !p.is_null() {
+        unsafe { *p = v };
+    }
+}
+
+/* Status of a two-part date result, writing the parts. */
+unsafe fn put2(r: Result<(f64, f64), i32>, a: *mut f64, b: *mut f64) -> c_int {
+    unsafe { put2s(r.map(|d| (d, 0)), a, b) }
+}
+
+/* The same for a result with a warning status, the parts being
+   written whenever the status is not negative, as in SOFA. */
+unsafe fn put2s(r: Result<((f64, f64), i32), i32>, a: *mut f64, b: *mut f64) -> c_int {
+    match r {
+        Ok(((x, y), j)) => {
+            unsafe {
+                put(a, x);
+                put(b, y);
+            }
+            j
+        }
+        Err(j) => j,
+    }
+}
+
+/* Scale name from a C string;  unknown scales fall through to the
+   UTC test in dtf2d/d2dtf as in SOFA. */
+unsafe fn scale<'a>(s: *const c_char) -> &'a str {
+    if s.is_null() {
+        return "";
+    }
+    unsafe { CStr::from_ptr(s) }.to_str().unwrap_or("")
+}
+
+/* ----------------------------------------------------------------- */
+/* Calendars and time scales                                          */
+/* ----------------------------------------------------------------- */
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauCal2jd(iy: c_int, im: c_int, id: c_int, djm0: *mut f64, djm: *mut f64) -> c_int {
+    unsafe { put2(cal2jd(iy, im, id), djm0, djm) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauJd2cal(dj1: f64, dj2: f64, iy: *mut c_int, im: *mut c_int,
+                                   id: *mut c_int, fd: *mut f64) -> c_int {
+    match jd2cal(dj1, dj2) {
+        Ok((y, m, d, f)) => {
+            unsafe {
+                put(iy, y);
+                put(im, m);
+                put(id, d);
+                put(fd, f);
+            }
+            0
+        }
+        Err(j) => j,
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauDat(iy: c_int, im: c_int, id: c_int, fd: f64, deltat: *mut f64) -> c_int {
+    /* Zero unless a valid result is available, as the C function does. */
+    let (d, j) = dat_status(iy, im, id, fd).unwrap_or_else(|j| (0.0, j));
+    unsafe { put(deltat, d) };
+    j
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauDtf2d(scale_: *const c_char, iy: c_int, im: c_int, id: c_int,
+                                  ihr: c_int, imn: c_int, sec: f64,
+                                  d1: *mut f64, d2: *mut f64) -> c_int {
+    unsafe { put2s(dtf2d_status(scale(scale_), iy, im, id, ihr, imn, sec), d1, d2) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauD2dtf(scale_: *const c_char, ndp: c_int, d1: f64, d2: f64,
+                                  iy: *mut c_int, im: *mut c_int, id: *mut c_int,
+                                  ihmsf: *mut [c_int; 4]) -> c_int {
+    match d2dtf_status(unsafe { scale(scale_) }, ndp, d1, d2) {
+        Ok(((y, m, d, t), j)) => {
+            unsafe {
+                put(iy, y);
+                put(im, m);
+                put(id, d);
+                put(ihmsf, t);
+            }
+            j
+        }
+        Err(j) => j,
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauUtctai(utc1: f64, utc2: f64, tai1: *mut f64, tai2: *mut f64) -> c_int {
+    unsafe { put2s(utctai_status(utc1, utc2), tai1, tai2) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauTaiutc(tai1: f64, tai2: f64, utc1: *mut f64, utc2: *mut f64) -> c_int {
+    unsafe { put2s(taiutc_status(tai1, tai2), utc1, utc2) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauTaitt(tai1: f64, tai2: f64, tt1: *mut f64, tt2: *mut f64) -> c_int {
+    unsafe { put2(taitt(tai1, tai2), tt1, tt2) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauTttai(tt1: f64, tt2: f64, tai1: *mut f64, tai2: *mut f64) -> c_int {
+    unsafe { put2(tttai(tt1, tt2), tai1, tai2) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauUtcut1(utc1: f64, utc2: f64, dut1: f64, ut11: *mut f64, ut12: *mut f64) -> c_int {
+    unsafe { put2s(utcut1_status(utc1, utc2, dut1), ut11, ut12) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauTaiut1(tai1: f64, tai2: f64, dta: f64, ut11: *mut f64, ut12: *mut f64) -> c_int {
+    unsafe { put2(taiut1(tai1, tai2, dta), ut11, ut12) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauTttdb(tt1: f64, tt2: f64, dtr: f64, tdb1: *mut f64, tdb2: *mut f64) -> c_int {
+    unsafe { put2(tttdb(tt1, tt2, dtr), tdb1, tdb2) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauTdbtt(tdb1: f64, tdb2: f64, dtr: f64, tt1: *mut f64, tt2: *mut f64) -> c_int {
+    unsafe { put2(tdbtt(tdb1, tdb2, dtr), tt1, tt2) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauTttcg(tt1: f64, tt2: f64, tcg1: *mut f64, tcg2: *mut f64) -> c_int {
+    unsafe { put2(tttcg(tt1, tt2), tcg1, tcg2) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauTcgtt(tcg1: f64, tcg2: f64, tt1: *mut f64, tt2: *mut f64) -> c_int {
+    unsafe { put2(tcgtt(tcg1, tcg2), tt1, tt2) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauTdbtcb(tdb1: f64, tdb2: f64, tcb1: *mut f64, tcb2: *mut f64) -> c_int {
+    unsafe { put2(tdbtcb(tdb1, tdb2), tcb1, tcb2) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauTcbtdb(tcb1: f64, tcb2: f64, tdb1: *mut f64, tdb2: *mut f64) -> c_int {
+    unsafe { put2(tcbtdb(tcb1, tcb2), tdb1, tdb2) }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn iauDtdb(date1: f64, date2: f64, ut: f64, elong: f64, u: f64, v: f64) -> f64 {
+    dtdb(date1, date2, ut, elong, u, v)
+}
+
+/* ----------------------------------------------------------------- */
+/* Earth rotation and sidereal time                                   */
+/* ----------------------------------------------------------------- */
+
+#[unsafe(no_mangle)]
+pub extern "C" fn iauEra00(dj1: f64, dj2: f64) -> f64 {
+    era00(dj1, dj2)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn iauGmst06(uta: f64, utb: f64, tta: f64, ttb: f64) -> f64 {
+    gmst06(uta, utb, tta, ttb)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauGst06(uta: f64, utb: f64, tta: f64, ttb: f64, rnpb: *const Mat) -> f64 {
+    gst06(uta, utb, tta, ttb, unsafe { &*rnpb })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn iauGst06a(uta: f64, utb: f64, tta: f64, ttb: f64) -> f64 {
+    gst06a(uta, utb, tta, ttb)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn iauEe06a(date1: f64, date2: f64) -> f64 {
+    ee06a(date1, date2)
+}
+
+/* ----------------------------------------------------------------- */
+/* Precession, nutation, polar motion                                 */
+/* ----------------------------------------------------------------- */
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauNut00a(date1: f64, date2: f64, dpsi: *mut f64, deps: *mut f64) {
+    let (dp, de) = nut00a(date1, date2);
+    unsafe {
+        put(dpsi, dp);
+        put(deps, de);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauNut06a(date1: f64, date2: f64, dpsi: *mut f64, deps: *mut f64) {
+    let (dp, de) = nut06a(date1, date2);
+    unsafe {
+        put(dpsi, dp);
+        put(deps, de);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauBp00(date1: f64, date2: f64, rb: *mut Mat, rp: *mut Mat, rbp: *mut Mat) {
+    let [b, p, bp] = bp00(date1, date2);
+    unsafe {
+        put(rb, b);
+        put(rp, p);
+        put(rbp, bp);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauPn00(date1: f64, date2: f64, dpsi: f64, deps: f64, epsa: *mut f64,
+                                 rb: *mut Mat, rp: *mut Mat, rbp: *mut Mat,
+                                 rn: *mut Mat, rbpn: *mut Mat) {
+    let r = pn00(date1, date2, dpsi, deps);
+    unsafe {
+        put(epsa, r.epsa);
+        put(rb, r.rb);
+        put(rp, r.rp);
+        put(rbp, r.rbp);
+        put(rn, r.rn);
+        put(rbpn, r.rbpn);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauPnm00a(date1: f64, date2: f64, rbpn: *mut Mat) {
+    unsafe { put(rbpn, pnm00a(date1, date2)) };
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauPnm06a(date1: f64, date2: f64, rbpn: *mut Mat) {
+    unsafe { put(rbpn, pnm06a(date1, date2)) };
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauBpn2xy(rbpn: *const Mat, x: *mut f64, y: *mut f64) {
+    let (a, b) = bpn2xy(unsafe { &*rbpn });
+    unsafe {
+        put(x, a);
+        put(y, b);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn iauS06(date1: f64, date2: f64, x: f64, y: f64) -> f64 {
+    s06(date1, date2, x, y)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn iauSp00(date1: f64, date2: f64) -> f64 {
+    sp00(date1, date2)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauPom00(xp: f64, yp: f64, sp: f64, rpom: *mut Mat) {
+    unsafe { put(rpom, pom00(xp, yp, sp)) };
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauC2i06a(date1: f64, date2: f64, rc2i: *mut Mat) {
+    unsafe { put(rc2i, c2i06a(date1, date2)) };
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauC2t06a(tta: f64, ttb: f64, uta: f64, utb: f64,
+                                   xp: f64, yp: f64, rc2t: *mut Mat) {
+    unsafe { put(rc2t, c2t06a(tta, ttb, uta, utb, xp, yp)) };
+}
+
+/* ----------------------------------------------------------------- */
+/* Ephemerides and astrometry                                         */
+/* ----------------------------------------------------------------- */
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauEpv00(date1: f64, date2: f64, pvh: *mut Pv, pvb: *mut Pv) -> c_int {
+    let (h, b, j) = epv00_status(date1, date2);
+    unsafe {
+        put(pvh, h);
+        put(pvb, b);
+    }
+    j
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauRefco(phpa: f64, tc: f64, rh: f64, wl: f64, refa: *mut f64, refb: *mut f64) {
+    let (a, b) = refco(phpa, tc, rh, wl);
+    unsafe {
+        put(refa, a);
+        put(refb, b);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauApci13(date1: f64, date2: f64, astrom: *mut IauAstrom, eo: *mut f64) {
+    let (a, e) = apci13(date1, date2);
+    unsafe {
+        put(astrom, a);
+        put(eo, e);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauApco13(utc1: f64, utc2: f64, dut1: f64,
+                                   elong: f64, phi: f64, hm: f64, xp: f64, yp: f64,
+                                   phpa: f64, tc: f64, rh: f64, wl: f64,
+                                   astrom: *mut IauAstrom, eo: *mut f64) -> c_int {
+    match apco13_status(utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl) {
+        Ok(((a, e), j)) => {
+            unsafe {
+                put(astrom, a);
+                put(eo, e);
+            }
+            j
+        }
+        Err(j) => j,
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauAtcc13(rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64,
+                                   date1: f64, date2: f64, ra: *mut f64, da: *mut f64) {
+    let (r, d) = atcc13(rc, dc, pr, pd, px, rv, date1, date2);
+    unsafe {
+        put(ra, r);
+        put(da, d);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauAtccq(rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64,
+                                  astrom: *const IauAstrom, ra: *mut f64, da: *mut f64) {
+    let (r, d) = atccq(rc, dc, pr, pd, px, rv, unsafe { &*astrom });
+    unsafe {
+        put(ra, r);
+        put(da, d);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauAtci13(rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64,
+                                   date1: f64, date2: f64,
+                                   ri: *mut f64, di: *mut f64, eo: *mut f64) {
+    let (r, d, e) = atci13(rc, dc, pr, pd, px, rv, date1, date2);
+    unsafe {
+        put(ri, r);
+        put(di, d);
+        put(eo, e);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauAtciq(rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64,
+                                  astrom: *const IauAstrom, ri: *mut f64, di: *mut f64) {
+    let (r, d) = atciq(rc, dc, pr, pd, px, rv, unsafe { &*astrom });
+    unsafe {
+        put(ri, r);
+        put(di, d);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauAtco13(rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64,
+                                   utc1: f64, utc2: f64, dut1: f64,
+                                   elong: f64, phi: f64, hm: f64, xp: f64, yp: f64,
+                                   phpa: f64, tc: f64, rh: f64, wl: f64,
+                                   aob: *mut f64, zob: *mut f64, hob: *mut f64,
+                                   dob: *mut f64, rob: *mut f64, eo: *mut f64) -> c_int {
+    match atco13_status(rc, dc, pr, pd, px, rv, utc1, utc2, dut1, elong, phi, hm, xp, yp,
+                        phpa, tc, rh, wl) {
+        Ok(((a, z, h, d, r, e), j)) => {
+            unsafe {
+                put(aob, a);
+                put(zob, z);
+                put(hob, h);
+                put(dob, d);
+                put(rob, r);
+                put(eo, e);
+            }
+            j
+        }
+        Err(j) => j,
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauAtio13(ri: f64, di: f64, utc1: f64, utc2: f64, dut1: f64,
+                                   elong: f64, phi: f64, hm: f64, xp: f64, yp: f64,
+                                   phpa: f64, tc: f64, rh: f64, wl: f64,
+                                   aob: *mut f64, zob: *mut f64, hob: *mut f64,
+                                   dob: *mut f64, rob: *mut f64) -> c_int {
+    match atio13_status(ri, di, utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl) {
+        Ok(((a, z, h, d, r), j)) => {
+            unsafe {
+                put(aob, a);
+                put(zob, z);
+                put(hob, h);
+                put(dob, d);
+                put(rob, r);
+            }
+            j
+        }
+        Err(j) => j,
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauAtioq(ri: f64, di: f64, astrom: *const IauAstrom,
+                                  aob: *mut f64, zob: *mut f64, hob: *mut f64,
+                                  dob: *mut f64, rob: *mut f64) {
+    let (a, z, h, d, r) = atioq(ri, di, unsafe { &*astrom });
+    unsafe {
+        put(aob, a);
+        put(zob, z);
+        put(hob, h);
+        put(dob, d);
+        put(rob, r);
+    }
+}
+
+/* ----------------------------------------------------------------- */
+/* Geodetic and horizon coordinates                                   */
+/* ----------------------------------------------------------------- */
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauGd2gc(n: c_int, elong: f64, phi: f64, height: f64, xyz: *mut [f64; 3]) -> c_int {
+    let mut p = [0.0; 3];
+    let j = gd2gc(n, elong, phi, height, &mut p).unwrap_or_else(|j| j);
+
+    /* Zero on error, as the C function does. */
+    unsafe { put(xyz, if j == 0 { p } else { [0.0; 3] }) };
+    j
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauGc2gd(n: c_int, xyz: *const [f64; 3],
+                                  elong: *mut f64, phi: *mut f64, height: *mut f64) -> c_int {
+    match gc2gd(n, unsafe { &*xyz }) {
+        Ok((e, p, h)) => {
+            unsafe {
+                put(elong, e);
+                put(phi, p);
+                put(height, h);
+            }
+            0
+        }
+        Err(j) => j,
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauHd2ae(ha: f64, dec: f64, phi: f64, az: *mut f64, el: *mut f64) {
+    let (a, e) = hd2ae(ha, dec, phi);
+    unsafe {
+        put(az, a);
+        put(el, e);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauAe2hd(az: f64, el: f64, phi: f64, ha: *mut f64, dec: *mut f64) {
+    let (h, d) = ae2hd(az, el, phi);
+    unsafe {
+        put(ha, h);
+        put(dec, d);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn iauHd2pa(ha: f64, dec: f64, phi: f64) -> f64 {
+    hd2pa(ha, dec, phi)
+}
+
+/* ----------------------------------------------------------------- */
+/* Vector/matrix                                                      */
+/* ----------------------------------------------------------------- */
+
+#[unsafe(no_mangle)]
+pub extern "C" fn iauAnp(a: f64) -> f64 {
+    anp(a)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn iauAnpm(a: f64) -> f64 {
+    anpm(a)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauS2c(theta: f64, phi: f64, c: *mut [f64; 3]) {
+    unsafe { put(c, s2c(theta, phi)) };
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauC2s(p: *const [f64; 3], theta: *mut f64, phi: *mut f64) {
+    let (t, f) = c2s(unsafe { &*p });
+    unsafe {
+        put(theta, t);
+        put(phi, f);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauRxp(r: *const Mat, p: *const [f64; 3], rp: *mut [f64; 3]) {
+    let w = rxp(unsafe { &*r }, unsafe { &*p });
+    unsafe { put(rp, w) };
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauRxr(a: *const Mat, b: *const Mat, atb: *mut Mat) {
+    let w = rxr(unsafe { &*a }, unsafe { &*b });
+    unsafe { put(atb, w) };
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn iauTr(r: *const Mat, rt: *mut Mat) {
+    let w = tr(unsafe { &*r });
+    unsafe { put(rt, w) };
+}
@@ -0,0 +1,215 @@
+use super::{iau2015, CMPS, DAU, DAYSEC, GK};
+
+///  Solar-system bodies with tabulated mass and size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Body {
+    Sun,
+    Mercury,
+    Venus,
+    Earth,
+    Moon,
+    Mars,
+    Jupiter,
+    Saturn,
+    Uranus,
+    Neptune,
+}
+
+impl Body {
+    /// The ten bodies, in the order of the `ConstantSet` arrays.
+    pub const ALL: [Body; 10] = [
+        Body::Sun,
+        Body::Mercury,
+        Body::Venus,
+        Body::Earth,
+        Body::Moon,
+        Body::Mars,
+        Body::Jupiter,
+        Body::Saturn,
+        Body::Uranus,
+        Body::Neptune,
+    ];
+}
+
+/* GM (au^3/day^2, with the given au in m) to m^3/s^2. */
+const fn au3d2(gm: f64, au: f64) -> f64 {
+    gm * au * au * au / (DAYSEC * DAYSEC)
+}
+
+/* The Earth and Moon from the Earth-Moon barycenter GM and the
+   Earth/Moon mass ratio. */
+const fn earth(gmb: f64, emrat: f64) -> f64 {
+    gmb * emrat / (1.0 + emrat)
+}
+
+const fn moon(gmb: f64, emrat: f64) -> f64 {
+    gmb / (1.0 + emrat)
+}
+
+/* DE405 (Standish 1998):  au (m), the Sun's GM k^2 (au^3/day^2), the
+   Sun/body mass ratios and EMRAT. */
+const AU405: f64 = 149597870691.0;
+const GMS405: f64 = au3d2(GK * GK, AU405);
+const GMB405: f64 = GMS405 / 328900.5614;
+const EMRAT405: f64 = 81.30056;
+
+/* DE430 (Folkner et al. 2014, Table 8):  GM (km^3/s^2) and EMRAT. */
+const GMB430: f64 = 403503.235502e9;
+const EMRAT430: f64 = 81.30056907419062;
+
+/* Equatorial radii (m), IAU WGCCRE 2015. */
+const RADII: [f64; 10] = [
+    6.957e8, 2.44053e6, 6.0518e6, 6.3781366e6, 1.7374e6,
+    3.39619e6, 7.1492e7, 6.0268e7, 2.5559e7, 2.4764e7,
+];
+
+///  Masses and radii of the Sun, Moon and planets, as one consistent set
+///
+///  ```text
+///     name     identifier of the set
+///     au       astronomical unit used by the set (m)
+///     gm       GM of each Body (m^3/s^2, in Body::ALL order)
+///     radius   equatorial radius of each Body (m)
+///  ```
+///  Notes:
+///
+///  1) The GM of Mars to Neptune is that of the planetary system
+///     (planet and satellites), as the ephemerides give it, except for
+///     Jupiter in IAU2015, whose nominal GM is of the planet alone.
+///
+///  2) IAU2015 is the nominal set (IAU 2015 Resolution B3) for the Sun,
+///     the Earth and Jupiter, with the other planets and the Moon from
+///     the IAU 2009 mass ratios.  DE405 and DE430 are the values with
+///     which those JPL ephemerides were integrated;  when working with
+///     positions from an ephemeris, use its set so that the masses
+///     match the orbits.  The DE headers give only the Earth's and the
+///     Moon's radii, the others being IAU values.
+///
+///  3) For another ephemeris start from the nearest set and replace
+///     what its header gives, for example with GM5 (Jupiter) in
+///     au^3/day^2:
+///  ```text
+///     let set = ConstantSet::DE430.named("DE440").with_gm_au(Body::Jupiter, gm5);
+///  ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConstantSet {
+    pub name: &'static str,
+    pub au: f64,
+    pub gm: [f64; 10],
+    pub radius: [f64; 10],
+}
+
+impl ConstantSet {
+    pub const IAU2015: ConstantSet = ConstantSet {
+        name: "IAU2015",
+        au: DAU,
+        gm: [
+            iau2015::GM_SUN,
+            iau2015::GM_SUN / 6.0236e6,
+            iau2015::GM_SUN / 4.08523719e5,
+            iau2015::GM_EARTH,
+            iau2015::GM_EARTH * 1.23000371e-2,
+            iau2015::GM_SUN / 3.09870359e6,
+            iau2015::GM_JUPITER,
+            iau2015::GM_SUN / 3.4979018e3,
+            iau2015::GM_SUN / 2.290298e4,
+            iau2015::GM_SUN / 1.941226e4,
+        ],
+        radius: [
+            iau2015::R_SUN, RADII[1], RADII[2], iau2015::R_EARTH_EQ, RADII[4],
+            RADII[5], iau2015::R_JUPITER_EQ, RADII[7], RADII[8], RADII[9],
+        ],
+    };
+
+    pub const DE405: ConstantSet = ConstantSet {
+        name: "DE405",
+        au: AU405,
+        gm: [
+            GMS405,
+            GMS405 / 6023600.0,
+            GMS405 / 408523.71,
+            earth(GMB405, EMRAT405),
+            moon(GMB405, EMRAT405),
+            GMS405 / 3098708.0,
+            GMS405 / 1047.3486,
+            GMS405 / 3497.898,
+            GMS405 / 22902.98,
+            GMS405 / 19412.24,
+        ],
+        radius: [
+            RADII[0], RADII[1], RADII[2], 6.378137e6, 1.738e6,
+            RADII[5], RADII[6], RADII[7], RADII[8], RADII[9],
+        ],
+    };
+
+    pub const DE430: ConstantSet = ConstantSet {
+        name: "DE430",
+        au: DAU,
+        gm: [
+            132712440041.9394e9,
+            22031.780000e9,
+            324858.592000e9,
+            earth(GMB430, EMRAT430),
+            moon(GMB430, EMRAT430),
+            42828.375214e9,
+            126712764.800000e9,
+            37940585.200000e9,
+            5794548.600000e9,
+            6836527.100580e9,
+        ],
+        radius: [
+            RADII[0], RADII[1], RADII[2], 6.3781366e6, 1.738e6,
+            RADII[5], RADII[6], RADII[7], RADII[8], RADII[9],
+        ],
+    };
+
+    /// GM of a body (m^3/s^2).
+    pub fn gm(&self, body: Body) -> f64 {
+        self.gm[body as usize]
+    }
+
+    /// Equatorial radius of a body (m).
+    pub fn radius(&self, body: Body) -> f64 {
+        self.radius[body as usize]
+    }
+
+    /// Mass of a body in solar masses, as `ld` and `IauLdBody` take it.
+    pub fn mass_ratio(&self, body: Body) -> f64 {
+        self.gm(body) / self.gm(Body::Sun)
+    }
+
+    /// Schwarzschild radius 2GM/c^2 of a body (au);  for the Sun, `SRS`.
+    pub fn srs(&self, body: Body) -> f64 {
+        2.0 * self.gm(body) / (CMPS * CMPS) / self.au
+    }
+
+    /// The same values under another name.
+    pub fn named(mut self, name: &'static str) -> Self {
+        self.name = name;
+        self
+    }
+
+    /// Replace the GM of a body (m^3/s^2).
+    pub fn with_gm(mut self, body: Body, gm: f64) -> Self {
+        self.gm[body as usize] = gm;
+        self
+    }
+
+    /// Replace the GM of a body, given as in a DE header (au^3/day^2).
+    pub fn with_gm_au(self, body: Body, gm: f64) -> Self {
+        let au = self.au;
+        self.with_gm(body, au3d2(gm, au))
+    }
+
+    /// Replace the equatorial radius of a body (m).
+    pub fn with_radius(mut self, body: Body, radius: f64) -> Self {
+        self.radius[body as usize] = radius;
+        self
+    }
+}
+
+impl Default for ConstantSet {
+    fn default() -> Self {
+        ConstantSet::IAU2015
+    }
+}
@@ -0,0 +1,41 @@
+//! IAU 2015 nominal solar and planetary conversion constants
+//!
+//! Resolution B3 of the IAU 2015 General Assembly defines these as
+//! exact values, to be used to express stellar and planetary
+//! properties in nominal units (for example R = 2.5 R_sun^N).  They are
+//! conversion constants, not best estimates:  the Sun's GM as fitted
+//! by the JPL ephemerides differs from GM_SUN in the eighth figure (see
+//! `ConstantSet`).
+
+/// Nominal solar radius (m)
+pub const R_SUN: f64 = 6.957e8;
+
+/// Nominal total solar irradiance (W/m^2)
+pub const S_SUN: f64 = 1361.0;
+
+/// Nominal solar luminosity (W)
+pub const L_SUN: f64 = 3.828e26;
+
+/// Nominal solar effective temperature (K)
+pub const T_SUN: f64 = 5772.0;
+
+/// Nominal solar mass parameter (m^3/s^2)
+pub const GM_SUN: f64 = 1.3271244e20;
+
+/// Nominal terrestrial equatorial radius (m)
+pub const R_EARTH_EQ: f64 = 6.3781e6;
+
+/// Nominal terrestrial polar radius (m)
+pub const R_EARTH_POLAR: f64 = 6.3568e6;
+
+/// Nominal jovian equatorial radius (m)
+pub const R_JUPITER_EQ: f64 = 7.1492e7;
+
+/// Nominal jovian polar radius (m)
+pub const R_JUPITER_POLAR: f64 = 6.6854e7;
+
+/// Nominal terrestrial mass parameter (m^3/s^2)
+pub const GM_EARTH: f64 = 3.986004e14;
+
+/// Nominal jovian mass parameter (m^3/s^2)
+pub const GM_JUPITER: f64 = 1.2668653e17;
@@ -79,6 +79,18 @@ pub const TDB0: f64 = -6.55e-5;
 /// Schwarzschild radius of the Sun (au)
 pub const SRS: f64 = 1.97412574336e-8;
 
+/// Geocentric gravitational constant (m^3/s^2, TCB-compatible, IAU 2009)
+pub const GM_EARTH: f64 = 3.986004418e14;
+
+/// Obliquity of the ecliptic at J2000.0, IAU 2006 (radians)
+pub const EPS0_06: f64 = 84381.406 * DAS2R;
+
+/// Obliquity of the ecliptic at J2000.0, IAU 1976 (radians)
+pub const EPS0_80: f64 = 84381.448 * DAS2R;
+
+/// Gaussian gravitational constant (au^3/2 per day, solar mass units)
+pub const GK: f64 = 0.017202098950;
+
 /// Reference ellipsoids
 pub const WGS84: i32 = 1;
 pub const GRS80: i32 = 2;
@@ -92,3 +104,8 @@ pub const MTAB: [i32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
 
 /// J2000.0-B1900.0 (2415019.81352) in days
 pub const D1900: f64 = 36524.68648;
+
+pub mod iau2015;
+
+mod bodies;
+pub use bodies::*;
@@ -1,29 +1,6 @@
-use crate::consts::{GRS80, WGS72, WGS84};
+use crate::consts::{DPI, GRS80, WGS72, WGS84};
 use crate::vm::zp;
-/// ecliptic to ICRS, IAU 2006
-pub fn eceq06() {}
-
-/// rotation matrix, ICRS to ecliptic, IAU 2006
-pub fn ecm06() {}
-
-/// ICRS to ecliptic, IAU 2006
-pub fn eqec06() {}
-
-/// ecliptic to ICRS, long term
-pub fn lteceq() {}
-
-/// rotation matrix, ICRS to ecliptic, long-term
-pub fn ltecm() {}
-
-/// ICRS to ecliptic, long term
-pub fn lteqec() {}
-
-/// transform IAU 1958 galactic coordinates to ICRS
-pub fn g2icrs() {}
-
-/// transform ICRS coordinates to IAU 1958 Galactic
-pub fn icrs2g() {}
-
+use crate::math::{atan, atan2, cos, sin};
 /// a,f for a nominated Earth reference ellipsoid
 pub fn eform(n: i32) -> Result<(f64, f64), i32> {
     match n {
@@ -35,10 +12,162 @@ pub fn eform(n: i32) -> Result<(f64, f64), i32> {
 }
 
 /// geocentric to geodetic for a nominated ellipsoid
-pub fn gc2gd() {}
+///
+///  Transform geocentric coordinates to geodetic using the specified
+///  reference ellipsoid.
+///
+///  Given:
+///  ```text
+///     n       int        ellipsoid identifier (WGS84, GRS80 or WGS72)
+///     xyz     double[3]  geocentric vector (Note 1)
+///  ```
+///  Returned:
+///  ```text
+///     elong   double     longitude (radians, east +ve)
+///     phi     double     latitude (geodetic, radians)
+///     height  double     height above ellipsoid (geodetic, Note 1)
+///  ```
+///  Returned (function value):
+///  ```text
+///             int        status:  0 = OK
+///                                -1 = illegal identifier
+///                                -2 = internal error
+///  ```
+///  Notes:
+///
+///  1) The geocentric vector (xyz, given) and height (height, returned)
+///     are in meters.
+///
+///  2) No validation is performed on the arguments; an illegal
+///     identifier is reported as Err(-1).
+pub fn gc2gd(n: i32, xyz: &[f64; 3]) -> Result<(f64, f64, f64), i32> {
+    let (a, f) = eform(n)?;
+    gc2gde(a, f, xyz).map_err(|_| -2)
+}
 
 /// geocentric to geodetic given ellipsoid a,f
-pub fn gc2gde() {}
+///
+///  Transform geocentric coordinates to geodetic for a reference
+///  ellipsoid of specified form.
+///
+///  Given:
+///  ```text
+///     a       double     equatorial radius (Notes 2,4)
+///     f       double     flattening (Note 3)
+///     xyz     double[3]  geocentric vector (Note 4)
+///  ```
+///  Returned:
+///  ```text
+///     elong   double     longitude (radians, east +ve)
+///     phi     double     latitude (geodetic, radians)
+///     height  double     height above ellipsoid (geodetic, Note 4)
+///  ```
+///  Returned (function value):
+///  ```text
+///             int        status:  0 = OK
+///                                -1 = illegal f
+///                                -2 = illegal a
+///  ```
+///  Notes:
+///
+///  1) This function is based on the GCONV2H Fortran subroutine by
+///     Toshio Fukushima (see reference).
+///
+///  2) The equatorial radius, a, can be in any units, but meters is
+///     the conventional choice.
+///
+///  3) The flattening, f, is (for the Earth) a value around 0.00335,
+///     i.e. around 1/298.
+///
+///  4) The equatorial radius, a, and the geocentric vector, xyz,
+///     must be given in the same units, and determine the units of
+///     the returned height, height.
+///
+///  Reference:
+///  ```text
+///     Fukushima, T., "Transformation from Cartesian to geodetic
+///     coordinates accelerated by Halley's method", J.Geodesy (2006)
+///     79: 689-693
+///  ```
+pub fn gc2gde(a: f64, f: f64, xyz: &[f64; 3]) -> Result<(f64, f64, f64), i32> {
+    /* Validate ellipsoid parameters. */
+    if !(0.0..1.0).contains(&f) {
+        return Err(-1);
+    }
+    if a <= 0.0 {
+        return Err(-2);
+    }
+
+    /* Functions of ellipsoid parameters (with further validation of f). */
+    let aeps2 = a * a * 1e-32;
+    let e2 = (2.0 - f) * f;
+    let e4t = e2 * e2 * 1.5;
+    let ec2 = 1.0 - e2;
+    if ec2 <= 0.0 {
+        return Err(-1);
+    }
+    let ec = ec2.sqrt();
+    let b = a * ec;
+
+    /* Cartesian components. */
+    let [x, y, z] = *xyz;
+
+    /* Distance from polar axis squared. */
+    let p2 = x * x + y * y;
+
+    /* Longitude. */
+    let elong = if p2 > 0.0 { atan2(y, x) } else { 0.0 };
+
+    /* Unsigned z-coordinate. */
+    let absz = z.abs();
+
+    let (mut phi, height);
+
+    /* Proceed unless polar case. */
+    if p2 > aeps2 {
+        /* Distance from polar axis. */
+        let p = p2.sqrt();
+
+        /* Normalization. */
+        let s0 = absz / a;
+        let pn = p / a;
+        let zc = ec * s0;
+
+        /* Prepare Newton correction factors. */
+        let c0 = ec * pn;
+        let c02 = c0 * c0;
+        let c03 = c02 * c0;
+        let s02 = s0 * s0;
+        let s03 = s02 * s0;
+        let a02 = c02 + s02;
+        let a0 = a02.sqrt();
+        let a03 = a02 * a0;
+        let d0 = zc * a03 + e2 * s03;
+        let f0 = pn * a03 - e2 * c03;
+
+        /* Prepare Halley correction factor. */
+        let b0 = e4t * s02 * c02 * pn * (a0 - ec);
+        let s1 = d0 * f0 - b0 * s0;
+        let cc = ec * (f0 * f0 - b0 * c0);
+
+        /* Evaluate latitude and height. */
+        phi = atan(s1 / cc);
+        let s12 = s1 * s1;
+        let cc2 = cc * cc;
+        height = (p * cc + absz * s1 - a * (ec2 * s12 + cc2).sqrt()) / (s12 + cc2).sqrt();
+    } else {
+        /* Exception: pole. */
+        phi = DPI / 2.0;
+        height = absz - b;
+    }
+
+    /* Restore sign of latitude. */
+    if z < 0.0 {
+        phi = -phi;
+    }
+
+    Ok((elong, phi, height))
+}
 
 /// geodetic to geocentric for a nominated ellipsoid
 pub fn gd2gc(n: i32, elong: f64, phi: f64, height: f64, xyz: &mut [f64; 3]) -> Result<i32, i32> {
@@ -66,8 +195,8 @@ pub fn gd2gce(
     let (sp, cp, mut w, d, ac, as_, r);
 
     /* Functions of geodetic latitude. */
-    sp = phi.sin();
-    cp = phi.cos();
+    sp = sin(phi);
+    cp = cos(phi);
     w = 1.0 - f;
     w = w * w;
     d = cp * cp + w * sp * sp;
@@ -79,8 +208,8 @@ pub fn gd2gce(
 
     /* Geocentric vector. */
     r = (ac + height) * cp;
-    xyz[0] = r * elong.cos();
-    xyz[1] = r * elong.sin();
+    xyz[0] = r * cos(elong);
+    xyz[1] = r * sin(elong);
     xyz[2] = (as_ + height) * sp;
 
     /* Success. */
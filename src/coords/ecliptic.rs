@@ -0,0 +1,307 @@
+use crate::consts::DAS2R;
+use crate::pnp::{ltpecl, ltpequ, obl06, pmat06};
+use crate::vm::{anp, anpm, c2s, ir, pn, pxp, rx, rxp, rxr, s2c, trxp};
+
+///  ICRS equatorial to ecliptic rotation matrix, IAU 2006.
+///
+///  Given:
+///  ```text
+///     date1,date2  double         TT as a 2-part Julian date (Note 1)
+///  ```
+///  Returned (function value):
+///  ```text
+///                  double[3][3]   ICRS to ecliptic rotation matrix
+///  ```
+///  Notes:
+///
+///  1) The TT date date1+date2 is a Julian Date, apportioned in any
+///     convenient way between the two arguments.
+///
+///  2) The matrix is in the sense
+///
+///        E_ep = rm x P_ICRS,
+///
+///     where P_ICRS is a vector with respect to ICRS right ascension
+///     and declination axes and E_ep is the same vector with respect to
+///     the (inertial) ecliptic and equinox of date.
+///
+///  3) P_ICRS is a free vector, merely a direction, typically of unit
+///     magnitude, and not bound to any particular spatial origin, such
+///     as the Earth, Sun or SSB.  No assumptions are made about whether
+///     it represents starlight and embodies astrometric effects such as
+///     parallax or aberration.  The transformation is approximately that
+///     between mean J2000.0 right ascension and declination and ecliptic
+///     longitude and latitude, with only frame bias (always less than
+///     25 mas) to disturb this classical picture.
+///
+///  Called:
+///  ```text
+///     iauObl06     mean obliquity, IAU 2006
+///     iauPmat06    PB matrix, IAU 2006
+///     iauIr        initialize r-matrix to identity
+///     iauRx        rotate around X-axis
+///     iauRxr       product of two r-matrices
+///  ```
+pub fn ecm06(date1: f64, date2: f64) -> [[f64; 3]; 3] {
+    /* Obliquity, IAU 2006. */
+    let ob = obl06(date1, date2);
+
+    /* Precession-bias matrix, IAU 2006. */
+    let bp = pmat06(date1, date2);
+
+    /* Equatorial of date to ecliptic matrix. */
+    let mut e = [[0.0; 3]; 3];
+    ir(&mut e);
+    rx(ob, &mut e);
+
+    /* ICRS to ecliptic coordinates rotation matrix, IAU 2006. */
+    rxr(&e, &bp)
+}
+
+///  Transformation from ICRS equatorial coordinates to ecliptic
+///  coordinates (mean equinox and ecliptic of date) using IAU 2006
+///  precession model.
+///
+///  Given:
+///  ```text
+///     date1,date2 double TT as a 2-part Julian date (Note 1)
+///     dr,dd       double ICRS right ascension and declination (radians)
+///  ```
+///  Returned:
+///  ```text
+///     dl,db       double ecliptic longitude and latitude (radians)
+///  ```
+///  Notes:
+///
+///  1) The TT date date1+date2 is a Julian Date, apportioned in any
+///     convenient way between the two arguments.
+///
+///  2) No assumptions are made about whether the coordinates represent
+///     starlight and embody astrometric effects such as parallax or
+///     aberration.
+///
+///  3) The transformation is approximately that from mean J2000.0 right
+///     ascension and declination to ecliptic longitude and latitude
+///     (mean equinox and ecliptic of date), with only frame bias (always
+///     less than 25 mas) to disturb this classical picture.
+pub fn eqec06(date1: f64, date2: f64, dr: f64, dd: f64) -> (f64, f64) {
+    /* Spherical to Cartesian. */
+    let v1 = s2c(dr, dd);
+
+    /* Rotation matrix, ICRS equatorial to ecliptic. */
+    let rm = ecm06(date1, date2);
+
+    /* The transformation from ICRS to ecliptic. */
+    let v2 = rxp(&rm, &v1);
+
+    /* Cartesian to spherical. */
+    let (a, b) = c2s(&v2);
+
+    /* Express in conventional ranges. */
+    (anp(a), anpm(b))
+}
+
+///  Transformation from ecliptic coordinates (mean equinox and ecliptic
+///  of date) to ICRS RA,Dec, using the IAU 2006 precession model.
+///
+///  Given:
+///  ```text
+///     date1,date2 double TT as a 2-part Julian date (Note 1)
+///     dl,db       double ecliptic longitude and latitude (radians)
+///  ```
+///  Returned:
+///  ```text
+///     dr,dd       double ICRS right ascension and declination (radians)
+///  ```
+///  Notes:
+///
+///  1) The TT date date1+date2 is a Julian Date, apportioned in any
+///     convenient way between the two arguments.
+///
+///  2) No assumptions are made about whether the coordinates represent
+///     starlight and embody astrometric effects such as parallax or
+///     aberration.
+///
+///  3) The transformation is approximately that from ecliptic longitude
+///     and latitude (mean equinox and ecliptic of date) to mean J2000.0
+///     right ascension and declination, with only frame bias (always
+///     less than 25 mas) to disturb this classical picture.
+pub fn eceq06(date1: f64, date2: f64, dl: f64, db: f64) -> (f64, f64) {
+    /* Spherical to Cartesian. */
+    let v1 = s2c(dl, db);
+
+    /* Rotation matrix, ICRS equatorial to ecliptic. */
+    let rm = ecm06(date1, date2);
+
+    /* The transformation from ecliptic to ICRS. */
+    let v2 = trxp(&rm, &v1);
+
+    /* Cartesian to spherical. */
+    let (a, b) = c2s(&v2);
+
+    /* Express in conventional ranges. */
+    (anp(a), anpm(b))
+}
+
+///  ICRS equatorial to ecliptic rotation matrix, long-term.
+///
+///  Given:
+///  ```text
+///     epj     double         Julian epoch (TT)
+///  ```
+///  Returned (function value):
+///  ```text
+///             double[3][3]   ICRS to ecliptic rotation matrix
+///  ```
+///  Notes:
+///
+///  1) The matrix is in the sense
+///
+///        E_ep = rm x P_ICRS,
+///
+///     where P_ICRS is a vector with respect to ICRS right ascension
+///     and declination axes and E_ep is the same vector with respect to
+///     the (inertial) ecliptic and equinox of epoch epj.
+///
+///  2) P_ICRS is a free vector, merely a direction, typically of unit
+///     magnitude, and not bound to any particular spatial origin, such
+///     as the Earth, Sun or SSB.  No assumptions are made about whether
+///     it represents starlight and embodies astrometric effects such as
+///     parallax or aberration.  The transformation is approximately that
+///     between mean J2000.0 right ascension and declination and ecliptic
+///     longitude and latitude, with only frame bias (always less than
+///     25 mas) to disturb this classical picture.
+///
+///  3) The Vondrak et al. (2011, 2012) 400 millennia precession model
+///     agrees with the IAU 2006 precession at J2000.0 and stays within
+///     100 microarcseconds during the 20th and 21st centuries.  It is
+///     accurate to a few arcseconds throughout the historical period,
+///     worsening to a few tenths of a degree at the end of the
+///     +/- 200,000 year time span.
+///
+///  Called:
+///  ```text
+///     iauLtpequ    equator pole, long term
+///     iauLtpecl    ecliptic pole, long term
+///     iauPxp       vector product
+///     iauPn        normalize vector
+///  ```
+///  References:
+///
+///     Vondrak, J., Capitaine, N. and Wallace, P., 2011, New precession
+///     expressions, valid for long time intervals, Astron.Astrophys. 534,
+///     A22
+///
+///     Vondrak, J., Capitaine, N. and Wallace, P., 2012, New precession
+///     expressions, valid for long time intervals (Corrigendum),
+///     Astron.Astrophys. 541, C1
+pub fn ltecm(epj: f64) -> [[f64; 3]; 3] {
+    /* Frame bias (IERS Conventions 2010, Eqs. 5.21 and 5.33) */
+    const DX: f64 = -0.016617 * DAS2R;
+    const DE: f64 = -0.0068192 * DAS2R;
+    const DR: f64 = -0.0146 * DAS2R;
+
+    /* Equator pole. */
+    let p = ltpequ(epj);
+
+    /* Ecliptic pole (bottom row of equatorial to ecliptic matrix). */
+    let z = ltpecl(epj);
+
+    /* Equinox (top row of matrix). */
+    let (_, x) = pn(&pxp(&p, &z));
+
+    /* Middle row of matrix. */
+    let y = pxp(&z, &x);
+
+    /* Combine with frame bias. */
+    [x, y, z].map(|r| {
+        [
+            r[0] - r[1] * DR + r[2] * DX,
+            r[0] * DR + r[1] + r[2] * DE,
+            -r[0] * DX - r[1] * DE + r[2],
+        ]
+    })
+}
+
+///  Transformation from ICRS equatorial coordinates to ecliptic
+///  coordinates (mean equinox and ecliptic of date) using a long-term
+///  precession model.
+///
+///  Given:
+///  ```text
+///     epj     double     Julian epoch (TT)
+///     dr,dd   double     ICRS right ascension and declination (radians)
+///  ```
+///  Returned:
+///  ```text
+///     dl,db   double     ecliptic longitude and latitude (radians)
+///  ```
+///  Notes:
+///
+///  1) No assumptions are made about whether the coordinates represent
+///     starlight and embody astrometric effects such as parallax or
+///     aberration.
+///
+///  2) The transformation is approximately that from mean J2000.0 right
+///     ascension and declination to ecliptic longitude and latitude
+///     (mean equinox and ecliptic of date), with only frame bias (always
+///     less than 25 mas) to disturb this classical picture.
+///
+///  3) See ltecm for the accuracy of the long-term precession model.
+pub fn lteqec(epj: f64, dr: f64, dd: f64) -> (f64, f64) {
+    /* Spherical to Cartesian. */
+    let v1 = s2c(dr, dd);
+
+    /* Rotation matrix, ICRS equatorial to ecliptic. */
+    let rm = ltecm(epj);
+
+    /* The transformation from ICRS to ecliptic. */
+    let v2 = rxp(&rm, &v1);
+
+    /* Cartesian to spherical. */
+    let (a, b) = c2s(&v2);
+
+    /* Express in conventional ranges. */
+    (anp(a), anpm(b))
+}
+
+///  Transformation from ecliptic coordinates (mean equinox and ecliptic
+///  of date) to ICRS RA,Dec, using a long-term precession model.
+///
+///  Given:
+///  ```text
+///     epj     double     Julian epoch (TT)
+///     dl,db   double     ecliptic longitude and latitude (radians)
+///  ```
+///  Returned:
+///  ```text
+///     dr,dd   double     ICRS right ascension and declination (radians)
+///  ```
+///  Notes:
+///
+///  1) No assumptions are made about whether the coordinates represent
+///     starlight and embody astrometric effects such as parallax or
+///     aberration.
+///
+///  2) The transformation is approximately that from ecliptic longitude
+///     and latitude (mean equinox and ecliptic of date) to mean J2000.0
+///     right ascension and declination, with only frame bias (always
+///     less than 25 mas) to disturb this classical picture.
+///
+///  3) See ltecm for the accuracy of the long-term precession model.
+pub fn lteceq(epj: f64, dl: f64, db: f64) -> (f64, f64) {
+    /* Spherical to Cartesian. */
+    let v1 = s2c(dl, db);
+
+    /* Rotation matrix, ICRS equatorial to ecliptic. */
+    let rm = ltecm(epj);
+
+    /* The transformation from ecliptic to ICRS. */
+    let v2 = trxp(&rm, &v1);
+
+    /* Cartesian to spherical. */
+    let (a, b) = c2s(&v2);
+
+    /* Express in conventional ranges. */
+    (anp(a), anpm(b))
+}
@@ -0,0 +1,79 @@
+use super::{eform, gc2gde, gd2gce};
+
+///  Reference ellipsoid
+///
+///  An Earth reference ellipsoid given by its equatorial radius and
+///  flattening.  The three SOFA ellipsoids are available as associated
+///  constants; any other figure (a local datum, another planet, ...)
+///  can be built with `Ellipsoid::new`.
+///
+///  ```text
+///     a       double     equatorial radius (meters)
+///     f       double     flattening
+///  ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipsoid {
+    pub a: f64,
+    pub f: f64,
+}
+
+impl Ellipsoid {
+    /// WGS84 ellipsoid
+    pub const WGS84: Ellipsoid = Ellipsoid { a: 6378137.0, f: 1.0 / 298.257223563 };
+
+    /// GRS80 ellipsoid
+    pub const GRS80: Ellipsoid = Ellipsoid { a: 6378137.0, f: 1.0 / 298.257222101 };
+
+    /// WGS72 ellipsoid
+    pub const WGS72: Ellipsoid = Ellipsoid { a: 6378135.0, f: 1.0 / 298.26 };
+
+    /// User-defined ellipsoid.
+    ///
+    /// Returns Err(-1) for an illegal flattening (outside 0 <= f < 1)
+    /// and Err(-2) for a non-positive equatorial radius, the same
+    /// status values as `gc2gde`.
+    pub fn new(a: f64, f: f64) -> Result<Self, i32> {
+        if !(0.0..1.0).contains(&f) {
+            return Err(-1);
+        }
+        if a <= 0.0 {
+            return Err(-2);
+        }
+        Ok(Ellipsoid { a, f })
+    }
+
+    /// Ellipsoid from one of the SOFA identifiers (WGS84, GRS80, WGS72).
+    pub fn from_id(n: i32) -> Result<Self, i32> {
+        eform(n).map(|(a, f)| Ellipsoid { a, f })
+    }
+
+    /// Polar radius, b = a(1-f).
+    pub fn b(&self) -> f64 {
+        self.a * (1.0 - self.f)
+    }
+
+    /// First eccentricity squared, e^2 = f(2-f).
+    pub fn e2(&self) -> f64 {
+        self.f * (2.0 - self.f)
+    }
+
+    /// Geodetic (elong, phi, height) to geocentric vector.
+    ///
+    /// Heights and the returned vector are in the units of `a`.
+    pub fn gd2gc(&self, elong: f64, phi: f64, height: f64) -> Result<[f64; 3], i32> {
+        let mut xyz = [0.0; 3];
+        gd2gce(self.a, self.f, elong, phi, height, &mut xyz)?;
+        Ok(xyz)
+    }
+
+    /// Geocentric vector to geodetic (elong, phi, height).
+    pub fn gc2gd(&self, xyz: &[f64; 3]) -> Result<(f64, f64, f64), i32> {
+        gc2gde(self.a, self.f, xyz)
+    }
+}
+
+impl Default for Ellipsoid {
+    fn default() -> Self {
+        Self::WGS84
+    }
+}
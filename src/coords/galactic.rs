@@ -0,0 +1,397 @@
+use crate::consts::{DAU, DAYSEC, DJY, DR2AS};
+use crate::math::sin_cos;
+use crate::star::Star;
+use crate::vm::{anp, anpm, c2s, rxp, s2c, trxp};
+
+///  Rotation matrix, ICRS to IAU 1958 Galactic
+///
+///  The ICRS to Galactic rotation matrix obtained by regarding the
+///  Hipparcos catalog as an ICRS realization, and the IAU 1958 Galactic
+///  north pole and longitude of the ascending node, given in FK4 B1950,
+///  as exact (Hipparcos Catalogue, Vol. 1, §1.5.3):
+///  ```text
+///     alpha_G = 192.85948 deg, delta_G = +27.12825 deg, l_Omega = 32.93192 deg
+///  ```
+#[allow(clippy::excessive_precision)]
+pub const RM_ICRS2G: [[f64; 3]; 3] = [
+    [-0.054875560416215368492398900454, -0.873437090234885048760383168409, -0.483835015548713226831774175116],
+    [0.494109427875583673525222371358, -0.444829629960011178146614061616, 0.746982244497218890527388004556],
+    [-0.867666149019004701181616534570, -0.198076373431201528180486091412, 0.455983776175066922272100478348],
+];
+
+///  Rotation matrix, Galactic to supergalactic
+///
+///  The supergalactic system of de Vaucouleurs et al. (1976): the
+///  supergalactic north pole is at Galactic l = 47.37 deg, b = +6.32 deg
+///  and the origin of supergalactic longitude at l = 137.37 deg, b = 0.
+///  The rows are the supergalactic axes in Galactic coordinates.
+#[allow(clippy::excessive_precision)]
+pub const RM_G2SG: [[f64; 3]; 3] = [
+    [-0.7357425748043749365131, 0.6772612964138942665042, 0.0],
+    [-0.07455377836523374886064, -0.08099147130697673240145, 0.9939225903997749305161],
+    [0.6731453021092075890651, 0.7312711658169645169504, 0.1100812622247820663537],
+];
+
+/* km/s per au/year (Julian year). */
+const AUYR2KMS: f64 = DAU / 1e3 / (DJY * DAYSEC);
+
+/* Rotate spherical coordinates through r, or through its transpose. */
+fn rotsph(r: &[[f64; 3]; 3], inverse: bool, a: f64, b: f64) -> (f64, f64) {
+    let v = s2c(a, b);
+    let v = if inverse { trxp(r, &v) } else { rxp(r, &v) };
+    let (a, b) = c2s(&v);
+    (anp(a), anpm(b))
+}
+
+/* Unit vector of direction (a,b) and its rate of change for rates
+   da/dt, db/dt. */
+fn s2cdot(a: f64, b: f64, pma: f64, pmb: f64) -> ([f64; 3], [f64; 3]) {
+    let (sa, ca) = sin_cos(a);
+    let (sb, cb) = sin_cos(b);
+    let u = [ca * cb, sa * cb, sb];
+    let du = [
+        -sa * cb * pma - ca * sb * pmb,
+        ca * cb * pma - sa * sb * pmb,
+        cb * pmb,
+    ];
+    (u, du)
+}
+
+/* Rotate spherical coordinates and their rates through r, or through
+   its transpose. */
+fn rotpm(
+    r: &[[f64; 3]; 3],
+    inverse: bool,
+    a: f64,
+    b: f64,
+    pma: f64,
+    pmb: f64,
+) -> (f64, f64, f64, f64) {
+    let (u, du) = s2cdot(a, b, pma, pmb);
+    let (u, du) = if inverse {
+        (trxp(r, &u), trxp(r, &du))
+    } else {
+        (rxp(r, &u), rxp(r, &du))
+    };
+    let [x, y, _] = u;
+    let (a, b) = c2s(&u);
+    let w2 = x * x + y * y;
+    let (pma, pmb) = if w2 > 0.0 {
+        ((x * du[1] - y * du[0]) / w2, du[2] / w2.sqrt())
+    } else {
+        (0.0, 0.0)
+    };
+    (anp(a), anpm(b), pma, pmb)
+}
+
+///  Transformation from ICRS to Galactic Coordinates.
+///
+///  Given:
+///  ```text
+///     dr     double      ICRS right ascension (radians)
+///     dd     double      ICRS declination (radians)
+///  ```
+///  Returned:
+///  ```text
+///     dl     double      galactic longitude (radians)
+///     db     double      galactic latitude (radians)
+///  ```
+///  Notes:
+///
+///  1) The IAU 1958 system of Galactic coordinates was defined with
+///     respect to the now obsolete reference system FK4 B1950.0.  When
+///     interpreting the system in a modern context, several factors have
+///     to be taken into account:
+///
+///     . The inclusion in FK4 positions of the E-terms of aberration.
+///
+///     . The distortion of the FK4 proper motion system by
+///     differential Galactic rotation.
+///
+///     . The use of the B1950.0 equinox rather than the now-standard
+///     J2000.0.
+///
+///     . The frame bias between ICRS and the J2000.0 mean place system.
+///
+///     The Hipparcos Catalogue (Perryman & ESA 1997) provides a rotation
+///     matrix that transforms directly between ICRS and Galactic
+///     coordinates with the above factors taken into account (see
+///     `RM_ICRS2G`).
+///
+///  2) The inverse transformation is performed by the function g2icrs.
+///
+///  Reference:
+///
+///     Perryman M.A.C. & ESA, 1997, ESA SP-1200, The Hipparcos and Tycho
+///     catalogues.  Astrometric and photometric star catalogues
+///     derived from the ESA Hipparcos Space Astrometry Mission.  ESA
+///     Publications Division, Noordwijk, Netherlands.
+pub fn icrs2g(dr: f64, dd: f64) -> (f64, f64) {
+    rotsph(&RM_ICRS2G, false, dr, dd)
+}
+
+///  Transformation from Galactic Coordinates to ICRS.
+///
+///  Given:
+///  ```text
+///     dl     double      galactic longitude (radians)
+///     db     double      galactic latitude (radians)
+///  ```
+///  Returned:
+///  ```text
+///     dr     double      ICRS right ascension (radians)
+///     dd     double      ICRS declination (radians)
+///  ```
+///  Notes:
+///
+///  1) See icrs2g for the interpretation of the IAU 1958 Galactic
+///     system in terms of the ICRS.
+///
+///  2) The inverse transformation is performed by the function icrs2g.
+pub fn g2icrs(dl: f64, db: f64) -> (f64, f64) {
+    rotsph(&RM_ICRS2G, true, dl, db)
+}
+
+///  Transformation from ICRS to Galactic Coordinates, with proper motion.
+///
+///  Given:
+///  ```text
+///     dr     double      ICRS right ascension (radians)
+///     dd     double      ICRS declination (radians)
+///     pmr    double      RA proper motion (radians/year; Note 1)
+///     pmd    double      Dec proper motion (radians/year)
+///  ```
+///  Returned:
+///  ```text
+///     dl     double      galactic longitude (radians)
+///     db     double      galactic latitude (radians)
+///     pml    double      longitude proper motion (radians/year; Note 1)
+///     pmb    double      latitude proper motion (radians/year)
+///  ```
+///  Notes:
+///
+///  1) The proper motions are the rates of change of the coordinates,
+///     dRA/dt and dl/dt rather than cos(Dec)*dRA/dt and cos(b)*dl/dt,
+///     as in the catalog functions and `Star`.  Any time unit can be
+///     used instead of the year, the same for given and returned.
+///
+///  2) At the Galactic poles dl/dt is undefined and pml and pmb are
+///     returned as zero.
+///
+///  3) The inverse transformation is performed by the function g2icrspm.
+pub fn icrs2gpm(dr: f64, dd: f64, pmr: f64, pmd: f64) -> (f64, f64, f64, f64) {
+    rotpm(&RM_ICRS2G, false, dr, dd, pmr, pmd)
+}
+
+///  Transformation from Galactic Coordinates to ICRS, with proper motion.
+///
+///  Given:
+///  ```text
+///     dl     double      galactic longitude (radians)
+///     db     double      galactic latitude (radians)
+///     pml    double      longitude proper motion (radians/year)
+///     pmb    double      latitude proper motion (radians/year)
+///  ```
+///  Returned:
+///  ```text
+///     dr     double      ICRS right ascension (radians)
+///     dd     double      ICRS declination (radians)
+///     pmr    double      RA proper motion (radians/year)
+///     pmd    double      Dec proper motion (radians/year)
+///  ```
+///  Notes:
+///
+///  1) The proper motions are dl/dt and dRA/dt, as in icrs2gpm.
+///
+///  2) At the celestial poles pmr and pmd are returned as zero.
+pub fn g2icrspm(dl: f64, db: f64, pml: f64, pmb: f64) -> (f64, f64, f64, f64) {
+    rotpm(&RM_ICRS2G, true, dl, db, pml, pmb)
+}
+
+///  Transformation from Galactic to supergalactic coordinates.
+///
+///  Given:
+///  ```text
+///     dl     double      galactic longitude (radians)
+///     db     double      galactic latitude (radians)
+///  ```
+///  Returned:
+///  ```text
+///     sgl    double      supergalactic longitude (radians)
+///     sgb    double      supergalactic latitude (radians)
+///  ```
+///  Notes:
+///
+///  1) The supergalactic system is defined in terms of Galactic
+///     coordinates (see `RM_G2SG`);  for ICRS positions apply icrs2g
+///     first.
+///
+///  2) The inverse transformation is performed by the function sg2g.
+///
+///  Reference:
+///
+///     de Vaucouleurs G., de Vaucouleurs A. & Corwin H.G., 1976, Second
+///     Reference Catalogue of Bright Galaxies, University of Texas
+///     Press, Austin.
+pub fn g2sg(dl: f64, db: f64) -> (f64, f64) {
+    rotsph(&RM_G2SG, false, dl, db)
+}
+
+///  Transformation from supergalactic to Galactic coordinates.
+///
+///  Given:
+///  ```text
+///     sgl    double      supergalactic longitude (radians)
+///     sgb    double      supergalactic latitude (radians)
+///  ```
+///  Returned:
+///  ```text
+///     dl     double      galactic longitude (radians)
+///     db     double      galactic latitude (radians)
+///  ```
+///  Note:
+///
+///     The inverse transformation is performed by the function g2sg.
+pub fn sg2g(sgl: f64, sgb: f64) -> (f64, f64) {
+    rotsph(&RM_G2SG, true, sgl, sgb)
+}
+
+///  Transformation from Galactic to supergalactic coordinates, with
+///  proper motion.
+///
+///  Given:
+///  ```text
+///     dl     double      galactic longitude (radians)
+///     db     double      galactic latitude (radians)
+///     pml    double      longitude proper motion (radians/year)
+///     pmb    double      latitude proper motion (radians/year)
+///  ```
+///  Returned:
+///  ```text
+///     sgl    double      supergalactic longitude (radians)
+///     sgb    double      supergalactic latitude (radians)
+///     pmsl   double      longitude proper motion (radians/year)
+///     pmsb   double      latitude proper motion (radians/year)
+///  ```
+///  Notes:
+///
+///  1) The proper motions are dl/dt and dSGL/dt, as in icrs2gpm.
+///
+///  2) The inverse transformation is performed by the function sg2gpm.
+pub fn g2sgpm(dl: f64, db: f64, pml: f64, pmb: f64) -> (f64, f64, f64, f64) {
+    rotpm(&RM_G2SG, false, dl, db, pml, pmb)
+}
+
+///  Transformation from supergalactic to Galactic coordinates, with
+///  proper motion.
+///
+///  Given:
+///  ```text
+///     sgl    double      supergalactic longitude (radians)
+///     sgb    double      supergalactic latitude (radians)
+///     pmsl   double      longitude proper motion (radians/year)
+///     pmsb   double      latitude proper motion (radians/year)
+///  ```
+///  Returned:
+///  ```text
+///     dl     double      galactic longitude (radians)
+///     db     double      galactic latitude (radians)
+///     pml    double      longitude proper motion (radians/year)
+///     pmb    double      latitude proper motion (radians/year)
+///  ```
+///  Note:
+///
+///     The inverse transformation is performed by the function g2sgpm.
+pub fn sg2gpm(sgl: f64, sgb: f64, pmsl: f64, pmsb: f64) -> (f64, f64, f64, f64) {
+    rotpm(&RM_G2SG, true, sgl, sgb, pmsl, pmsb)
+}
+
+///  Galactic space velocity (U,V,W) of a star.
+///
+///  Given:
+///  ```text
+///     star   Star        ICRS catalog data (Note 1)
+///  ```
+///  Returned (function value):
+///  ```text
+///            double[3]   U,V,W (km/s, Note 2)
+///  ```
+///  or Err(-1) if the parallax is not positive.
+///
+///  Notes:
+///
+///  1) The star's RA,Dec, proper motions (dRA/dt, dDec/dt, radians per
+///     Julian year), parallax (arcsec) and radial velocity (km/s,
+///     +ve if receding) are used as given:  no correction is made for
+///     light time or for the relativistic Doppler effect (see starpv).
+///
+///  2) The velocity is heliocentric, in the Galactic frame, with U
+///     towards the Galactic center, V in the direction of Galactic
+///     rotation (l = 90 deg) and W towards the north Galactic pole.
+///     For velocities relative to the local standard of rest subtract
+///     the Sun's peculiar motion.
+///
+///  3) The inverse transformation is performed by the function uvw2star.
+pub fn uvw(star: &Star) -> Result<[f64; 3], i32> {
+    if star.px <= 0.0 {
+        return Err(-1);
+    }
+
+    /* Distance (au) times km/s per au/year. */
+    let w = DR2AS / star.px * AUYR2KMS;
+
+    /* ICRS space velocity (km/s). */
+    let (u, du) = s2cdot(star.ra, star.dec, star.pmr, star.pmd);
+    let v: [f64; 3] = std::array::from_fn(|i| star.rv * u[i] + w * du[i]);
+
+    /* Rotate into the Galactic frame. */
+    Ok(rxp(&RM_ICRS2G, &v))
+}
+
+///  Star catalog data from a position, parallax and Galactic space
+///  velocity (U,V,W).
+///
+///  Given:
+///  ```text
+///     ra     double      ICRS right ascension (radians)
+///     dec    double      ICRS declination (radians)
+///     px     double      parallax (arcsec)
+///     uvw    double[3]   U,V,W (km/s, see function uvw)
+///  ```
+///  Returned (function value):
+///  ```text
+///            Star        ICRS catalog data (Note 1)
+///  ```
+///  or Err(-1) if the parallax is not positive.
+///
+///  Notes:
+///
+///  1) The proper motions are dRA/dt and dDec/dt (radians per Julian
+///     year).
+///
+///  2) At the celestial poles the proper motion in RA is returned as
+///     zero.
+///
+///  3) The inverse transformation is performed by the function uvw.
+pub fn uvw2star(ra: f64, dec: f64, px: f64, uvw: &[f64; 3]) -> Result<Star, i32> {
+    if px <= 0.0 {
+        return Err(-1);
+    }
+
+    /* ICRS space velocity (km/s). */
+    let v = trxp(&RM_ICRS2G, uvw);
+
+    /* Components along the line of sight, east and north. */
+    let (sa, ca) = sin_cos(ra);
+    let (sd, cd) = sin_cos(dec);
+    let rv = cd * (ca * v[0] + sa * v[1]) + sd * v[2];
+    let ve = -sa * v[0] + ca * v[1];
+    let vn = -sd * (ca * v[0] + sa * v[1]) + cd * v[2];
+
+    /* Transverse velocity to proper motion. */
+    let w = DR2AS / px * AUYR2KMS;
+    let pmr = if cd > 0.0 { ve / (w * cd) } else { 0.0 };
+    let pmd = vn / w;
+
+    Ok(Star::new(ra, dec, pmr, pmd, px, rv))
+}
@@ -0,0 +1,155 @@
+use crate::consts::DMAS2R;
+
+///  7-parameter similarity (Helmert) transformation
+///
+///  Follows the IERS convention for transformations between terrestrial
+///  reference frames:
+///
+///  ```text
+///     X2 = X1 + T + D X1 + R X1
+///
+///          ( 0   -R3   R2 )
+///     R =  ( R3   0   -R1 )
+///          (-R2   R1   0  )
+///  ```
+///  with small-angle rotations.  Fields are stored in SI units:
+///  ```text
+///     tx,ty,tz   double   translation (meters)
+///     d          double   scale difference (dimensionless, not ppb)
+///     rx,ry,rz   double   rotations (radians)
+///  ```
+///  `Helmert::iers` accepts the mm / ppb / mas units in which IERS
+///  publishes the parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Helmert {
+    pub tx: f64,
+    pub ty: f64,
+    pub tz: f64,
+    pub d: f64,
+    pub rx: f64,
+    pub ry: f64,
+    pub rz: f64,
+}
+
+impl Helmert {
+    /// Parameters from translations in mm, scale in ppb and rotations in
+    /// mas, as tabulated by the IERS.
+    pub const fn iers(t1: f64, t2: f64, t3: f64, d: f64, r1: f64, r2: f64, r3: f64) -> Self {
+        Helmert {
+            tx: t1 * 1e-3,
+            ty: t2 * 1e-3,
+            tz: t3 * 1e-3,
+            d: d * 1e-9,
+            rx: r1 * DMAS2R,
+            ry: r2 * DMAS2R,
+            rz: r3 * DMAS2R,
+        }
+    }
+
+    /// Transform a geocentric vector (meters).
+    pub fn apply(&self, xyz: &[f64; 3]) -> [f64; 3] {
+        let [x, y, z] = *xyz;
+        [
+            x + self.tx + self.d * x - self.rz * y + self.ry * z,
+            y + self.ty + self.rz * x + self.d * y - self.rx * z,
+            z + self.tz - self.ry * x + self.rx * y + self.d * z,
+        ]
+    }
+
+    /// The reverse transformation (to first order in the parameters).
+    pub fn inverse(&self) -> Self {
+        Helmert {
+            tx: -self.tx,
+            ty: -self.ty,
+            tz: -self.tz,
+            d: -self.d,
+            rx: -self.rx,
+            ry: -self.ry,
+            rz: -self.rz,
+        }
+    }
+}
+
+///  14-parameter (time-dependent) Helmert transformation
+///
+///  The 7 parameters at a reference epoch plus their rates per year.
+///  The parameters at epoch t (Julian epoch, years) are
+///  P(t) = P(t0) + Pdot (t - t0).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Helmert14 {
+    /// parameters at the reference epoch
+    pub p: Helmert,
+    /// rates of change of the parameters (per year)
+    pub rate: Helmert,
+    /// reference epoch (Julian epoch, years)
+    pub epoch: f64,
+}
+
+impl Helmert14 {
+    /// The 7 parameters at a given epoch (Julian epoch, years).
+    pub fn at(&self, epoch: f64) -> Helmert {
+        let dt = epoch - self.epoch;
+        let (p, r) = (&self.p, &self.rate);
+        Helmert {
+            tx: p.tx + r.tx * dt,
+            ty: p.ty + r.ty * dt,
+            tz: p.tz + r.tz * dt,
+            d: p.d + r.d * dt,
+            rx: p.rx + r.rx * dt,
+            ry: p.ry + r.ry * dt,
+            rz: p.rz + r.rz * dt,
+        }
+    }
+
+    /// Transform a station position (meters) given at `epoch`.
+    pub fn apply(&self, xyz: &[f64; 3], epoch: f64) -> [f64; 3] {
+        self.at(epoch).apply(xyz)
+    }
+
+    /// Transform a station position and velocity (meters, meters/year)
+    /// given at `epoch`.
+    ///
+    /// The velocity picks up the rate terms, Xdot2 = Xdot1 + Tdot +
+    /// Ddot X1 + Rdot X1, neglecting second-order products.
+    pub fn apply_pv(&self, xyz: &[f64; 3], vel: &[f64; 3], epoch: f64) -> ([f64; 3], [f64; 3]) {
+        let p = self.at(epoch).apply(xyz);
+        let r = &self.rate;
+        let [x, y, z] = *xyz;
+        let v = [
+            vel[0] + r.tx + r.d * x - r.rz * y + r.ry * z,
+            vel[1] + r.ty + r.rz * x + r.d * y - r.rx * z,
+            vel[2] + r.tz - r.ry * x + r.rx * y + r.d * z,
+        ];
+        (p, v)
+    }
+
+    /// The reverse transformation (to first order in the parameters).
+    pub fn inverse(&self) -> Self {
+        Helmert14 {
+            p: self.p.inverse(),
+            rate: self.rate.inverse(),
+            epoch: self.epoch,
+        }
+    }
+}
+
+/// ITRF2020 to ITRF2014 (IERS, epoch 2015.0)
+pub const ITRF2020_TO_ITRF2014: Helmert14 = Helmert14 {
+    p: Helmert::iers(-1.4, -0.9, 1.4, -0.42, 0.0, 0.0, 0.0),
+    rate: Helmert::iers(0.0, -0.1, 0.2, 0.0, 0.0, 0.0, 0.0),
+    epoch: 2015.0,
+};
+
+/// ITRF2020 to ITRF2008 (IERS, epoch 2015.0)
+pub const ITRF2020_TO_ITRF2008: Helmert14 = Helmert14 {
+    p: Helmert::iers(0.2, 1.0, 3.3, -0.29, 0.0, 0.0, 0.0),
+    rate: Helmert::iers(0.0, -0.1, 0.1, 0.03, 0.0, 0.0, 0.0),
+    epoch: 2015.0,
+};
+
+/// ITRF2020 to ITRF2005 (IERS, epoch 2015.0)
+pub const ITRF2020_TO_ITRF2005: Helmert14 = Helmert14 {
+    p: Helmert::iers(2.7, 0.1, -1.4, 0.65, 0.0, 0.0, 0.0),
+    rate: Helmert::iers(0.3, -0.1, 0.1, 0.03, 0.0, 0.0, 0.0),
+    epoch: 2015.0,
+};
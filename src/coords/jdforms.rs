@@ -0,0 +1,9 @@
+use crate::cal::jd_forms;
+
+use super::*;
+
+jd_forms! {
+    value ecm06_jd = ecm06() -> [[f64; 3]; 3];
+    value eceq06_jd = eceq06(dl: f64, db: f64) -> (f64, f64);
+    value eqec06_jd = eqec06(dr: f64, dd: f64) -> (f64, f64);
+}
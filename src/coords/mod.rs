@@ -1,3 +1,18 @@
 //! Ecliptic/Galactic/Geodetic coordinates
 mod coords;
 pub use coords::*;
+
+mod galactic;
+pub use galactic::*;
+
+mod ecliptic;
+pub use ecliptic::*;
+
+mod ellipsoid;
+pub use ellipsoid::*;
+
+mod helmert;
+pub use helmert::*;
+
+mod jdforms;
+pub use jdforms::*;
@@ -1,5 +1,6 @@
 #![allow(non_upper_case_globals)]
 use crate::consts::{DJ00, DJY};
+use crate::math::{cos, sin, Sum};
 
 // Matrix elements for orienting the analytical model to DE405.
 //
@@ -2304,6 +2305,15 @@ const s2z: [f64; 6] =  [
 ///  5) It is permissible to use the same array for pvh and pvb, which
 ///     will receive the barycentric values.
 pub fn epv00(date1: f64, date2: f64) -> Option<([[f64; 3]; 2], [[f64; 3]; 2])> {
+    match epv00_status(date1, date2) {
+        (pvh, pvb, 0) => Some((pvh, pvb)),
+        _ => None,
+    }
+}
+
+/* As epv00, the vectors being returned whatever the date, with the
+   status of the C function:  0 = OK, +1 = outside 1900-2100 AD. */
+pub(crate) fn epv00_status(date1: f64, date2: f64) -> ([[f64; 3]; 2], [[f64; 3]; 2], i32) {
     // Pointers to coefficient arrays, in x,y,z sets
     let ce0: [&[f64]; 3] = [&e0x, &e0y, &e0z];
     let ce1: [&[f64]; 3] = [&e1x, &e1y, &e1z];
@@ -2322,8 +2332,8 @@ pub fn epv00(date1: f64, date2: f64) -> Option<([[f64; 3]; 2], [[f64; 3]; 2])> {
 
     /* Miscellaneous */
     let jstat: i32;
-    let (mut xyz, mut xyzd, mut a, mut b, mut c, 
-            mut ct, mut p, mut cp): (f64, f64, f64, f64, f64, f64, f64, f64);
+    let (mut xyz, mut xyzd): (Sum, Sum);
+    let (mut a, mut b, mut c, mut ct, mut p, mut cp): (f64, f64, f64, f64, f64, f64);
 
     let (mut ph, mut vh, mut pb, mut vb): 
         ([f64; 3], [f64; 3], [f64; 3], [f64; 3]) = ([0.0; 3], [0.0; 3], [0.0; 3], [0.0; 3]);
@@ -2343,8 +2353,8 @@ pub fn epv00(date1: f64, date2: f64) -> Option<([[f64; 3]; 2], [[f64; 3]; 2])> {
     for i in 0..3 {
 
         /* Initialize position and velocity component. */
-        xyz = 0.0;
-        xyzd = 0.0;
+        xyz = Sum::default();
+        xyzd = Sum::default();
      
         /* ------------------------------------------------ */
         /* Obtain component of Sun to Earth ecliptic vector */
@@ -2356,8 +2366,8 @@ pub fn epv00(date1: f64, date2: f64) -> Option<([[f64; 3]; 2], [[f64; 3]; 2])> {
             b = ce0[i][j*3+1];
             c = ce0[i][j*3+2];
             p = b + c*t;
-            xyz  += a*p.cos();
-            xyzd -= a*c*p.sin();
+            xyz  += a*cos(p);
+            xyzd -= a*c*sin(p);
         }
      
         /* Sun to Earth, T^1 terms. */
@@ -2367,9 +2377,9 @@ pub fn epv00(date1: f64, date2: f64) -> Option<([[f64; 3]; 2], [[f64; 3]; 2])> {
             c = ce1[i][j*3+2];
             ct = c*t;
             p = b + ct;
-            cp = p.cos();
+            cp = cos(p);
             xyz  += a*t*cp;
-            xyzd += a*( cp - ct*p.sin() );
+            xyzd += a*( cp - ct*sin(p) );
         }
      
         /* Sun to Earth, T^2 terms. */
@@ -2379,14 +2389,14 @@ pub fn epv00(date1: f64, date2: f64) -> Option<([[f64; 3]; 2], [[f64; 3]; 2])> {
             c = ce2[i][j*3+2];
             ct = c*t;
             p = b + ct;
-            cp = p.cos();
+            cp = cos(p);
             xyz  += a*t2*cp;
-            xyzd += a*t*( 2.0*cp - ct*p.sin() );
+            xyzd += a*t*( 2.0*cp - ct*sin(p) );
         }
      
         /* Heliocentric Earth position and velocity component. */
-        ph[i] = xyz;
-        vh[i] = xyzd / DJY;
+        ph[i] = xyz.value();
+        vh[i] = xyzd.value() / DJY;
      
         /* ------------------------------------------------ */
         /* Obtain component of SSB to Earth ecliptic vector */
@@ -2398,8 +2408,8 @@ pub fn epv00(date1: f64, date2: f64) -> Option<([[f64; 3]; 2], [[f64; 3]; 2])> {
             b = cs0[i][j*3+1];
             c = cs0[i][j*3+2];
             p = b + c*t;
-            xyz  += a*p.cos();
-            xyzd -= a*c*p.sin();
+            xyz  += a*cos(p);
+            xyzd -= a*c*sin(p);
         }
      
         /* SSB to Sun, T^1 terms. */
@@ -2409,9 +2419,9 @@ pub fn epv00(date1: f64, date2: f64) -> Option<([[f64; 3]; 2], [[f64; 3]; 2])> {
             c = cs1[i][j*3+2];
             ct = c*t;
             p = b + ct;
-            cp = p.cos();
+            cp = cos(p);
             xyz  += a*t*cp;
-            xyzd += a*(cp - ct*p.sin());
+            xyzd += a*(cp - ct*sin(p));
         }
      
         /* SSB to Sun, T^2 terms. */
@@ -2421,14 +2431,14 @@ pub fn epv00(date1: f64, date2: f64) -> Option<([[f64; 3]; 2], [[f64; 3]; 2])> {
             c = cs2[i][j*3+2];
             ct = c*t;
             p = b + ct;
-            cp = p.cos();
+            cp = cos(p);
             xyz  += a*t2*cp;
-            xyzd += a*t*(2.0*cp - ct*p.sin());
+            xyzd += a*t*(2.0*cp - ct*sin(p));
         }
      
         /* Barycentric Earth position and velocity component. */
-        pb[i] = xyz;
-        vb[i] = xyzd / DJY;
+        pb[i] = xyz.value();
+        vb[i] = xyzd.value() / DJY;
      
         /* Next Cartesian component. */
     }
@@ -2465,8 +2475,5 @@ pub fn epv00(date1: f64, date2: f64) -> Option<([[f64; 3]; 2], [[f64; 3]; 2])> {
     pvb[1][1] = am21*x + am22*y + am23*z;
     pvb[1][2] =          am32*y + am33*z;
 
-    match jstat {
-        0 => Some((pvh, pvb)),
-        _ => None,
-    }
+    (pvh, pvb, jstat)
 }
\ No newline at end of file
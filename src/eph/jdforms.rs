@@ -0,0 +1,31 @@
+use crate::cal::{jd_forms, Jd};
+use crate::vm::PvVec;
+
+use super::*;
+
+/* Heliocentric and barycentric position/velocity, as from epv00. */
+type PvHb = ([[f64; 3]; 2], [[f64; 3]; 2]);
+
+jd_forms! {
+    value epv00_jd = epv00() -> Option<PvHb>;
+    value moon98_jd = moon98() -> [[f64; 3]; 2];
+}
+
+/// As `oscpv`, with the TT date as a `Jd` (or a (date1, date2) pair).
+pub fn oscpv_jd(el: &OscElements, date: impl Into<Jd>) -> Result<PvVec, i32> {
+    let Jd(d1, d2) = date.into();
+    oscpv(el, d1, d2)
+}
+
+/// As `planet_elements`, with the TT date as a `Jd` (or a (date1, date2)
+/// pair).
+pub fn planet_elements_jd(planet: Planet, date: impl Into<Jd>) -> OscElements {
+    let Jd(d1, d2) = date.into();
+    planet_elements(planet, d1, d2)
+}
+
+/// As `planet_pv`, with the TT date as a `Jd` (or a (date1, date2) pair).
+pub fn planet_pv_jd(planet: Planet, date: impl Into<Jd>) -> Result<PvVec, i32> {
+    let Jd(d1, d2) = date.into();
+    planet_pv(planet, d1, d2)
+}
@@ -1,3 +1,15 @@
 //! Ephemerides (limited precision)
 mod epv00;
-pub use epv00::*;
\ No newline at end of file
+pub use epv00::*;
+
+mod moon98;
+pub use moon98::*;
+
+mod oscel;
+pub use oscel::*;
+
+mod planets;
+pub use planets::*;
+
+mod jdforms;
+pub use jdforms::*;
@@ -0,0 +1,363 @@
+use crate::consts::{DAU, DD2R, DJ00, DJC};
+use crate::math::{cos, sin};
+use crate::pnp::pfw06;
+use crate::vm::{ir, rx, rxpv, rz};
+
+/* Coefficients for fundamental arguments:  degrees and powers of T. */
+
+/* Moon's mean longitude (wrt mean equinox and ecliptic of date);
+   constant term from Simon et al. (1994), the rest Meeus. */
+const ELP: [f64; 5] = [218.31665436, 481267.88123421, -0.0015786, 1.0 / 538841.0, -1.0 / 65194000.0];
+
+/* Moon's mean elongation */
+const D: [f64; 5] = [297.8501921, 445267.1114034, -0.0018819, 1.0 / 545868.0, -1.0 / 113065000.0];
+
+/* Sun's mean anomaly */
+const EM: [f64; 5] = [357.5291092, 35999.0502909, -0.0001536, 1.0 / 24490000.0, 0.0];
+
+/* Moon's mean anomaly */
+const EMP: [f64; 5] = [134.9633964, 477198.8675055, 0.0087414, 1.0 / 69699.0, -1.0 / 14712000.0];
+
+/* Mean distance of the Moon from its ascending node */
+const F: [f64; 5] = [93.2720950, 483202.0175233, -0.0036539, -1.0 / 3526000.0, 1.0 / 863310000.0];
+
+/* Meeus A_1, due to Venus (deg) */
+const A1: [f64; 2] = [119.75, 131.849];
+
+/* Meeus A_2, due to Jupiter (deg) */
+const A2: [f64; 2] = [53.09, 479264.290];
+
+/* Meeus A_3, due to sidereal motion of the Moon in longitude (deg) */
+const A3: [f64; 2] = [313.45, 481266.484];
+
+/* Coefficients for Meeus "additive terms" (deg) */
+const AL1: f64 = 0.003958;
+const AL2: f64 = 0.001962;
+const AL3: f64 = 0.000318;
+const AB1: f64 = -0.002235;
+const AB2: f64 = 0.000382;
+const AB3: f64 = 0.000175;
+const AB4: f64 = 0.000175;
+const AB5: f64 = 0.000127;
+const AB6: f64 = -0.000115;
+
+/* Fixed term in distance (m) */
+const R0: f64 = 385000560.0;
+
+/* Coefficients for (dimensionless) E factor */
+const E1: f64 = -0.002516;
+const E2: f64 = -0.0000074;
+
+/* Coefficients for Moon longitude and distance series:  multiples of
+   D, M, M', F, then longitude (deg) and distance (m) coefficients. */
+const TLR: [(i32, i32, i32, i32, f64, f64); 60] = [
+    (0, 0, 1, 0, 6.288774, -20905355.0),
+    (2, 0, -1, 0, 1.274027, -3699111.0),
+    (2, 0, 0, 0, 0.658314, -2955968.0),
+    (0, 0, 2, 0, 0.213618, -569925.0),
+    (0, 1, 0, 0, -0.185116, 48888.0),
+    (0, 0, 0, 2, -0.114332, -3149.0),
+    (2, 0, -2, 0, 0.058793, 246158.0),
+    (2, -1, -1, 0, 0.057066, -152138.0),
+    (2, 0, 1, 0, 0.053322, -170733.0),
+    (2, -1, 0, 0, 0.045758, -204586.0),
+    (0, 1, -1, 0, -0.040923, -129620.0),
+    (1, 0, 0, 0, -0.034720, 108743.0),
+    (0, 1, 1, 0, -0.030383, 104755.0),
+    (2, 0, 0, -2, 0.015327, 10321.0),
+    (0, 0, 1, 2, -0.012528, 0.0),
+    (0, 0, 1, -2, 0.010980, 79661.0),
+    (4, 0, -1, 0, 0.010675, -34782.0),
+    (0, 0, 3, 0, 0.010034, -23210.0),
+    (4, 0, -2, 0, 0.008548, -21636.0),
+    (2, 1, -1, 0, -0.007888, 24208.0),
+    (2, 1, 0, 0, -0.006766, 30824.0),
+    (1, 0, -1, 0, -0.005163, -8379.0),
+    (1, 1, 0, 0, 0.004987, -16675.0),
+    (2, -1, 1, 0, 0.004036, -12831.0),
+    (2, 0, 2, 0, 0.003994, -10445.0),
+    (4, 0, 0, 0, 0.003861, -11650.0),
+    (2, 0, -3, 0, 0.003665, 14403.0),
+    (0, 1, -2, 0, -0.002689, -7003.0),
+    (2, 0, -1, 2, -0.002602, 0.0),
+    (2, -1, -2, 0, 0.002390, 10056.0),
+    (1, 0, 1, 0, -0.002348, 6322.0),
+    (2, -2, 0, 0, 0.002236, -9884.0),
+    (0, 1, 2, 0, -0.002120, 5751.0),
+    (0, 2, 0, 0, -0.002069, 0.0),
+    (2, -2, -1, 0, 0.002048, -4950.0),
+    (2, 0, 1, -2, -0.001773, 4130.0),
+    (2, 0, 0, 2, -0.001595, 0.0),
+    (4, -1, -1, 0, 0.001215, -3958.0),
+    (0, 0, 2, 2, -0.001110, 0.0),
+    (3, 0, -1, 0, -0.000892, 3258.0),
+    (2, 1, 1, 0, -0.000810, 2616.0),
+    (4, -1, -2, 0, 0.000759, -1897.0),
+    (0, 2, -1, 0, -0.000713, -2117.0),
+    (2, 2, -1, 0, -0.000700, 2354.0),
+    (2, 1, -2, 0, 0.000691, 0.0),
+    (2, -1, 0, -2, 0.000596, 0.0),
+    (4, 0, 1, 0, 0.000549, -1423.0),
+    (0, 0, 4, 0, 0.000537, -1117.0),
+    (4, -1, 0, 0, 0.000520, -1571.0),
+    (1, 0, -2, 0, -0.000487, -1739.0),
+    (2, 1, 0, -2, -0.000399, 0.0),
+    (0, 0, 2, -2, -0.000381, -4421.0),
+    (1, 1, 1, 0, 0.000351, 0.0),
+    (3, 0, -2, 0, -0.000340, 0.0),
+    (4, 0, -3, 0, 0.000330, 0.0),
+    (2, -1, 2, 0, 0.000327, 0.0),
+    (0, 2, 1, 0, -0.000323, 1165.0),
+    (1, 1, -1, 0, 0.000299, 0.0),
+    (2, 0, 3, 0, 0.000294, 0.0),
+    (2, 0, -1, -2, 0.000000, 8752.0),
+];
+
+/* Coefficients for Moon latitude series:  multiples of D, M, M', F,
+   then latitude coefficient (deg). */
+const TB: [(i32, i32, i32, i32, f64); 60] = [
+    (0, 0, 0, 1, 5.128122),
+    (0, 0, 1, 1, 0.280602),
+    (0, 0, 1, -1, 0.277693),
+    (2, 0, 0, -1, 0.173237),
+    (2, 0, -1, 1, 0.055413),
+    (2, 0, -1, -1, 0.046271),
+    (2, 0, 0, 1, 0.032573),
+    (0, 0, 2, 1, 0.017198),
+    (2, 0, 1, -1, 0.009266),
+    (0, 0, 2, -1, 0.008822),
+    (2, -1, 0, -1, 0.008216),
+    (2, 0, -2, -1, 0.004324),
+    (2, 0, 1, 1, 0.004200),
+    (2, 1, 0, -1, -0.003359),
+    (2, -1, -1, 1, 0.002463),
+    (2, -1, 0, 1, 0.002211),
+    (2, -1, -1, -1, 0.002065),
+    (0, 1, -1, -1, -0.001870),
+    (4, 0, -1, -1, 0.001828),
+    (0, 1, 0, 1, -0.001794),
+    (0, 0, 0, 3, -0.001749),
+    (0, 1, -1, 1, -0.001565),
+    (1, 0, 0, 1, -0.001491),
+    (0, 1, 1, 1, -0.001475),
+    (0, 1, 1, -1, -0.001410),
+    (0, 1, 0, -1, -0.001344),
+    (1, 0, 0, -1, -0.001335),
+    (0, 0, 3, 1, 0.001107),
+    (4, 0, 0, -1, 0.001021),
+    (4, 0, -1, 1, 0.000833),
+    (0, 0, 1, -3, 0.000777),
+    (4, 0, -2, 1, 0.000671),
+    (2, 0, 0, -3, 0.000607),
+    (2, 0, 2, -1, 0.000596),
+    (2, -1, 1, -1, 0.000491),
+    (2, 0, -2, 1, -0.000451),
+    (0, 0, 3, -1, 0.000439),
+    (2, 0, 2, 1, 0.000422),
+    (2, 0, -3, -1, 0.000421),
+    (2, 1, -1, 1, -0.000366),
+    (2, 1, 0, 1, -0.000351),
+    (4, 0, 0, 1, 0.000331),
+    (2, -1, 1, 1, 0.000315),
+    (2, -2, 0, -1, 0.000302),
+    (0, 0, 1, 3, -0.000283),
+    (2, 1, 1, -1, -0.000229),
+    (1, 1, 0, -1, 0.000223),
+    (1, 1, 0, 1, 0.000223),
+    (0, 1, -2, -1, -0.000220),
+    (2, 1, -1, -1, -0.000220),
+    (1, 0, 1, 1, -0.000185),
+    (2, -1, -2, -1, 0.000181),
+    (0, 1, 2, 1, -0.000177),
+    (4, 0, -2, -1, 0.000176),
+    (4, -1, -1, -1, 0.000166),
+    (1, 0, 1, -1, -0.000164),
+    (4, 0, 1, -1, 0.000132),
+    (1, 0, -1, -1, -0.000119),
+    (4, -1, 0, -1, 0.000115),
+    (2, -2, 0, 1, 0.000107),
+];
+
+/* Polynomial argument (radians) and its rate (radians per century). */
+fn arg(c: &[f64; 5], t: f64) -> (f64, f64) {
+    (
+        DD2R * (c[0] + (c[1] + (c[2] + (c[3] + c[4] * t) * t) * t) * t).rem_euclid(360.0),
+        DD2R * (c[1] + (c[2] * 2.0 + (c[3] * 3.0 + c[4] * 4.0 * t) * t) * t),
+    )
+}
+
+///  Approximate geocentric position and velocity of the Moon.
+///
+///  This function is part of the International Astronomical Union's
+///  SOFA (Standards of Fundamental Astronomy) software collection.
+///
+///  Status:  support function.
+///
+///  Given:
+///  ```text
+///     date1  double         TT date part A (Notes 1,4)
+///     date2  double         TT date part B (Notes 1,4)
+///  ```
+///  Returned:
+///  ```text
+///     pv     double[2][3]   Moon p,v, GCRS (au, au/d, Note 5)
+///  ```
+///  Notes:
+///
+///  1) The TT date date1+date2 is a Julian Date, apportioned in any
+///     convenient way between the two arguments.  The limited accuracy
+///     of the present algorithm is such that any of the methods is
+///     satisfactory.
+///
+///  2) This function is a full implementation of the algorithm
+///     published by Meeus (see reference) except that the light-time
+///     correction to the Moon's mean longitude has been omitted.
+///
+///  3) Comparisons with ELP/MPP02 over the interval 1950-2100 gave RMS
+///     errors of 2.9 arcsec in geocentric direction, 6.1 km in position
+///     and 36 mm/s in velocity.  The worst case errors were 18.3 arcsec
+///     in geocentric direction, 31.7 km in position and 172 mm/s in
+///     velocity.
+///
+///  4) The original algorithm is expressed in terms of "dynamical time",
+///     which can either be TDB or TT without any significant change in
+///     accuracy.  UT cannot be used without incurring significant
+///     errors (30 arcsec in the present era) due to the Moon's 0.5
+///     arcsec/sec movement.
+///
+///  5) The result is with respect to the GCRS (the same as J2000.0 mean
+///     equator and equinox to within 23 mas).
+///
+///  6) Velocity is obtained by a complete analytical differentiation of
+///     the Meeus model.
+///
+///  7) The Meeus algorithm generates position and velocity in mean
+///     ecliptic coordinates of date, which the present function then
+///     rotates into GCRS.  Because the ecliptic system is precessing,
+///     there is a coupling between this spin (about 1.4 degrees per
+///     century) and the Moon position that produces a small velocity
+///     contribution.  In the present function this effect is neglected
+///     as it corresponds to a maximum difference of less than 3 mm/s
+///     and increases the RMS error by only 0.4%.
+///
+///  References:
+///
+///     Meeus, J., Astronomical Algorithms, 2nd edition, Willmann-Bell,
+///     1998, p337.
+///
+///     Simon, J.L., Bretagnon, P., Chapront, J., Chapront-Touze, M.,
+///     Francou, G. & Laskar, J., Astron.Astrophys., 1994, 282, 663
+///
+///  Called:
+///  ```text
+///     iauS2pv      spherical coordinates to pv-vector
+///     iauPfw06     bias-precession F-W angles, IAU 2006
+///     iauIr        initialize r-matrix to identity
+///     iauRz        rotate around Z-axis
+///     iauRx        rotate around X-axis
+///     iauRxpv      product of r-matrix and pv-vector
+///  ```
+pub fn moon98(date1: f64, date2: f64) -> [[f64; 3]; 2] {
+    /* Centuries since J2000. */
+    let t = ((date1 - DJ00) + date2) / DJC;
+
+    /* Fundamental arguments, and their rates. */
+    let (elp, delp) = arg(&ELP, t);
+    let (d, dd) = arg(&D, t);
+    let (em, dem) = arg(&EM, t);
+    let (emp, demp) = arg(&EMP, t);
+    let (f, df) = arg(&F, t);
+
+    /* Meeus further arguments. */
+    let a1 = DD2R * (A1[0] + A1[1] * t);
+    let da1 = DD2R * A1[1];
+    let a2 = DD2R * (A2[0] + A2[1] * t);
+    let da2 = DD2R * A2[1];
+    let a3 = DD2R * (A3[0] + A3[1] * t);
+    let da3 = DD2R * A3[1];
+
+    /* E-factor, and square. */
+    let e = 1.0 + (E1 + E2 * t) * t;
+    let de = E1 + 2.0 * E2 * t;
+    let esq = e * e;
+    let desq = 2.0 * e * de;
+
+    /* Use the Meeus additive terms (deg) to start off the summations. */
+    let elpmf = elp - f;
+    let delpmf = delp - df;
+    let mut vel = AL1 * sin(a1) + AL2 * sin(elpmf) + AL3 * sin(a2);
+    let mut vdel = AL1 * cos(a1) * da1 + AL2 * cos(elpmf) * delpmf + AL3 * cos(a2) * da2;
+
+    let mut vr = 0.0;
+    let mut vdr = 0.0;
+
+    let a1mf = a1 - f;
+    let da1mf = da1 - df;
+    let a1pf = a1 + f;
+    let da1pf = da1 + df;
+    let dlpmp = elp - emp;
+    let slpmp = elp + emp;
+    let mut vb = AB1 * sin(elp) + AB2 * sin(a3) + AB3 * sin(a1mf) + AB4 * sin(a1pf)
+        + AB5 * sin(dlpmp) + AB6 * sin(slpmp);
+    let mut vdb = AB1 * cos(elp) * delp + AB2 * cos(a3) * da3 + AB3 * cos(a1mf) * da1mf
+        + AB4 * cos(a1pf) * da1pf + AB5 * cos(dlpmp) * (delp - demp)
+        + AB6 * cos(slpmp) * (delp + demp);
+
+    /* E-factor for a multiple of the Sun's mean anomaly. */
+    let efac = |i: i32| match i.abs() {
+        1 => (e, de),
+        2 => (esq, desq),
+        _ => (1.0, 0.0),
+    };
+
+    /* Longitude and distance plus derivatives. */
+    for &(nd, nem, nemp, nf, coefl, coefr) in TLR.iter().rev() {
+        let (en, den) = efac(nem);
+        let theta = nd as f64 * d + nem as f64 * em + nemp as f64 * emp + nf as f64 * f;
+        let dtheta = nd as f64 * dd + nem as f64 * dem + nemp as f64 * demp + nf as f64 * df;
+        let (st, ct) = (sin(theta), cos(theta));
+        vel += coefl * en * st;
+        vdel += coefl * (den * st + en * ct * dtheta);
+        vr += coefr * en * ct;
+        vdr += coefr * (den * ct - en * st * dtheta);
+    }
+    let el = elp + DD2R * vel;
+    let del = (delp + DD2R * vdel) / DJC;
+    let r = (vr + R0) / DAU;
+    let dr = vdr / DAU / DJC;
+
+    /* Latitude plus derivative. */
+    for &(nd, nem, nemp, nf, coefb) in TB.iter().rev() {
+        let (en, den) = efac(nem);
+        let theta = nd as f64 * d + nem as f64 * em + nemp as f64 * emp + nf as f64 * f;
+        let dtheta = nd as f64 * dd + nem as f64 * dem + nemp as f64 * demp + nf as f64 * df;
+        let (st, ct) = (sin(theta), cos(theta));
+        vb += coefb * en * st;
+        vdb += coefb * (den * st + en * ct * dtheta);
+    }
+    let b = vb * DD2R;
+    let db = vdb * DD2R / DJC;
+
+    /* Longitude, latitude to x,y,z (au), as iauS2pv. */
+    let (sl, cl) = (sin(el), cos(el));
+    let (sb, cb) = (sin(b), cos(b));
+    let rcb = r * cb;
+    let x = rcb * cl;
+    let y = rcb * sl;
+    let rdb = r * db;
+    let w = rdb * sb - cb * dr;
+    let pv = [[x, y, r * sb], [-y * del - w * cl, x * del - w * sl, rdb * cb + sb * dr]];
+
+    /* IAU 2006 Fukushima-Williams bias+precession angles. */
+    let (gamb, phib, psib, _) = pfw06(date1, date2);
+
+    /* Mean ecliptic coordinates to GCRS rotation matrix. */
+    let mut rm = [[0.0; 3]; 3];
+    ir(&mut rm);
+    rz(psib, &mut rm);
+    rx(-phib, &mut rm);
+    rz(-gamb, &mut rm);
+
+    /* Rotate the Moon position and velocity into GCRS. */
+    rxpv(&rm, &pv)
+}
@@ -0,0 +1,214 @@
+use crate::consts::{DJ00, GK};
+use crate::pnp::obl80;
+use crate::vm::{anpm, PvVec};
+use crate::math::{cbrt, cosh, ln, sin, sin_cos, sinh};
+
+/* Obliquity of the J2000 ecliptic used for MPC and JPL elements
+   (84381.448 arcsec). */
+const EPS0: f64 = obl80(DJ00, 0.0);
+
+/* Kepler's equation:  iteration limit and tolerance. */
+const MAXIT: usize = 100;
+const TOL: f64 = 1e-14;
+
+///  Heliocentric osculating elements, cometary form
+///
+///  ```text
+///     q       double   perihelion distance (au)
+///     e       double   eccentricity (any e >= 0)
+///     incl    double   inclination (radians)
+///     node    double   longitude of the ascending node (radians)
+///     argp    double   argument of perihelion (radians)
+///     tp      double   time of perihelion passage (TT Julian Date)
+///  ```
+///  The angles are referred to the mean ecliptic and equinox of J2000,
+///  as in the MPC and JPL element sets.  The same form serves elliptic,
+///  parabolic and hyperbolic orbits;  asteroid elements given as a, e
+///  and mean anomaly convert with `OscElements::from_mean_anomaly`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct OscElements {
+    pub q: f64,
+    pub e: f64,
+    pub incl: f64,
+    pub node: f64,
+    pub argp: f64,
+    pub tp: f64,
+}
+
+impl OscElements {
+    ///  From asteroid-style elements:  the epoch of osculation (TT JD),
+    ///  semi-major axis a (au), e < 1, the three angles and the mean
+    ///  anomaly m at the epoch (radians).
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_mean_anomaly(
+        epoch: f64,
+        a: f64,
+        e: f64,
+        incl: f64,
+        node: f64,
+        argp: f64,
+        m: f64,
+    ) -> Self {
+        let n = GK / (a * a * a).sqrt();
+        OscElements { q: a * (1.0 - e), e, incl, node, argp, tp: epoch - anpm(m) / n }
+    }
+}
+
+///  Heliocentric position and velocity from osculating elements.
+///
+///  Given:
+///  ```text
+///     el          OscElements   cometary-form elements
+///     date1,date2 double        TT as a 2-part Julian Date
+///  ```
+///  Returned (function value):
+///  ```text
+///                 PvVec         heliocentric position and velocity
+///                               (au, au/day, ICRS-aligned equatorial)
+///  ```
+///  Status values:
+///  ```text
+///     -1   illegal elements (q <= 0 or e < 0)
+///     -2   Kepler's equation failed to converge
+///  ```
+///  Notes:
+///
+///  1) The motion is the two-body solution with the mass of the body
+///     neglected (the Gaussian constant GK), so perturbations by the
+///     planets accumulate with time from the osculation epoch.
+///
+///  2) Elliptic orbits are solved for the eccentric anomaly,
+///     hyperbolic orbits for the hyperbolic anomaly, both by Newton's
+///     method, and e = 1 exactly by the cubic for tan(v/2) (Barker's
+///     equation).  For e within about 1e-4 of 1 the elliptic and
+///     hyperbolic solutions lose some precision well away from
+///     perihelion.
+///
+///  3) The ecliptic to equator rotation uses the J2000 obliquity of
+///     IAU 1976, 84381.448 arcsec, which is how the published element
+///     sets are defined.
+pub fn oscpv(el: &OscElements, date1: f64, date2: f64) -> Result<PvVec, i32> {
+    let q = el.q;
+    let e = el.e;
+    if q <= 0.0 || e < 0.0 {
+        return Err(-1);
+    }
+
+    /* Time since perihelion (days). */
+    let dt = (date1 - el.tp) + date2;
+
+    /* Position and velocity in the orbital plane, x to perihelion. */
+    let (x, y, vx, vy);
+    if e < 1.0 {
+        let a = q / (1.0 - e);
+        let m = anpm(GK / (a * a * a).sqrt() * dt);
+        let mut ea = if e < 0.8 { m + e * sin(m) } else { std::f64::consts::PI.copysign(m) };
+        let mut it = 0;
+        loop {
+            /* Written to keep precision for e near 1. */
+            let s2 = sin(0.5 * ea);
+            let f = (1.0 - e) * ea + e * x_minus_sin(ea) - m;
+            let d = f / ((1.0 - e) + 2.0 * e * s2 * s2);
+            ea -= d;
+            if d.abs() < TOL {
+                break;
+            }
+            it += 1;
+            if it == MAXIT {
+                return Err(-2);
+            }
+        }
+        let (se, ce) = sin_cos(ea);
+        let s2 = sin(0.5 * ea);
+        let b = (1.0 - e * e).sqrt();
+        let r = q + 2.0 * a * e * s2 * s2;
+        let w = GK * a.sqrt() / r;
+        x = q - 2.0 * a * s2 * s2;
+        y = a * b * se;
+        vx = -w * se;
+        vy = w * b * ce;
+    } else if e > 1.0 {
+        let a = q / (e - 1.0);
+        let m = GK / (a * a * a).sqrt() * dt;
+        let mut h = ln(2.0 * m.abs() / e + 1.8).copysign(m);
+        let mut it = 0;
+        loop {
+            let s2 = sinh(0.5 * h);
+            let f = (e - 1.0) * h + e * sinh_minus_x(h) - m;
+            let d = f / ((e - 1.0) + 2.0 * e * s2 * s2);
+            h -= d;
+            if d.abs() < TOL * h.abs().max(1.0) {
+                break;
+            }
+            it += 1;
+            if it == MAXIT {
+                return Err(-2);
+            }
+        }
+        let (sh, ch) = (sinh(h), cosh(h));
+        let s2 = sinh(0.5 * h);
+        let b = (e * e - 1.0).sqrt();
+        let r = q + 2.0 * a * e * s2 * s2;
+        let w = GK * a.sqrt() / r;
+        x = q - 2.0 * a * s2 * s2;
+        y = a * b * sh;
+        vx = -w * sh;
+        vy = w * b * ch;
+    } else {
+        let w = GK / (2.0 * q * q * q).sqrt();
+        let bb = 1.5 * w * dt;
+        let yy = cbrt(bb + (1.0 + bb * bb).sqrt());
+        let d = yy - 1.0 / yy;
+        let dd = w / (1.0 + d * d);
+        x = q * (1.0 - d * d);
+        y = 2.0 * q * d;
+        vx = -2.0 * q * d * dd;
+        vy = 2.0 * q * dd;
+    }
+
+    /* Orbital plane to ecliptic. */
+    let (so, co) = sin_cos(el.node);
+    let (sw, cw) = sin_cos(el.argp);
+    let (si, ci) = sin_cos(el.incl);
+    let p = [co * cw - so * sw * ci, so * cw + co * sw * ci, sw * si];
+    let qv = [-co * sw - so * cw * ci, -so * sw + co * cw * ci, cw * si];
+    let ecl = |u: f64, v: f64| [u * p[0] + v * qv[0], u * p[1] + v * qv[1], u * p[2] + v * qv[2]];
+
+    /* Ecliptic to equator. */
+    let (se, ce) = sin_cos(EPS0);
+    let eq = |v: [f64; 3]| [v[0], v[1] * ce - v[2] * se, v[1] * se + v[2] * ce];
+
+    Ok(PvVec::new(eq(ecl(x, y)), eq(ecl(vx, vy))))
+}
+
+/* x - sin(x) and sinh(x) - x, by series when x is small. */
+fn x_minus_sin(x: f64) -> f64 {
+    if x.abs() < 0.5 {
+        series3(x, -1.0)
+    } else {
+        x - sin(x)
+    }
+}
+
+fn sinh_minus_x(x: f64) -> f64 {
+    if x.abs() < 0.5 {
+        series3(x, 1.0)
+    } else {
+        sinh(x) - x
+    }
+}
+
+/* x^3/3! + sg x^5/5! + x^7/7! + ...  (sg = -1 gives x - sin x,
+   sg = +1 gives sinh x - x). */
+fn series3(x: f64, sg: f64) -> f64 {
+    let x2 = x * x;
+    let mut t = x * x2 / 6.0;
+    let mut sum = t;
+    let mut k = 3.0;
+    while t.abs() > 1e-17 * sum.abs() {
+        t *= sg * x2 / ((k + 1.0) * (k + 2.0));
+        sum += t;
+        k += 2.0;
+    }
+    sum
+}
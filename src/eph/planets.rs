@@ -0,0 +1,154 @@
+use crate::consts::{Body, DD2R, DJ00, DJC};
+use crate::vm::PvVec;
+
+use super::{oscpv, OscElements};
+
+///  The major planets other than the Earth
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Planet {
+    Mercury,
+    Venus,
+    Mars,
+    Jupiter,
+    Saturn,
+    Uranus,
+    Neptune,
+}
+
+impl Planet {
+    /// The seven planets, Mercury first.
+    pub const ALL: [Planet; 7] = [
+        Planet::Mercury,
+        Planet::Venus,
+        Planet::Mars,
+        Planet::Jupiter,
+        Planet::Saturn,
+        Planet::Uranus,
+        Planet::Neptune,
+    ];
+}
+
+impl From<Planet> for Body {
+    fn from(p: Planet) -> Self {
+        match p {
+            Planet::Mercury => Body::Mercury,
+            Planet::Venus => Body::Venus,
+            Planet::Mars => Body::Mars,
+            Planet::Jupiter => Body::Jupiter,
+            Planet::Saturn => Body::Saturn,
+            Planet::Uranus => Body::Uranus,
+            Planet::Neptune => Body::Neptune,
+        }
+    }
+}
+
+/* Keplerian elements and their rates per Julian century, mean
+   ecliptic and equinox of J2000 (Standish, Table 1):  a (au), e, i,
+   L, long. perihelion, long. node (degrees). */
+const ELEMENTS: [[[f64; 6]; 2]; 7] = [
+    /* Mercury */
+    [
+        [0.38709927, 0.20563593, 7.00497902, 252.25032350, 77.45779628, 48.33076593],
+        [0.00000037, 0.00001906, -0.00594749, 149472.67411175, 0.16047689, -0.12534081],
+    ],
+    /* Venus */
+    [
+        [0.72333566, 0.00677672, 3.39467605, 181.97909950, 131.60246718, 76.67984255],
+        [0.00000390, -0.00004107, -0.00078890, 58517.81538729, 0.00268329, -0.27769418],
+    ],
+    /* Mars */
+    [
+        [1.52371034, 0.09339410, 1.84969142, -4.55343205, -23.94362959, 49.55953891],
+        [0.00001847, 0.00007882, -0.00813131, 19140.30268499, 0.44441088, -0.29257343],
+    ],
+    /* Jupiter */
+    [
+        [5.20288700, 0.04838624, 1.30439695, 34.39644051, 14.72847983, 100.47390909],
+        [-0.00011607, -0.00013253, -0.00183714, 3034.74612775, 0.21252668, 0.20469106],
+    ],
+    /* Saturn */
+    [
+        [9.53667594, 0.05386179, 2.48599187, 49.95424423, 92.59887831, 113.66242448],
+        [-0.00125060, -0.00050991, 0.00193609, 1222.49362201, -0.41897216, -0.28867794],
+    ],
+    /* Uranus */
+    [
+        [19.18916464, 0.04725744, 0.77263783, 313.23810451, 170.95427630, 74.01692503],
+        [-0.00196176, -0.00004397, -0.00242939, 428.48202785, 0.40805281, 0.04240589],
+    ],
+    /* Neptune */
+    [
+        [30.06992276, 0.00859048, 1.77004347, -55.12002969, 44.96476227, 131.78422574],
+        [0.00026291, 0.00005105, 0.00035372, 218.45945325, -0.32241464, -0.00508664],
+    ],
+];
+
+///  Mean elements of a planet
+///
+///  Given:
+///  ```text
+///     planet       Planet        the planet
+///     date1,date2  double        TDB as a 2-part Julian Date
+///  ```
+///  Returned (function value):
+///  ```text
+///                  OscElements   heliocentric elements, mean ecliptic
+///                                and equinox of J2000
+///  ```
+///  Notes:
+///
+///  1) The elements are the linear fits of Standish to the JPL DE200
+///     ephemeris over 1800-2050, evaluated at the date:  they describe
+///     the instantaneous ellipse, not an orbit to be propagated.
+///
+///  2) TT can be used instead of TDB.
+///
+///  Reference:
+///
+///     Standish, E.M., "Keplerian Elements for Approximate Positions of
+///     the Major Planets", JPL Solar System Dynamics (1992, revised).
+pub fn planet_elements(planet: Planet, date1: f64, date2: f64) -> OscElements {
+    let t = ((date1 - DJ00) + date2) / DJC;
+    let [e0, rate] = &ELEMENTS[planet as usize];
+    let el: [f64; 6] = std::array::from_fn(|i| e0[i] + rate[i] * t);
+    let [a, e, incl, l, peri, node] = el;
+    OscElements::from_mean_anomaly(
+        date1 + date2,
+        a,
+        e,
+        incl * DD2R,
+        node * DD2R,
+        (peri - node) * DD2R,
+        (l - peri) * DD2R,
+    )
+}
+
+///  Approximate heliocentric position and velocity of a planet
+///
+///  Given:
+///  ```text
+///     planet       Planet   the planet
+///     date1,date2  double   TDB as a 2-part Julian Date
+///  ```
+///  Returned (function value):
+///  ```text
+///                  PvVec    heliocentric position and velocity
+///                           (au, au/day, ICRS-aligned equatorial)
+///  ```
+///  Status values:
+///  ```text
+///     -2   Kepler's equation failed to converge (from oscpv)
+///  ```
+///  Notes:
+///
+///  1) The mean elements of `planet_elements` are taken to `oscpv`.
+///
+///  2) Over 1800-2050 the errors are about 15-40 arcsec in heliocentric
+///     longitude for Mercury to Mars and for Neptune, 50 arcsec for
+///     Uranus and up to 400 and 600 arcsec for Jupiter and Saturn,
+///     whose mutual perturbations the mean elements omit.  This is
+///     enough to point a small telescope or to find rising and setting
+///     times, but not for astrometry.
+pub fn planet_pv(planet: Planet, date1: f64, date2: f64) -> Result<PvVec, i32> {
+    oscpv(&planet_elements(planet, date1, date2), date1, date2)
+}
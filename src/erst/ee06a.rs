@@ -0,0 +1,44 @@
+use crate::vm::anpm;
+
+use super::{gmst06, gst06a};
+
+///  Equation of the equinoxes, compatible with IAU 2000 resolutions and
+///  IAU 2006/2000A precession-nutation.
+///
+///  This function is part of the International Astronomical Union's
+///  SOFA (Standards of Fundamental Astronomy) software collection.
+///
+///  Status:  support function.
+///
+///  Given:
+///     date1,date2  double   TT as a 2-part Julian Date (Note 1)
+///
+///  Returned (function value):
+///                  double   equation of the equinoxes (Note 2)
+///
+///  Notes:
+///
+///  1) The TT date date1+date2 is a Julian Date, apportioned in any
+///     convenient way between the two arguments (see `gmst06`).
+///
+///  2) The result, which is in radians, operates in the following sense:
+///     ```text
+///        Greenwich apparent ST = GMST + equation of the equinoxes
+///     ```
+///  Called:
+///     iauAnpm      normalize angle into range +/- pi
+///     iauGst06a    Greenwich apparent sidereal time, IAU 2006/2000A
+///     iauGmst06    Greenwich mean sidereal time, IAU 2006
+///
+///  Reference:
+///
+///     McCarthy, D. D., Petit, G. (eds.), 2004, IERS Conventions (2003),
+///     IERS Technical Note No. 32, BKG
+pub fn ee06a(date1: f64, date2: f64) -> f64 {
+    /* Apparent and mean sidereal times. */
+    let gst06a = gst06a(0.0, 0.0, date1, date2);
+    let gmst06 = gmst06(0.0, 0.0, date1, date2);
+
+    /* Equation of the equinoxes. */
+    anpm(gst06a - gmst06)
+}
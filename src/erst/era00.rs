@@ -1,8 +1,6 @@
-use std::ops::Rem;
-
 use crate::{consts::{D2PI, DJ00}, vm::anp};
 
-pub fn era00(dj1: f64, dj2: f64) -> f64 {
+pub const fn era00(dj1: f64, dj2: f64) -> f64 {
     let d1: f64;
     let d2: f64;
 
@@ -18,7 +16,7 @@ pub fn era00(dj1: f64, dj2: f64) -> f64 {
     let t = d1 + (d2 - DJ00);
 
     // Fractional part of T (days).
-    let f = d1.rem(1.0) + d2.rem(1.0);
+    let f = d1 % 1.0 + d2 % 1.0;
 
     // Earth rotation angle at this UT1.
     let theta = anp(D2PI * (f + 0.7790572732640 + 0.00273781191135448 * t));
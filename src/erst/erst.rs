@@ -7,39 +7,17 @@ pub fn ee00a() {}
 /// Equation of the equinoxes, IAU 2000B
 pub fn ee00b() {}
 
-/// Equation of the equinoxes, IAU 2006/2000A
-pub fn ee06a() {}
-
 /// Equation of the equinoxes complementary terms, IAU 2000
 pub fn eect00() {}
 
 /// Equation of the equinoxes, IAU 1994
 pub fn eqeq94() {}
 
-/// Greenwich mean sidereal time, IAU 2000
-pub fn gmst00() {}
-
-/// Greenwich mean sidereal time, IAU 2006
-pub fn gmst06() {}
-
-/// Greenwich mean sidereal time, IAU 1982
-pub fn gmst82() {}
-
 /// Greenwich apparent sidereal time, IAU 2000A
 pub fn gst00a() {}
 
 /// Greenwich apparent sidereal time, IAU 2000B
 pub fn gst00b() {}
 
-/// Greenwich apparent ST, IAU 2006, given NPB matrix
-pub fn gst06() {}
-
-/// Greenwich apparent sidereal time, IAU 2006/2000A
-pub fn gst06a() {}
-
 /// Greenwich apparent sidereal time, IAU 1994
 pub fn gst94() {}
-
-pub fn pvstar() {}
-
-pub fn starpv() {}
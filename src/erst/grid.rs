@@ -0,0 +1,179 @@
+use crate::consts::{D2PI, DAS2R, DJ00, DJC};
+use crate::pnp::eo06a;
+use crate::stream::Chebyshev;
+use crate::vm::anp;
+
+/* Span (days) and degree of the Chebyshev fits of the equation of the
+   origins in gst06a_grid. */
+const SPAN: f64 = 1.0;
+const DEGREE: usize = 12;
+
+fn check(n: usize, m: usize, name: &str) {
+    assert_eq!(n, m, "{}: length mismatch", name);
+}
+
+///  Earth rotation angle (IAU 2000) for many UT1 dates
+///
+///  Given:
+///  ```text
+///     uta      double      UT1 as a 2-part Julian Date:  the part
+///     utb      double[n]   common to all the dates, and one part each
+///  ```
+///  Returned (function value):
+///  ```text
+///              double[n]   Earth rotation angle (radians), 0-2pi
+///  ```
+///  Notes:
+///
+///  1) The results are those of `era00(uta, utb[i])`, to the last bit.
+///     The part of the date that is shared is split once, so that the
+///     loop is a fractional part and a multiply-add per date, which the
+///     compiler is free to vectorize.
+///
+///  2) For the best precision uta should be the 0h UT1 nearest the
+///     dates (or J2000.0) and utb the fractions of a day from it.
+pub fn era00_grid(uta: f64, utb: &[f64]) -> Vec<f64> {
+    let mut out = vec![0.0; utb.len()];
+    era00_grid_into(uta, utb, &mut out);
+    out
+}
+
+///  As `era00_grid`, writing to era (of the same length as utb).
+///
+///  # Panics
+///
+///  If utb and era differ in length.
+pub fn era00_grid_into(uta: f64, utb: &[f64], era: &mut [f64]) {
+    check(utb.len(), era.len(), "era00_grid");
+
+    /* As era00, with uta taken as the larger part. */
+    let fa = uta % 1.0;
+    let ta = uta - DJ00;
+    for (e, &ub) in era.iter_mut().zip(utb) {
+        let (t, f) = if ub <= uta {
+            (ub + ta, ub % 1.0 + fa)
+        } else {
+            (uta + (ub - DJ00), fa + ub % 1.0)
+        };
+        *e = anp(D2PI * (f + 0.7790572732640 + 0.00273781191135448 * t));
+    }
+}
+
+///  Greenwich mean sidereal time (IAU 2006) for many dates
+///
+///  Given:
+///  ```text
+///     uta      double      UT1 as a 2-part Julian Date (Note 1)
+///     utb      double[n]
+///     tta      double      TT as a 2-part Julian Date
+///     ttb      double[n]
+///  ```
+///  Returned (function value):
+///  ```text
+///              double[n]   Greenwich mean sidereal time (radians)
+///  ```
+///  Notes:
+///
+///  1) The dates are uta+utb[i] and tta+ttb[i], as for `era00_grid`.
+///     The results are those of `gmst06`, to the last bit.
+///
+///  2) Panics if utb and ttb differ in length.
+pub fn gmst06_grid(uta: f64, utb: &[f64], tta: f64, ttb: &[f64]) -> Vec<f64> {
+    let mut out = vec![0.0; utb.len()];
+    gmst06_grid_into(uta, utb, tta, ttb, &mut out);
+    out
+}
+
+///  As `gmst06_grid`, writing to gmst.
+///
+///  # Panics
+///
+///  If utb, ttb and gmst differ in length.
+pub fn gmst06_grid_into(uta: f64, utb: &[f64], tta: f64, ttb: &[f64], gmst: &mut [f64]) {
+    check(utb.len(), ttb.len(), "gmst06_grid");
+    era00_grid_into(uta, utb, gmst);
+
+    let ta = tta - DJ00;
+    for (g, &tb) in gmst.iter_mut().zip(ttb) {
+        let t = (ta + tb) / DJC;
+        *g = anp(*g +
+            (    0.014506     +
+            ( 4612.156534     +
+            (    1.3915817    +
+            (   -0.00000044   +
+            (   -0.000029956  +
+            (   -0.0000000368 )
+            * t) * t) * t) * t) * t) * DAS2R);
+    }
+}
+
+///  Greenwich apparent sidereal time (IAU 2006/2000A) for many dates
+///
+///  Given:
+///  ```text
+///     uta      double      UT1 as a 2-part Julian Date (Note 1)
+///     utb      double[n]
+///     tta      double      TT as a 2-part Julian Date
+///     ttb      double[n]
+///  ```
+///  Returned (function value):
+///  ```text
+///              double[n]   Greenwich apparent sidereal time (radians)
+///  ```
+///  Notes:
+///
+///  1) The dates are uta+utb[i] and tta+ttb[i], as for `era00_grid`,
+///     in any order.
+///
+///  2) GAST is ERA minus the equation of the origins.  The EO, which
+///     needs the full precession-nutation series, is fitted by a
+///     Chebyshev polynomial over each day of the TT dates (`Chebyshev`,
+///     13 evaluations of `eo06a` per day) and the ERA is evaluated at
+///     every date.  The results agree with `gst06a` to about 1e-13
+///     radians;  if there are fewer dates than nodes, `eo06a` is simply
+///     evaluated at each.
+///
+///  3) Panics if utb and ttb differ in length.
+pub fn gst06a_grid(uta: f64, utb: &[f64], tta: f64, ttb: &[f64]) -> Vec<f64> {
+    let mut out = vec![0.0; utb.len()];
+    gst06a_grid_into(uta, utb, tta, ttb, &mut out);
+    out
+}
+
+///  As `gst06a_grid`, writing to gast.
+///
+///  # Panics
+///
+///  If utb, ttb and gast differ in length.
+pub fn gst06a_grid_into(uta: f64, utb: &[f64], tta: f64, ttb: &[f64], gast: &mut [f64]) {
+    check(utb.len(), ttb.len(), "gst06a_grid");
+    era00_grid_into(uta, utb, gast);
+    if ttb.is_empty() {
+        return;
+    }
+
+    /* The days spanned by the TT dates. */
+    let t0 = ttb.iter().copied().fold(f64::INFINITY, f64::min);
+    let t1 = ttb.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let nspan = ((t1 - t0) / SPAN).floor() as usize + 1;
+
+    if ttb.len() <= nspan * (DEGREE + 1) {
+        for (g, &tb) in gast.iter_mut().zip(ttb) {
+            *g = anp(*g - eo06a(tta, tb));
+        }
+        return;
+    }
+
+    let fits: Vec<Chebyshev<1>> = (0..nspan)
+        .map(|k| {
+            let a = t0 + k as f64 * SPAN;
+            Chebyshev::fit(a, a + SPAN, DEGREE, |tb| Ok([eo06a(tta, tb)]))
+        })
+        .collect::<Result<_, i32>>()
+        .expect("eo06a does not fail");
+
+    for (g, &tb) in gast.iter_mut().zip(ttb) {
+        let k = (((tb - t0) / SPAN) as usize).min(nspan - 1);
+        *g = anp(*g - fits[k].eval(tb)[0]);
+    }
+}
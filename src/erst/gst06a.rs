@@ -0,0 +1,50 @@
+use crate::pnp::pnm06a;
+
+use super::gst06;
+
+///  Greenwich apparent sidereal time (consistent with IAU 2000 and 2006
+///  resolutions).
+///
+///  This function is part of the International Astronomical Union's
+///  SOFA (Standards of Fundamental Astronomy) software collection.
+///
+///  Status:  canonical model.
+///
+///  Given:
+///     uta,utb    double    UT1 as a 2-part Julian Date (Notes 1,2)
+///     tta,ttb    double    TT as a 2-part Julian Date (Notes 1,2)
+///
+///  Returned (function value):
+///                double    Greenwich apparent sidereal time (radians)
+///
+///  Notes:
+///
+///  1) The UT1 and TT dates uta+utb and tta+ttb respectively, are both
+///     Julian Dates, apportioned in any convenient way between the
+///     argument pairs (see `gmst06`).
+///
+///  2) Both UT1 and TT are required, UT1 to predict the Earth rotation
+///     and TT to predict the effects of precession-nutation.  If UT1 is
+///     used for both purposes, errors of order 100 microarcseconds
+///     result.
+///
+///  3) This GAST is compatible with the IAU 2000/2006 resolutions and
+///     must be used only in conjunction with IAU 2006 precession and
+///     IAU 2000A nutation.
+///
+///  4) The result is returned in the range 0 to 2pi.
+///
+///  Called:
+///     iauPnm06a    classical NPB matrix, IAU 2006/2000A
+///     iauGst06     Greenwich apparent ST, IAU 2006, given NPB matrix
+///
+///  Reference:
+///
+///     Wallace, P.T. & Capitaine, N., 2006, Astron.Astrophys. 459, 981
+pub fn gst06a(uta: f64, utb: f64, tta: f64, ttb: f64) -> f64 {
+    /* Classical nutation x precession x bias matrix, IAU 2000A. */
+    let rnpb = pnm06a(tta, ttb);
+
+    /* Greenwich apparent sidereal time. */
+    gst06(uta, utb, tta, ttb, &rnpb)
+}
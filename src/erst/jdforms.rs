@@ -0,0 +1,29 @@
+use crate::cal::{jd_forms, Jd};
+
+use super::*;
+
+jd_forms! {
+    value era00_jd = era00() -> f64;
+    value ee06a_jd = ee06a() -> f64;
+}
+
+/// As `gmst06`, with the UT1 and TT dates as `Jd`s (or (date1, date2)
+/// pairs).
+pub fn gmst06_jd(ut: impl Into<Jd>, tt: impl Into<Jd>) -> f64 {
+    let (Jd(uta, utb), Jd(tta, ttb)) = (ut.into(), tt.into());
+    gmst06(uta, utb, tta, ttb)
+}
+
+/// As `gst06`, with the UT1 and TT dates as `Jd`s (or (date1, date2)
+/// pairs).
+pub fn gst06_jd(ut: impl Into<Jd>, tt: impl Into<Jd>, rnpb: &[[f64; 3]; 3]) -> f64 {
+    let (Jd(uta, utb), Jd(tta, ttb)) = (ut.into(), tt.into());
+    gst06(uta, utb, tta, ttb, rnpb)
+}
+
+/// As `gst06a`, with the UT1 and TT dates as `Jd`s (or (date1, date2)
+/// pairs).
+pub fn gst06a_jd(ut: impl Into<Jd>, tt: impl Into<Jd>) -> f64 {
+    let (Jd(uta, utb), Jd(tta, ttb)) = (ut.into(), tt.into());
+    gst06a(uta, utb, tta, ttb)
+}
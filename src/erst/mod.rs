@@ -1,6 +1,40 @@
 //! Earth rotation angle and sidereal time
+//!
+//! The purely polynomial models here (era00, gmst00, gmst06, gmst82) are
+//! `const fn`, as are obl80 and obl06 in pnp.
+//!
+//! All take UT1.  Without IERS data, UT1 can be estimated from the
+//! approximate Delta T model with `ts::Epoch::to_approx`.
+//!
+//! For sidereal time over many dates at once (a night's schedule at
+//! one-second steps, say) use the `_grid` forms, which share the setup
+//! and, for GAST, the precession-nutation between the dates.
 mod erst;
 pub use erst::*;
 
 mod era00;
-pub use era00::*;
\ No newline at end of file
+pub use era00::*;
+
+mod ee06a;
+pub use ee06a::*;
+
+mod gmst00;
+pub use gmst00::*;
+
+mod gmst06;
+pub use gmst06::*;
+
+mod gmst82;
+pub use gmst82::*;
+
+mod gst06;
+pub use gst06::*;
+
+mod gst06a;
+pub use gst06a::*;
+
+mod grid;
+pub use grid::*;
+
+mod jdforms;
+pub use jdforms::*;
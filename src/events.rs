@@ -0,0 +1,300 @@
+//! Rise, set and transit times
+//!
+//! The altitude and hour angle of a target are sampled over a time
+//! span at a fixed step, bracketing each crossing of the altitude
+//! threshold (rise, set) and of the meridian (upper transit), and each
+//! bracket is then refined to about a millisecond by regula falsi.
+//! The CIP coordinates are interpolated between daily nodes, which
+//! costs a few milliarcseconds and saves re-evaluating the nutation
+//! series at every step.
+//!
+//! The target is a fixed GCRS direction, the Sun, or any body for which
+//! the caller supplies an ephemeris.  The Earth orientation parameters
+//! come from a function of the epoch, so that a table or model can be
+//! consulted as the search proceeds;  leap seconds are those of `dat`.
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::astro::{refract, sun_direction, Site};
+use crate::consts::{DAU, DAYSEC, DD2R};
+use crate::coords::gd2gc;
+use crate::erst::era00;
+use crate::frames::{EarthOrientation, EopProvider, OMEGA_EARTH};
+use crate::horeq::hd2ae;
+use crate::pnp::{c2ixys, pom00, sp00};
+use crate::stream::xys;
+use crate::ts::{Epoch, TimeScale};
+use crate::vm::{anpm, c2s, s2c, PvVec, RotMat};
+
+/// Rise/set altitude of a star:  refraction at the horizon, -34'.
+pub const ALT_STAR: f64 = -0.5667 * DD2R;
+
+/// Rise/set altitude of the Sun's upper limb:  refraction and
+/// semidiameter, -50'.
+pub const ALT_SUN: f64 = -0.8333 * DD2R;
+
+/// Civil twilight:  the Sun's centre 6 deg below the horizon.
+pub const ALT_CIVIL: f64 = -6.0 * DD2R;
+
+/// Nautical twilight, -12 deg.
+pub const ALT_NAUTICAL: f64 = -12.0 * DD2R;
+
+/// Astronomical twilight, -18 deg.
+pub const ALT_ASTRONOMICAL: f64 = -18.0 * DD2R;
+
+/* Convergence of the refinement (days). */
+const TOL: f64 = 1e-8;
+
+/// GCRS RA,Dec (radians) of a body at an epoch.
+pub type Ephemeris<'a> = dyn Fn(&Epoch) -> Result<(f64, f64), i32> + 'a;
+
+/// Body whose events are sought
+pub enum Target<'a> {
+    /// Fixed RA,Dec, GCRS (radians).
+    Fixed { ra: f64, dec: f64 },
+    /// The Sun, topocentric apparent place (see `sun_topocentric`).
+    Sun,
+    /// GCRS RA,Dec (radians) at a given epoch, for example a Moon or
+    /// planet ephemeris.
+    Ephemeris(&'a Ephemeris<'a>),
+}
+
+///  Event search settings
+///
+///  ```text
+///     altitude     threshold altitude (radians, default ALT_STAR)
+///     refraction   (refa, refb) from refco, or None (default)
+///     step         sampling step (days, default 1/48)
+///  ```
+///  With refraction None the threshold applies to the geometric
+///  altitude, and the conventional thresholds (ALT_STAR, ALT_SUN)
+///  allow for refraction at the horizon.  With refraction given, the
+///  altitude is refracted (see `refract`) before comparison;  the A,B
+///  model is unreliable within a few degrees of the horizon, so this
+///  is for thresholds well above it.
+///
+///  Two events of the same kind closer than the step may be missed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EventOptions {
+    pub altitude: f64,
+    pub refraction: Option<(f64, f64)>,
+    pub step: f64,
+}
+
+impl Default for EventOptions {
+    fn default() -> Self {
+        EventOptions { altitude: ALT_STAR, refraction: None, step: 1.0 / 48.0 }
+    }
+}
+
+impl EventOptions {
+    /// Default settings with the given threshold altitude.
+    pub fn with_altitude(altitude: f64) -> Self {
+        EventOptions { altitude, ..Default::default() }
+    }
+}
+
+/// Kind of event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    Rise,
+    Set,
+    /// Upper transit (hour angle zero).
+    Transit,
+}
+
+/// An event and its epoch (in the time scale of the search start).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Event {
+    pub kind: EventKind,
+    pub epoch: Epoch,
+}
+
+/// First rise, set and transit in a day (see `rise_set_transit`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RiseSetTransit {
+    pub rise: Option<Epoch>,
+    pub set: Option<Epoch>,
+    pub transit: Option<Epoch>,
+}
+
+/* Hour angle and altitude, given the CIP. */
+fn local(target: &Target, site: &Site, eo: &EarthOrientation, epoch: &Epoch, cip: &[f64; 3])
+         -> Result<(f64, f64), i32> {
+    /* GCRS to ITRS. */
+    let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+    let (ut1, ut2) = epoch.to_ut1(eo.ut1_utc)?.jd.into();
+    let rc2i = c2ixys(cip[0], cip[1], cip[2]);
+    let rpom = pom00(eo.xp, eo.yp, sp00(tt1, tt2));
+    let r = RotMat::new(rpom) * RotMat::rz(era00(ut1, ut2)) * RotMat::new(rc2i);
+
+    let p = match target {
+        Target::Fixed { ra, dec } => s2c(*ra, *dec),
+        Target::Sun => {
+            /* Site pv, GCRS, au and au/day. */
+            let mut xyz = [0.0; 3];
+            gd2gc(1, site.elong, site.phi, site.hm, &mut xyz)?;
+            let v = [-OMEGA_EARTH * xyz[1], OMEGA_EARTH * xyz[0], 0.0];
+            let obs = PvVec::new(r.apply_inverse(&xyz), r.apply_inverse(&v)).scale2(1.0 / DAU, DAYSEC / DAU);
+            sun_direction(tt1, tt2, &obs)?.1
+        }
+        Target::Ephemeris(f) => {
+            let (ra, dec) = f(epoch)?;
+            s2c(ra, dec)
+        }
+    };
+    let (hm, dec) = c2s(&(RotMat::rz(site.elong) * r * p));
+    let (_, el) = hd2ae(-hm, dec, site.phi);
+    Ok((anpm(-hm), el))
+}
+
+///  Hour angle and altitude of a target
+///
+///  Given:
+///  ```text
+///     target   Target             the body
+///     site     Site               geodetic position (WGS84)
+///     eop      EopProvider        UT1-UTC, polar motion, dX,dY
+///     epoch    Epoch              UTC, TAI or TT
+///  ```
+///  Returned (function value):
+///  ```text
+///              double             hour angle (radians, +/-pi)
+///              double             geometric altitude (radians)
+///  ```
+///  Status values are those of `gcrs_to_itrs` and of the target.
+pub fn hour_angle_altitude(target: &Target, site: &Site, eop: &(impl EopProvider + ?Sized), epoch: &Epoch)
+                           -> Result<(f64, f64), i32> {
+    let eo = &eop.eop(epoch)?;
+    local(target, site, eo, epoch, &xys(epoch, eo)?)
+}
+
+/* Regula falsi (Illinois) for a root of f in [a,b], fa and fb of
+   opposite sign. */
+fn refine(f: impl Fn(f64) -> Result<f64, i32>, mut a: f64, mut fa: f64, mut b: f64, mut fb: f64)
+          -> Result<f64, i32> {
+    let mut side = 0;
+    let mut c0 = a;
+    for _ in 0..60 {
+        let c = (a * fb - b * fa) / (fb - fa);
+        let fc = f(c)?;
+        if fc * fb > 0.0 {
+            b = c;
+            fb = fc;
+            if side == -1 {
+                fa /= 2.0;
+            }
+            side = -1;
+        } else {
+            a = c;
+            fa = fc;
+            if side == 1 {
+                fb /= 2.0;
+            }
+            side = 1;
+        }
+        if (c - c0).abs() < TOL || fc == 0.0 {
+            return Ok(c);
+        }
+        c0 = c;
+    }
+    Ok((a + b) / 2.0)
+}
+
+///  Rise, set and transit events in a time span
+///
+///  Given:
+///  ```text
+///     target   Target                        the body
+///     site     Site                          geodetic position (WGS84)
+///     eop      EopProvider                   Earth orientation source
+///     start    Epoch                         start of the span (UTC,
+///                                            TAI or TT)
+///     days     double                        length of the span (days)
+///     opts     EventOptions                  threshold, refraction, step
+///  ```
+///  Returned (function value):
+///  ```text
+///              Vec<Event>                    events in time order, in
+///                                            the time scale of start
+///  ```
+///  Status values are those of `hour_angle_altitude`.
+///
+///  Notes:
+///
+///  1) For a fixed direction, use the GCRS place including aberration
+///     (e.g. astrometric RA,Dec from atcc13 plus ab), or accept errors
+///     of up to 20 arcsec, about 1.5 s in the event times.
+///
+///  2) A circumpolar or never-rising target has transits but no rise
+///     or set.
+///
+///  3) A span that is not positive (or not a number) has no events.
+pub fn find_events(target: &Target, site: &Site, eop: &(impl EopProvider + ?Sized),
+                   start: &Epoch, days: f64, opts: &EventOptions) -> Result<Vec<Event>, i32> {
+    if days.is_nan() || days <= 0.0 {
+        return Ok(Vec::new());
+    }
+    let at = |dt: f64| Epoch::new(start.scale, start.jd + dt);
+
+    /* CIP at daily nodes. */
+    let nn = days.ceil().max(1.0) as usize;
+    let hn = days / nn as f64;
+    let nodes = (0..=nn)
+        .map(|i| {
+            let t = at(i as f64 * hn);
+            xys(&t, &eop.eop(&t)?)
+        })
+        .collect::<Result<Vec<_>, i32>>()?;
+    let cip = |dt: f64| {
+        let i = ((dt / hn) as usize).min(nn - 1);
+        let f = dt / hn - i as f64;
+        [0, 1, 2].map(|k| nodes[i][k] + f * (nodes[i + 1][k] - nodes[i][k]))
+    };
+
+    let sample = |dt: f64| -> Result<(f64, f64), i32> {
+        let t = at(dt);
+        let (ha, mut el) = local(target, site, &eop.eop(&t)?, &t, &cip(dt))?;
+        if let Some((refa, refb)) = opts.refraction {
+            el = FRAC_PI_2 - refract(refa, refb, FRAC_PI_2 - el);
+        }
+        Ok((ha, el - opts.altitude))
+    };
+
+    let n = (days / opts.step).ceil().max(1.0) as usize;
+    let h = days / n as f64;
+    let mut events = Vec::new();
+    let (mut t0, (mut ha0, mut f0)) = (0.0, sample(0.0)?);
+    for i in 1..=n {
+        let t1 = i as f64 * h;
+        let (ha1, f1) = sample(t1)?;
+        if (f0 < 0.0) != (f1 < 0.0) {
+            let t = refine(|dt| Ok(sample(dt)?.1), t0, f0, t1, f1)?;
+            let kind = if f0 < 0.0 { EventKind::Rise } else { EventKind::Set };
+            events.push(Event { kind, epoch: at(t) });
+        }
+        if ha0 < 0.0 && ha1 >= 0.0 && ha1 - ha0 < PI {
+            let t = refine(|dt| Ok(sample(dt)?.0), t0, ha0, t1, ha1)?;
+            events.push(Event { kind: EventKind::Transit, epoch: at(t) });
+        }
+        (t0, ha0, f0) = (t1, ha1, f1);
+    }
+    events.sort_by(|a, b| (a.epoch.jd - b.epoch.jd).total_cmp(&0.0));
+    Ok(events)
+}
+
+///  The first rise, set and transit in the day following start
+///
+///  See `find_events`;  each is None if it does not occur.
+pub fn rise_set_transit(target: &Target, site: &Site, eop: &(impl EopProvider + ?Sized),
+                        start: &Epoch, opts: &EventOptions) -> Result<RiseSetTransit, i32> {
+    let mut rst = RiseSetTransit::default();
+    for e in find_events(target, site, eop, start, 1.0, opts)? {
+        let slot = match e.kind {
+            EventKind::Rise => &mut rst.rise,
+            EventKind::Set => &mut rst.set,
+            EventKind::Transit => &mut rst.transit,
+        };
+        slot.get_or_insert(e.epoch);
+    }
+    Ok(rst)
+}
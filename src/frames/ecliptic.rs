@@ -0,0 +1,115 @@
+use crate::cal::epj;
+use crate::consts::DJ00;
+use crate::coords::{ecm06, ltecm};
+use crate::ts::{Epoch, TimeScale};
+use crate::vm::{anp, anpm, c2s, s2c, PvVec, RotMat};
+
+///  Mean ecliptic and equinox
+///
+///  ```text
+///     OfDate     of the given epoch, IAU 2006 precession (ecm06)
+///     J2000      of J2000.0, IAU 2006 (the epoch is not used)
+///     LongTerm   of the given epoch, Vondrak et al. long-term
+///                precession (ltecm, Note 1)
+///  ```
+///  Notes:
+///
+///  1) OfDate and LongTerm agree to within 100 microarcseconds during
+///     the 20th and 21st centuries.  Outside a few thousand years of
+///     J2000 use LongTerm, which is valid for +/- 200,000 years.
+///
+///  2) All three include the frame bias, so that they are rotations
+///     of the ICRS (GCRS).  J2000 differs from the FK5 ecliptic
+///     equator and equinox of J2000 by less than 25 mas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ecliptic {
+    OfDate,
+    J2000,
+    LongTerm,
+}
+
+///  ICRS to ecliptic rotation matrix.
+///
+///  Given:
+///  ```text
+///     ecl      Ecliptic   ecliptic and equinox
+///     epoch    Epoch      UTC, TAI or TT
+///  ```
+///  Returned (function value):
+///  ```text
+///              RotMat     [ecliptic] = r * [ICRS]
+///  ```
+///  Status values are those of the time scale conversions (see
+///  `Epoch::to`).
+pub fn icrs_to_ecliptic(ecl: Ecliptic, epoch: &Epoch) -> Result<RotMat, i32> {
+    let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+    let r = match ecl {
+        Ecliptic::OfDate => ecm06(tt1, tt2),
+        Ecliptic::J2000 => ecm06(DJ00, 0.0),
+        Ecliptic::LongTerm => ltecm(epj(tt1, tt2)),
+    };
+    Ok(RotMat::new(r))
+}
+
+///  Ecliptic to ICRS rotation matrix, the transpose of
+///  `icrs_to_ecliptic`.
+pub fn ecliptic_to_icrs(ecl: Ecliptic, epoch: &Epoch) -> Result<RotMat, i32> {
+    Ok(icrs_to_ecliptic(ecl, epoch)?.transpose())
+}
+
+///  Transform an ICRS (BCRS or GCRS) position/velocity into ecliptic
+///  coordinates.
+///
+///  Given:
+///  ```text
+///     ecl      Ecliptic   ecliptic and equinox
+///     epoch    Epoch      UTC, TAI or TT
+///     pv       PvVec      equatorial position and velocity
+///  ```
+///  Returned (function value):
+///  ```text
+///              PvVec      ecliptic position and velocity (same units)
+///  ```
+///  Note:
+///
+///     The ecliptic of date is treated as inertial:  both parts are
+///     rotated by the same matrix and the precession rate (about
+///     2.4e-7 radians per year) is not applied to the velocity, as is
+///     usual for osculating elements referred to the ecliptic of date.
+pub fn icrs_to_ecliptic_pv(ecl: Ecliptic, epoch: &Epoch, pv: &PvVec) -> Result<PvVec, i32> {
+    Ok(pv.rotate(&icrs_to_ecliptic(ecl, epoch)?))
+}
+
+///  Transform an ecliptic position/velocity into the ICRS, the inverse
+///  of `icrs_to_ecliptic_pv`.
+pub fn ecliptic_to_icrs_pv(ecl: Ecliptic, epoch: &Epoch, pv: &PvVec) -> Result<PvVec, i32> {
+    Ok(pv.rotate_inverse(&icrs_to_ecliptic(ecl, epoch)?))
+}
+
+///  ICRS RA,Dec to ecliptic longitude and latitude.
+///
+///  Given:
+///  ```text
+///     ecl      Ecliptic   ecliptic and equinox
+///     epoch    Epoch      UTC, TAI or TT
+///     dr,dd    double     ICRS right ascension and declination (radians)
+///  ```
+///  Returned:
+///  ```text
+///     dl,db    double     ecliptic longitude and latitude (radians)
+///  ```
+///  For OfDate this is eqec06 and for LongTerm lteqec.
+pub fn icrs_to_ecliptic_lonlat(ecl: Ecliptic, epoch: &Epoch, dr: f64, dd: f64)
+                               -> Result<(f64, f64), i32> {
+    let (a, b) = c2s(&icrs_to_ecliptic(ecl, epoch)?.apply(&s2c(dr, dd)));
+    Ok((anp(a), anpm(b)))
+}
+
+///  Ecliptic longitude and latitude to ICRS RA,Dec, the inverse of
+///  `icrs_to_ecliptic_lonlat`.  For OfDate this is eceq06 and for
+///  LongTerm lteceq.
+pub fn ecliptic_to_icrs_lonlat(ecl: Ecliptic, epoch: &Epoch, dl: f64, db: f64)
+                               -> Result<(f64, f64), i32> {
+    let (a, b) = c2s(&icrs_to_ecliptic(ecl, epoch)?.apply_inverse(&s2c(dl, db)));
+    Ok((anp(a), anpm(b)))
+}
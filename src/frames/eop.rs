@@ -0,0 +1,100 @@
+use crate::consts::DAYSEC;
+use crate::ts::{deltat_approx, Epoch, TimeScale};
+
+///  Earth orientation parameters
+///
+///  The quantities published by the IERS (Bulletin A/B, EOP C04) that,
+///  together with the IAU 2006/2000A model, fix the orientation of the
+///  ITRS in the GCRS at a given instant.
+///  ```text
+///     ut1_utc  double   UT1-UTC (seconds)
+///     xp,yp    double   polar motion, CIP in the ITRS (radians)
+///     dx,dy    double   celestial pole offsets dX,dY (radians)
+///  ```
+///  IERS tables give xp,yp in arcseconds and dX,dY in milliarcseconds;
+///  `EarthOrientation::from_iers` takes those units.  Leaving a field
+///  zero drops the corresponding correction.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EarthOrientation {
+    pub ut1_utc: f64,
+    pub xp: f64,
+    pub yp: f64,
+    pub dx: f64,
+    pub dy: f64,
+}
+
+impl EarthOrientation {
+    /// From IERS units:  UT1-UTC in s, xp,yp in arcsec, dX,dY in mas.
+    pub fn from_iers(ut1_utc: f64, xp_as: f64, yp_as: f64, dx_mas: f64, dy_mas: f64) -> Self {
+        use crate::consts::{DAS2R, DMAS2R};
+        EarthOrientation {
+            ut1_utc,
+            xp: xp_as * DAS2R,
+            yp: yp_as * DAS2R,
+            dx: dx_mas * DMAS2R,
+            dy: dy_mas * DMAS2R,
+        }
+    }
+
+    ///  APPROXIMATE parameters from the Delta T model, for use when no
+    ///  IERS data are to hand:  UT1-UTC from `deltat_approx`, no polar
+    ///  motion and no pole offsets.
+    ///
+    ///  The epoch must be convertible to UTC (1960 onwards);  UT1-UTC
+    ///  may be out by several seconds, i.e. errors of a minute of arc
+    ///  or more in Earth rotation.
+    pub fn from_deltat(epoch: &Epoch) -> Result<Self, i32> {
+        let (u1, u2) = epoch.to(TimeScale::UTC)?.jd.into();
+        let (t1, t2) = epoch.to(TimeScale::TT)?.jd.into();
+        let ttmutc = ((t1 - u1) + (t2 - u2)) * DAYSEC;
+        Ok(EarthOrientation {
+            ut1_utc: ttmutc - deltat_approx(t1, t2),
+            ..Default::default()
+        })
+    }
+}
+
+///  A source of Earth orientation parameters
+///
+///  Everything that needs UT1-UTC, polar motion or dX,dY at an epoch
+///  (`gcrs_to_itrs` and the other terrestrial transformations, the
+///  sidereal times `era_at`, `gmst_at` and `gast_at`, `PipelineBuilder`
+///  and the rise/set search) takes a provider, so that the values can
+///  come from wherever the application keeps them:
+///  ```text
+///     EarthOrientation   the same values at every epoch
+///     ZeroEop            all zero (UT1 = UTC, no polar motion)
+///     EopTable           interpolated in a table of IERS values
+///     Fn(&Epoch) -> EarthOrientation
+///                        anything else, e.g. a database lookup
+///  ```
+///  A provider's own errors are passed on as the status of the
+///  function using it;  those of EopTable are listed there.
+pub trait EopProvider {
+    /// The parameters at an epoch (UTC, TAI or TT).
+    fn eop(&self, epoch: &Epoch) -> Result<EarthOrientation, i32>;
+}
+
+/// The same parameters at every epoch.
+impl EopProvider for EarthOrientation {
+    fn eop(&self, _epoch: &Epoch) -> Result<EarthOrientation, i32> {
+        Ok(*self)
+    }
+}
+
+impl<F: Fn(&Epoch) -> EarthOrientation> EopProvider for F {
+    fn eop(&self, epoch: &Epoch) -> Result<EarthOrientation, i32> {
+        Ok(self(epoch))
+    }
+}
+
+///  No Earth orientation data:  UT1-UTC, polar motion and dX,dY all
+///  zero.  UT1 is then in error by up to 0.9 s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ZeroEop;
+
+impl EopProvider for ZeroEop {
+    fn eop(&self, _epoch: &Epoch) -> Result<EarthOrientation, i32> {
+        Ok(EarthOrientation::default())
+    }
+}
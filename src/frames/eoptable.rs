@@ -0,0 +1,138 @@
+use crate::cal::jd2cal;
+use crate::consts::DJM0;
+use crate::ts::{dat, Epoch, TimeScale};
+
+use super::{EarthOrientation, EopProvider, TidalEop};
+
+/* Status:  epoch outside the table. */
+const ERANGE: i32 = -10;
+
+///  One day of an EOP table
+///
+///  ```text
+///     mjd      double             date (UTC MJD, usually 0h)
+///     eo       EarthOrientation   the parameters at that date
+///  ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EopEntry {
+    pub mjd: f64,
+    pub eo: EarthOrientation,
+}
+
+///  Earth orientation parameters interpolated in a table
+///
+///  The table is the caller's, typically daily values read from IERS
+///  finals2000A or EOP C04 (`EarthOrientation::from_iers` takes their
+///  units).  Between the entries the parameters are interpolated with
+///  a cubic through the four nearest points (fewer at the ends of the
+///  table), as the IERS routine INTERP does.
+///
+///  Notes:
+///
+///  1) UT1-UTC is interpolated as UT1-TAI, so that leap seconds within
+///     the table do no harm.  Dates before 1960 are not supported.
+///
+///  2) The published values are smoothed:  the diurnal and subdiurnal
+///     tidal variations (up to 0.5 mas in polar motion, 0.05 ms in UT1)
+///     are removed.  `with_tides` restores them after interpolation.
+///
+///  3) Status values of `eop`:
+///  ```text
+///     -10    epoch outside the table
+///     other  from the time scale conversions and dat
+///  ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EopTable {
+    entries: Vec<EopEntry>,
+    tides: Option<TidalEop>,
+}
+
+impl EopTable {
+    /// From a table of entries;  they are sorted by date.
+    pub fn new(mut entries: Vec<EopEntry>) -> Self {
+        entries.sort_by(|a, b| a.mjd.total_cmp(&b.mjd));
+        EopTable { entries, tides: None }
+    }
+
+    /// Add an entry (or replace that for the same date), for example
+    /// from the latest bulletin.
+    pub fn push(&mut self, e: EopEntry) {
+        self.entries.retain(|x| x.mjd != e.mjd);
+        self.entries.push(e);
+        self.entries.sort_by(|a, b| a.mjd.total_cmp(&b.mjd));
+    }
+
+    /// Add the tidal variations to the interpolated values.
+    pub fn with_tides(mut self, tides: TidalEop) -> Self {
+        self.tides = Some(tides);
+        self
+    }
+
+    /// The table.
+    pub fn entries(&self) -> &[EopEntry] {
+        &self.entries
+    }
+
+    /// The first and last dates (UTC MJD), if any.
+    pub fn span(&self) -> Option<(f64, f64)> {
+        Some((self.entries.first()?.mjd, self.entries.last()?.mjd))
+    }
+
+    /* Smoothed parameters for a date (UTC MJD). */
+    fn interpolate(&self, mjd: f64) -> Result<EarthOrientation, i32> {
+        let t = &self.entries;
+        let (first, last) = self.span().ok_or(ERANGE)?;
+        if !(first..=last).contains(&mjd) {
+            return Err(ERANGE);
+        }
+
+        /* The interval containing the date, and up to two nodes either side. */
+        let i = t.partition_point(|e| e.mjd <= mjd).clamp(1, t.len().max(2) - 1);
+        let nodes = &t[i.saturating_sub(2)..(i + 2).min(t.len())];
+
+        /* UT1-UTC as UT1-TAI. */
+        let ut1_tai = nodes
+            .iter()
+            .map(|e| Ok(e.eo.ut1_utc - tai_utc(e.mjd)?))
+            .collect::<Result<Vec<f64>, i32>>()?;
+
+        let f = |g: fn(&EarthOrientation) -> f64| lagrange(nodes, mjd, |k| g(&nodes[k].eo));
+        Ok(EarthOrientation {
+            ut1_utc: lagrange(nodes, mjd, |k| ut1_tai[k]) + tai_utc(mjd)?,
+            xp: f(|eo| eo.xp),
+            yp: f(|eo| eo.yp),
+            dx: f(|eo| eo.dx),
+            dy: f(|eo| eo.dy),
+        })
+    }
+}
+
+impl EopProvider for EopTable {
+    fn eop(&self, epoch: &Epoch) -> Result<EarthOrientation, i32> {
+        let (u1, u2) = epoch.to(TimeScale::UTC)?.jd.into();
+        let eo = self.interpolate((u1 - DJM0) + u2)?;
+        match &self.tides {
+            Some(tides) => eo.with_tides(epoch, tides),
+            None => Ok(eo),
+        }
+    }
+}
+
+/* TAI-UTC (s) for a date (UTC MJD). */
+fn tai_utc(mjd: f64) -> Result<f64, i32> {
+    let (iy, im, id, fd) = jd2cal(DJM0, mjd)?;
+    dat(iy, im, id, fd)
+}
+
+/* Lagrange interpolation at x through the nodes' dates and values y(k). */
+fn lagrange(nodes: &[EopEntry], x: f64, y: impl Fn(usize) -> f64) -> f64 {
+    (0..nodes.len())
+        .map(|k| {
+            let w: f64 = (0..nodes.len())
+                .filter(|&j| j != k)
+                .map(|j| (x - nodes[j].mjd) / (nodes[k].mjd - nodes[j].mjd))
+                .product();
+            w * y(k)
+        })
+        .sum()
+}
@@ -0,0 +1,152 @@
+use crate::consts::{DAS2R, DJ00, DJC, DPI};
+use crate::erst::gmst06;
+use crate::fundargs::{fad03, faf03, fal03, falp03, faom03};
+use crate::math::sin_cos;
+use crate::ts::{Epoch, TimeScale};
+
+use super::EarthOrientation;
+
+///  One term of a diurnal/subdiurnal EOP tidal series
+///
+///  ```text
+///     arg          multipliers of chi = GMST+pi and the Delaunay
+///                  arguments l, l', F, D, Omega
+///     xsin,xcos    coefficients for xp (microarcseconds)
+///     ysin,ycos    coefficients for yp (microarcseconds)
+///     utsin,utcos  coefficients for UT1 (microseconds)
+///  ```
+///  The contribution is xsin sin(arg) + xcos cos(arg), and so on, as in
+///  the IERS routines PMUT1_OCEANS and PM_GRAVI and the IERS
+///  Conventions (2010) Tables 5.1a,b and 8.2a,b, 8.3a,b.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TidalEopTerm {
+    pub arg: [i32; 6],
+    pub xsin: f64,
+    pub xcos: f64,
+    pub ysin: f64,
+    pub ycos: f64,
+    pub utsin: f64,
+    pub utcos: f64,
+}
+
+const fn pm(arg: [i32; 6], xsin: f64, xcos: f64, ysin: f64, ycos: f64) -> TidalEopTerm {
+    TidalEopTerm { arg, xsin, xcos, ysin, ycos, utsin: 0.0, utcos: 0.0 }
+}
+
+const fn ut(arg: [i32; 6], utsin: f64, utcos: f64) -> TidalEopTerm {
+    TidalEopTerm { arg, xsin: 0.0, xcos: 0.0, ysin: 0.0, ycos: 0.0, utsin, utcos }
+}
+
+/* Libration:  diurnal polar motion (IERS Conventions 2010, Table 5.1a,
+   microarcsec) and semidiurnal UT1 (Table 5.1b, microsec). */
+const LIBRATION: [TidalEopTerm; 21] = [
+    pm([ 1, -1,  0, -2,  0, -1],  -0.4,   0.3,  -0.3,  -0.4),
+    pm([ 1, -1,  0, -2,  0, -2],  -2.3,   1.3,  -1.3,  -2.3),
+    pm([ 1,  1,  0, -2, -2, -2],  -0.4,   0.3,  -0.3,  -0.4),
+    pm([ 1,  0,  0, -2,  0, -1],  -2.1,   1.2,  -1.2,  -2.1),
+    pm([ 1,  0,  0, -2,  0, -2], -11.4,   6.5,  -6.5, -11.4),
+    pm([ 1, -1,  0,  0,  0,  0],   0.8,  -0.5,   0.5,   0.8),
+    pm([ 1,  0,  0, -2,  2, -2],  -4.8,   2.7,  -2.7,  -4.8),
+    pm([ 1,  0,  0,  0,  0,  0],  14.3,  -8.2,   8.2,  14.3),
+    pm([ 1,  0,  0,  0,  0, -1],   1.9,  -1.1,   1.1,   1.9),
+    pm([ 1,  1,  0,  0,  0,  0],   0.8,  -0.4,   0.4,   0.8),
+    ut([ 2, -2,  0, -2,  0, -2],  0.05, -0.03),
+    ut([ 2,  0,  0, -2, -2, -2],  0.06, -0.03),
+    ut([ 2, -1,  0, -2,  0, -2],  0.35, -0.20),
+    ut([ 2,  1,  0, -2, -2, -2],  0.07, -0.04),
+    ut([ 2,  0,  0, -2,  0, -1], -0.07,  0.04),
+    ut([ 2,  0,  0, -2,  0, -2],  1.75, -1.01),
+    ut([ 2,  1,  0, -2,  0, -2], -0.05,  0.03),
+    ut([ 2,  0, -1, -2,  2, -2],  0.04, -0.03),
+    ut([ 2,  0,  0, -2,  2, -2],  0.76, -0.44),
+    ut([ 2,  0,  0,  0,  0,  0],  0.21, -0.12),
+    ut([ 2,  0,  0,  0,  0, -1],  0.06, -0.04),
+];
+
+///  Diurnal and subdiurnal variations of polar motion and UT1
+///
+///  The ocean-tidal and libration variations that the IERS removes
+///  from its published EOP series and that the user restores:  the
+///  "smoothed + tidal" parameters are those of `with_tides`.  They
+///  reach 0.5 mas in polar motion and 0.05 ms in UT1.
+///
+///  The libration series of the IERS Conventions (2010) are built in
+///  (`TidalEop::libration`).  The ocean-tide series (Tables 8.2 and
+///  8.3), or those of any other model, are supplied as terms, one list
+///  or several concatenated:
+///  ```text
+///     let mut terms = TidalEop::libration().terms;
+///     terms.extend(ocean_terms);
+///     let eo = eo_smoothed.with_tides(&epoch, &TidalEop::new(terms))?;
+///  ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TidalEop {
+    pub terms: Vec<TidalEopTerm>,
+}
+
+impl TidalEop {
+    pub fn new(terms: Vec<TidalEopTerm>) -> Self {
+        TidalEop { terms }
+    }
+
+    ///  The libration in polar motion and UT1 of the IERS Conventions
+    ///  (2010), Tables 5.1a (10 diurnal terms, up to 17 microarcsec)
+    ///  and 5.1b (11 semidiurnal terms, up to 2 microsec), as computed
+    ///  by the IERS routines PMSDNUT2 and UTLIBR.
+    pub fn libration() -> Self {
+        TidalEop::new(LIBRATION.to_vec())
+    }
+
+    ///  Corrections to polar motion (radians) and UT1 (s), (dxp, dyp,
+    ///  dut1), for a TT and a UT1 date.
+    ///
+    ///  chi is GMST (`gmst06`) + pi;  l, l', F, D, Omega are the IERS
+    ///  2003 fundamental arguments.
+    pub fn corrections_at(&self, tt1: f64, tt2: f64, ut1: f64, ut2: f64) -> (f64, f64, f64) {
+        let t = ((tt1 - DJ00) + tt2) / DJC;
+        let args = [
+            gmst06(ut1, ut2, tt1, tt2) + DPI,
+            fal03(t),
+            falp03(t),
+            faf03(t),
+            fad03(t),
+            faom03(t),
+        ];
+
+        let (mut dx, mut dy, mut dut) = (0.0, 0.0, 0.0);
+        for term in &self.terms {
+            let a: f64 = term.arg.iter().zip(&args).map(|(&n, &f)| n as f64 * f).sum();
+            let (s, c) = sin_cos(a);
+            dx += term.xsin * s + term.xcos * c;
+            dy += term.ysin * s + term.ycos * c;
+            dut += term.utsin * s + term.utcos * c;
+        }
+        (dx * DAS2R * 1e-6, dy * DAS2R * 1e-6, dut * 1e-6)
+    }
+
+    ///  Corrections for an epoch (UTC, TAI or TT), given UT1-UTC.
+    ///
+    ///  Status values are those of `Epoch::to` and `Epoch::to_ut1`.
+    pub fn corrections(&self, epoch: &Epoch, ut1_utc: f64) -> Result<(f64, f64, f64), i32> {
+        let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+        let (ut1, ut2) = epoch.to_ut1(ut1_utc)?.jd.into();
+        Ok(self.corrections_at(tt1, tt2, ut1, ut2))
+    }
+}
+
+impl EarthOrientation {
+    ///  The parameters with the diurnal and subdiurnal tidal variations
+    ///  added:  xp, yp and UT1-UTC from smoothed (published) values to
+    ///  the actual ones.  dX,dY are unchanged.
+    ///
+    ///  Status values are those of `TidalEop::corrections`.
+    pub fn with_tides(&self, epoch: &Epoch, tides: &TidalEop) -> Result<Self, i32> {
+        let (dx, dy, dut) = tides.corrections(epoch, self.ut1_utc)?;
+        Ok(EarthOrientation {
+            ut1_utc: self.ut1_utc + dut,
+            xp: self.xp + dx,
+            yp: self.yp + dy,
+            ..*self
+        })
+    }
+}
@@ -0,0 +1,174 @@
+use crate::consts::{D2PI, DJM0, DJM00, DMAS2R};
+use crate::math::sin_cos;
+use crate::ts::{Epoch, TimeScale};
+
+use super::EarthOrientation;
+
+///  A source of celestial pole offsets dX,dY
+///
+///  Corrections to the IAU 2006/2000A CIP coordinates X,Y, as consumed
+///  by `c2i06a_cip`, `pnm06a_cip` and EarthOrientation:  IERS
+///  observations, or a model such as `FcnModel` when none are loaded.
+pub trait CipCorrectionProvider {
+    /// dX,dY (radians) for the TT date date1+date2.
+    fn cip_corrections(&self, date1: f64, date2: f64) -> (f64, f64);
+}
+
+/// The parameters' own dX,dY, whatever the date.
+impl CipCorrectionProvider for EarthOrientation {
+    fn cip_corrections(&self, _date1: f64, _date2: f64) -> (f64, f64) {
+        (self.dx, self.dy)
+    }
+}
+
+/* Lambert's FCN amplitudes, 1984.0-2013.0:  MJD, xc, xs
+   (microarcseconds), as in the IERS routine FCNNUT. */
+const LAMBERT: [[f64; 3]; 30] = [
+    [45700.0,     4.55,   -36.58],   /* 1984.0 */
+    [46066.0,  -141.82,  -105.35],   /* 1985.0 */
+    [46431.0,  -246.56,  -170.21],   /* 1986.0 */
+    [46796.0,  -281.89,  -159.24],   /* 1987.0 */
+    [47161.0,  -255.05,   -43.58],   /* 1988.0 */
+    [47527.0,  -210.46,   -88.56],   /* 1989.0 */
+    [47892.0,  -187.79,   -57.35],   /* 1990.0 */
+    [48257.0,  -163.01,    26.26],   /* 1991.0 */
+    [48622.0,  -145.53,    44.65],   /* 1992.0 */
+    [48988.0,  -145.12,    51.49],   /* 1993.0 */
+    [49353.0,  -109.93,    16.87],   /* 1994.0 */
+    [49718.0,   -87.30,     5.36],   /* 1995.0 */
+    [50083.0,   -90.61,     1.52],   /* 1996.0 */
+    [50449.0,   -94.73,    35.35],   /* 1997.0 */
+    [50814.0,   -67.52,    27.57],   /* 1998.0 */
+    [51179.0,   -44.11,   -14.31],   /* 1999.0 */
+    [51544.0,     5.21,   -74.87],   /* 2000.0 */
+    [51910.0,    70.37,  -129.66],   /* 2001.0 */
+    [52275.0,    86.47,  -127.84],   /* 2002.0 */
+    [52640.0,   110.44,   -42.73],   /* 2003.0 */
+    [53005.0,   114.78,    -0.13],   /* 2004.0 */
+    [53371.0,   132.96,    -4.78],   /* 2005.0 */
+    [53736.0,   157.36,    28.63],   /* 2006.0 */
+    [54101.0,   160.40,    58.87],   /* 2007.0 */
+    [54466.0,   156.76,   101.24],   /* 2008.0 */
+    [54832.0,   142.99,   143.01],   /* 2009.0 */
+    [55197.0,    33.70,   184.46],   /* 2010.0 */
+    [55562.0,     0.76,   253.70],   /* 2011.0 */
+    [55927.0,    25.47,   271.66],   /* 2012.0 */
+    [56293.0,   113.42,   256.50],   /* 2013.0 */
+];
+
+///  One epoch of the free core nutation amplitudes
+///
+///  ```text
+///     mjd      double   date (MJD)
+///     xc,xs    double   cosine and sine amplitudes (microarcseconds)
+///  ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FcnAmplitude {
+    pub mjd: f64,
+    pub xc: f64,
+    pub xs: f64,
+}
+
+///  Empirical model of the free core nutation
+///
+///  The FCN as a retrograde circular motion of the CIP of period
+///  -430.21 days, with amplitudes that vary slowly and are fitted to
+///  VLBI data year by year (Lambert, IERS Conventions 2010 Section
+///  5.5.5, routine FCNNUT):
+///  ```text
+///     dX = xc cos(phi) - xs sin(phi)
+///     dY = xs cos(phi) + xc sin(phi)
+///     phi = 2 pi (MJD - 51544.5) / period
+///  ```
+///  The amplitudes are interpolated linearly between the table epochs
+///  and held at the end values outside them.  The table of the IERS
+///  Conventions (2010), 1984-2013, is built in (`lambert`);  as it is
+///  extended each year, later amplitudes can be added (`push`) or a
+///  table supplied (`new`).
+///
+///  Notes:
+///
+///  1) The FCN accounts for most of the dX,dY published by the IERS,
+///     about 0.1-0.3 mas;  the model reproduces it to about 0.05 mas
+///     within the table, less well when extrapolating.
+///
+///  2) Used for dX,dY when no IERS values are to hand, see
+///     `EarthOrientation::with_cip_corrections`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FcnModel {
+    pub period: f64,
+    amplitudes: Vec<FcnAmplitude>,
+}
+
+impl FcnModel {
+    /// The FCN period (days), Lambert's -430.21.
+    pub const PERIOD: f64 = -430.21;
+
+    /// From a table of amplitudes;  the entries are sorted by date.
+    pub fn new(mut amplitudes: Vec<FcnAmplitude>) -> Self {
+        amplitudes.sort_by(|a, b| a.mjd.total_cmp(&b.mjd));
+        FcnModel { period: Self::PERIOD, amplitudes }
+    }
+
+    /// Lambert's table as published with the IERS Conventions (2010),
+    /// yearly from 1984.0 to 2013.0, as used by the routine FCNNUT.
+    pub fn lambert() -> Self {
+        FcnModel::new(LAMBERT.iter().map(|&[mjd, xc, xs]| FcnAmplitude { mjd, xc, xs }).collect())
+    }
+
+    /// Add the amplitudes for a later epoch (or replace those for the
+    /// same epoch), for example from the latest table.
+    pub fn push(&mut self, a: FcnAmplitude) {
+        self.amplitudes.retain(|x| x.mjd != a.mjd);
+        self.amplitudes.push(a);
+        self.amplitudes.sort_by(|a, b| a.mjd.total_cmp(&b.mjd));
+    }
+
+    /// The amplitude table.
+    pub fn amplitudes(&self) -> &[FcnAmplitude] {
+        &self.amplitudes
+    }
+
+    /* Amplitudes (microarcseconds) for a date, interpolated. */
+    fn amplitude(&self, mjd: f64) -> (f64, f64) {
+        let t = &self.amplitudes;
+        match t.iter().position(|a| a.mjd > mjd) {
+            None => t.last().map_or((0.0, 0.0), |a| (a.xc, a.xs)),
+            Some(0) => (t[0].xc, t[0].xs),
+            Some(i) => {
+                let (a, b) = (&t[i - 1], &t[i]);
+                let f = (mjd - a.mjd) / (b.mjd - a.mjd);
+                (a.xc + f * (b.xc - a.xc), a.xs + f * (b.xs - a.xs))
+            }
+        }
+    }
+
+    /// dX,dY (radians) for a date (MJD).
+    pub fn xy(&self, mjd: f64) -> (f64, f64) {
+        let (xc, xs) = self.amplitude(mjd);
+        let (s, c) = sin_cos(D2PI * (mjd - DJM00) / self.period);
+        (
+            (xc * c - xs * s) * DMAS2R * 1e-3,
+            (xs * c + xc * s) * DMAS2R * 1e-3,
+        )
+    }
+}
+
+impl CipCorrectionProvider for FcnModel {
+    fn cip_corrections(&self, date1: f64, date2: f64) -> (f64, f64) {
+        self.xy((date1 - DJM0) + date2)
+    }
+}
+
+impl EarthOrientation {
+    ///  The parameters with dX,dY from a provider, e.g. an FCN model in
+    ///  place of missing IERS values.  The other fields are unchanged.
+    ///
+    ///  Status values are those of `Epoch::to`.
+    pub fn with_cip_corrections(&self, epoch: &Epoch, cip: &(impl CipCorrectionProvider + ?Sized))
+                                -> Result<Self, i32> {
+        let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+        let (dx, dy) = cip.cip_corrections(tt1, tt2);
+        Ok(EarthOrientation { dx, dy, ..*self })
+    }
+}
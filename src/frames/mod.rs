@@ -0,0 +1,30 @@
+//! Reference frame transformations
+mod ecliptic;
+pub use ecliptic::*;
+
+mod eop;
+pub use eop::*;
+
+mod eoptable;
+pub use eoptable::*;
+
+mod eoptide;
+pub use eoptide::*;
+
+mod fcn;
+pub use fcn::*;
+
+mod origins;
+pub use origins::*;
+
+mod sidereal;
+pub use sidereal::*;
+
+mod terrestrial;
+pub use terrestrial::*;
+
+mod teme;
+pub use teme::*;
+
+mod tide;
+pub use tide::*;
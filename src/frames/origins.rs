@@ -0,0 +1,93 @@
+use crate::pnp::{bpn2xy, eors, pnm06a, pnm06a_cip, s06};
+use crate::ts::{Epoch, TimeScale};
+use crate::vm::{anp, RotMat};
+
+use super::EopProvider;
+
+///  Equinox and CIO based quantities for one epoch
+///
+///  The classical NPB matrix, the CIO locator s and the equation of the
+///  origins (EO, ERA-GST) are computed once, after which right
+///  ascensions and sidereal angles convert between the two paradigms
+///  without evaluating the series again:
+///  ```text
+///     let eqc = CioEquinox::new(tt1, tt2);
+///     let ra_eq = eqc.cirs_to_true_equinox(ra_cirs);
+///     let gst = eqc.gst(era00(ut1, ut2));
+///  ```
+///  Notes:
+///
+///  1) IAU 2006/2000A (`pnm06a`, `s06`, `eors`):  EO is `eo06a`.
+///
+///  2) RA(equinox) = RA(CIO) - EO, and GST = ERA - EO.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CioEquinox {
+    rnpb: RotMat,
+    s: f64,
+    eo: f64,
+}
+
+impl CioEquinox {
+    /// For a TT date, IAU 2006/2000A.
+    pub fn new(date1: f64, date2: f64) -> Self {
+        Self::from_npb(date1, date2, pnm06a(date1, date2))
+    }
+
+    ///  For an epoch (UTC, TAI or TT), with the CIP moved by eo.dx,dy
+    ///  (see `pnm06a_cip`).  The other parameters are not used.
+    ///
+    ///  Status values are those of `Epoch::to`.
+    pub fn from_epoch(epoch: &Epoch, eop: &(impl EopProvider + ?Sized)) -> Result<Self, i32> {
+        let eo = &eop.eop(epoch)?;
+        let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+        Ok(Self::from_npb(tt1, tt2, pnm06a_cip(tt1, tt2, eo.dx, eo.dy)))
+    }
+
+    fn from_npb(date1: f64, date2: f64, rnpb: [[f64; 3]; 3]) -> Self {
+        let (x, y) = bpn2xy(&rnpb);
+        let s = s06(date1, date2, x, y);
+        CioEquinox { rnpb: RotMat::new(rnpb), s, eo: eors(&rnpb, s) }
+    }
+
+    /// The equation of the origins (radians).
+    pub fn eo(&self) -> f64 {
+        self.eo
+    }
+
+    /// The CIO locator s (radians).
+    pub fn s(&self) -> f64 {
+        self.s
+    }
+
+    /// GCRS to true equator and equinox of date.
+    pub fn rnpb(&self) -> RotMat {
+        self.rnpb
+    }
+
+    /// GCRS to CIRS, R_3(-EO) x rnpb.
+    pub fn rc2i(&self) -> RotMat {
+        RotMat::rz(-self.eo) * self.rnpb
+    }
+
+    /// CIO based (CIRS) RA to true equinox based RA (radians, 0-2pi).
+    pub fn cirs_to_true_equinox(&self, ra: f64) -> f64 {
+        anp(ra - self.eo)
+    }
+
+    /// True equinox based RA to CIO based (CIRS) RA (radians, 0-2pi).
+    pub fn true_equinox_to_cirs(&self, ra: f64) -> f64 {
+        anp(ra + self.eo)
+    }
+
+    /// Greenwich apparent sidereal time from the Earth rotation angle
+    /// (radians, 0-2pi).
+    pub fn gst(&self, era: f64) -> f64 {
+        anp(era - self.eo)
+    }
+
+    /// Earth rotation angle from Greenwich apparent sidereal time
+    /// (radians, 0-2pi).
+    pub fn era(&self, gst: f64) -> f64 {
+        anp(gst + self.eo)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::erst::{era00, gmst06, gst06};
+use crate::pnp::pnm06a_cip;
+use crate::ts::{Epoch, TimeScale};
+
+use super::EopProvider;
+
+///  Earth rotation angle at an epoch
+///
+///  Given:
+///  ```text
+///     epoch    Epoch              UTC, TAI or TT
+///     eop      EopProvider        only ut1_utc is used
+///  ```
+///  Returned (function value):
+///  ```text
+///              double             Earth rotation angle (radians, 0-2pi)
+///  ```
+///  Status values are those of `Epoch::to_ut1` and of the provider.
+pub fn era_at(epoch: &Epoch, eop: &(impl EopProvider + ?Sized)) -> Result<f64, i32> {
+    let eo = eop.eop(epoch)?;
+    let (ut1, ut2) = epoch.to_ut1(eo.ut1_utc)?.jd.into();
+    Ok(era00(ut1, ut2))
+}
+
+///  Greenwich mean sidereal time (IAU 2006) at an epoch
+///
+///  As `era_at`;  this is `gmst06` with UT1 and TT from the epoch.
+pub fn gmst_at(epoch: &Epoch, eop: &(impl EopProvider + ?Sized)) -> Result<f64, i32> {
+    let eo = eop.eop(epoch)?;
+    let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+    let (ut1, ut2) = epoch.to_ut1(eo.ut1_utc)?.jd.into();
+    Ok(gmst06(ut1, ut2, tt1, tt2))
+}
+
+///  Greenwich apparent sidereal time (IAU 2006/2000A) at an epoch
+///
+///  Given:
+///  ```text
+///     epoch    Epoch              UTC, TAI or TT
+///     eop      EopProvider        ut1_utc, dx and dy are used
+///  ```
+///  Returned (function value):
+///  ```text
+///              double             Greenwich apparent sidereal time
+///                                 (radians, 0-2pi)
+///  ```
+///  Notes:
+///
+///  1) This is `gst06` with the bias-precession-nutation matrix of
+///     `pnm06a_cip`, so that the equinox includes the observed
+///     celestial pole offsets;  with dX,dY zero it is `gst06a`.
+///
+///  2) Status values are those of `Epoch::to`, `Epoch::to_ut1` and of
+///     the provider.
+pub fn gast_at(epoch: &Epoch, eop: &(impl EopProvider + ?Sized)) -> Result<f64, i32> {
+    let eo = eop.eop(epoch)?;
+    let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+    let (ut1, ut2) = epoch.to_ut1(eo.ut1_utc)?.jd.into();
+    Ok(gst06(ut1, ut2, tt1, tt2, &pnm06a_cip(tt1, tt2, eo.dx, eo.dy)))
+}
@@ -0,0 +1,159 @@
+use crate::erst::{gmst06, gmst82, gst06};
+use crate::pnp::{pnm06a_cip, pom00};
+use crate::ts::{Epoch, TimeScale};
+use crate::vm::{anpm, cov6_transform, pv_jacobian, Cov6, PvVec, RotMat};
+
+use super::{EarthOrientation, EopProvider, OMEGA_EARTH};
+
+/* The TEME frame is the true equator of date with the x-axis at the
+   "uniform" (mean) equinox:  TEME = Rz(ee) * [true equator and equinox
+   of date], and the ITRS is W * Rz(GMST) * TEME. */
+
+///  GCRS to TEME rotation matrix.
+///
+///  Given:
+///  ```text
+///     epoch    Epoch              UTC, TAI or TT
+///     eop      EopProvider        only dx,dy are used
+///  ```
+///  Returned (function value):
+///  ```text
+///              RotMat             [TEME] = r * [GCRS]
+///  ```
+///  Notes:
+///
+///  1) TEME ("true equator, mean equinox") is the frame of the SGP4
+///     propagator and of the state vectors derived from two-line
+///     elements.  Its pole is the CIP and its x-axis lies in the true
+///     equator, at the mean equinox of date.
+///
+///  2) The NPB matrix is IAU 2006/2000A (`pnm06a`), with the celestial
+///     pole offsets dx,dy applied, and the equation of the equinoxes is
+///     taken from the same model, GAST-GMST (`ee06a` when dx,dy are
+///     zero).  Older implementations use IAU 1976/1980 and differ by
+///     up to a few metres at LEO distances.
+pub fn gcrs_to_teme(epoch: &Epoch, eop: &(impl EopProvider + ?Sized)) -> Result<RotMat, i32> {
+    let eo = &eop.eop(epoch)?;
+    let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+
+    /* True equator and equinox of date. */
+    let rnpb = pnm06a_cip(tt1, tt2, eo.dx, eo.dy);
+
+    /* Equation of the equinoxes (ERA cancels in the difference). */
+    let ee = anpm(gst06(0.0, 0.0, tt1, tt2, &rnpb) - gmst06(0.0, 0.0, tt1, tt2));
+
+    Ok(RotMat::rz(ee) * RotMat::new(rnpb))
+}
+
+///  TEME to GCRS rotation matrix, the transpose of `gcrs_to_teme`.
+pub fn teme_to_gcrs(epoch: &Epoch, eop: &(impl EopProvider + ?Sized)) -> Result<RotMat, i32> {
+    Ok(gcrs_to_teme(epoch, eop)?.transpose())
+}
+
+/* Rz(GMST 1982) and the polar motion matrix. */
+fn teme_components(epoch: &Epoch, eo: &EarthOrientation) -> Result<(RotMat, RotMat), i32> {
+    let (ut1, ut2) = epoch.to_ut1(eo.ut1_utc)?.jd.into();
+    Ok((RotMat::rz(gmst82(ut1, ut2)), RotMat::new(pom00(eo.xp, eo.yp, 0.0))))
+}
+
+///  TEME to ITRS rotation matrix.
+///
+///  Given:
+///  ```text
+///     epoch    Epoch              UTC, TAI or TT
+///     eop      EopProvider        ut1_utc, xp and yp are used
+///  ```
+///  Returned (function value):
+///  ```text
+///              RotMat             [ITRS] = r * [TEME]
+///  ```
+///  Notes:
+///
+///  1) To match SGP4 the sidereal angle is GMST (IAU 1982, `gmst82`)
+///     of UT1 and the polar motion matrix omits s', as in Vallado et
+///     al. (2006).  Results agree with that paper's reference case to
+///     about a centimetre.
+///
+///  2) This route and `teme_to_gcrs` followed by `gcrs_to_itrs` differ
+///     by the difference between GMST82 and the IAU 2006 GMST, a few
+///     milliarcseconds (some centimetres at LEO).
+///
+///  Reference:
+///
+///     Vallado, D.A., Crawford, P., Hujsak, R. & Kelso, T.S., 2006,
+///     "Revisiting Spacetrack Report #3", AIAA 2006-6753
+pub fn teme_to_itrs(epoch: &Epoch, eop: &(impl EopProvider + ?Sized)) -> Result<RotMat, i32> {
+    let eo = &eop.eop(epoch)?;
+    let (rst, rpom) = teme_components(epoch, eo)?;
+    Ok(rpom * rst)
+}
+
+///  ITRS to TEME rotation matrix, the transpose of `teme_to_itrs`.
+pub fn itrs_to_teme(epoch: &Epoch, eop: &(impl EopProvider + ?Sized)) -> Result<RotMat, i32> {
+    Ok(teme_to_itrs(epoch, eop)?.transpose())
+}
+
+///  Transform a TEME position/velocity, e.g. from SGP4, into the ITRS.
+///
+///  As `teme_to_itrs`, with the velocity corrected for the rotation of
+///  the Earth at the nominal rate OMEGA_EARTH (see `gcrs_to_itrs_pv`).
+///  Velocities must be per second.
+pub fn teme_to_itrs_pv(epoch: &Epoch, eop: &(impl EopProvider + ?Sized), pv: &PvVec) -> Result<PvVec, i32> {
+    let eo = &eop.eop(epoch)?;
+    let (rst, rpom) = teme_components(epoch, eo)?;
+    Ok(teme2t_pv(&rst, &rpom, pv))
+}
+
+///  As `teme_to_itrs_pv`, also transforming the 6x6 covariance, for
+///  example of an SGP4 state (see `gcrs_to_itrs_pv_cov`).
+pub fn teme_to_itrs_pv_cov(epoch: &Epoch, eop: &(impl EopProvider + ?Sized), pv: &PvVec, cov: &Cov6)
+                           -> Result<(PvVec, Cov6), i32> {
+    let eo = &eop.eop(epoch)?;
+    let (rst, rpom) = teme_components(epoch, eo)?;
+    let f = |x: &PvVec| teme2t_pv(&rst, &rpom, x);
+    Ok((f(pv), cov6_transform(&pv_jacobian(f), cov)))
+}
+
+/* TEME to ITRS pv, given the components. */
+fn teme2t_pv(rst: &RotMat, rpom: &RotMat, pv: &PvVec) -> PvVec {
+    /* To the pseudo Earth-fixed frame, removing omega x r. */
+    let t = pv.rotate(rst);
+    let v = [
+        t.v[0] + OMEGA_EARTH * t.p[1],
+        t.v[1] - OMEGA_EARTH * t.p[0],
+        t.v[2],
+    ];
+
+    PvVec::new(t.p, v).rotate(rpom)
+}
+
+///  Transform an ITRS position/velocity into TEME, the inverse of
+///  `teme_to_itrs_pv`.
+pub fn itrs_to_teme_pv(epoch: &Epoch, eop: &(impl EopProvider + ?Sized), pv: &PvVec) -> Result<PvVec, i32> {
+    let eo = &eop.eop(epoch)?;
+    let (rst, rpom) = teme_components(epoch, eo)?;
+    Ok(t2teme_pv(&rst, &rpom, pv))
+}
+
+///  As `itrs_to_teme_pv`, also transforming the 6x6 covariance (see
+///  `gcrs_to_itrs_pv_cov`).
+pub fn itrs_to_teme_pv_cov(epoch: &Epoch, eop: &(impl EopProvider + ?Sized), pv: &PvVec, cov: &Cov6)
+                           -> Result<(PvVec, Cov6), i32> {
+    let eo = &eop.eop(epoch)?;
+    let (rst, rpom) = teme_components(epoch, eo)?;
+    let f = |x: &PvVec| t2teme_pv(&rst, &rpom, x);
+    Ok((f(pv), cov6_transform(&pv_jacobian(f), cov)))
+}
+
+/* ITRS to TEME pv, given the components. */
+fn t2teme_pv(rst: &RotMat, rpom: &RotMat, pv: &PvVec) -> PvVec {
+    /* To the pseudo Earth-fixed frame, adding omega x r. */
+    let t = pv.rotate_inverse(rpom);
+    let v = [
+        t.v[0] - OMEGA_EARTH * t.p[1],
+        t.v[1] + OMEGA_EARTH * t.p[0],
+        t.v[2],
+    ];
+
+    PvVec::new(t.p, v).rotate_inverse(rst)
+}
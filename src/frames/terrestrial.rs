@@ -0,0 +1,145 @@
+use crate::erst::era00;
+use crate::pnp::{c2i06a_cip, pom00, sp00};
+use crate::ts::{Epoch, TimeScale};
+use crate::vm::{cov6_transform, pv_jacobian, Cov6, PvVec, RotMat};
+
+use super::{EarthOrientation, EopProvider};
+
+/// Nominal Earth rotation rate (radians per second of UT1), the rate
+/// of the IAU 2000 Earth rotation angle.
+pub const OMEGA_EARTH: f64 = 7.292115146706979e-5;
+
+/* The CIO-based pieces:  celestial-to-intermediate matrix, ERA and the
+   polar motion matrix. */
+fn components(epoch: &Epoch, eo: &EarthOrientation) -> Result<(RotMat, f64, RotMat), i32> {
+    let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+    let (ut1, ut2) = epoch.to_ut1(eo.ut1_utc)?.jd.into();
+
+    let rc2i = RotMat::new(c2i06a_cip(tt1, tt2, eo.dx, eo.dy));
+    let era = era00(ut1, ut2);
+    Ok((rc2i, era, RotMat::new(pom00(eo.xp, eo.yp, sp00(tt1, tt2)))))
+}
+
+///  GCRS to ITRS rotation matrix for a given epoch and set of Earth
+///  orientation parameters.
+///
+///  Given:
+///  ```text
+///     epoch    Epoch              UTC, TAI or TT (Note 1)
+///     eop      EopProvider        UT1-UTC, polar motion, dX,dY
+///  ```
+///  Returned (function value):
+///  ```text
+///              RotMat             [ITRS] = rc2t * [GCRS]
+///  ```
+///  Status values are those of the time scale conversions (see
+///  `Epoch::to`).
+///
+///  Notes:
+///
+///  1) TT is used for precession-nutation and s', UT1 (from UTC and
+///     eo.ut1_utc) for the Earth rotation angle.
+///
+///  2) This is `c2t06a_cip`:  IAU 2006/2000A, CIO based.
+pub fn gcrs_to_itrs(epoch: &Epoch, eop: &(impl EopProvider + ?Sized)) -> Result<RotMat, i32> {
+    let eo = &eop.eop(epoch)?;
+    let (rc2i, era, rpom) = components(epoch, eo)?;
+    Ok(rpom * RotMat::rz(era) * rc2i)
+}
+
+///  ITRS to GCRS rotation matrix, the transpose of `gcrs_to_itrs`.
+pub fn itrs_to_gcrs(epoch: &Epoch, eop: &(impl EopProvider + ?Sized)) -> Result<RotMat, i32> {
+    Ok(gcrs_to_itrs(epoch, eop)?.transpose())
+}
+
+///  Transform a GCRS position/velocity into the ITRS.
+///
+///  Given:
+///  ```text
+///     epoch    Epoch              UTC, TAI or TT
+///     eop      EopProvider        UT1-UTC, polar motion, dX,dY
+///     pv       PvVec              GCRS position and velocity (Note 1)
+///  ```
+///  Returned (function value):
+///  ```text
+///              PvVec              ITRS position and velocity
+///  ```
+///  Notes:
+///
+///  1) Velocities must be per second (e.g. m, m/s).  The ITRS velocity
+///     includes the term -omega x r due to the rotation of the Earth,
+///     taken at the nominal rate OMEGA_EARTH;  the rates of change of
+///     precession-nutation and polar motion (below 1e-11 rad/s) are
+///     neglected.
+///
+///  2) A point fixed in the ITRS comes out with zero velocity, and the
+///     inverse is `itrs_to_gcrs_pv`.
+pub fn gcrs_to_itrs_pv(epoch: &Epoch, eop: &(impl EopProvider + ?Sized), pv: &PvVec) -> Result<PvVec, i32> {
+    let eo = &eop.eop(epoch)?;
+    let (rc2i, era, rpom) = components(epoch, eo)?;
+    Ok(c2t_pv(&rc2i, era, &rpom, pv))
+}
+
+///  As `gcrs_to_itrs_pv`, also transforming the 6x6 covariance of the
+///  position/velocity (in the same units).
+///
+///  The Jacobian includes the rotation-rate term, so that position
+///  uncertainty feeds the velocity covariance.
+pub fn gcrs_to_itrs_pv_cov(epoch: &Epoch, eop: &(impl EopProvider + ?Sized), pv: &PvVec, cov: &Cov6)
+                           -> Result<(PvVec, Cov6), i32> {
+    let eo = &eop.eop(epoch)?;
+    let (rc2i, era, rpom) = components(epoch, eo)?;
+    let f = |x: &PvVec| c2t_pv(&rc2i, era, &rpom, x);
+    Ok((f(pv), cov6_transform(&pv_jacobian(f), cov)))
+}
+
+/* GCRS to ITRS pv, given the components. */
+fn c2t_pv(rc2i: &RotMat, era: f64, rpom: &RotMat, pv: &PvVec) -> PvVec {
+    /* To the terrestrial intermediate frame. */
+    let t = pv.rotate(&(RotMat::rz(era) * *rc2i));
+
+    /* Remove the rotation of the frame:  v' = v - omega x r. */
+    let v = [
+        t.v[0] + OMEGA_EARTH * t.p[1],
+        t.v[1] - OMEGA_EARTH * t.p[0],
+        t.v[2],
+    ];
+
+    PvVec::new(t.p, v).rotate(rpom)
+}
+
+///  Transform an ITRS position/velocity into the GCRS.
+///
+///  The inverse of `gcrs_to_itrs_pv`;  the same notes apply.  For a
+///  station at rest in the ITRS the result is its geocentric celestial
+///  velocity, about 465 m/s at the equator.
+pub fn itrs_to_gcrs_pv(epoch: &Epoch, eop: &(impl EopProvider + ?Sized), pv: &PvVec) -> Result<PvVec, i32> {
+    let eo = &eop.eop(epoch)?;
+    let (rc2i, era, rpom) = components(epoch, eo)?;
+    Ok(t2c_pv(&rc2i, era, &rpom, pv))
+}
+
+///  As `itrs_to_gcrs_pv`, also transforming the 6x6 covariance (see
+///  `gcrs_to_itrs_pv_cov`).
+pub fn itrs_to_gcrs_pv_cov(epoch: &Epoch, eop: &(impl EopProvider + ?Sized), pv: &PvVec, cov: &Cov6)
+                           -> Result<(PvVec, Cov6), i32> {
+    let eo = &eop.eop(epoch)?;
+    let (rc2i, era, rpom) = components(epoch, eo)?;
+    let f = |x: &PvVec| t2c_pv(&rc2i, era, &rpom, x);
+    Ok((f(pv), cov6_transform(&pv_jacobian(f), cov)))
+}
+
+/* ITRS to GCRS pv, given the components. */
+fn t2c_pv(rc2i: &RotMat, era: f64, rpom: &RotMat, pv: &PvVec) -> PvVec {
+    /* To the terrestrial intermediate frame. */
+    let t = pv.rotate_inverse(rpom);
+
+    /* Add the rotation of the frame:  v' = v + omega x r. */
+    let v = [
+        t.v[0] - OMEGA_EARTH * t.p[1],
+        t.v[1] + OMEGA_EARTH * t.p[0],
+        t.v[2],
+    ];
+
+    PvVec::new(t.p, v).rotate_inverse(&(RotMat::rz(era) * *rc2i))
+}
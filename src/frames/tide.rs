@@ -0,0 +1,314 @@
+use crate::cal::jd2cal;
+use crate::consts::{DAU, DAYSEC, DD2R, DJ00, DJC, TTMTAI};
+use crate::eph::{epv00, moon98};
+use crate::ts::{dat_status, Epoch, TimeScale};
+use crate::vm::pm;
+
+use super::{gcrs_to_itrs, EopProvider};
+
+/* Mass ratios Sun/Earth and Moon/Earth, and the Earth's equatorial
+   radius (m), as in the IERS routine DEHANTTIDEINEL. */
+const MASS_RATIO_SUN: f64 = 332946.0482;
+const MASS_RATIO_MOON: f64 = 0.0123000371;
+const RE: f64 = 6378136.6;
+
+/* Nominal degree 2 and degree 3 Love and Shida numbers. */
+const H20: f64 = 0.6078;
+const L20: f64 = 0.0847;
+const H3: f64 = 0.292;
+const L3: f64 = 0.015;
+
+/* Step 2, diurnal band (IERS Conventions 2010, Table 7.3a):
+   multipliers of s, h, p, N' and ps, and the radial and transverse
+   corrections, in phase and out of phase (mm), as in STEP2DIU. */
+const DIURNAL: [[f64; 9]; 31] = [
+    [-3.0,  0.0,  2.0,  0.0,  0.0,  -0.01,  0.00,  0.00,  0.00],
+    [-3.0,  2.0,  0.0,  0.0,  0.0,  -0.01,  0.00,  0.00,  0.00],
+    [-2.0,  0.0,  1.0, -1.0,  0.0,  -0.02,  0.00,  0.00,  0.00],
+    [-2.0,  0.0,  1.0,  0.0,  0.0,  -0.08,  0.00, -0.01,  0.01],
+    [-2.0,  2.0, -1.0,  0.0,  0.0,  -0.02,  0.00,  0.00,  0.00],
+    [-1.0,  0.0,  0.0, -1.0,  0.0,  -0.10,  0.00,  0.00,  0.00],
+    [-1.0,  0.0,  0.0,  0.0,  0.0,  -0.51,  0.00, -0.02,  0.03],
+    [-1.0,  2.0,  0.0,  0.0,  0.0,   0.01,  0.00,  0.00,  0.00],
+    [ 0.0, -2.0,  1.0,  0.0,  0.0,   0.01,  0.00,  0.00,  0.00],
+    [ 0.0,  0.0, -1.0,  0.0,  0.0,   0.02,  0.00,  0.00,  0.00],
+    [ 0.0,  0.0,  1.0,  0.0,  0.0,   0.06,  0.00,  0.00,  0.00],
+    [ 0.0,  0.0,  1.0,  1.0,  0.0,   0.01,  0.00,  0.00,  0.00],
+    [ 0.0,  2.0, -1.0,  0.0,  0.0,   0.01,  0.00,  0.00,  0.00],
+    [ 1.0, -3.0,  0.0,  0.0,  1.0,  -0.06,  0.00,  0.00,  0.00],
+    [ 1.0, -2.0,  0.0, -1.0,  0.0,   0.01,  0.00,  0.00,  0.00],
+    [ 1.0, -2.0,  0.0,  0.0,  0.0,  -1.23, -0.07,  0.06,  0.01],
+    [ 1.0, -1.0,  0.0,  0.0, -1.0,   0.02,  0.00,  0.00,  0.00],
+    [ 1.0, -1.0,  0.0,  0.0,  1.0,   0.04,  0.00,  0.00,  0.00],
+    [ 1.0,  0.0,  0.0, -1.0,  0.0,  -0.22,  0.01,  0.01,  0.00],
+    [ 1.0,  0.0,  0.0,  0.0,  0.0,  12.00, -0.80, -0.67, -0.03],
+    [ 1.0,  0.0,  0.0,  1.0,  0.0,   1.73, -0.12, -0.10,  0.00],
+    [ 1.0,  0.0,  0.0,  2.0,  0.0,  -0.04,  0.00,  0.00,  0.00],
+    [ 1.0,  1.0,  0.0,  0.0, -1.0,  -0.50, -0.01,  0.03,  0.00],
+    [ 1.0,  1.0,  0.0,  0.0,  1.0,   0.01,  0.00,  0.00,  0.00],
+    [ 0.0,  1.0,  0.0,  1.0, -1.0,  -0.01,  0.00,  0.00,  0.00],
+    [ 1.0,  2.0, -2.0,  0.0,  0.0,  -0.01,  0.00,  0.00,  0.00],
+    [ 1.0,  2.0,  0.0,  0.0,  0.0,  -0.11,  0.01,  0.01,  0.00],
+    [ 2.0, -2.0,  1.0,  0.0,  0.0,  -0.01,  0.00,  0.00,  0.00],
+    [ 2.0,  0.0, -1.0,  0.0,  0.0,  -0.02,  0.00,  0.00,  0.00],
+    [ 3.0,  0.0,  0.0,  0.0,  0.0,   0.00,  0.00,  0.00,  0.00],
+    [ 3.0,  0.0,  0.0,  1.0,  0.0,   0.00,  0.00,  0.00,  0.00],
+];
+
+/* Step 2, long-period band (Table 7.3b):  multipliers as above, and
+   the radial and transverse corrections in phase, then out of phase
+   (mm), as in STEP2LON. */
+const LONG_PERIOD: [[f64; 9]; 5] = [
+    [0.0, 0.0,  0.0, 1.0, 0.0,   0.47,  0.23,  0.16,  0.07],
+    [0.0, 2.0,  0.0, 0.0, 0.0,  -0.20, -0.12, -0.11, -0.05],
+    [1.0, 0.0, -1.0, 0.0, 0.0,  -0.11, -0.08, -0.09, -0.04],
+    [2.0, 0.0,  0.0, 0.0, 0.0,  -0.13, -0.11, -0.15, -0.07],
+    [2.0, 0.0,  0.0, 1.0, 0.0,  -0.05, -0.05, -0.06, -0.03],
+];
+
+/* Station geometry:  sin and cos of the geocentric latitude and of the
+   longitude. */
+struct Geometry {
+    sinphi: f64,
+    cosphi: f64,
+    sinla: f64,
+    cosla: f64,
+}
+
+impl Geometry {
+    fn new(xsta: &[f64; 3]) -> Self {
+        let rsta = pm(xsta);
+        let sinphi = xsta[2] / rsta;
+        let cosphi = (xsta[0] * xsta[0] + xsta[1] * xsta[1]).sqrt() / rsta;
+        Geometry {
+            sinphi,
+            cosphi,
+            sinla: xsta[1] / cosphi / rsta,
+            cosla: xsta[0] / cosphi / rsta,
+        }
+    }
+
+    /* Radial, north and east components to ITRS. */
+    fn to_xyz(&self, dr: f64, dn: f64, de: f64) -> [f64; 3] {
+        [
+            dr * self.cosla * self.cosphi - de * self.sinla - dn * self.sinphi * self.cosla,
+            dr * self.sinla * self.cosphi + de * self.cosla - dn * self.sinphi * self.sinla,
+            dr * self.sinphi + dn * self.cosphi,
+        ]
+    }
+}
+
+/* In-phase degree 2 and 3 displacement by one body, with the
+   latitude dependence of h2 and l2. */
+fn in_phase(xsta: &[f64; 3], xb: &[f64; 3], mass_ratio: f64, g: &Geometry) -> [f64; 3] {
+    let rsta = pm(xsta);
+    let rb = pm(xb);
+    let sc = (xsta[0] * xb[0] + xsta[1] * xb[1] + xsta[2] * xb[2]) / rsta / rb;
+
+    let t = 1.0 - 1.5 * g.cosphi * g.cosphi;
+    let h2 = H20 - 0.0006 * t;
+    let l2 = L20 + 0.0002 * t;
+
+    let p2 = 3.0 * (h2 / 2.0 - l2) * sc * sc - h2 / 2.0;
+    let p3 = 2.5 * (H3 - 3.0 * L3) * sc * sc * sc + 1.5 * (L3 - H3) * sc;
+    let x2 = 3.0 * l2 * sc;
+    let x3 = 1.5 * L3 * (5.0 * sc * sc - 1.0);
+
+    let fac2 = mass_ratio * RE * (RE / rb).powi(3);
+    let fac3 = fac2 * (RE / rb);
+
+    [0, 1, 2].map(|i| {
+        fac2 * (x2 * xb[i] / rb + p2 * xsta[i] / rsta)
+            + fac3 * (x3 * xb[i] / rb + p3 * xsta[i] / rsta)
+    })
+}
+
+/* Out-of-phase (mantle anelasticity) and l^(1) corrections by one body
+   in the diurnal and semidiurnal bands. */
+fn corrections(xb: &[f64; 3], mass_ratio: f64, g: &Geometry) -> [f64; 3] {
+    let (sinphi, cosphi, sinla, cosla) = (g.sinphi, g.cosphi, g.sinla, g.cosla);
+    let cos2phi = cosphi * cosphi - sinphi * sinphi;
+    let costwola = cosla * cosla - sinla * sinla;
+    let sintwola = 2.0 * cosla * sinla;
+
+    let rb = pm(xb);
+    let fac2 = mass_ratio * RE * (RE / rb).powi(3) / (rb * rb);
+
+    /* Diurnal and semidiurnal forcing, in and out of the station
+       meridian. */
+    let d_s = fac2 * xb[2] * (xb[0] * sinla - xb[1] * cosla);
+    let d_c = fac2 * xb[2] * (xb[0] * cosla + xb[1] * sinla);
+    let s_s = fac2 * ((xb[0] * xb[0] - xb[1] * xb[1]) * sintwola - 2.0 * xb[0] * xb[1] * costwola);
+    let s_c = fac2 * ((xb[0] * xb[0] - xb[1] * xb[1]) * costwola + 2.0 * xb[0] * xb[1] * sintwola);
+
+    /* Out of phase, diurnal (dhi = -0.0025, dli = -0.0007). */
+    let (dhi, dli) = (-0.0025, -0.0007);
+    let mut dr = -3.0 * dhi * sinphi * cosphi * d_s;
+    let mut dn = -3.0 * dli * cos2phi * d_s;
+    let mut de = -3.0 * dli * sinphi * d_c;
+
+    /* Out of phase, semidiurnal (dhi = -0.0022, dli = -0.0007). */
+    let (dhi, dli) = (-0.0022, -0.0007);
+    dr += -0.75 * dhi * cosphi * cosphi * s_s;
+    dn += 1.5 * dli * sinphi * cosphi * s_s;
+    de += -1.5 * dli * cosphi * s_c;
+
+    /* Latitude dependence from l^(1), diurnal and semidiurnal. */
+    let (l1d, l1sd) = (0.0012, 0.0024);
+    dn += -l1d * sinphi * sinphi * d_c;
+    de += l1d * sinphi * cos2phi * d_s;
+    dn += -l1sd / 2.0 * sinphi * cosphi * s_c;
+    de += -l1sd / 2.0 * sinphi * sinphi * cosphi * s_s;
+
+    g.to_xyz(dr, dn, de)
+}
+
+/* Doodson arguments s, h, p, N' and ps (degrees) at t Julian
+   centuries (TT) since J2000.0, s including the general precession in
+   longitude, and the hour angle tau of the mean Moon at fhr UTC hours
+   into the day. */
+fn doodson(t: f64, fhr: f64) -> ([f64; 5], f64) {
+    let s = 218.31664563 + (481267.88194 + (-0.0014663889 + 0.00000185139 * t) * t) * t;
+    let tau = 280.4606184 + (36000.7700536 + (0.00038793 - 0.0000000258 * t) * t) * t
+        + fhr * 15.0 - s;
+    let pr = (1.396971278 + (0.000308889 + (0.000000021 + 0.000000007 * t) * t) * t) * t;
+    let h = 280.46645 + (36000.7697489 + (0.00030322222 + (0.000000020 - 0.00000000654 * t) * t) * t) * t;
+    let p = 83.35324312 + (4069.01363525 + (-0.01032172222 + (-0.0000124991 + 0.00000005263 * t) * t) * t) * t;
+    let zns = 234.95544499 + (1934.13626197 + (-0.00207561111 + (-0.00000213944 + 0.00000001650 * t) * t) * t) * t;
+    let ps = 282.93734098 + (1.71945766667 + (0.00045688889 + (-0.00000001778 - 0.00000000334 * t) * t) * t) * t;
+    ([(s + pr) % 360.0, h % 360.0, p % 360.0, zns % 360.0, ps % 360.0], tau % 360.0)
+}
+
+/* Frequency-dependent corrections to the Love and Shida numbers
+   (Step 2), diurnal and long-period bands. */
+fn step2(xsta: &[f64; 3], t: f64, fhr: f64, g: &Geometry) -> [f64; 3] {
+    let (sinphi, cosphi) = (g.sinphi, g.cosphi);
+    let (args, tau) = doodson(t, fhr);
+    let arg = |c: &[f64; 9]| (0..5).map(|k| c[k] * args[k]).sum::<f64>();
+
+    /* Diurnal band, the phase measured from the station meridian. */
+    let zla = xsta[1].atan2(xsta[0]);
+    let mut d = [0.0; 3];
+    for c in &DIURNAL {
+        let (sf, cf) = ((tau + arg(c)) * DD2R + zla).sin_cos();
+        let dr = 2.0 * sinphi * cosphi * (c[5] * sf + c[6] * cf);
+        let dn = (cosphi * cosphi - sinphi * sinphi) * (c[7] * sf + c[8] * cf);
+        let de = sinphi * (c[7] * cf - c[8] * sf);
+        let x = g.to_xyz(dr, dn, de);
+        d = [0, 1, 2].map(|i| d[i] + x[i]);
+    }
+
+    /* Long-period band. */
+    for c in &LONG_PERIOD {
+        let (sf, cf) = (arg(c) * DD2R).sin_cos();
+        let dr = (3.0 * sinphi * sinphi - 1.0) / 2.0 * (c[5] * cf + c[7] * sf);
+        let dn = 2.0 * cosphi * sinphi * (c[6] * cf + c[8] * sf);
+        let x = g.to_xyz(dr, dn, 0.0);
+        d = [0, 1, 2].map(|i| d[i] + x[i]);
+    }
+
+    /* Millimeters to meters. */
+    d.map(|x| x / 1000.0)
+}
+
+///  Displacement of a station by the solid Earth tides
+///
+///  Given:
+///  ```text
+///     xsta   double[3]   geocentric ITRS position of the station (m)
+///     xsun   double[3]   geocentric ITRS position of the Sun (m)
+///     xmon   double[3]   geocentric ITRS position of the Moon (m)
+///     utc1   double      UTC as a 2-part...
+///     utc2   double      ...quasi Julian Date (Note 2)
+///  ```
+///  Returned (function value):
+///  ```text
+///            double[3]   displacement of the station (ITRS, m)
+///  ```
+///  Status values (from jd2cal and dat):
+///  ```text
+///     -1   unacceptable date
+///  ```
+///  Notes:
+///
+///  1) This is the IERS Conventions (2010) model, Section 7.1.1,
+///     following the IERS routine DEHANTTIDEINEL.  Step 1 is the degree
+///     2 and 3 in-phase displacements with the latitude dependence of
+///     the Love and Shida numbers, and the out-of-phase and l^(1)
+///     corrections in the diurnal and semidiurnal bands.  Step 2 is the
+///     frequency-dependent corrections (Tables 7.3a,b, STEP2DIU and
+///     STEP2LON):  about a centimeter radial, mostly from K1, and a
+///     few millimeters in the long-period band.
+///
+///  2) The date is used only by Step 2, the hour angle of the mean Moon
+///     coming from the UTC hour and the Doodson arguments from the
+///     corresponding TT, as in DEHANTTIDEINEL.  It is a quasi-JD as in
+///     `utctai`;  before 1960, TT-UTC is taken as 32.184s.
+///
+///  3) The displacement is "conventional tide free":  the permanent
+///     tide (-12 cm radial at the poles, +6 cm at the equator) is part
+///     of it, as ITRF station positions require.
+///
+///  4) The total reaches about 40 cm radial and several centimeters
+///     horizontal.  The corrected position xsta + displacement can then
+///     be used in the terrestrial-to-celestial chain (`itrs_to_gcrs_pv`).
+///
+///  Reference:
+///
+///     Petit, G. & Luzum, B. (eds.), IERS Conventions (2010), IERS
+///     Technical Note No. 36, BKG (2010), Section 7.1.1
+pub fn solid_tide(xsta: &[f64; 3], xsun: &[f64; 3], xmon: &[f64; 3], utc1: f64, utc2: f64)
+                  -> Result<[f64; 3], i32> {
+    let g = Geometry::new(xsta);
+
+    /* Step 1. */
+    let mut d = [0.0; 3];
+    for (xb, mass_ratio) in [(xsun, MASS_RATIO_SUN), (xmon, MASS_RATIO_MOON)] {
+        let a = in_phase(xsta, xb, mass_ratio, &g);
+        let b = corrections(xb, mass_ratio, &g);
+        d = [0, 1, 2].map(|i| d[i] + a[i] + b[i]);
+    }
+
+    /* UTC hours into the day, and TT in Julian centuries since J2000. */
+    let (iy, im, id, fd) = jd2cal(utc1, utc2)?;
+    let (dat, _) = dat_status(iy, im, id, fd)?;
+    let t = ((utc1 - DJ00) + utc2) / DJC + (dat + TTMTAI) / DAYSEC / DJC;
+
+    /* Step 2. */
+    let c = step2(xsta, t, fd * 24.0, &g);
+    Ok([0, 1, 2].map(|i| d[i] + c[i]))
+}
+
+///  Displacement of a station by the solid Earth tides at an epoch
+///
+///  Given:
+///  ```text
+///     xsta     double[3]          geocentric ITRS position (m)
+///     epoch    Epoch              UTC, TAI or TT
+///     eop      EopProvider        UT1-UTC, polar motion, dX,dY
+///  ```
+///  Returned (function value):
+///  ```text
+///              double[3]          displacement (ITRS, m)
+///  ```
+///  Status values:
+///  ```text
+///      1   date outside 1900-2100 (from epv00)
+///     other  from the time scale conversions and solid_tide
+///  ```
+///  The geometric positions of the Sun (`epv00`) and Moon (`moon98`)
+///  are rotated into the ITRS with `gcrs_to_itrs` and passed to
+///  `solid_tide`.  Their errors, up to 30 km for the Moon, change the
+///  displacement by less than 0.1 mm.
+pub fn solid_tide_at(xsta: &[f64; 3], epoch: &Epoch, eop: &(impl EopProvider + ?Sized)) -> Result<[f64; 3], i32> {
+    let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+    let (pvh, _) = epv00(tt1, tt2).ok_or(1)?;
+    let pvm = moon98(tt1, tt2);
+
+    let (utc1, utc2) = epoch.to(TimeScale::UTC)?.jd.into();
+
+    let rc2t = gcrs_to_itrs(epoch, eop)?;
+    let xsun = rc2t * pvh[0].map(|x| -x * DAU);
+    let xmon = rc2t * pvm[0].map(|x| x * DAU);
+    solid_tide(xsta, &xsun, &xmon, utc1, utc2)
+}
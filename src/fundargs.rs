@@ -1,6 +1,6 @@
 //! Fundamental arguments for nutation etc.
 use std::ops::Rem;
-use crate::consts::{DAS2R, TURNAS, D2PI};
+use crate::consts::{DAS2R, DJ00, DJC, TURNAS, D2PI};
 
 /// mean elongation of the Moon from the Sun
 pub fn fad03(t: f64) -> f64 {
@@ -64,7 +64,10 @@ pub fn fame03(t: f64) -> f64 {
 }
 
 /// mean longitude of Neptune
-pub fn fane03() {}
+pub fn fane03(t: f64) -> f64 {
+    /* Mean longitude of Neptune (IERS Conventions 2003). */
+    (5.311886287 + 3.8133035638 * t).rem(D2PI)
+}
 
 /// mean longitude of the Moon’s ascending node
 pub fn faom03(t: f64) -> f64 {
@@ -97,3 +100,79 @@ pub fn faur03(t: f64) -> f64 {
 pub fn fave03(t: f64) -> f64 {
     (3.176146697 + 1021.3285546211 * t).rem(D2PI)
 }
+
+///  Fundamental arguments for one epoch
+///
+///  The IERS Conventions 2003 arguments, as returned by the fa*03
+///  functions, evaluated once for a TT date.  Series functions that
+///  accept a cache (`nut00a_with`, `nut06a_with`, `s06_with`) use it
+///  instead of recomputing the arguments, so that a pipeline step
+///  needing several of them pays for the arguments only once:
+///  ```text
+///     let fa = EpochCache::new(tt1, tt2);
+///     let (dp, de) = nut06a_with(&fa);
+///     let s = s06_with(&fa, x, y);
+///  ```
+///  The cache is opt-in;  the plain date forms build one internally.
+///  All angles are in radians, t in Julian centuries since J2000.0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EpochCache {
+    date1: f64,
+    date2: f64,
+    pub t: f64,  // TT interval since J2000.0 (Julian centuries)
+    pub l: f64,  // mean anomaly of the Moon
+    pub lp: f64, // mean anomaly of the Sun
+    pub f: f64,  // mean longitude of the Moon minus that of the node
+    pub d: f64,  // mean elongation of the Moon from the Sun
+    pub om: f64, // mean longitude of the Moon's ascending node
+    pub me: f64, // mean longitude of Mercury
+    pub ve: f64, // mean longitude of Venus
+    pub e: f64,  // mean longitude of Earth
+    pub ma: f64, // mean longitude of Mars
+    pub ju: f64, // mean longitude of Jupiter
+    pub sa: f64, // mean longitude of Saturn
+    pub ur: f64, // mean longitude of Uranus
+    pub ne: f64, // mean longitude of Neptune
+    pub pa: f64, // general accumulated precession in longitude
+}
+
+impl EpochCache {
+    /// Arguments for the TT date date1+date2.
+    pub fn new(date1: f64, date2: f64) -> Self {
+        let t = ((date1 - DJ00) + date2) / DJC;
+        EpochCache {
+            date1,
+            date2,
+            t,
+            l: fal03(t),
+            lp: falp03(t),
+            f: faf03(t),
+            d: fad03(t),
+            om: faom03(t),
+            me: fame03(t),
+            ve: fave03(t),
+            e: fae03(t),
+            ma: fama03(t),
+            ju: faju03(t),
+            sa: fasa03(t),
+            ur: faur03(t),
+            ne: fane03(t),
+            pa: fapa03(t),
+        }
+    }
+
+    /// The TT date the arguments are for.
+    pub fn date(&self) -> (f64, f64) {
+        (self.date1, self.date2)
+    }
+
+    /// Move the cache to another date, recomputing only if the date
+    /// has changed.  Returns whether it was recomputed.
+    pub fn update(&mut self, date1: f64, date2: f64) -> bool {
+        if (date1, date2) == (self.date1, self.date2) {
+            return false;
+        }
+        *self = EpochCache::new(date1, date2);
+        true
+    }
+}
@@ -1,18 +1,629 @@
 //! Gnomonic projection
-/// Solve for tangent point, spherical
-pub fn tpors() {}
+use crate::vm::anp;
+use crate::math::{atan2, cos, sin};
 
-/// Solve for tangent point, vector
-pub fn tporv() {}
+/* Smallest distance of a point from the tangent plane's pole. */
+const TINY: f64 = 1e-6;
 
-/// Deproject tangent plane to celestial, spherical
-pub fn tpsts() {}
+/// TPORS (solve for tangent point, spherical)
+///
+///  In the tangent plane projection, given the rectangular coordinates
+///  of a star and its spherical coordinates, determine the spherical
+///  coordinates of the tangent point.
+///
+///  Given:
+///  ```text
+///     xi,eta     double  rectangular coordinates of star image (Note 2)
+///     a,b        double  star's spherical coordinates (Note 3)
+///  ```
+///  Returned (function value):
+///  ```text
+///     n          int     number of solutions:
+///                        0 = no solutions returned (Note 5)
+///                        1 = only the first solution is useful (Note 6)
+///                        2 = both solutions are useful (Note 6)
+///     a01,b01    double  tangent point's spherical coordinates, Soln. 1
+///     a02,b02    double  tangent point's spherical coordinates, Soln. 2
+///  ```
+///  Notes:
+///
+///  1) The tangent plane projection is also called the "gnomonic
+///     projection" and the "central projection".
+///
+///  2) The eta axis points due north in the adopted coordinate system.
+///     If the spherical coordinates are observed (RA,Dec), the tangent
+///     plane coordinates (xi,eta) are conventionally called the
+///     "standard coordinates".  If the spherical coordinates are with
+///     respect to a right-handed triad, (xi,eta) are also right-handed.
+///     The units of (xi,eta) are, effectively, radians at the tangent
+///     point.
+///
+///  3) All angular arguments are in radians.
+///
+///  4) The angles a01 and a02 are returned in the range 0-2pi.  The
+///     angles b01 and b02 are returned in the range +/-pi, but in the
+///     usual, non-pole-crossing, case, the range is +/-pi/2.
+///
+///  5) Cases where there is no solution can arise only near the poles.
+///     For example, it is clearly impossible for a star at the pole
+///     itself to have a non-zero xi value, and hence it is meaningless
+///     to ask where the tangent point would have to be to bring about
+///     this combination of xi and dec.
+///
+///  6) Also near the poles, cases can arise where there are two useful
+///     solutions.  The return value indicates whether the second of the
+///     two solutions returned is useful;  1 indicates only one useful
+///     solution, the usual case.
+///
+///  7) The basis of the algorithm is to solve the spherical triangle
+///     PSC, where P is the north celestial pole, S is the star and C is
+///     the tangent point.  The spherical coordinates of the tangent
+///     point are [a0,b0];  writing rho^2 = (xi^2+eta^2) and
+///     r^2 = (1+rho^2), side c is then (pi/2-b), side p is sqrt(xi^2+
+///     eta^2) and side s (to be found) is (pi/2-b0).  Angle C is given
+///     by sin(C) = xi/rho and cos(C) = eta/rho.  Angle P (to be found)
+///     is the longitude difference between star and tangent point
+///     (a-a0).
+///
+///  8) This function is a member of the following set:
+///     ```text
+///         spherical      vector         solve for
+///
+///         tpxes          tpxev            xi,eta
+///         tpsts          tpstv             star
+///       > tpors <        tporv            origin
+///     ```
+///  References:
+///  ```text
+///     Calabretta M.R. & Greisen, E.W., 2002, "Representations of
+///     celestial coordinates in FITS", Astron.Astrophys. 395, 1077
+///
+///     Green, R.M., "Spherical Astronomy", Cambridge University Press,
+///     1987, Chapter 13.
+///  ```
+pub fn tpors(xi: f64, eta: f64, a: f64, b: f64) -> (i32, f64, f64, f64, f64) {
+    let xi2 = xi * xi;
+    let r = (1.0 + xi2 + eta * eta).sqrt();
+    let sb = sin(b);
+    let cb = cos(b);
+    let rsb = r * sb;
+    let rcb = r * cb;
+    let w2 = rcb * rcb - xi2;
+    if w2 >= 0.0 {
+        let mut w = w2.sqrt();
+        let mut s = rsb - eta * w;
+        let mut c = rsb * eta + w;
+        if xi == 0.0 && w == 0.0 {
+            w = 1.0;
+        }
+        let a01 = anp(a - atan2(xi, w));
+        let b01 = atan2(s, c);
+        w = -w;
+        s = rsb - eta * w;
+        c = rsb * eta + w;
+        let a02 = anp(a - atan2(xi, w));
+        let b02 = atan2(s, c);
+        let n = if rsb.abs() < 1.0 { 1 } else { 2 };
+        (n, a01, b01, a02, b02)
+    } else {
+        (0, 0.0, 0.0, 0.0, 0.0)
+    }
+}
 
-/// Deproject tangent plane to celestial, vector
-pub fn tpstv() {}
+/// TPORV (solve for tangent point, vector)
+///
+///  In the tangent plane projection, given the rectangular coordinates
+///  of a star and its direction cosines, determine the direction
+///  cosines of the tangent point.
+///
+///  Given:
+///  ```text
+///     xi,eta   double      rectangular coordinates of star image (Note 2)
+///     v        double[3]   star's direction cosines (Note 3)
+///  ```
+///  Returned (function value):
+///  ```text
+///     n        int         number of solutions:
+///                          0 = no solutions returned (Note 4)
+///                          1 = only the first solution is useful (Note 5)
+///                          2 = both solutions are useful (Note 5)
+///     v01      double[3]   tangent point's direction cosines, Solution 1
+///     v02      double[3]   tangent point's direction cosines, Solution 2
+///  ```
+///  Notes:
+///
+///  1) The tangent plane projection is also called the "gnomonic
+///     projection" and the "central projection".
+///
+///  2) The eta axis points due north in the adopted coordinate system.
+///     If the direction cosines represent observed (RA,Dec), the tangent
+///     plane coordinates (xi,eta) are conventionally called the
+///     "standard coordinates".  If the direction cosines are with
+///     respect to a right-handed triad, (xi,eta) are also right-handed.
+///     The units of (xi,eta) are, effectively, radians at the tangent
+///     point.
+///
+///  3) The vector v must be of unit length or the result will be wrong.
+///
+///  4) Cases where there is no solution can arise only near the poles.
+///     For example, it is clearly impossible for a star at the pole
+///     itself to have a non-zero xi value, and hence it is meaningless
+///     to ask where the tangent point would have to be.
+///
+///  5) Also near the poles, cases can arise where there are two useful
+///     solutions.  The return value indicates whether the second of the
+///     two solutions returned is useful;  1 indicates only one useful
+///     solution, the usual case.
+///
+///  6) This function is the vector form of `tpors`, and is a member of
+///     the following set:
+///     ```text
+///         spherical      vector         solve for
+///
+///         tpxes          tpxev            xi,eta
+///         tpsts          tpstv             star
+///         tpors        > tporv <          origin
+///     ```
+pub fn tporv(xi: f64, eta: f64, v: &[f64; 3]) -> (i32, [f64; 3], [f64; 3]) {
+    let [x, y, z] = *v;
+    let rxy2 = x * x + y * y;
+    let xi2 = xi * xi;
+    let eta2p1 = eta * eta + 1.0;
+    let r = (xi2 + eta2p1).sqrt();
+    let rsb = r * z;
+    let rcb = r * rxy2.sqrt();
+    let w2 = rcb * rcb - xi2;
+    if w2 > 0.0 {
+        let mut w = w2.sqrt();
+        let mut c = (rsb * eta + w) / (eta2p1 * (rxy2 * (w2 + xi2)).sqrt());
+        let v01 = [c * (x * w + y * xi), c * (y * w - x * xi), (rsb - eta * w) / eta2p1];
+        w = -w;
+        c = (rsb * eta + w) / (eta2p1 * (rxy2 * (w2 + xi2)).sqrt());
+        let v02 = [c * (x * w + y * xi), c * (y * w - x * xi), (rsb - eta * w) / eta2p1];
+        let n = if rsb.abs() < 1.0 { 1 } else { 2 };
+        (n, v01, v02)
+    } else {
+        (0, [0.0; 3], [0.0; 3])
+    }
+}
 
-/// Project celestial to tangent plane, spherical
-pub fn tpxes() {}
+/// TPSTS (deproject tangent plane to celestial, spherical)
+///
+///  In the tangent plane projection, given the star's rectangular
+///  coordinates and the spherical coordinates of the tangent point,
+///  solve for the spherical coordinates of the star.
+///
+///  Given:
+///  ```text
+///     xi,eta    double  rectangular coordinates of star image (Note 2)
+///     a0,b0     double  tangent point's spherical coordinates
+///  ```
+///  Returned:
+///  ```text
+///     a,b       double  star's spherical coordinates
+///  ```
+///  Notes:
+///
+///  1) The tangent plane projection is also called the "gnomonic
+///     projection" and the "central projection".
+///
+///  2) The eta axis points due north in the adopted coordinate system.
+///     If the spherical coordinates are observed (RA,Dec), the tangent
+///     plane coordinates (xi,eta) are conventionally called the
+///     "standard coordinates".  If the spherical coordinates are with
+///     respect to a right-handed triad, (xi,eta) are also right-handed.
+///     The units of (xi,eta) are, effectively, radians at the tangent
+///     point.
+///
+///  3) All angular arguments are in radians.
+///
+///  4) This function is a member of the following set:
+///     ```text
+///         spherical      vector         solve for
+///
+///         tpxes          tpxev            xi,eta
+///       > tpsts <        tpstv             star
+///         tpors          tporv            origin
+///     ```
+pub fn tpsts(xi: f64, eta: f64, a0: f64, b0: f64) -> (f64, f64) {
+    let sb0 = sin(b0);
+    let cb0 = cos(b0);
+    let d = cb0 - eta * sb0;
+    let a = anp(atan2(xi, d) + a0);
+    let b = atan2(sb0 + eta * cb0, (xi * xi + d * d).sqrt());
 
-/// Project celestial to tangent plane, vector
-pub fn tpxev() {}
+    (a, b)
+}
+
+/// TPSTV (deproject tangent plane to celestial, vector)
+///
+///  In the tangent plane projection, given the star's rectangular
+///  coordinates and the direction cosines of the tangent point, solve
+///  for the direction cosines of the star.
+///
+///  Given:
+///  ```text
+///     xi,eta  double     rectangular coordinates of star image (Note 2)
+///     v0      double[3]  tangent point's direction cosines
+///  ```
+///  Returned (function value):
+///  ```text
+///             double[3]  star's direction cosines
+///  ```
+///  Notes:
+///
+///  1) The tangent plane projection is also called the "gnomonic
+///     projection" and the "central projection".
+///
+///  2) The eta axis points due north in the adopted coordinate system.
+///     If the direction cosines represent observed (RA,Dec), the tangent
+///     plane coordinates (xi,eta) are conventionally called the
+///     "standard coordinates".  If the direction cosines are with
+///     respect to a right-handed triad, (xi,eta) are also right-handed.
+///     The units of (xi,eta) are, effectively, radians at the tangent
+///     point.
+///
+///  3) The method used is to complete the star vector in the (xi,eta)
+///     based triad and normalize it, then rotate the triad to put the
+///     tangent point at the pole with the x-axis aligned to zero
+///     longitude.  Writing (a0,b0) for the celestial spherical
+///     coordinates of the tangent point, the sequence of rotations is
+///     (b-pi/2) around the x-axis followed by (-a-pi/2) around the
+///     z-axis.
+///
+///  4) If vector v0 is not of unit length, the returned vector v will be
+///     wrong.
+///
+///  5) If vector v0 points at a pole, the returned vector v will be
+///     based on the arbitrary assumption that the longitude coordinate
+///     of the tangent point is zero.
+///
+///  6) This function is the vector form of `tpsts`, and is a member of
+///     the following set:
+///     ```text
+///         spherical      vector         solve for
+///
+///         tpxes          tpxev            xi,eta
+///         tpsts        > tpstv <           star
+///         tpors          tporv            origin
+///     ```
+pub fn tpstv(xi: f64, eta: f64, v0: &[f64; 3]) -> [f64; 3] {
+    let [mut x, y, z] = *v0;
+
+    /* Deal with polar case. */
+    let mut r = (x * x + y * y).sqrt();
+    if r == 0.0 {
+        r = 1e-20;
+        x = r;
+    }
+
+    /* Star vector length to tangent plane. */
+    let f = (1.0 + xi * xi + eta * eta).sqrt();
+
+    /* Apply the transformation and normalize. */
+    [
+        (x - (xi * y + eta * x * z) / r) / f,
+        (y + (xi * x - eta * y * z) / r) / f,
+        (z + eta * r) / f,
+    ]
+}
+
+/// TPXES (project celestial to tangent plane, spherical)
+///
+///  In the tangent plane projection, given celestial spherical
+///  coordinates for a star and the tangent point, solve for the star's
+///  rectangular coordinates in the tangent plane.
+///
+///  Given:
+///  ```text
+///     a,b       double  star's spherical coordinates
+///     a0,b0     double  tangent point's spherical coordinates
+///  ```
+///  Returned:
+///  ```text
+///     xi,eta    double  rectangular coordinates of star image (Note 2)
+///  ```
+///  Returned (function value):
+///  ```text
+///               int     status:  0 = OK (returned as Ok, others as Err)
+///                                1 = star too far from axis
+///                                2 = antistar on tangent plane
+///                                3 = antistar too far from axis
+///  ```
+///  Notes:
+///
+///  1) The tangent plane projection is also called the "gnomonic
+///     projection" and the "central projection".
+///
+///  2) The eta axis points due north in the adopted coordinate system.
+///     If the spherical coordinates are observed (RA,Dec), the tangent
+///     plane coordinates (xi,eta) are conventionally called the
+///     "standard coordinates".  For right-handed spherical coordinates,
+///     (xi,eta) are also right-handed.  The units of (xi,eta) are,
+///     effectively, radians at the tangent point.
+///
+///  3) All angular arguments are in radians.
+///
+///  4) This function is a member of the following set:
+///     ```text
+///         spherical      vector         solve for
+///
+///       > tpxes <        tpxev            xi,eta
+///         tpsts          tpstv             star
+///         tpors          tporv            origin
+///     ```
+pub fn tpxes(a: f64, b: f64, a0: f64, b0: f64) -> Result<(f64, f64), i32> {
+    /* Functions of the spherical coordinates. */
+    let sb0 = sin(b0);
+    let sb = sin(b);
+    let cb0 = cos(b0);
+    let cb = cos(b);
+    let da = a - a0;
+    let sda = sin(da);
+    let cda = cos(da);
+
+    /* Reciprocal of star vector length to tangent plane. */
+    let d = sb * sb0 + cb * cb0 * cda;
+
+    /* Check for error cases. */
+    let d = check_d(d)?;
+
+    /* Project the star onto the tangent plane. */
+    let xi = cb * sda / d;
+    let eta = (sb * cb0 - cb * sb0 * cda) / d;
+
+    Ok((xi, eta))
+}
+
+/// TPXEV (project celestial to tangent plane, vector)
+///
+///  In the tangent plane projection, given celestial direction cosines
+///  for a star and the tangent point, solve for the star's rectangular
+///  coordinates in the tangent plane.
+///
+///  Given:
+///  ```text
+///     v         double[3]  direction cosines of star (Note 4)
+///     v0        double[3]  direction cosines of tangent point (Note 4)
+///  ```
+///  Returned:
+///  ```text
+///     xi,eta    double     tangent plane coordinates of star
+///  ```
+///  Returned (function value):
+///  ```text
+///               int        status:  0 = OK (returned as Ok, others as Err)
+///                                   1 = star too far from axis
+///                                   2 = antistar on tangent plane
+///                                   3 = antistar too far from axis
+///  ```
+///  Notes:
+///
+///  1) The tangent plane projection is also called the "gnomonic
+///     projection" and the "central projection".
+///
+///  2) The eta axis points due north in the adopted coordinate system.
+///     If the direction cosines represent observed (RA,Dec), the tangent
+///     plane coordinates (xi,eta) are conventionally called the
+///     "standard coordinates".  If the direction cosines are with
+///     respect to a right-handed triad, (xi,eta) are also right-handed.
+///     The units of (xi,eta) are, effectively, radians at the tangent
+///     point.
+///
+///  3) The method used is to extend the star vector to the tangent
+///     plane and then rotate the triad so that (x,y) becomes (xi,eta).
+///     Writing (a,b) for the celestial spherical coordinates of the
+///     star, the sequence of rotations is (a+pi/2) around the z-axis
+///     followed by (pi/2-b) around the x-axis.
+///
+///  4) If vector v0 is not of unit length, or if vector v is of zero
+///     length, the results will be wrong.
+///
+///  5) If v0 points at a pole, the returned (xi,eta) will be based on
+///     the arbitrary assumption that the longitude coordinate of the
+///     tangent point is zero.
+///
+///  6) This function is the vector form of `tpxes`, and is a member of
+///     the following set:
+///     ```text
+///         spherical      vector         solve for
+///
+///         tpxes        > tpxev <          xi,eta
+///         tpsts          tpstv             star
+///         tpors          tporv            origin
+///     ```
+pub fn tpxev(v: &[f64; 3], v0: &[f64; 3]) -> Result<(f64, f64), i32> {
+    let [x, y, z] = *v;
+    let [mut x0, y0, z0] = *v0;
+
+    /* Deal with polar case. */
+    let r2 = x0 * x0 + y0 * y0;
+    let mut r = r2.sqrt();
+    if r == 0.0 {
+        r = 1e-20;
+        x0 = r;
+    }
+
+    /* Reciprocal of star vector length to tangent plane. */
+    let w = x * x0 + y * y0;
+    let d = w + z * z0;
+
+    /* Check for error cases. */
+    let d = check_d(d)? * r;
+
+    /* The tangent plane coordinates. */
+    let xi = (y * x0 - x * y0) / d;
+    let eta = (z * r2 - z0 * w) / d;
+
+    Ok((xi, eta))
+}
+
+/* Status of the tangent plane projection for cos(distance) d. */
+fn check_d(d: f64) -> Result<f64, i32> {
+    if d > TINY {
+        Ok(d)
+    } else if d >= 0.0 {
+        Err(1)
+    } else if d > -TINY {
+        Err(2)
+    } else {
+        Err(3)
+    }
+}
+
+///  Six-constant plate solution
+///
+///  The linear model relating measured positions (x,y), for example
+///  pixel coordinates, to standard coordinates:
+///  ```text
+///     xi  = a*x + b*y + c
+///     eta = d*x + e*y + f
+///  ```
+///  which absorbs zero point, scale, rotation, non-perpendicularity of
+///  the axes and differential scale.  (xi,eta) are in radians, as used
+///  by `tpxes` and its relatives.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PlateConstants {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub d: f64,
+    pub e: f64,
+    pub f: f64,
+}
+
+impl PlateConstants {
+    /// Measured (x,y) to standard coordinates (xi,eta).
+    pub fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+        (
+            self.a * x + self.b * y + self.c,
+            self.d * x + self.e * y + self.f,
+        )
+    }
+
+    /// Standard coordinates (xi,eta) to measured (x,y);  Err(-2) if the
+    /// plate constants are singular.
+    pub fn invert(&self, xi: f64, eta: f64) -> Result<(f64, f64), i32> {
+        let det = self.a * self.e - self.b * self.d;
+        if det == 0.0 {
+            return Err(-2);
+        }
+        let u = xi - self.c;
+        let v = eta - self.f;
+        Ok(((self.e * u - self.b * v) / det, (self.a * v - self.d * u) / det))
+    }
+}
+
+///  Result of a plate-constant fit
+///
+///  ```text
+///     pc          PlateConstants   fitted constants
+///     residuals   [xi,eta][n]      observed minus fitted, radians
+///     rms         double           rms of the residual moduli, radians
+///  ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PlateSolution {
+    pub pc: PlateConstants,
+    pub residuals: Vec<[f64; 2]>,
+    pub rms: f64,
+}
+
+///  Least-squares fit of six plate constants.
+///
+///  Given:
+///  ```text
+///     xy       double[n][2]   measured positions, e.g. pixels
+///     xieta    double[n][2]   standard coordinates of the same stars
+///  ```
+///  Returned (function value):
+///  ```text
+///              PlateSolution  constants, residuals and rms
+///  ```
+///  Status values:
+///  ```text
+///     -1   fewer than 3 stars, or the slices differ in length
+///     -2   the measured positions are degenerate (collinear)
+///  ```
+///  Notes:
+///
+///  1) The standard coordinates come from the catalog positions and an
+///     adopted tangent point via `tpxes` or `tpxev`.  With the constants
+///     fitted, `PlateConstants::apply` followed by `tpsts` gives the
+///     celestial position of any other measured object.
+///
+///  2) The measured positions are referred to their centroid before
+///     the normal equations are formed, so large pixel offsets do not
+///     degrade the solution.
+///
+///  3) Three stars determine the solution exactly (zero residuals);
+///     more are needed for the residuals to mean anything.
+pub fn platefit(xy: &[[f64; 2]], xieta: &[[f64; 2]]) -> Result<PlateSolution, i32> {
+    let n = xy.len();
+    if n < 3 || xieta.len() != n {
+        return Err(-1);
+    }
+    let nf = n as f64;
+
+    /* Centroids. */
+    let (mut xm, mut ym, mut xim, mut etam) = (0.0, 0.0, 0.0, 0.0);
+    for (p, s) in xy.iter().zip(xieta) {
+        xm += p[0];
+        ym += p[1];
+        xim += s[0];
+        etam += s[1];
+    }
+    xm /= nf;
+    ym /= nf;
+    xim /= nf;
+    etam /= nf;
+
+    /* Normal equations about the centroid. */
+    let (mut sxx, mut sxy, mut syy) = (0.0, 0.0, 0.0);
+    let (mut sxi_x, mut sxi_y, mut seta_x, mut seta_y) = (0.0, 0.0, 0.0, 0.0);
+    for (p, s) in xy.iter().zip(xieta) {
+        let x = p[0] - xm;
+        let y = p[1] - ym;
+        let xi = s[0] - xim;
+        let eta = s[1] - etam;
+        sxx += x * x;
+        sxy += x * y;
+        syy += y * y;
+        sxi_x += xi * x;
+        sxi_y += xi * y;
+        seta_x += eta * x;
+        seta_y += eta * y;
+    }
+    let det = sxx * syy - sxy * sxy;
+    if det <= 1e-12 * sxx * syy || det == 0.0 {
+        return Err(-2);
+    }
+
+    /* Solve. */
+    let a = (sxi_x * syy - sxi_y * sxy) / det;
+    let b = (sxi_y * sxx - sxi_x * sxy) / det;
+    let d = (seta_x * syy - seta_y * sxy) / det;
+    let e = (seta_y * sxx - seta_x * sxy) / det;
+    let pc = PlateConstants {
+        a,
+        b,
+        c: xim - a * xm - b * ym,
+        d,
+        e,
+        f: etam - d * xm - e * ym,
+    };
+
+    /* Residuals. */
+    let mut ss = 0.0;
+    let residuals: Vec<[f64; 2]> = xy
+        .iter()
+        .zip(xieta)
+        .map(|(p, s)| {
+            let (xi, eta) = pc.apply(p[0], p[1]);
+            let r = [s[0] - xi, s[1] - eta];
+            ss += r[0] * r[0] + r[1] * r[1];
+            r
+        })
+        .collect();
+
+    Ok(PlateSolution { pc, residuals, rms: (ss / nf).sqrt() })
+}
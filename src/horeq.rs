@@ -1,9 +1,205 @@
 //! Horizon/equatorial
+use crate::consts::D2PI;
+use crate::math::{atan2, cos, sin};
+
 /// AE2HD (azimuth, altitude) to (hour angle, declination)
-pub fn ae2hd() {}
+///
+///  Horizon to equatorial coordinates:  transform azimuth and altitude
+///  to hour angle and declination.
+///
+///  Given:
+///  ```text
+///     az       double       azimuth
+///     el       double       altitude (informally, elevation)
+///     phi      double       site latitude
+///  ```
+///  Returned:
+///  ```text
+///     ha       double       local hour angle
+///     dec      double       declination
+///  ```
+///  Notes:
+///
+///  1)  All the arguments are angles in radians.
+///
+///  2)  The sign convention for azimuth is north zero, east +pi/2.
+///
+///  3)  HA is returned in the range +/-pi.  Declination is returned in
+///      the range +/-pi/2.
+///
+///  4)  The latitude phi is pi/2 minus the angle between the Earth's
+///      rotation axis and the adopted zenith.  In many applications it
+///      will be sufficient to use the published geodetic latitude of the
+///      site.  In very precise (sub-arcsecond) applications, phi can be
+///      corrected for polar motion.
+///
+///  5)  The azimuth az must be with respect to the rotational north pole,
+///      as opposed to the ITRS pole, and an azimuth with respect to north
+///      on a map of the Earth's surface will need to be adjusted for
+///      polar motion if sub-arcsecond accuracy is required.
+///
+///  6)  Should the user wish to work with respect to the astronomical
+///      zenith rather than the geodetic zenith, phi will need to be
+///      adjusted for deflection of the vertical (often tens of
+///      arcseconds), and the zero point of ha will also be affected.
+///
+///  7)  The transformation is the same as Ve = Ry(phi-pi/2)*Rz(pi)*Vh,
+///      where Ve and Vh are lefthanded unit vectors in the (ha,dec) and
+///      (az,el) systems respectively and Rz and Ry are rotations about
+///      first the z-axis and then the y-axis.  (n.b. Rz(pi) simply
+///      reverses the signs of the x and y components.)  For efficiency,
+///      the algorithm is written out rather than calling other utility
+///      functions.  For applications that require even greater
+///      efficiency, additional savings are possible if constant terms
+///      such as functions of latitude are computed once and for all.
+///
+///  8)  Again for efficiency, no range checking of arguments is carried
+///      out.
+pub fn ae2hd(az: f64, el: f64, phi: f64) -> (f64, f64) {
+    /* Useful trig functions. */
+    let sa = sin(az);
+    let ca = cos(az);
+    let se = sin(el);
+    let ce = cos(el);
+    let sp = sin(phi);
+    let cp = cos(phi);
+
+    /* HA,Dec unit vector. */
+    let x = -ca * ce * sp + se * cp;
+    let y = -sa * ce;
+    let z = ca * ce * cp + se * sp;
+
+    /* To spherical. */
+    let r = (x * x + y * y).sqrt();
+    let ha = if r != 0.0 { atan2(y, x) } else { 0.0 };
+    let dec = atan2(z, r);
+
+    (ha, dec)
+}
 
 /// HD2AE (hour angle, declination) to (azimuth, altitude)
-pub fn hd2ae() {}
+///
+///  Equatorial to horizon coordinates:  transform hour angle and
+///  declination to azimuth and altitude.
+///
+///  Given:
+///  ```text
+///     ha       double       hour angle (local)
+///     dec      double       declination
+///     phi      double       site latitude
+///  ```
+///  Returned:
+///  ```text
+///     az       double       azimuth (N=0,E=90)
+///     el       double       altitude (informally, elevation)
+///  ```
+///  Notes:
+///
+///  1)  All the arguments are angles in radians.
+///
+///  2)  Azimuth is returned in the range 0-2pi;  north is zero, and east
+///      is +pi/2.  Altitude is returned in the range +/- pi/2.
+///
+///  3)  The latitude phi is pi/2 minus the angle between the Earth's
+///      rotation axis and the adopted zenith.  In many applications it
+///      will be sufficient to use the published geodetic latitude of the
+///      site.  In very precise (sub-arcsecond) applications, phi can be
+///      corrected for polar motion.
+///
+///  4)  The returned azimuth az is with respect to the rotational north
+///      pole, as opposed to the ITRS pole, and for sub-arcsecond
+///      accuracy will need to be adjusted for polar motion if it is to
+///      be with respect to north on a map of the Earth's surface.
+///
+///  5)  Should the user wish to work with respect to the astronomical
+///      zenith rather than the geodetic zenith, phi will need to be
+///      adjusted for deflection of the vertical (often tens of
+///      arcseconds), and the zero point of the hour angle ha will also
+///      be affected.
+///
+///  6)  The transformation is the same as Vh = Rz(pi)*Ry(pi/2-phi)*Ve,
+///      where Vh and Ve are lefthanded unit vectors in the (az,el) and
+///      (ha,dec) systems respectively and Ry and Rz are rotations about
+///      first the y-axis and then the z-axis.  (n.b. Rz(pi) simply
+///      reverses the signs of the x and y components.)  For efficiency,
+///      the algorithm is written out rather than calling other utility
+///      functions.  For applications that require even greater
+///      efficiency, additional savings are possible if constant terms
+///      such as functions of latitude are computed once and for all.
+///
+///  7)  Again for efficiency, no range checking of arguments is carried
+///      out.
+pub fn hd2ae(ha: f64, dec: f64, phi: f64) -> (f64, f64) {
+    /* Useful trig functions. */
+    let sh = sin(ha);
+    let ch = cos(ha);
+    let sd = sin(dec);
+    let cd = cos(dec);
+    let sp = sin(phi);
+    let cp = cos(phi);
+
+    /* Az,Alt unit vector. */
+    let x = -ch * cd * sp + sd * cp;
+    let y = -sh * cd;
+    let z = ch * cd * cp + sd * sp;
+
+    /* To spherical. */
+    let r = (x * x + y * y).sqrt();
+    let a = if r != 0.0 { atan2(y, x) } else { 0.0 };
+    let az = if a < 0.0 { a + D2PI } else { a };
+    let el = atan2(z, r);
+
+    (az, el)
+}
 
 /// HD2PA (parallactic angle)
-pub fn hd2pa() {}
+///
+///  Parallactic angle for a given hour angle and declination.
+///
+///  Given:
+///  ```text
+///     ha     double     hour angle
+///     dec    double     declination
+///     phi    double     site latitude
+///  ```
+///  Returned (function value):
+///  ```text
+///            double     parallactic angle
+///  ```
+///  Notes:
+///
+///  1)  All the arguments are angles in radians.
+///
+///  2)  The parallactic angle at a point in the sky is the position
+///      angle of the vertical, i.e. the angle between the directions to
+///      the north celestial pole and to the zenith respectively.
+///
+///  3)  The result is returned in the range -pi to +pi.
+///
+///  4)  At the pole itself a zero result is returned.
+///
+///  5)  The latitude phi is pi/2 minus the angle between the Earth's
+///      rotation axis and the adopted zenith.  In many applications it
+///      will be sufficient to use the published geodetic latitude of the
+///      site.  In very precise (sub-arcsecond) applications, phi can be
+///      corrected for polar motion.
+///
+///  6)  Should the user wish to work with respect to the astronomical
+///      zenith rather than the geodetic zenith, phi will need to be
+///      adjusted for deflection of the vertical (often tens of
+///      arcseconds), and the zero point of the hour angle ha will also
+///      be affected.
+///
+///  Reference:
+///     Smart, W.M., "Spherical Astronomy", Cambridge University Press,
+///     6th edition (Green, 1977), p49.
+pub fn hd2pa(ha: f64, dec: f64, phi: f64) -> f64 {
+    let cp = cos(phi);
+    let sqsz = cp * sin(ha);
+    let cqsz = sin(phi) * cos(dec) - cp * sin(dec) * cos(ha);
+    if sqsz != 0.0 || cqsz != 0.0 {
+        atan2(sqsz, cqsz)
+    } else {
+        0.0
+    }
+}
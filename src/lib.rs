@@ -136,9 +136,8 @@
 //!     pvb[1][2] = -0.0066892203821059;
 //! 
 //!     /* IAU 2000 CIP */
-//!     let r = &mut [[0.0; 3]; 3];
-//!     pnm00a(tt1, tt2, r);
-//!     let (mut x, mut y) = bpn2xy(r);
+//!     let r = pnm00a(tt1, tt2);
+//!     let (mut x, mut y) = bpn2xy(&r);
 //! 
 //!     /* Apply IERS corrections */
 //!     x += dx;
@@ -147,11 +146,10 @@
 //!     /* SOFA CIO locator. */
 //!     let s = s06(tt1, tt2, x, y);
 //! 
-//!     let astrom = &mut IauAstrom::default();
 //!     /* Populate the context. */
-//!     apci(tt1, tt2, &pvb, &pvh[0], x, y, s, astrom);
+//!     let astrom = apci(tt1, tt2, &pvb, &pvh[0], x, y, s);
 //! 
-//!     let (ri, di) = atciq(rc, dc, pr, pd, px, rv, astrom);
+//!     let (ri, di) = atciq(rc, dc, pr, pd, px, rv, &astrom);
 //!     reprd("ICRS -> CIRS (JPL, IERS):", ri, di);
 //! }
 //! 
@@ -182,15 +180,31 @@
 //！```
 //!
 pub mod astro;
+#[cfg(feature = "rayon")]
+pub mod batch;
 pub mod cal;
+#[cfg(feature = "capi")]
+pub mod capi;
 pub mod consts;
 pub mod coords;
 pub mod eph;
 pub mod erst;
+pub mod events;
+pub mod frames;
 pub mod fundargs;
 pub mod gnomic;
 pub mod horeq;
+mod math;
+pub mod orbit;
 pub mod pnp;
+pub mod prelude;
+#[cfg(feature = "python")]
+pub mod python;
 pub mod star;
+pub mod stream;
 pub mod ts;
-pub mod vm;
\ No newline at end of file
+pub mod units;
+pub mod validate;
+pub mod vm;
+#[cfg(feature = "wasm")]
+pub mod wasm;
\ No newline at end of file
@@ -0,0 +1,133 @@
+//! Transcendental functions
+//!
+//! The library calls these rather than the f64 methods, so that the
+//! "libm" feature can route every one of them through the pure Rust
+//! libm crate.  With it, results are bit-for-bit the same on every
+//! platform, including wasm32 in the browser;  without it the
+//! platform's own maths library is used, which may differ in the last
+//! bit from one system to another.  sqrt, abs, floor and the
+//! remainder functions are exact in IEEE 754 arithmetic and are not
+//! affected.
+
+#[cfg(feature = "libm")]
+macro_rules! unary {
+    ($($f:ident => $l:ident),*) => {$(
+        #[inline]
+        pub(crate) fn $f(x: f64) -> f64 {
+            libm::$l(x)
+        }
+    )*};
+}
+
+#[cfg(not(feature = "libm"))]
+macro_rules! unary {
+    ($($f:ident => $l:ident),*) => {$(
+        #[inline]
+        pub(crate) fn $f(x: f64) -> f64 {
+            x.$f()
+        }
+    )*};
+}
+
+#[cfg(feature = "libm")]
+macro_rules! binary {
+    ($($f:ident => $l:ident),*) => {$(
+        #[inline]
+        pub(crate) fn $f(x: f64, y: f64) -> f64 {
+            libm::$l(x, y)
+        }
+    )*};
+}
+
+#[cfg(not(feature = "libm"))]
+macro_rules! binary {
+    ($($f:ident => $l:ident),*) => {$(
+        #[inline]
+        pub(crate) fn $f(x: f64, y: f64) -> f64 {
+            x.$f(y)
+        }
+    )*};
+}
+
+unary!(sin => sin, cos => cos, tan => tan, atan => atan, sinh => sinh, cosh => cosh,
+       ln => log, cbrt => cbrt, exp => exp);
+binary!(atan2 => atan2, powf => pow, hypot => hypot);
+
+/// (sin x, cos x).
+#[inline]
+pub(crate) fn sin_cos(x: f64) -> (f64, f64) {
+    (sin(x), cos(x))
+}
+
+/// Accumulator for the long trigonometric series.
+///
+/// With the "compensated" feature every addition is compensated
+/// (Neumaier's variant of Kahan summation), so that the error of the
+/// total no longer grows with the number of terms:  the last one or
+/// two bits lost in plain accumulation are recovered and, as the
+/// feature brings in "libm", the results are the same on every
+/// platform.  Without it the sum is the plain one of the SOFA C code,
+/// term by term in the same order.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Sum {
+    s: f64,
+    #[cfg(feature = "compensated")]
+    c: f64,
+}
+
+impl Sum {
+    #[inline]
+    pub(crate) fn new(s: f64) -> Self {
+        #[cfg(feature = "compensated")]
+        return Sum { s, c: 0.0 };
+        #[cfg(not(feature = "compensated"))]
+        return Sum { s };
+    }
+
+    #[inline]
+    pub(crate) fn value(&self) -> f64 {
+        #[cfg(feature = "compensated")]
+        return self.s + self.c;
+        #[cfg(not(feature = "compensated"))]
+        return self.s;
+    }
+}
+
+impl std::ops::AddAssign<f64> for Sum {
+    #[cfg(feature = "compensated")]
+    #[inline]
+    fn add_assign(&mut self, x: f64) {
+        /* Two-sum:  t is the rounded sum, the error goes to c. */
+        let t = self.s + x;
+        self.c += if self.s.abs() >= x.abs() { (self.s - t) + x } else { (x - t) + self.s };
+        self.s = t;
+    }
+
+    #[cfg(not(feature = "compensated"))]
+    #[inline]
+    fn add_assign(&mut self, x: f64) {
+        self.s += x;
+    }
+}
+
+impl std::ops::SubAssign<f64> for Sum {
+    #[inline]
+    fn sub_assign(&mut self, x: f64) {
+        *self += -x;
+    }
+}
+
+#[cfg(all(test, feature = "compensated"))]
+mod tests {
+    use super::Sum;
+
+    #[test]
+    fn test_sum_cancellation() {
+        /* Plain summation loses the 1 entirely. */
+        let mut s = Sum::new(1e16);
+        s += 1.0;
+        s -= 1e16;
+        assert_eq!(s.value(), 1.0);
+        assert_eq!(1e16 + 1.0 - 1e16, 0.0);
+    }
+}
@@ -0,0 +1,18 @@
+use crate::cal::Jd;
+use crate::astro::ApparentPlace;
+use crate::vm::PvVec;
+
+use super::*;
+
+/// As `pv2osc`, with the TT date as a `Jd` (or a (date1, date2) pair).
+pub fn pv2osc_jd(pv: &PvVec, date: impl Into<Jd>) -> Result<OscElements, i32> {
+    let Jd(d1, d2) = date.into();
+    pv2osc(pv, d1, d2)
+}
+
+/// As `pvplace`, with the epoch of the state and the date of observation
+/// as `Jd`s (or (date1, date2) pairs).
+pub fn pvplace_jd(pv: &PvVec, t: impl Into<Jd>, date: impl Into<Jd>) -> Result<ApparentPlace, i32> {
+    let (Jd(t1, t2), Jd(d1, d2)) = (t.into(), date.into());
+    pvplace(pv, t1, t2, d1, d2)
+}
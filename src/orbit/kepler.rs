@@ -0,0 +1,137 @@
+use std::f64::consts::PI;
+
+use crate::consts::GK;
+use crate::math::{cos, cosh, sin, sinh};
+use crate::vm::{pdp, PvVec};
+
+/// Heliocentric gravitational parameter k^2 (au^3/day^2)
+pub const GMS: f64 = GK * GK;
+
+/* Universal Kepler equation:  iteration limit, tolerance and the
+   Laguerre order. */
+const MAXIT: usize = 50;
+const TOL: f64 = 1e-15;
+const NLAG: f64 = 5.0;
+
+/* Stumpff functions c2(z) and c3(z), by series near z = 0. */
+fn stumpff(z: f64) -> (f64, f64) {
+    if z.abs() < 0.1 {
+        let (mut c2, mut c3) = (0.0, 0.0);
+        let (mut t2, mut t3): (f64, f64) = (0.5, 1.0 / 6.0);
+        let mut k = 2.0;
+        while t3.abs() > 1e-18 || t2.abs() > 1e-18 {
+            c2 += t2;
+            c3 += t3;
+            t2 *= -z / ((k + 1.0) * (k + 2.0));
+            t3 *= -z / ((k + 2.0) * (k + 3.0));
+            k += 2.0;
+        }
+        (c2, c3)
+    } else if z > 0.0 {
+        let s = z.sqrt();
+        ((1.0 - cos(s)) / z, (s - sin(s)) / (z * s))
+    } else {
+        let s = (-z).sqrt();
+        ((cosh(s) - 1.0) / -z, (sinh(s) - s) / (-z * s))
+    }
+}
+
+///  Two-body propagation of a state vector (universal variables)
+///
+///  Given:
+///  ```text
+///     pv       PvVec      position and velocity relative to the
+///                         central body (au, au/day)
+///     dt       double     time interval (days, either sign)
+///     gm       double     gravitational parameter (au^3/day^2, GMS
+///                         for the Sun)
+///  ```
+///  Returned (function value):
+///  ```text
+///              PvVec      position and velocity dt later
+///  ```
+///  Status values:
+///  ```text
+///     -1   illegal input (gm <= 0 or zero position)
+///     -2   Kepler's equation failed to converge
+///  ```
+///  Notes:
+///
+///  1) The universal anomaly chi is found from the universal form of
+///     Kepler's equation, which covers the ellipse, parabola and
+///     hyperbola alike, by Laguerre's method (Conway 1986), which
+///     converges from a rough starting value where Newton's method can
+///     fail;  the state follows from the f and g functions.
+///
+///  2) For an ellipse, whole periods are first removed from dt, so
+///     that the precision does not fall off over many revolutions.
+///
+///  3) Any consistent units will do, the velocity and gm being per
+///     unit of dt.
+///
+///  References:
+///
+///     Conway, B.A., 1986, Celest.Mech. 39, 199
+///
+///     Vallado, D.A., "Fundamentals of Astrodynamics and Applications",
+///     4th ed., 2013, Algorithm 8
+pub fn kepler_uv(pv: &PvVec, dt: f64, gm: f64) -> Result<PvVec, i32> {
+    let (r0v, v0v) = (pv.p, pv.v);
+    let r0 = pdp(&r0v, &r0v).sqrt();
+    if gm <= 0.0 || r0 == 0.0 {
+        return Err(-1);
+    }
+    let sqmu = gm.sqrt();
+    let sig0 = pdp(&r0v, &v0v) / sqmu;
+
+    /* Reciprocal semi-major axis (negative for a hyperbola). */
+    let alpha = 2.0 / r0 - pdp(&v0v, &v0v) / gm;
+
+    /* Whole revolutions of an ellipse. */
+    let mut dt = dt;
+    if alpha > 0.0 {
+        let period = 2.0 * PI / (sqmu * alpha * alpha.sqrt());
+        dt -= period * (dt / period).round();
+    }
+    if dt == 0.0 {
+        return Ok(*pv);
+    }
+
+    /* Laguerre iteration for chi. */
+    let b = 1.0 - r0 * alpha;
+    let mut chi = if alpha > 0.0 { sqmu * dt * alpha } else { sqmu * dt / r0 };
+    let (mut c2, mut c3, mut r);
+    let mut it = 0;
+    loop {
+        let chi2 = chi * chi;
+        let z = alpha * chi2;
+        (c2, c3) = stumpff(z);
+        let f = sig0 * chi2 * c2 + b * chi * chi2 * c3 + r0 * chi - sqmu * dt;
+        r = chi2 * c2 + sig0 * chi * (1.0 - z * c3) + r0 * (1.0 - z * c2);
+        let fpp = sig0 * (1.0 - z * c2) + b * chi * (1.0 - z * c3);
+        let d = ((NLAG - 1.0) * (NLAG - 1.0) * r * r - NLAG * (NLAG - 1.0) * f * fpp).abs().sqrt();
+        let delta = NLAG * f / (r + d.copysign(r));
+        chi -= delta;
+        if delta.abs() <= TOL * chi.abs().max(1.0) {
+            break;
+        }
+        it += 1;
+        if it == MAXIT {
+            return Err(-2);
+        }
+    }
+
+    /* The f and g functions, at the converged chi. */
+    let chi2 = chi * chi;
+    let z = alpha * chi2;
+    (c2, c3) = stumpff(z);
+    r = chi2 * c2 + sig0 * chi * (1.0 - z * c3) + r0 * (1.0 - z * c2);
+    let f = 1.0 - chi2 * c2 / r0;
+    let g = dt - chi * chi2 * c3 / sqmu;
+    let fd = sqmu / (r * r0) * chi * (z * c3 - 1.0);
+    let gd = 1.0 - chi2 * c2 / r;
+    Ok(PvVec::new(
+        std::array::from_fn(|i| f * r0v[i] + g * v0v[i]),
+        std::array::from_fn(|i| fd * r0v[i] + gd * v0v[i]),
+    ))
+}
@@ -0,0 +1,30 @@
+//! Two-body orbits
+//!
+//! Osculating elements (`OscElements`, cometary form) and heliocentric
+//! state vectors (`PvVec`, au and au/day, ICRS-aligned as `oscpv`
+//! returns them) convert both ways, for elliptic, parabolic and
+//! hyperbolic motion, and a state can be carried forward in time
+//! directly, without elements, by the universal-variable solution of
+//! Kepler's problem:
+//! ```text
+//!     elements -> state       eph::oscpv
+//!     state -> elements       pv2osc
+//!     state -> later state    kepler_uv
+//!     state -> place          pvplace (as astro::oscplace)
+//! ```
+//! The heliocentric gravitational parameter is GMS, the square of the
+//! Gaussian constant, the mass of the body being neglected as in
+//! `oscpv`.
+pub use crate::eph::{oscpv, OscElements};
+
+mod kepler;
+pub use kepler::*;
+
+mod pv2osc;
+pub use pv2osc::*;
+
+mod pvplace;
+pub use pvplace::*;
+
+mod jdforms;
+pub use jdforms::*;
@@ -0,0 +1,99 @@
+use crate::consts::{DJ00, GK};
+use crate::math::{atan2, ln, sin, sin_cos, sinh, tan};
+use crate::pnp::obl80;
+use crate::vm::{anp, pdp, pn, pxp, PvVec};
+
+use super::{OscElements, GMS};
+
+/* Obliquity of the J2000 ecliptic of the element sets, as in oscpv. */
+const EPS0: f64 = obl80(DJ00, 0.0);
+
+/* Eccentricities within this of 1 are treated as parabolic when
+   finding the time of perihelion. */
+const EPAR: f64 = 1e-10;
+
+///  Osculating elements from a heliocentric state vector
+///
+///  Given:
+///  ```text
+///     pv          PvVec         heliocentric position and velocity
+///                               (au, au/day, ICRS-aligned equatorial)
+///     date1,date2 double        TT as a 2-part Julian Date of pv
+///  ```
+///  Returned (function value):
+///  ```text
+///                 OscElements   cometary-form elements (ecliptic and
+///                               equinox J2000)
+///  ```
+///  Status values:
+///  ```text
+///     -1   illegal state (zero position, or rectilinear motion)
+///  ```
+///  Notes:
+///
+///  1) The inverse of `oscpv`:  the same two-body model (GMS), frame
+///     and J2000 obliquity are used, so that oscpv(pv2osc(pv)) is pv
+///     at the same date.
+///
+///  2) For an orbit in the ecliptic (incl 0 or pi) the node is set to
+///     zero, and for a circular orbit the argument of perihelion is
+///     zero, perihelion being at the node.  Whatever the choice, the
+///     angles combine to give the same state.
+///
+///  3) The time of perihelion is the nearest one, before or after the
+///     date for an ellipse.  For e within 1e-10 of 1 it is found from
+///     Barker's equation.
+pub fn pv2osc(pv: &PvVec, date1: f64, date2: f64) -> Result<OscElements, i32> {
+    /* Equator to ecliptic. */
+    let (se, ce) = sin_cos(EPS0);
+    let ecl = |v: &[f64; 3]| [v[0], v[1] * ce + v[2] * se, -v[1] * se + v[2] * ce];
+    let (r, v) = (ecl(&pv.p), ecl(&pv.v));
+
+    let (rm, ru) = pn(&r);
+    let h = pxp(&r, &v);
+    let (hm, hu) = pn(&h);
+    if rm == 0.0 || hm == 0.0 {
+        return Err(-1);
+    }
+
+    /* Eccentricity vector, and the perihelion distance. */
+    let rv = pdp(&r, &v);
+    let w = pdp(&v, &v) - GMS / rm;
+    let ev: [f64; 3] = std::array::from_fn(|i| (w * r[i] - rv * v[i]) / GMS);
+    let (e, eu) = pn(&ev);
+    let q = hm * hm / GMS / (1.0 + e);
+
+    /* Inclination and node. */
+    let incl = atan2((h[0] * h[0] + h[1] * h[1]).sqrt(), h[2]);
+    let node = if h[0] == 0.0 && h[1] == 0.0 { 0.0 } else { anp(atan2(h[0], -h[1])) };
+    let (so, co) = sin_cos(node);
+    let nu = [co, so, 0.0];
+
+    /* Angle from a to b in the orbital plane. */
+    let angle = |a: &[f64; 3], b: &[f64; 3]| atan2(pdp(&pxp(a, b), &hu), pdp(a, b));
+
+    /* Argument of perihelion and true anomaly. */
+    let (argp, nu_t) = if e == 0.0 {
+        (0.0, angle(&nu, &ru))
+    } else {
+        (anp(angle(&nu, &eu)), angle(&eu, &ru))
+    };
+
+    /* Time since perihelion. */
+    let dt = if (e - 1.0).abs() < EPAR {
+        let d = tan(nu_t / 2.0);
+        (2.0 * q * q * q).sqrt() / GK * (d + d * d * d / 3.0)
+    } else if e < 1.0 {
+        let a = q / (1.0 - e);
+        let (sv, cv) = sin_cos(nu_t);
+        let ea = atan2((1.0 - e * e).sqrt() * sv, e + cv);
+        (ea - e * sin(ea)) / (GK / (a * a * a).sqrt())
+    } else {
+        let a = q / (e - 1.0);
+        let t = ((e - 1.0) / (e + 1.0)).sqrt() * tan(nu_t / 2.0);
+        let hh = ln((1.0 + t) / (1.0 - t));
+        (e * sinh(hh) - hh) / (GK / (a * a * a).sqrt())
+    };
+
+    Ok(OscElements { q, e, incl, node, argp, tp: (date1 - dt) + date2 })
+}
@@ -0,0 +1,32 @@
+use crate::astro::{oscplace, ApparentPlace};
+use crate::vm::PvVec;
+
+use super::pv2osc;
+
+///  Astrometric and apparent place of a body from a heliocentric
+///  state vector
+///
+///  Given:
+///  ```text
+///     pv          PvVec         heliocentric position and velocity
+///                               (au, au/day, ICRS-aligned equatorial)
+///     t1,t2       double        TT as a 2-part Julian Date of pv
+///     date1,date2 double        TT as a 2-part Julian Date
+///  ```
+///  Returned (function value):
+///  ```text
+///                 ApparentPlace astrometric and apparent place
+///  ```
+///  Status values are those of `pv2osc` and `oscplace`.
+///
+///  Notes:
+///
+///  1) The state, for example from an orbit determination or a
+///     numerical integration, is converted to osculating elements and
+///     the place computed by `oscplace`, the body following the two-body
+///     orbit from t1+t2 to the date.
+///
+///  2) See `oscplace` for the steps and the accuracy.
+pub fn pvplace(pv: &PvVec, t1: f64, t2: f64, date1: f64, date2: f64) -> Result<ApparentPlace, i32> {
+    oscplace(&pv2osc(pv, t1, t2)?, date1, date2)
+}
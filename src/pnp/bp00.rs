@@ -1,6 +1,7 @@
 use crate::consts::{DAS2R, DJ00, DJC};
 use crate::pnp::{bi00, pr00};
-use crate::vm::{cr, ir, rx, rxr, ry, rz};
+use crate::vm::{ir, rx, rxr, ry, rz};
+use crate::math::sin;
 
 ///  Frame bias and precession matrices, IAU 2000.
 ///
@@ -14,7 +15,7 @@ use crate::vm::{cr, ir, rx, rxr, ry, rz};
 ///  Given:
 ///     date1,date2  double         TT as a 2-part Julian Date (Note 1)
 ///
-///  Returned:
+///  Returned (function value, in this order):
 ///     rb           double[3][3]   frame bias matrix (Note 2)
 ///     rp           double[3][3]   precession matrix (Note 3)
 ///     rbp          double[3][3]   bias-precession matrix (Note 4)
@@ -51,8 +52,7 @@ use crate::vm::{cr, ir, rx, rxr, ry, rz};
 ///     equinox of date by applying frame bias then precession.  It is
 ///     the product rp x rb.
 ///
-///  5) It is permissible to re-use the same array in the returned
-///     arguments.  The arrays are filled in the order given.
+///  5) The three matrices are returned as an array [rb, rp, rbp].
 ///
 ///  Called:
 ///     iauBi00      frame bias components, IAU 2000
@@ -61,7 +61,6 @@ use crate::vm::{cr, ir, rx, rxr, ry, rz};
 ///     iauRx        rotate around X-axis
 ///     iauRy        rotate around Y-axis
 ///     iauRz        rotate around Z-axis
-///     iauCr        copy r-matrix
 ///     iauRxr       product of two r-matrices
 ///
 ///  Reference:
@@ -78,7 +77,7 @@ use crate::vm::{cr, ir, rx, rxr, ry, rz};
 ///
 ///  Copyright (C) 2023 IAU SOFA Board.  See notes at end.
 ///
-pub fn bp00(date1: f64, date2: f64, rb: &mut [[f64; 3]; 3], rp: &mut [[f64; 3]; 3], rbp: &mut [[f64; 3]; 3]) {
+pub fn bp00(date1: f64, date2: f64) -> [[[f64; 3]; 3]; 3] {
     /* J2000.0 obliquity (Lieske et al. 1977) */
     const EPS0: f64 = 84381.448 * DAS2R;
 
@@ -99,14 +98,14 @@ pub fn bp00(date1: f64, date2: f64, rb: &mut [[f64; 3]; 3], rp: &mut [[f64; 3];
     let oma  = oma77  + depspr;
 
     /* Frame bias matrix: GCRS to J2000.0. */
-    let rbw = &mut [[0.0; 3]; 3];
-    ir(rbw);
-    rz(dra0, rbw);
-    ry(dpsibi*EPS0.sin(), rbw);
-    rx(-depsbi, rbw);
-    cr(rbw, rb);
+    let rb = &mut [[0.0; 3]; 3];
+    ir(rb);
+    rz(dra0, rb);
+    ry(dpsibi*sin(EPS0), rb);
+    rx(-depsbi, rb);
 
     /* Precession matrix: J2000.0 to mean of date. */
+    let rp = &mut [[0.0; 3]; 3];
     ir(rp);
     rx(EPS0, rp);
     rz(-psia, rp);
@@ -114,5 +113,5 @@ pub fn bp00(date1: f64, date2: f64, rb: &mut [[f64; 3]; 3], rp: &mut [[f64; 3];
     rz(chia, rp);
 
     /* Bias-precession matrix: GCRS to mean of date. */
-    rxr(rp, rbw, rbp);
+    [*rb, *rp, rxr(rp, rb)]
 }
\ No newline at end of file
@@ -0,0 +1,91 @@
+use super::{bpn2xy, c2ixys, pnm06a, s06};
+
+///  Form the celestial-to-intermediate matrix for a given date using the
+///  IAU 2006 precession and IAU 2000A nutation models.
+///
+///  This function is part of the International Astronomical Union's
+///  SOFA (Standards of Fundamental Astronomy) software collection.
+///
+///  Status:  support function.
+///
+///  Given:
+///  ```text
+///     date1,date2 double       TT as a 2-part Julian Date (Note 1)
+///  ```
+///  Returned (function value):
+///  ```text
+///     rc2i        double[3][3] celestial-to-intermediate matrix (Note 2)
+///  ```
+///  Notes:
+///
+///  1) The TT date date1+date2 is a Julian Date, apportioned in any
+///     convenient way between the two arguments, as for `pnm06a`.
+///
+///  2) The matrix rc2i is the first stage in the transformation from
+///     celestial to terrestrial coordinates:
+///     ```text
+///        [TRS]  =  RPOM * R_3(ERA) * rc2i * [CRS]
+///               =  RC2T * [CRS]
+///     ```
+///     where [CRS] is a vector in the Geocentric Celestial Reference
+///     System and [TRS] is a vector in the International Terrestrial
+///     Reference System (see IERS Conventions 2003), ERA is the Earth
+///     Rotation Angle and RPOM is the polar motion matrix.
+///
+///  3) The IAU 2006/2000A CIP is used as it stands;  `c2i06a_cip`
+///     applies IERS corrections dX,dY.
+///
+///  Called:
+///     iauPnm06a    classical NPB matrix, IAU 2006/2000A
+///     iauBpn2xy    extract CIP X,Y coordinates from NPB matrix
+///     iauS06       the CIO locator s, given X,Y, IAU 2006
+///     iauC2ixys    celestial-to-intermediate matrix, given X,Y and s
+///
+///  References:
+///
+///     McCarthy, D. D., Petit, G. (eds.), 2004, IERS Conventions (2003),
+///     IERS Technical Note No. 32, BKG
+pub fn c2i06a(date1: f64, date2: f64) -> [[f64; 3]; 3] {
+    c2i06a_cip(date1, date2, 0.0, 0.0)
+}
+
+///  Celestial-to-intermediate matrix, IAU 2006/2000A, with the CIP
+///  corrected by the IERS celestial pole offsets dX,dY.
+///
+///  Given:
+///  ```text
+///     date1,date2 double       TT as a 2-part Julian Date
+///     dx,dy       double       CIP offsets dX,dY (radians, Note 1)
+///  ```
+///  Returned (function value):
+///  ```text
+///     rc2i        double[3][3] celestial-to-intermediate matrix
+///  ```
+///  Notes:
+///
+///  1) dX,dY are the celestial pole offsets published by the IERS
+///     (Bulletin A/B, EOP C04) with respect to the IAU 2006/2000A
+///     model.  They account for the free core nutation and for the
+///     deficiencies of the model, and amount to a few tenths of a
+///     milliarcsecond.  IERS tables are in mas:  multiply by DMAS2R.
+///     Without IERS values, an FCN model (`FcnModel`) supplies most of
+///     them.
+///
+///  2) The CIO locator s is recomputed for the corrected X,Y.  With
+///     dx = dy = 0 the result is that of `c2i06a`.
+pub fn c2i06a_cip(date1: f64, date2: f64, dx: f64, dy: f64) -> [[f64; 3]; 3] {
+
+    /* Obtain the celestial-to-true matrix (IAU 2006/2000A). */
+    let rbpn = pnm06a(date1, date2);
+
+    /* Extract the X,Y coordinates and apply the corrections. */
+    let (mut x, mut y) = bpn2xy(&rbpn);
+    x += dx;
+    y += dy;
+
+    /* Obtain the CIO locator. */
+    let s = s06(date1, date2, x, y);
+
+    /* Form the celestial-to-intermediate matrix. */
+    c2ixys(x, y, s)
+}
@@ -1,14 +1,16 @@
 use crate::vm::{ry, rz, ir};
+use crate::math::{atan, atan2};
 
 ///  Celestial−to−intermediate matrix, given X,Y, IAU 2000
-pub fn c2ixys(x: f64, y: f64, s: f64, rc2i: &mut [[f64; 3]; 3]) {
+pub fn c2ixys(x: f64, y: f64, s: f64) -> [[f64; 3]; 3] {
   let r2 = x * x + y * y;
-  let e = if r2 > 0.0 { y.atan2(x) } else { 0.0 };
-  let d = (r2 / (1.0 - r2)).sqrt().atan();
-
-  ir(rc2i);
-  rz(e, rc2i);
-  ry(d, rc2i);
-  rz(-(e + s), rc2i);
+  let e = if r2 > 0.0 { atan2(y, x) } else { 0.0 };
+  let d = atan((r2 / (1.0 - r2)).sqrt());
 
+  let mut rc2i = [[0.0; 3]; 3];
+  ir(&mut rc2i);
+  rz(e, &mut rc2i);
+  ry(d, &mut rc2i);
+  rz(-(e + s), &mut rc2i);
+  rc2i
 }
@@ -1,3 +1,5 @@
+use crate::math::atan2;
+
 pub fn eors(rnpb: &[[f64; 3]; 3], s: f64) -> f64 {
     let x = rnpb[2][0];
     let ax = x / (1.0 + rnpb[2][2]);
@@ -7,7 +9,7 @@ pub fn eors(rnpb: &[[f64; 3]; 3], s: f64) -> f64 {
     let p = rnpb[0][0] * xs + rnpb[0][1] * ys + rnpb[0][2] * zs;
     let q = rnpb[1][0] * xs + rnpb[1][1] * ys + rnpb[1][2] * zs;
     let eo = if p != 0.0 || q != 0.0 {
-        s - q.atan2(p)
+        s - atan2(q, p)
     } else {
         s
     };
@@ -1,10 +1,12 @@
 use crate::vm::{ir, rx, rz};
 
-pub fn fw2m(gamb: f64, phib: f64, psi: f64, eps: f64, r: &mut [[f64; 3]; 3]) {
+pub fn fw2m(gamb: f64, phib: f64, psi: f64, eps: f64) -> [[f64; 3]; 3] {
     /* Construct the matrix. */
-    ir(r);
-    rz(gamb, r);
-    rx(phib, r);
-    rz(-psi, r);
-    rx(-eps, r);
+    let mut r = [[0.0; 3]; 3];
+    ir(&mut r);
+    rz(gamb, &mut r);
+    rx(phib, &mut r);
+    rz(-psi, &mut r);
+    rx(-eps, &mut r);
+    r
 }
\ No newline at end of file
@@ -0,0 +1,82 @@
+use crate::consts::{DJM0, DJM00};
+use crate::math::sin;
+use crate::vm::RotMat;
+
+use super::{fw2m, fw2xy, pfw06};
+
+///  Fukushima-Williams angles
+///
+///  ```text
+///     gamb     F-W angle gamma_bar (radians)
+///     phib     F-W angle phi_bar (radians)
+///     psib     F-W angle psi_bar (radians)
+///     epsa     F-W angle epsilon_A (radians)
+///  ```
+///  The bias-precession angles of `pfw06`, to which nutation, or any
+///  other correction to the pole, is added before the matrix is built:
+///  ```text
+///     let (dpsi, deps) = nut06a(date1, date2);
+///     let rbpn = FwAngles::iau2006(date1, date2)
+///         .with_nutation(dpsi, deps)
+///         .with_cip_offsets(dx, dy)
+///         .matrix();
+///  ```
+///  Without nutation the matrix is PxB, and with it NxPxB;  see `fw2m`
+///  and `pnm06a`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FwAngles {
+    pub gamb: f64,
+    pub phib: f64,
+    pub psib: f64,
+    pub epsa: f64,
+}
+
+impl FwAngles {
+    pub fn new(gamb: f64, phib: f64, psib: f64, epsa: f64) -> Self {
+        FwAngles { gamb, phib, psib, epsa }
+    }
+
+    /// IAU 2006 bias-precession angles for a TT date (see `pfw06`).
+    pub fn iau2006(date1: f64, date2: f64) -> Self {
+        let (gamb, phib, psib, epsa) = pfw06(date1, date2);
+        FwAngles { gamb, phib, psib, epsa }
+    }
+
+    /// Frame bias alone:  the IAU 2006 angles at J2000.0.
+    pub fn bias() -> Self {
+        Self::iau2006(DJM0, DJM00)
+    }
+
+    /// Add nutation in longitude and obliquity (radians).
+    pub fn with_nutation(self, dpsi: f64, deps: f64) -> Self {
+        FwAngles { psib: self.psib + dpsi, epsa: self.epsa + deps, ..self }
+    }
+
+    /// Move the pole by the CIP offsets dX,dY (radians).
+    ///
+    /// The corrections to psi and epsilon are found by iterating on
+    /// dX = dpsi sin(eps), dY = deps, so that the CIP X,Y of the result
+    /// are those of self plus dX,dY to better than 1e-15 for offsets
+    /// of up to a few milliarcseconds.
+    pub fn with_cip_offsets(self, dx: f64, dy: f64) -> Self {
+        let (x0, y0) = self.xy();
+        let mut fw = self;
+        for _ in 0..2 {
+            let (x, y) = fw.xy();
+            fw.psib += (x0 + dx - x) / sin(fw.epsa);
+            fw.epsa += y0 + dy - y;
+        }
+        fw
+    }
+
+    /// The rotation matrix, R_1(-epsa).R_3(-psib).R_1(phib).R_3(gamb)
+    /// (see `fw2m`).
+    pub fn matrix(&self) -> RotMat {
+        RotMat::new(fw2m(self.gamb, self.phib, self.psib, self.epsa))
+    }
+
+    /// The CIP X,Y (see `fw2xy`).
+    pub fn xy(&self) -> (f64, f64) {
+        fw2xy(self.gamb, self.phib, self.psib, self.epsa)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::cal::{jd_forms, Jd};
+use crate::vm::Mat3;
+
+use super::*;
+
+jd_forms! {
+    value bp00_jd = bp00() -> [[[f64; 3]; 3]; 3];
+    value c2i06a_jd = c2i06a() -> [[f64; 3]; 3];
+    value c2i06a_cip_jd = c2i06a_cip(dx: f64, dy: f64) -> [[f64; 3]; 3];
+    value eo06a_jd = eo06a() -> f64;
+    value nut00a_jd = nut00a() -> (f64, f64);
+    value nut06a_jd = nut06a() -> (f64, f64);
+    value obl06_jd = obl06() -> f64;
+    value pfw06_jd = pfw06() -> (f64, f64, f64, f64);
+    value pmat06_jd = pmat06() -> [[f64; 3]; 3];
+    value pn00_jd = pn00(dpsi: f64, deps: f64) -> PrecNut;
+    value pn00_mat3_jd = pn00_mat3(dpsi: f64, deps: f64) -> PrecNut<Mat3>;
+    value pn00a_jd = pn00a() -> PrecNut;
+    value pnm00a_jd = pnm00a() -> [[f64; 3]; 3];
+    value pnm06a_jd = pnm06a() -> [[f64; 3]; 3];
+    value pnm06a_cip_jd = pnm06a_cip(dx: f64, dy: f64) -> [[f64; 3]; 3];
+    value pr00_jd = pr00() -> (f64, f64);
+    value s06_jd = s06(x: f64, y: f64) -> f64;
+    value sp00_jd = sp00() -> f64;
+}
+
+/// As `c2t06a`, with the TT and UT1 dates as `Jd`s (or (date1, date2)
+/// pairs).
+pub fn c2t06a_jd(tt: impl Into<Jd>, ut: impl Into<Jd>, xp: f64, yp: f64) -> [[f64; 3]; 3] {
+    let (Jd(tta, ttb), Jd(uta, utb)) = (tt.into(), ut.into());
+    c2t06a(tta, ttb, uta, utb, xp, yp)
+}
+
+/// As `c2t06a_cip`, with the TT and UT1 dates as `Jd`s (or (date1,
+/// date2) pairs).
+#[allow(clippy::too_many_arguments)]
+pub fn c2t06a_cip_jd(tt: impl Into<Jd>, ut: impl Into<Jd>, xp: f64, yp: f64,
+                     dx: f64, dy: f64) -> [[f64; 3]; 3] {
+    let (Jd(tta, ttb), Jd(uta, utb)) = (tt.into(), ut.into());
+    c2t06a_cip(tta, ttb, uta, utb, xp, yp, dx, dy)
+}
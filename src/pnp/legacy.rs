@@ -0,0 +1,61 @@
+//! Out-parameter forms of the pnp routines
+//!
+//! The matrices are now returned by value, and pn00 and pn00a return
+//! a `PrecNut`.  The old forms, writing through `&mut` arguments as in
+//! the C library, are kept here, deprecated;  the original paths name
+//! the new forms, so old calls need `use sofars::pnp::legacy::*;`
+//! until they are moved over:
+//! ```text
+//!     pom00(xp, yp, sp, &mut rpom);   ->   let rpom = pom00(xp, yp, sp);
+//!     bp00(d1, d2, &mut rb, &mut rp, &mut rbp);
+//!                                     ->   let [rb, rp, rbp] = bp00(d1, d2);
+//! ```
+
+#[deprecated(note = "use pnp::bp00, which returns [rb, rp, rbp]")]
+pub fn bp00(date1: f64, date2: f64, rb: &mut [[f64; 3]; 3], rp: &mut [[f64; 3]; 3], rbp: &mut [[f64; 3]; 3]) {
+    [*rb, *rp, *rbp] = super::bp00(date1, date2);
+}
+
+#[deprecated(note = "use pnp::c2ixys, which returns the matrix")]
+pub fn c2ixys(x: f64, y: f64, s: f64, rc2i: &mut [[f64; 3]; 3]) {
+    *rc2i = super::c2ixys(x, y, s);
+}
+
+#[deprecated(note = "use pnp::fw2m, which returns the matrix")]
+pub fn fw2m(gamb: f64, phib: f64, psi: f64, eps: f64, r: &mut [[f64; 3]; 3]) {
+    *r = super::fw2m(gamb, phib, psi, eps);
+}
+
+#[deprecated(note = "use pnp::numat, which returns the matrix")]
+pub fn numat(epsa: f64, dpsi: f64, deps: f64, rmatn: &mut [[f64; 3]; 3]) {
+    *rmatn = super::numat(epsa, dpsi, deps);
+}
+
+#[deprecated(note = "use pnp::pn00, which returns a PrecNut")]
+#[allow(clippy::too_many_arguments)]
+pub fn pn00(date1: f64, date2: f64, dpsi: f64, deps: f64, epsa: &mut f64,
+            rb: &mut [[f64; 3]; 3], rp: &mut [[f64; 3]; 3], rbp: &mut [[f64; 3]; 3],
+            rn: &mut [[f64; 3]; 3], rbpn: &mut [[f64; 3]; 3]) {
+    let r = super::pn00(date1, date2, dpsi, deps);
+    (*epsa, *rb, *rp, *rbp, *rn, *rbpn) = (r.epsa, r.rb, r.rp, r.rbp, r.rn, r.rbpn);
+}
+
+#[deprecated(note = "use pnp::pn00a, which returns a PrecNut")]
+#[allow(clippy::too_many_arguments)]
+pub fn pn00a(date1: f64, date2: f64, dpsi: &mut f64, deps: &mut f64, epsa: &mut f64,
+             rb: &mut [[f64; 3]; 3], rp: &mut [[f64; 3]; 3], rbp: &mut [[f64; 3]; 3],
+             rn: &mut [[f64; 3]; 3], rbpn: &mut [[f64; 3]; 3]) {
+    let r = super::pn00a(date1, date2);
+    (*dpsi, *deps, *epsa) = (r.dpsi, r.deps, r.epsa);
+    (*rb, *rp, *rbp, *rn, *rbpn) = (r.rb, r.rp, r.rbp, r.rn, r.rbpn);
+}
+
+#[deprecated(note = "use pnp::pnm00a, which returns the matrix")]
+pub fn pnm00a(date1: f64, date2: f64, rbpn: &mut [[f64; 3]; 3]) {
+    *rbpn = super::pnm00a(date1, date2);
+}
+
+#[deprecated(note = "use pnp::pom00, which returns the matrix")]
+pub fn pom00(xp: f64, yp: f64, sp: f64, rpom: &mut [[f64; 3]; 3]) {
+    *rpom = super::pom00(xp, yp, sp);
+}
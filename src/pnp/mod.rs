@@ -23,6 +23,24 @@ pub use pnm06a::*;
 mod fw2m;
 pub use fw2m::*;
 
+mod fw2xy;
+pub use fw2xy::*;
+
+mod fwangles;
+pub use fwangles::*;
+
+mod ltp;
+pub use ltp::*;
+
+mod ltpb;
+pub use ltpb::*;
+
+mod ltpecl;
+pub use ltpecl::*;
+
+mod ltpequ;
+pub use ltpequ::*;
+
 mod numat;
 pub use numat::*;
 
@@ -38,6 +56,9 @@ pub use obl80::*;
 mod pfw06;
 pub use pfw06::*;
 
+mod pmat06;
+pub use pmat06::*;
+
 mod bpn2xy;
 pub use bpn2xy::*;
 
@@ -56,8 +77,25 @@ pub use c2ixys::*;
 mod pom00;
 pub use pom00::*;
 
+mod eo06a;
+pub use eo06a::*;
+
 mod eors;
 pub use eors::*;
 
 mod obl06;
 pub use obl06::*;
+
+mod c2i06a;
+pub use c2i06a::*;
+
+mod c2tcio;
+pub use c2tcio::*;
+
+mod c2t06a;
+pub use c2t06a::*;
+
+pub mod legacy;
+
+mod jdforms;
+pub use jdforms::*;
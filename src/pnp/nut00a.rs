@@ -1,7 +1,8 @@
 #![allow(non_snake_case)]
 use std::ops::Rem;
-use crate::consts::{D2PI, DAS2R, DJ00, DJC, TURNAS};
-use crate::fundargs::{fal03, faom03, faf03, fapa03, fame03, fave03, fae03, fama03, faju03, fasa03, faur03};
+use crate::consts::{D2PI, DAS2R, TURNAS};
+use crate::fundargs::EpochCache;
+use crate::math::{cos, sin, Sum};
 
 struct LSCOF(i32, i32,i32, i32, i32, f64, f64, f64, f64, f64, f64);
 
@@ -1693,11 +1694,18 @@ const XPL: [PLCOF; 687] = [
 ///     Wallace, P.T., "Software for Implementing the IAU 2000
 ///     Resolutions", in IERS Workshop 5.1 (2002)
 pub fn nut00a(date1: f64, date2: f64) -> (f64, f64) {
+    nut00a_with(&EpochCache::new(date1, date2))
+}
+
+///  Nutation, IAU 2000A model, with the fundamental arguments taken
+///  from a cache built for the TT date (see `EpochCache`).  The results
+///  are the same as those of `nut00a`.
+pub fn nut00a_with(fa: &EpochCache) -> (f64, f64) {
     /* Units of 0.1 microarcsecond to radians */
     let U2R = DAS2R / 1e7;
 
     /* Interval between fundamental date J2000.0 and given date (JC). */
-    let t = ((date1 - DJ00) + date2) / DJC;
+    let t = fa.t;
 
     /* ------------------- */
     /* LUNI-SOLAR NUTATION */
@@ -1706,7 +1714,7 @@ pub fn nut00a(date1: f64, date2: f64) -> (f64, f64) {
     /* Fundamental (Delaunay) arguments */
 
     /* Mean anomaly of the Moon (IERS 2003). */
-    let el = fal03(t);
+    let el = fa.l;
 
     /* Mean anomaly of the Sun (MHB2000). */
     let elp = (1287104.79305  +
@@ -1717,7 +1725,7 @@ pub fn nut00a(date1: f64, date2: f64) -> (f64, f64) {
 
     /* Mean longitude of the Moon minus that of the ascending node */
     /* (IERS 2003). */
-    let f = faf03(t);
+    let f = fa.f;
 
     /* Mean elongation of the Moon from the Sun (MHB2000). */
     let d = (1072260.70369  +
@@ -1727,8 +1735,8 @@ pub fn nut00a(date1: f64, date2: f64) -> (f64, f64) {
         t * (-0.00003169))))).rem(TURNAS) * DAS2R;
 
     /* Mean longitude of the ascending node of the Moon (IERS 2003). */
-    let om = faom03(t);
-    let (mut dp, mut de) = (0.0, 0.0);
+    let om = fa.om;
+    let (mut dp, mut de) = (Sum::default(), Sum::default());
 
     // int nl,nlp,nf,nd,nom; /* coefficients of l,l',F,D,Om */
     // double sp,spt,cp;     /* longitude sin, t*sin, cos coefficients */
@@ -1740,15 +1748,15 @@ pub fn nut00a(date1: f64, date2: f64) -> (f64, f64) {
                     XLS[i].2 as f64 * f +
                     XLS[i].3 as f64 * d + 
                     XLS[i].4 as f64 * om).rem(D2PI);
-        let sarg = arg.sin();
-        let carg = arg.cos();
+        let sarg = sin(arg);
+        let carg = cos(arg);
         dp += (XLS[i].5 + XLS[i].6 * t) * sarg + XLS[i].7 * carg;
         de += (XLS[i].8 + XLS[i].9 * t) * carg + XLS[i].10 * sarg;
     }
 
     /* Convert from 0.1 microarcsec units to radians. */
-    let dpsils = dp * U2R;
-    let depsls = de * U2R;
+    let dpsils = dp.value() * U2R;
+    let depsls = de.value() * U2R;
 
     /* ------------------ */
     /* PLANETARY NUTATION */
@@ -1775,22 +1783,22 @@ pub fn nut00a(date1: f64, date2: f64) -> (f64, f64) {
     let aom = (2.18243920 - 33.757045 * t).rem(D2PI);
 
     /* General accumulated precession in longitude (IERS 2003). */
-    let apa = fapa03(t);
+    let apa = fa.pa;
 
     /* Planetary longitudes, Mercury through Uranus (IERS 2003). */
-    let alme = fame03(t);
-    let alve = fave03(t);
-    let alea = fae03(t);
-    let alma = fama03(t);
-    let alju = faju03(t);
-    let alsa = fasa03(t);
-    let alur = faur03(t);    
+    let alme = fa.me;
+    let alve = fa.ve;
+    let alea = fa.e;
+    let alma = fa.ma;
+    let alju = fa.ju;
+    let alsa = fa.sa;
+    let alur = fa.ur;    
 
     /* Neptune longitude (MHB2000). */
     let alne = (5.321159000 + 3.8127774000 * t).rem(D2PI);
     
     /* Initialize the nutation values. */
-    let (mut dp, mut de) = (0.0, 0.0);
+    let (mut dp, mut de) = (Sum::default(), Sum::default());
     
     /* Summation of planetary nutation series (in reverse order). */
     for i in (0..XPL.len()).rev() {
@@ -1807,16 +1815,16 @@ pub fn nut00a(date1: f64, date2: f64) -> (f64, f64) {
                     XPL[i].10 as f64 * alur + 
                     XPL[i].11 as f64 * alne + 
                     XPL[i].12 as f64 * apa).rem(D2PI);
-        let sarg = arg.sin();
-        let carg = arg.cos();
+        let sarg = sin(arg);
+        let carg = cos(arg);
         /* Term. */
         dp += XPL[i].13 as f64 * sarg + XPL[i].14 as f64 * carg;
         de += XPL[i].15 as f64 * sarg + XPL[i].16 as f64 * carg;
     }
     
     /* Convert from 0.1 microarcsec units to radians. */
-    let dpsipl = dp * U2R;
-    let depspl = de * U2R;
+    let dpsipl = dp.value() * U2R;
+    let depspl = de.value() * U2R;
 
     /* ------- */
     /* RESULTS */
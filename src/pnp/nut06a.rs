@@ -1,5 +1,5 @@
-use crate::consts::{DJ00, DJC};
-use crate::pnp::nut00a;
+use crate::fundargs::EpochCache;
+use crate::pnp::nut00a_with;
 
 ///
 ///  IAU 2000A nutation with adjustments to match the IAU 2006
@@ -73,14 +73,18 @@ use crate::pnp::nut00a;
 ///     Wallace, P.T., "Software for Implementing the IAU 2000
 ///     Resolutions", in IERS Workshop 5.1 (2002)
 pub fn nut06a(date1: f64, date2: f64) -> (f64, f64) {
-    /* Interval between fundamental date J2000.0 and given date (JC). */
-    let t = ((date1 - DJ00) + date2) / DJC;
+    nut06a_with(&EpochCache::new(date1, date2))
+}
 
+///  Nutation, IAU 2006/2000A, as `nut06a`, with the fundamental
+///  arguments taken from a cache built for the TT date (see
+///  `EpochCache`).
+pub fn nut06a_with(fa: &EpochCache) -> (f64, f64) {
     /* Factor correcting for secular variation of J2. */
-    let fj2 = -2.7774e-6 * t;
+    let fj2 = -2.7774e-6 * fa.t;
 
     /* Obtain IAU 2000A nutation. */
-    let (dp, de) = nut00a(date1, date2);
+    let (dp, de) = nut00a_with(fa);
 
     /* Apply P03 adjustments (Wallace & Capitaine, 2006, Eqs.5). */
     let dpsi = dp + dp * (0.4697e-6 + fj2);
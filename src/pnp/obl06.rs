@@ -40,7 +40,7 @@ use crate::consts::{DAS2R, DJ00, DJC};
 ///  Reference:
 ///
 ///     Hilton, J. et al., 2006, Celest.Mech.Dyn.Astron. 94, 351
-pub fn obl06(date1: f64, date2: f64) -> f64 {
+pub const fn obl06(date1: f64, date2: f64) -> f64 {
     let (t, eps0): (f64, f64);
 
     /* Interval between fundamental date J2000.0 and given date (JC). */
@@ -42,7 +42,7 @@ use crate::consts::{DAS2R, DJ00, DJC};
 ///     Explanatory Supplement to the Astronomical Almanac,
 ///     P. Kenneth Seidelmann (ed), University Science Books (1992),
 ///     Expression 3.222-1 (p114).
-pub fn obl80(date1: f64, date2: f64) -> f64 {
+pub const fn obl80(date1: f64, date2: f64) -> f64 {
     
     /* Interval between fundamental epoch J2000.0 and given date (JC). */
     let t = ((date1 - DJ00) + date2) / DJC;
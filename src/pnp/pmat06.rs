@@ -0,0 +1,46 @@
+use super::{fw2m, pfw06};
+
+///  Precession matrix (including frame bias) from GCRS to a specified
+///  date, IAU 2006 model.
+///
+///  This function is part of the International Astronomical Union's
+///  SOFA (Standards of Fundamental Astronomy) software collection.
+///
+///  Status:  support function.
+///
+///  Given:
+///  ```text
+///     date1,date2  double          TT as a 2-part Julian Date (Note 1)
+///  ```
+///  Returned (function value):
+///  ```text
+///                  double[3][3]    bias-precession matrix (Note 2)
+///  ```
+///  Notes:
+///
+///  1) The TT date date1+date2 is a Julian Date, apportioned in any
+///     convenient way between the two arguments, as in the other
+///     IAU 2006 functions.
+///
+///  2) The matrix operates in the sense V(date) = rbp * V(GCRS), where
+///     the p-vector V(GCRS) is with respect to the Geocentric Celestial
+///     Reference System (IAU, 2000) and the p-vector V(date) is with
+///     respect to the mean equatorial triad of the given date.
+///
+///  Called:
+///  ```text
+///     iauPfw06     bias-precession F-W angles, IAU 2006
+///     iauFw2m      F-W angles to r-matrix
+///  ```
+///  References:
+///
+///     Capitaine, N. & Wallace, P.T., 2006, Astron.Astrophys. 450, 855
+///
+///     Wallace, P.T. & Capitaine, N., 2006, Astron.Astrophys. 459, 981
+pub fn pmat06(date1: f64, date2: f64) -> [[f64; 3]; 3] {
+    /* Bias-precession Fukushima-Williams angles. */
+    let (gamb, phib, psib, epsa) = pfw06(date1, date2);
+
+    /* Form the matrix. */
+    fw2m(gamb, phib, psib, epsa)
+}
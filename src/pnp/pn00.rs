@@ -1,8 +1,63 @@
-#![allow(unused_variables)]
-use crate::vm::{cr, rxr};
+use crate::vm::{rxr, Mat3};
 
 use super::{bp00, numat, obl80, pr00};
 
+///  Bias-precession-nutation results of pn00 and pn00a
+///
+///  ```text
+///     dpsi,deps    double          nutation
+///     epsa         double          mean obliquity
+///     rb           double[3][3]    frame bias matrix
+///     rp           double[3][3]    precession matrix
+///     rbp          double[3][3]    bias-precession matrix
+///     rn           double[3][3]    nutation matrix
+///     rbpn         double[3][3]    GCRS-to-true matrix
+///  ```
+///
+///  The matrices are r-matrices unless M says otherwise; pn00_mat3
+///  returns them as Mat3, and `From` converts between the two.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PrecNut<M = [[f64; 3]; 3]> {
+    pub dpsi: f64,
+    pub deps: f64,
+    pub epsa: f64,
+    pub rb: M,
+    pub rp: M,
+    pub rbp: M,
+    pub rn: M,
+    pub rbpn: M,
+}
+
+impl From<PrecNut<Mat3>> for PrecNut {
+    fn from(p: PrecNut<Mat3>) -> Self {
+        PrecNut {
+            dpsi: p.dpsi,
+            deps: p.deps,
+            epsa: p.epsa,
+            rb: p.rb.to_rows(),
+            rp: p.rp.to_rows(),
+            rbp: p.rbp.to_rows(),
+            rn: p.rn.to_rows(),
+            rbpn: p.rbpn.to_rows(),
+        }
+    }
+}
+
+impl From<PrecNut> for PrecNut<Mat3> {
+    fn from(p: PrecNut) -> Self {
+        PrecNut {
+            dpsi: p.dpsi,
+            deps: p.deps,
+            epsa: p.epsa,
+            rb: Mat3::from_rows(&p.rb),
+            rp: Mat3::from_rows(&p.rp),
+            rbp: Mat3::from_rows(&p.rbp),
+            rn: Mat3::from_rows(&p.rn),
+            rbpn: Mat3::from_rows(&p.rbpn),
+        }
+    }
+}
+
 ///  Bias/precession/nutation results, IAU 2000
 ///
 ///  Precession-nutation, IAU 2000 model:  a multi-purpose function,
@@ -18,7 +73,8 @@ use super::{bp00, numat, obl80, pr00};
 ///     date1,date2  double          TT as a 2-part Julian Date (Note 1)
 ///     dpsi,deps    double          nutation (Note 2)
 ///
-///  Returned:
+///  Returned (function value, PrecNut):
+///     dpsi,deps    double          nutation, as given
 ///     epsa         double          mean obliquity (Note 3)
 ///     rb           double[3][3]    frame bias matrix (Note 4)
 ///     rp           double[3][3]    precession matrix (Note 5)
@@ -75,14 +131,12 @@ use super::{bp00, numat, obl80, pr00};
 ///     equinox of date.  It is the product rn x rbp, applying frame
 ///     bias, precession and nutation in that order.
 ///
-///  9) It is permissible to re-use the same array in the returned
-///     arguments.  The arrays are filled in the order given.
+///  9) The results are returned together in a PrecNut.
 ///
 ///  Called:
 ///     iauPr00      IAU 2000 precession adjustments
 ///     iauObl80     mean obliquity, IAU 1980
 ///     iauBp00      frame bias and precession matrices, IAU 2000
-///     iauCr        copy r-matrix
 ///     iauNumat     form nutation matrix
 ///     iauRxr       product of two r-matrices
 ///
@@ -95,28 +149,26 @@ use super::{bp00, numat, obl80, pr00};
 ///
 ///     n.b. The celestial ephemeris origin (CEO) was renamed "celestial
 ///          intermediate origin" (CIO) by IAU 2006 Resolution 2.
-pub fn pn00(date1: f64, date2: f64, dpsi: f64, deps: f64,
-    epsa: &mut f64,
-    rb: &mut [[f64; 3]; 3], rp: &mut [[f64; 3]; 3], rbp: &mut [[f64; 3]; 3],
-    rn: &mut [[f64; 3]; 3], rbpn: &mut [[f64; 3]; 3]) {
-    let rbpw = &mut [[0.0; 3]; 3];
-    let rnw = &mut [[0.0; 3]; 3];
-
+pub fn pn00(date1: f64, date2: f64, dpsi: f64, deps: f64) -> PrecNut {
     /* IAU 2000 precession-rate adjustments. */
-    let (dpsipr, depspr) = &mut pr00(date1, date2);
+    let (_, depspr) = pr00(date1, date2);
     
     /* Mean obliquity, consistent with IAU 2000 precession-nutation. */
-    *epsa = obl80(date1, date2) + *depspr;
+    let epsa = obl80(date1, date2) + depspr;
     
     /* Frame bias and precession matrices and their product. */
-    bp00(date1, date2, rb, rp, rbpw);
-
-    cr(rbpw, rbp);
+    let [rb, rp, rbp] = bp00(date1, date2);
     
     /* Nutation matrix. */
-    numat(*epsa, dpsi, deps, rnw);
-    cr(rnw, rn);
+    let rn = numat(epsa, dpsi, deps);
     
     /* Bias-precession-nutation matrix (classical). */
-    rxr(rnw, rbpw, rbpn);
+    let rbpn = rxr(&rn, &rbp);
+
+    PrecNut { dpsi, deps, epsa, rb, rp, rbp, rn, rbpn }
+}
+
+///  As pn00, with the matrices returned as Mat3.
+pub fn pn00_mat3(date1: f64, date2: f64, dpsi: f64, deps: f64) -> PrecNut<Mat3> {
+    pn00(date1, date2, dpsi, deps).into()
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use super::{nut00a, pn00};
+use super::{nut00a, pn00, PrecNut};
 
 ///  Bias/precession/nutation, IAU 2000A
 ///
@@ -14,7 +14,7 @@ use super::{nut00a, pn00};
 ///  Given:
 ///     date1,date2  double          TT as a 2-part Julian Date (Note 1)
 ///
-///  Returned:
+///  Returned (function value, PrecNut):
 ///     dpsi,deps    double          nutation (Note 2)
 ///     epsa         double          mean obliquity (Note 3)
 ///     rb           double[3][3]    frame bias matrix (Note 4)
@@ -76,8 +76,7 @@ use super::{nut00a, pn00};
 ///      Pole are elements (3,1-3) of the GCRS-to-true matrix,
 ///      i.e. rbpn[2][0-2].
 ///
-///  10) It is permissible to re-use the same array in the returned
-///      arguments.  The arrays are filled in the stated order.
+///  10) The results are returned together in a PrecNut.
 ///
 ///  Called:
 ///     iauNut00a    nutation, IAU 2000A
@@ -99,21 +98,10 @@ use super::{nut00a, pn00};
 ///
 ///  Copyright (C) 2023 IAU SOFA Board.  See notes at end.
 ///
-pub fn pn00a(
-    date1: f64, 
-    date2: f64, 
-    dpsi: &mut f64, 
-    deps: &mut f64, 
-    epsa: &mut f64, 
-    rb: &mut [[f64; 3]; 3], 
-    rp: &mut [[f64; 3]; 3], 
-    rbp: &mut [[f64; 3]; 3], 
-    rn: &mut [[f64; 3]; 3], 
-    rbpn: &mut [[f64; 3]; 3]
-) {
+pub fn pn00a(date1: f64, date2: f64) -> PrecNut {
     /* Nutation. */
-    (*dpsi, *deps) = nut00a(date1, date2);
+    let (dpsi, deps) = nut00a(date1, date2);
 
     /* Remaining results. */
-    pn00(date1, date2, *dpsi, *deps, epsa, rb, rp, rbp, rn, rbpn);
-}
\ No newline at end of file
+    pn00(date1, date2, dpsi, deps)
+}
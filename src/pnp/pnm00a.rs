@@ -14,7 +14,7 @@ use super::pn00a;
 ///  Given:
 ///     date1,date2 double       TT as a 2-part Julian Date (Note 1)
 ///
-///  Returned:
+///  Returned (function value):
 ///     rbpn        double[3][3] bias-precession-nutation matrix (Note 2)
 ///
 ///  Notes:
@@ -51,11 +51,7 @@ use super::pn00a;
 ///  Reference:
 ///
 ///     Capitaine, N. & Wallace, P.T., 2006, Astron.Astrophys. 450, 855.
-pub fn pnm00a(date1: f64, date2: f64, rbpn: &mut [[f64; 3]; 3]) {
-    let (dpsi, deps) = &mut (0.0, 0.0);
-    let epsa = &mut 0.0;
-    let (rb, rp, rbp, rn) = &mut ([[0.0; 3]; 3], [[0.0; 3]; 3], [[0.0; 3]; 3], [[0.0; 3]; 3]);
-
+pub fn pnm00a(date1: f64, date2: f64) -> [[f64; 3]; 3] {
     /* Obtain the required matrix (discarding other results). */
-    pn00a(date1, date2, dpsi, deps, epsa, rb, rp, rbp, rn, rbpn);
-}
\ No newline at end of file
+    pn00a(date1, date2).rbpn
+}
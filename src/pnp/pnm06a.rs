@@ -1,4 +1,6 @@
-use super::{fw2m, nut06a, pfw06};
+use crate::vm::{rxr, rz};
+
+use super::{bpn2xy, c2ixys, eors, fw2m, nut06a, pfw06, s06};
 
 ///  Classical NPB matrix, IAU 2006/2000A
 ///
@@ -54,7 +56,6 @@ use super::{fw2m, nut06a, pfw06};
 ///
 ///     Capitaine, N. & Wallace, P.T., 2006, Astron.Astrophys. 450, 855.
 pub fn pnm06a(date1: f64, date2: f64) -> [[f64; 3]; 3] {
-    let mut rbpn = [[0.0; 3]; 3];
     /* Fukushima-Williams angles for frame bias and precession. */
     let (gamb, phib, psib, epsa) = pfw06(date1, date2);
     
@@ -62,7 +63,46 @@ pub fn pnm06a(date1: f64, date2: f64) -> [[f64; 3]; 3] {
     let (dp, de) = nut06a(date1, date2);
     
     /* Equinox based nutation x precession x bias matrix. */
-    fw2m(gamb, phib, psib + dp, epsa + de, &mut rbpn);
+    fw2m(gamb, phib, psib + dp, epsa + de)
+}
+
+///  Classical NPB matrix, IAU 2006/2000A, with the CIP corrected by the
+///  IERS celestial pole offsets dX,dY.
+///
+///  Given:
+///  ```text
+///     date1,date2 double       TT as a 2-part Julian Date
+///     dx,dy       double       CIP offsets dX,dY (radians)
+///  ```
+///  Returned (function value):
+///  ```text
+///     rbpn        double[3][3] bias-precession-nutation matrix
+///  ```
+///  Notes:
+///
+///  1) The matrix is GCRS to true equator and equinox of date, as for
+///     `pnm06a`, but with the pole moved to the observed CIP.  It is
+///     formed as R_3(EO) x C2I, where C2I is the celestial-to-
+///     intermediate matrix for the corrected X,Y (see `c2i06a_cip`) and
+///     EO the equation of the origins of the model.  The change in EO
+///     caused by dX,dY is of second order (below 1e-12 radian) and is
+///     neglected.
+///
+///  2) This avoids converting dX,dY into nutation corrections
+///     ddpsi,ddeps, which to first order are dX/sin(eps) and dY.
+pub fn pnm06a_cip(date1: f64, date2: f64, dx: f64, dy: f64) -> [[f64; 3]; 3] {
+    let mut req = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+    /* Model NPB matrix, CIP and equation of the origins. */
+    let rm = pnm06a(date1, date2);
+    let (x, y) = bpn2xy(&rm);
+    let eo = eors(&rm, s06(date1, date2, x, y));
+
+    /* Corrected celestial-to-intermediate matrix. */
+    let (x, y) = (x + dx, y + dy);
+    let rc2i = c2ixys(x, y, s06(date1, date2, x, y));
 
-    rbpn
-}
\ No newline at end of file
+    /* Move the origin from the CIO to the equinox. */
+    rz(eo, &mut req);
+    rxr(&req, &rc2i)
+}
@@ -7,9 +7,6 @@ pub fn c2i00a() {}
 /// celestial−to−intermediate matrix, IAU 2000B
 pub fn c2i00b() {}
 
-/// celestial−to−intermediate matrix, IAU 2006/2000A
-pub fn c2i06a() {}
-
 /// celestial−to−intermediate matrix, given NPB matrix, IAU 2000
 pub fn c2ibpn() {}
 
@@ -22,12 +19,6 @@ pub fn c2t00a() {}
 /// celestial−to−terrestrial matrix, IAU 2000B
 pub fn c2t00b() {}
 
-/// celestial−to−terrestrial matrix, IAU 2006/2000A
-pub fn c2t06a() {}
-
-/// form CIO−based celestial−to−terrestrial matrix
-pub fn c2tcio() {}
-
 /// form equinox−based celestial−to−terrestrial matrix
 pub fn c2teqx() {}
 
@@ -37,24 +28,6 @@ pub fn c2tpe() {}
 /// celestial−to−terrestrial matrix given CIP, IAU 2000
 pub fn c2txy() {}
 
-/// equation of the origins, IAU 2006/2000A
-pub fn eo06a() {}
-
-/// Fukushima−Williams angles to X,Y
-pub fn fw2xy() {}
-
-/// long−term precession matrix
-pub fn ltp() {}
-
-/// long−term precession matrix, including ICRS frame bias
-pub fn ltpb() {}
-
-/// long−term precession of the ecliptic
-pub fn ltpecl() {}
-
-/// long−term precession of the equator
-pub fn ltpequ() {}
-
 /// nutation matrix, IAU 2000A
 pub fn num00a() {}
 
@@ -79,9 +52,6 @@ pub fn pb06() {}
 /// precession matrix (including frame bias), IAU 2000
 pub fn pmat00() {}
 
-/// PB matrix, IAU 2006
-pub fn pmat06() {}
-
 /// precession matrix, IAU 1976
 pub fn pmat76() {}
 
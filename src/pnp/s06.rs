@@ -1,6 +1,7 @@
 #![allow(non_upper_case_globals)]
-use crate::consts::{DAS2R, DJ00, DJC};
-use crate::fundargs::{fal03, falp03, faf03, fad03, faom03, fave03, fae03, fapa03};
+use crate::consts::DAS2R;
+use crate::fundargs::EpochCache;
+use crate::math::{cos, sin, Sum};
 
 struct TERM(
     [i32; 8], // nfa
@@ -97,78 +98,61 @@ const s4: [TERM; 1] = [
 ];
 
 pub fn s06(date1: f64, date2: f64, x: f64, y: f64) -> f64 {
-    
-    /* Interval between fundamental epoch J2000.0 and current date (JC). */
-    let t = ((date1 - DJ00) + date2) / DJC;
-
-    /* Fundamental Arguments (from IERS Conventions 2003) */
-
-    let mut fa: [f64; 8] = [0.0; 8];
-    /* Mean anomaly of the Moon. */
-    fa[0] = fal03(t);
-
-    /* Mean anomaly of the Sun. */
-    fa[1] = falp03(t);
-
-    /* Mean longitude of the Moon minus that of the ascending node. */
-    fa[2] = faf03(t);
+    s06_with(&EpochCache::new(date1, date2), x, y)
+}
 
-    /* Mean elongation of the Moon from the Sun. */
-    fa[3] = fad03(t);
-
-    /* Mean longitude of the ascending node of the Moon. */
-    fa[4] = faom03(t);
-
-    /* Mean longitude of Venus. */
-    fa[5] = fave03(t);
-
-    /* Mean longitude of Earth. */
-    fa[6] = fae03(t);
+///  The CIO locator s, as `s06`, with the fundamental arguments taken
+///  from a cache built for the TT date (see `EpochCache`).
+pub fn s06_with(fa: &EpochCache, x: f64, y: f64) -> f64 {
+    /* Interval between fundamental epoch J2000.0 and current date (JC). */
+    let t = fa.t;
 
-    /* General precession in longitude. */
-    fa[7] = fapa03(t);
+    /* Fundamental Arguments (from IERS Conventions 2003):  l, l', F, D, */
+    /* Om, LVe, LE, pA. */
+    let fa = [fa.l, fa.lp, fa.f, fa.d, fa.om, fa.ve, fa.e, fa.pa];
 
-    let [ mut w0, mut w1, mut w2, mut w3, mut w4, w5 ] = sp;
+    let [ mut w0, mut w1, mut w2, mut w3, mut w4 ] = [0, 1, 2, 3, 4].map(|i| Sum::new(sp[i]));
+    let w5 = sp[5];
     for i in (0..s0.len()).rev() {
         let nfa = s0[i].0;
         let [s, c] = [s0[i].1, s0[i].2];
         let a = fa.iter().zip(nfa.iter()).fold(0.0, |acc, (&fa, &nfa)| acc + fa * nfa as f64);
-        w0 += s * a.sin() + c * a.cos();
+        w0 += s * sin(a) + c * cos(a);
     }
 
     for i in (0..s1.len()).rev() {
         let nfa = s1[i].0;
         let [s, c] = [s1[i].1, s1[i].2];
         let a = fa.iter().zip(nfa.iter()).fold(0.0, |acc, (&fa, &nfa)| acc + fa * nfa as f64);
-        w1 += s * a.sin() + c * a.cos();
+        w1 += s * sin(a) + c * cos(a);
     }
 
     for i in (0..s2.len()).rev() {
         let nfa = s2[i].0;
         let [s, c] = [s2[i].1, s2[i].2];
         let a = fa.iter().zip(nfa.iter()).fold(0.0, |acc, (&fa, &nfa)| acc + fa * nfa as f64);
-        w2 += s * a.sin() + c * a.cos();
+        w2 += s * sin(a) + c * cos(a);
     }
 
     for i in (0..s3.len()).rev() {
         let nfa = s3[i].0;
         let [s, c] = [s3[i].1, s3[i].2];
         let a = fa.iter().zip(nfa.iter()).fold(0.0, |acc, (&fa, &nfa)| acc + fa * nfa as f64);
-        w3 += s * a.sin() + c * a.cos();
+        w3 += s * sin(a) + c * cos(a);
     }
 
     for i in (0..s4.len()).rev() {
         let nfa = s4[i].0;
         let [s, c] = [s4[i].1, s4[i].2];
         let a = fa.iter().zip(nfa.iter()).fold(0.0, |acc, (&fa, &nfa)| acc + fa * nfa as f64);
-        w4 += s * a.sin() + c * a.cos();
+        w4 += s * sin(a) + c * cos(a);
     }
 
-    let s = (w0 +
-        (w1 +
-        (w2 +
-        (w3 +
-        (w4 +
+    let s = (w0.value() +
+        (w1.value() +
+        (w2.value() +
+        (w3.value() +
+        (w4.value() +
          w5 * t) * t) * t) * t) * t) * DAS2R - x*y/2.0;
 
     s
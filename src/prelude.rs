@@ -0,0 +1,22 @@
+//! The commonly used types and traits
+//!
+//! ```
+//! use sofars::prelude::*;
+//!
+//! let epoch = Epoch::from_calendar(TimeScale::UTC, 2024, 3, 20, 3, 6, 0.0).unwrap();
+//! let site = Site::new(-(70.0 + 44.0 / 60.0).deg(), -30.24.deg(), 2200.0);
+//! let (az, alt) = sun_altaz(&epoch, &site, &EarthOrientation::default()).unwrap();
+//! # let _ = (az, alt);
+//! ```
+//! The routines themselves stay in their modules;  only a few one-call
+//! helpers are included here.
+pub use crate::astro::{
+    planet_altaz, sun_altaz, ApparentPlace, AstroPipeline, IauAstrom, LunarPhase, PlanetAltAz, Site,
+};
+pub use crate::cal::Jd;
+pub use crate::eph::{OscElements, Planet};
+pub use crate::frames::{CipCorrectionProvider, EarthOrientation, EopProvider, EopTable, ZeroEop};
+pub use crate::star::Star;
+pub use crate::ts::{Epoch, TimeScale};
+pub use crate::units::Units;
+pub use crate::vm::{Angle, PvVec, RotMat, SphCoord, Wrap, WrappedAngle};
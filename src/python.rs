@@ -0,0 +1,263 @@
+//! Python bindings
+//!
+//! A pyo3 extension module, `sofars`, exposing the high-level API:
+//! ```text
+//!     Epoch              scale-tagged epoch (ISO 8601 in and out,
+//!                        conversion between scales)
+//!     Site               geodetic observing site
+//!     EarthOrientation   UT1-UTC, polar motion and CIP corrections
+//!     gcrs_to_itrs ...   frame transformation matrices (3x3 lists)
+//!                        and position/velocity transformations
+//!     refco              refraction constants
+//!     epv00              Earth position and velocity
+//! ```
+//! Build the extension with maturin (see pyproject.toml), which enables
+//! pyo3's "extension-module" feature.  Angles are in radians and dates
+//! are two-part Julian Dates, as in the Rust API.  A SOFA error status
+//! is raised as ValueError.
+//!
+//! Requires the "python" feature.
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::astro;
+use crate::eph;
+use crate::frames;
+use crate::ts::{self, TimeScale};
+use crate::vm::PvVec;
+
+type Mat = [[f64; 3]; 3];
+type Pv = [[f64; 3]; 2];
+
+fn status(j: i32) -> PyErr {
+    PyValueError::new_err(format!("SOFA status {}", j))
+}
+
+fn scale(name: &str) -> PyResult<TimeScale> {
+    TimeScale::from_name(name).ok_or_else(|| PyValueError::new_err(format!("unknown time scale {:?}", name)))
+}
+
+/// Scale-tagged epoch:  Epoch(scale, jd1, jd2=0.0).
+#[pyclass(name = "Epoch", module = "sofars", frozen)]
+#[derive(Clone, Copy)]
+pub struct PyEpoch(pub ts::Epoch);
+
+/* Python methods take &self, Copy or not. */
+#[allow(clippy::wrong_self_convention)]
+#[pymethods]
+impl PyEpoch {
+    #[new]
+    #[pyo3(signature = (scale_, jd1, jd2 = 0.0))]
+    fn new(scale_: &str, jd1: f64, jd2: f64) -> PyResult<Self> {
+        Ok(PyEpoch(ts::Epoch::new(scale(scale_)?, (jd1, jd2).into())))
+    }
+
+    /// Epoch from calendar date and time of day.
+    #[staticmethod]
+    #[pyo3(signature = (scale_, iy, im, id, ihr = 0, imn = 0, sec = 0.0))]
+    fn from_calendar(scale_: &str, iy: i32, im: i32, id: i32, ihr: i32, imn: i32, sec: f64) -> PyResult<Self> {
+        ts::Epoch::from_calendar(scale(scale_)?, iy, im, id, ihr, imn, sec).map(PyEpoch).map_err(status)
+    }
+
+    /// Epoch from an ISO 8601 string in the given scale.
+    #[staticmethod]
+    #[pyo3(signature = (s, scale_ = "UTC"))]
+    fn from_iso(s: &str, scale_: &str) -> PyResult<Self> {
+        ts::Epoch::from_iso(s, scale(scale_)?).map(PyEpoch).map_err(status)
+    }
+
+    /// Epoch from an ISO 8601 string carrying its scale.
+    #[staticmethod]
+    fn parse(s: &str) -> PyResult<Self> {
+        ts::Epoch::parse(s).map(PyEpoch).map_err(status)
+    }
+
+    /// The scale ID, e.g. "TT".
+    #[getter]
+    fn scale(&self) -> &'static str {
+        self.0.scale.name()
+    }
+
+    /// The two-part Julian Date.
+    #[getter]
+    fn jd(&self) -> (f64, f64) {
+        self.0.jd.into()
+    }
+
+    /// The same instant in another scale.
+    fn to(&self, scale_: &str) -> PyResult<Self> {
+        self.0.to(scale(scale_)?).map(PyEpoch).map_err(status)
+    }
+
+    /// As `to`, with TDB-TT for an observer at the site.
+    fn to_topocentric(&self, scale_: &str, site: &PySite) -> PyResult<Self> {
+        self.0.to_topocentric(scale(scale_)?, &site.0).map(PyEpoch).map_err(status)
+    }
+
+    /// UT1, given UT1-UTC (seconds).
+    fn to_ut1(&self, dut1: f64) -> PyResult<Self> {
+        self.0.to_ut1(dut1).map(PyEpoch).map_err(status)
+    }
+
+    /// ISO 8601 string to ndp decimal places in the seconds.
+    #[pyo3(signature = (ndp = 3))]
+    fn to_iso(&self, ndp: i32) -> PyResult<String> {
+        self.0.to_iso(ndp).map_err(status)
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        let (d1, d2): (f64, f64) = self.0.jd.into();
+        format!("Epoch({:?}, {:?}, {:?})", self.0.scale.name(), d1, d2)
+    }
+}
+
+/// Observing site:  Site(elong, phi, hm), radians and metres (WGS84).
+#[pyclass(name = "Site", module = "sofars")]
+#[derive(Clone, Copy)]
+pub struct PySite(pub astro::Site);
+
+#[pymethods]
+impl PySite {
+    #[new]
+    #[pyo3(signature = (elong, phi, hm = 0.0))]
+    fn new(elong: f64, phi: f64, hm: f64) -> Self {
+        PySite(astro::Site::new(elong, phi, hm))
+    }
+
+    #[getter]
+    fn elong(&self) -> f64 {
+        self.0.elong
+    }
+
+    #[getter]
+    fn phi(&self) -> f64 {
+        self.0.phi
+    }
+
+    #[getter]
+    fn hm(&self) -> f64 {
+        self.0.hm
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Site({:?}, {:?}, {:?})", self.0.elong, self.0.phi, self.0.hm)
+    }
+}
+
+/// Earth orientation parameters, in seconds and radians;  see
+/// `from_iers` for the units of the IERS bulletins.
+#[pyclass(name = "EarthOrientation", module = "sofars", frozen)]
+#[derive(Clone, Copy)]
+pub struct PyEarthOrientation(pub frames::EarthOrientation);
+
+#[pymethods]
+impl PyEarthOrientation {
+    #[new]
+    #[pyo3(signature = (ut1_utc = 0.0, xp = 0.0, yp = 0.0, dx = 0.0, dy = 0.0))]
+    fn new(ut1_utc: f64, xp: f64, yp: f64, dx: f64, dy: f64) -> Self {
+        PyEarthOrientation(frames::EarthOrientation { ut1_utc, xp, yp, dx, dy })
+    }
+
+    /// From IERS values:  seconds, arcsec, arcsec, mas, mas.
+    #[staticmethod]
+    #[pyo3(signature = (ut1_utc, xp_as, yp_as, dx_mas = 0.0, dy_mas = 0.0))]
+    fn from_iers(ut1_utc: f64, xp_as: f64, yp_as: f64, dx_mas: f64, dy_mas: f64) -> Self {
+        PyEarthOrientation(frames::EarthOrientation::from_iers(ut1_utc, xp_as, yp_as, dx_mas, dy_mas))
+    }
+
+    /// UT1-UTC from the approximate Delta T model, no polar motion.
+    #[staticmethod]
+    fn from_deltat(epoch: &PyEpoch) -> PyResult<Self> {
+        frames::EarthOrientation::from_deltat(&epoch.0).map(PyEarthOrientation).map_err(status)
+    }
+
+    #[getter]
+    fn ut1_utc(&self) -> f64 {
+        self.0.ut1_utc
+    }
+
+    #[getter]
+    fn xp(&self) -> f64 {
+        self.0.xp
+    }
+
+    #[getter]
+    fn yp(&self) -> f64 {
+        self.0.yp
+    }
+}
+
+/* Frame matrix and pv functions, all (epoch, eo[, p, v]). */
+macro_rules! frame {
+    ($name:ident, $pv:ident) => {
+        #[doc = concat!("See `frames::", stringify!($name), "`;  a 3x3 list.")]
+        #[pyfunction]
+        fn $name(epoch: &PyEpoch, eo: &PyEarthOrientation) -> PyResult<Mat> {
+            frames::$name(&epoch.0, &eo.0).map(|r| r.to_array()).map_err(status)
+        }
+
+        #[doc = concat!("See `frames::", stringify!($pv), "`;  (p, v) in, (p, v) out.")]
+        #[pyfunction]
+        fn $pv(epoch: &PyEpoch, eo: &PyEarthOrientation, p: [f64; 3], v: [f64; 3])
+               -> PyResult<([f64; 3], [f64; 3])> {
+            let pv = frames::$pv(&epoch.0, &eo.0, &PvVec::new(p, v)).map_err(status)?;
+            Ok((pv.p, pv.v))
+        }
+    };
+}
+
+frame!(gcrs_to_itrs, gcrs_to_itrs_pv);
+frame!(itrs_to_gcrs, itrs_to_gcrs_pv);
+frame!(teme_to_itrs, teme_to_itrs_pv);
+frame!(itrs_to_teme, itrs_to_teme_pv);
+
+/// See `frames::gcrs_to_teme`;  a 3x3 list.
+#[pyfunction]
+fn gcrs_to_teme(epoch: &PyEpoch, eo: &PyEarthOrientation) -> PyResult<Mat> {
+    frames::gcrs_to_teme(&epoch.0, &eo.0).map(|r| r.to_array()).map_err(status)
+}
+
+/// See `frames::teme_to_gcrs`;  a 3x3 list.
+#[pyfunction]
+fn teme_to_gcrs(epoch: &PyEpoch, eo: &PyEarthOrientation) -> PyResult<Mat> {
+    frames::teme_to_gcrs(&epoch.0, &eo.0).map(|r| r.to_array()).map_err(status)
+}
+
+/// Refraction constants A and B (radians) for pressure (hPa),
+/// temperature (C), relative humidity (0-1) and wavelength (micron).
+#[pyfunction]
+fn refco(phpa: f64, tc: f64, rh: f64, wl: f64) -> (f64, f64) {
+    astro::refco(phpa, tc, rh, wl)
+}
+
+/// Earth heliocentric and barycentric (p, v), au and au/day, for a TDB
+/// date:  ((ph, vh), (pb, vb)).
+#[pyfunction]
+fn epv00(date1: f64, date2: f64) -> PyResult<(Pv, Pv)> {
+    eph::epv00(date1, date2).ok_or_else(|| status(1))
+}
+
+/// The extension module.
+#[pymodule]
+pub fn sofars(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyEpoch>()?;
+    m.add_class::<PySite>()?;
+    m.add_class::<PyEarthOrientation>()?;
+    m.add_function(wrap_pyfunction!(gcrs_to_itrs, m)?)?;
+    m.add_function(wrap_pyfunction!(gcrs_to_itrs_pv, m)?)?;
+    m.add_function(wrap_pyfunction!(itrs_to_gcrs, m)?)?;
+    m.add_function(wrap_pyfunction!(itrs_to_gcrs_pv, m)?)?;
+    m.add_function(wrap_pyfunction!(teme_to_itrs, m)?)?;
+    m.add_function(wrap_pyfunction!(teme_to_itrs_pv, m)?)?;
+    m.add_function(wrap_pyfunction!(itrs_to_teme, m)?)?;
+    m.add_function(wrap_pyfunction!(itrs_to_teme_pv, m)?)?;
+    m.add_function(wrap_pyfunction!(gcrs_to_teme, m)?)?;
+    m.add_function(wrap_pyfunction!(teme_to_gcrs, m)?)?;
+    m.add_function(wrap_pyfunction!(refco, m)?)?;
+    m.add_function(wrap_pyfunction!(epv00, m)?)?;
+    Ok(())
+}
@@ -1,4 +1,453 @@
 //! Star catalog conversions
+
+use crate::consts::{DAU, DAYSEC, DC, DJY, DR2AS};
+use crate::math::{cos, hypot};
+use crate::vm::{anp, pdp, pm, pn, pv2s, s2pv, sxp, PvVec};
+
+///  Catalog star
+///
+///  The six astrometric parameters taken by the SOFA catalog functions
+///  (atccq, atciq, pmpx ...), in their units:
+///  ```text
+///     ra,dec   ICRS RA,Dec at the catalog epoch (radians)
+///     pmr      proper motion in RA, dRA/dt (radians/year)
+///     pmd      proper motion in Dec, dDec/dt (radians/year)
+///     px       parallax (arcsec)
+///     rv       radial velocity (km/s, +ve if receding)
+///  ```
+///  Note that pmr is dRA/dt rather than cos(Dec)*dRA/dt.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Star {
+    pub ra: f64,
+    pub dec: f64,
+    pub pmr: f64,
+    pub pmd: f64,
+    pub px: f64,
+    pub rv: f64,
+}
+
+impl Star {
+    pub fn new(ra: f64, dec: f64, pmr: f64, pmd: f64, px: f64, rv: f64) -> Self {
+        Star { ra, dec, pmr, pmd, px, rv }
+    }
+
+    /// A star with no proper motion, parallax or radial velocity.
+    pub fn fixed(ra: f64, dec: f64) -> Self {
+        Star { ra, dec, ..Default::default() }
+    }
+
+    ///  Barycentric position and velocity (au, au/day), as starpv, with
+    ///  the radial velocity read according to `opts`.
+    ///
+    ///  The status is that of starpv (0 = OK, +1, +2, +4 warnings, see
+    ///  there);  the pv-vector is usable whatever its value.
+    ///
+    ///  Round trip:  for status 0, `Star::from_pv` with the same options
+    ///  gives back the star, with RA in the range 0-2pi, to rounding
+    ///  error (about 1e-12 relative in each parameter).
+    pub fn to_pv(&self, opts: &StarPvOptions) -> (PvVec, i32) {
+        let rv = match opts.rv {
+            RvConvention::Kinematic => self.rv,
+            RvConvention::Barycentric => cz2rv(self.rv, self.beta_t()),
+        };
+        let (pv, j) = if opts.relativistic {
+            starpv(self.ra, self.dec, self.pmr, self.pmd, self.px, rv)
+        } else {
+            let (r, j) = distance(self.px);
+            let rad = self.pmr / DJY;
+            let decd = self.pmd / DJY;
+            let mut pv = s2pv(self.ra, self.dec, r, rad, decd, rv * KMS2AUD);
+            if pm(&pv[1]) / DC > VMAX {
+                pv[1] = [0.0; 3];
+                (pv, j + 2)
+            } else {
+                (pv, j)
+            }
+        };
+        (pv.into(), j)
+    }
+
+    ///  Catalog parameters from a barycentric position and velocity
+    ///  (au, au/day), as pvstar, with the radial velocity given
+    ///  according to `opts`.  The errors are those of pvstar.
+    pub fn from_pv(pv: &PvVec, opts: &StarPvOptions) -> Result<Star, i32> {
+        let mut star = if opts.relativistic {
+            pvstar(&pv.to_array())?
+        } else {
+            let (a, dec, r, rad, decd, rd) = pv2s(&pv.to_array());
+            if r == 0.0 {
+                return Err(-2);
+            }
+            Star::new(anp(a), dec, rad * DJY, decd * DJY, DR2AS / r, rd / KMS2AUD)
+        };
+        if opts.rv == RvConvention::Barycentric {
+            star.rv = rv2cz(star.rv, star.beta_t());
+        }
+        Ok(star)
+    }
+
+    /* Transverse velocity over c. */
+    fn beta_t(&self) -> f64 {
+        let (r, _) = distance(self.px);
+        let pmt = hypot(self.pmr * cos(self.dec), self.pmd) / DJY;
+        r * pmt / DC
+    }
+}
+
+///  How the radial velocity of a `Star` is to be understood
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RvConvention {
+    /// Kinematic radial velocity, the rate of change of distance
+    /// (km/s), as starpv and pvstar take it.
+    #[default]
+    Kinematic,
+    /// Barycentric radial-velocity measure c*z_B (km/s, IAU 2000
+    /// Resolution C1), the spectroscopic value corrected to the
+    /// barycenter;  related to the radial velocity by the relativistic
+    /// Doppler formula, gravitational redshifts being ignored.
+    Barycentric,
+}
+
+///  Options for `Star::to_pv` and `Star::from_pv`
+///
+///  ```text
+///     rv             how rv is to be understood (see RvConvention)
+///     relativistic   apply the starpv/pvstar correction between the
+///                    observed and the inertial space motion (Note 1)
+///  ```
+///  Notes:
+///
+///  1) With `relativistic` the proper motion and radial velocity are
+///     taken to be as observed, and the pv-vector is the inertial
+///     motion, as in starpv (see its Note 4).  Without, the conversion
+///     is purely geometric, as s2pv and pv2s, which is the right choice
+///     when the catalog itself was built that way.  The two differ by
+///     about v/c in the velocity.
+///
+///  2) The default is `SOFA`, giving the same results as starpv and
+///     pvstar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StarPvOptions {
+    pub rv: RvConvention,
+    pub relativistic: bool,
+}
+
+impl StarPvOptions {
+    /// As starpv and pvstar.
+    pub const SOFA: StarPvOptions = StarPvOptions {
+        rv: RvConvention::Kinematic,
+        relativistic: true,
+    };
+
+    /// Purely geometric conversion.
+    pub const GEOMETRIC: StarPvOptions = StarPvOptions {
+        rv: RvConvention::Kinematic,
+        relativistic: false,
+    };
+}
+
+impl Default for StarPvOptions {
+    fn default() -> Self {
+        StarPvOptions::SOFA
+    }
+}
+
+/* Smallest allowed parallax (arcsec). */
+const PXMIN: f64 = 1e-7;
+
+/* Largest allowed speed (fraction of c). */
+const VMAX: f64 = 0.5;
+
+/* Maximum number of iterations for relativistic solution. */
+const IMAX: usize = 100;
+
+/* km/s to au/day. */
+const KMS2AUD: f64 = DAYSEC * 1e3 / DAU;
+
+/* Distance (au) from parallax, with the starpv warning if too small. */
+fn distance(px: f64) -> (f64, i32) {
+    if px >= PXMIN {
+        (DR2AS / px, 0)
+    } else {
+        (DR2AS / PXMIN, 1)
+    }
+}
+
+/* Speed of light, km/s. */
+const CKMS: f64 = DC / KMS2AUD;
+
+/* Radial velocity from barycentric measure cz, given the transverse
+   beta:  1 + z = (1 + beta_r) / sqrt(1 - beta_r^2 - beta_t^2). */
+fn cz2rv(cz: f64, bt: f64) -> f64 {
+    let k2 = (1.0 + cz / CKMS).powi(2);
+    let disc = k2 * (k2 - bt * bt * (1.0 + k2));
+    CKMS * (disc.max(0.0).sqrt() - 1.0) / (1.0 + k2)
+}
+
+/* Barycentric measure cz from radial velocity and transverse beta. */
+fn rv2cz(rv: f64, bt: f64) -> f64 {
+    let br = rv / CKMS;
+    CKMS * ((1.0 + br) / (1.0 - br * br - bt * bt).sqrt() - 1.0)
+}
+
+///  Convert star catalog coordinates to position+velocity vector.
+///
+///  Given (Note 1):
+///  ```text
+///     ra     double        right ascension (radians)
+///     dec    double        declination (radians)
+///     pmr    double        RA proper motion (radians/year)
+///     pmd    double        Dec proper motion (radians/year)
+///     px     double        parallax (arcseconds)
+///     rv     double        radial velocity (km/s, positive = receding)
+///  ```
+///  Returned:
+///  ```text
+///     pv     double[2][3]  pv-vector (au, au/day)
+///  ```
+///  Returned (function value):
+///  ```text
+///            int           status:
+///                              0 = no warnings
+///                              1 = distance overridden (Note 6)
+///                              2 = excessive speed (Note 7)
+///                              4 = solution didn't converge (Note 8)
+///                           else = binary logical OR of the above
+///  ```
+///  Notes:
+///
+///  1) The star data accepted by this function are "observables" for an
+///     imaginary observer at the solar-system barycenter.  Proper motion
+///     and radial velocity are, strictly, in terms of barycentric
+///     coordinate time, TCB.  For most practical applications, it is
+///     permissible to neglect the distinction between TCB and ordinary
+///     "proper" time on Earth (TT/TAI).  The result will, as a rule, be
+///     limited by the intrinsic accuracy of the proper-motion and
+///     radial-velocity data;  moreover, the pv-vector is likely to be
+///     merely an intermediate result, so that a change of time unit
+///     would cancel out overall.
+///
+///     In accordance with normal star-catalog conventions, the object's
+///     right ascension and declination are freed from the effects of
+///     secular aberration.  The frame, which is aligned to the catalog
+///     equator and equinox, is Lorentzian and centered on the SSB.
+///
+///  2) The resulting position and velocity pv-vector is with respect to
+///     the same frame and, like the catalog coordinates, is freed from
+///     the effects of secular aberration.  Should the "coordinate
+///     direction", where the object was located at the catalog epoch, be
+///     required, it may be obtained by calculating the magnitude of the
+///     position vector pv[0][0-2] dividing by the speed of light in
+///     au/day to give the light-time, and then multiplying the space
+///     velocity pv[1][0-2] by this light-time and adding the result to
+///     pv[0][0-2].
+///
+///     Summarizing, the pv-vector returned is for most stars almost
+///     identical to the result of applying the standard geometrical
+///     "space motion" transformation.  The differences, which are the
+///     subject of the Stumpff paper referenced below, are:
+///
+///     (i) In stars with significant radial velocity and proper motion,
+///     the constantly changing light-time distorts the apparent proper
+///     motion.  Note that this is a classical, not a relativistic,
+///     effect.
+///
+///     (ii) The transformation complies with special relativity.
+///
+///  3) Care is needed with units.  The star coordinates are in radians
+///     and the proper motions in radians per Julian year, but the
+///     parallax is in arcseconds;  the radial velocity is in km/s, but
+///     the pv-vector result is in au and au/day.
+///
+///  4) The RA proper motion is in terms of coordinate angle, not true
+///     angle.  If the catalog uses arcseconds for both RA and Dec proper
+///     motions, the RA proper motion will need to be divided by cos(Dec)
+///     before use.
+///
+///  5) Straight-line motion at constant speed, in the inertial frame,
+///     is assumed.
+///
+///  6) An extremely small (or zero or negative) parallax is interpreted
+///     to mean that the object is on the "celestial sphere", the radius
+///     of which is an arbitrary (large) value (see the constant PXMIN).
+///     When the distance is overridden in this way, the status,
+///     initially zero, has 1 added to it.
+///
+///  7) If the space velocity is a significant fraction of c (see the
+///     constant VMAX), it is arbitrarily set to zero.  When this action
+///     occurs, 2 is added to the status.
+///
+///  8) The relativistic adjustment involves an iterative calculation.
+///     If the process fails to converge within a set number (IMAX) of
+///     iterations, 4 is added to the status.
+///
+///  9) The inverse transformation is performed by the function pvstar.
+///     `Star::to_pv` offers other conventions for the radial velocity.
+///
+///  Reference:
+///
+///     Stumpff, P., 1985, Astron.Astrophys. 144, 232-240.
+pub fn starpv(ra: f64, dec: f64, pmr: f64, pmd: f64, px: f64, rv: f64) -> ([[f64; 3]; 2], i32) {
+    /* Distance (au). */
+    let (r, mut iwarn) = distance(px);
+
+    /* Radial velocity (au/day). */
+    let rd = rv * KMS2AUD;
+
+    /* Proper motion (radian/day). */
+    let rad = pmr / DJY;
+    let decd = pmd / DJY;
+
+    /* To pv-vector (au,au/day). */
+    let mut pv = s2pv(ra, dec, r, rad, decd, rd);
+
+    /* If excessive velocity, arbitrarily set it to zero. */
+    if pm(&pv[1]) / DC > VMAX {
+        pv[1] = [0.0; 3];
+        iwarn += 2;
+    }
+
+    /* Isolate the radial component of the velocity (au/day). */
+    let (_, x) = pn(&pv[0]);
+    let vsr = pdp(&x, &pv[1]);
+    let usr = sxp(vsr, &x);
+
+    /* Isolate the transverse component of the velocity (au/day). */
+    let ust: [f64; 3] = std::array::from_fn(|i| pv[1][i] - usr[i]);
+    let vst = pm(&ust);
+
+    /* Special-relativity dimensionless parameters. */
+    let betsr = vsr / DC;
+    let betst = vst / DC;
+
+    /* Determine the inertial-to-observed relativistic correction terms. */
+    let (mut d, mut del) = (0.0, 0.0);
+    let (mut od, mut odel, mut odd, mut oddel) = (0.0, 0.0, 0.0, 0.0);
+    let mut bett = betst;
+    let mut betr = betsr;
+    let mut i = 0;
+    while i < IMAX {
+        d = 1.0 + betr;
+        let w = betr * betr + bett * bett;
+        del = -w / ((1.0 - w).sqrt() + 1.0);
+        betr = d * betsr + del;
+        bett = d * betst;
+        if i > 0 {
+            let dd = (d - od).abs();
+            let ddel = (del - odel).abs();
+            if i > 1 && dd >= odd && ddel >= oddel {
+                break;
+            }
+            odd = dd;
+            oddel = ddel;
+        }
+        od = d;
+        odel = del;
+        i += 1;
+    }
+    if i >= IMAX {
+        iwarn += 4;
+    }
+
+    /* Replace observed radial velocity with inertial value. */
+    let w = if betsr != 0.0 { d + del / betsr } else { 1.0 };
+    let ur = sxp(w, &usr);
+
+    /* Replace observed tangential velocity with inertial value. */
+    let ut = sxp(d, &ust);
+
+    /* Combine the two to obtain the inertial space velocity. */
+    pv[1] = std::array::from_fn(|i| ur[i] + ut[i]);
+
+    (pv, iwarn)
+}
+
+///  Convert star position+velocity vector to catalog coordinates.
+///
+///  Given (Note 1):
+///  ```text
+///     pv     double[2][3]   pv-vector (au, au/day)
+///  ```
+///  Returned (function value):
+///  ```text
+///            Star           ra, dec (radians), pmr, pmd (radians/year),
+///                           px (arcsec), rv (km/s, positive = receding)
+///  ```
+///  or Err:
+///  ```text
+///            -1 = superluminal speed (Note 5)
+///            -2 = null position vector
+///  ```
+///  Notes:
+///
+///  1) The specified pv-vector is the coordinate direction (and its rate
+///     of change) for the date at which the light leaving the star
+///     reached the solar-system barycenter.
+///
+///  2) The star data returned by this function are "observables" for an
+///     imaginary observer located at the SSB, in the frame of the
+///     pv-vector, as in starpv.
+///
+///  3) The RA proper motion is in terms of coordinate angle, not true
+///     angle, and will thus be numerically larger than the true angle
+///     rate near the celestial poles.
+///
+///  4) The proper motions and radial velocity are those with respect to
+///     the light-time of the star, as in starpv (Notes 1, 2).  The
+///     parallax is simply DR2AS over the distance in au.
+///
+///  5) The relativistic adjustment is carried out in a single step,
+///     rather than iteratively as in starpv, and fails if the speed
+///     is not less than that of light.
+///
+///  6) The inverse transformation is performed by the function starpv.
+///     `Star::from_pv` offers other conventions for the radial velocity.
+///
+///  Reference:
+///
+///     Stumpff, P., 1985, Astron.Astrophys. 144, 232-240.
+pub fn pvstar(pv: &[[f64; 3]; 2]) -> Result<Star, i32> {
+    /* Isolate the radial component of the velocity (au/day, inertial). */
+    let (_, x) = pn(&pv[0]);
+    let vr = pdp(&x, &pv[1]);
+    let ur = sxp(vr, &x);
+
+    /* Isolate the transverse component of the velocity (au/day, inertial). */
+    let ut: [f64; 3] = std::array::from_fn(|i| pv[1][i] - ur[i]);
+    let vt = pm(&ut);
+
+    /* Special-relativity dimensionless parameters. */
+    let bett = vt / DC;
+    let betr = vr / DC;
+
+    /* The inertial-to-observed correction terms. */
+    let d = 1.0 + betr;
+    let w = betr * betr + bett * bett;
+    if d == 0.0 || w >= 1.0 {
+        return Err(-1);
+    }
+    let del = -w / ((1.0 - w).sqrt() + 1.0);
+
+    /* Scale inertial tangential velocity vector into observed (au/d). */
+    let ust = sxp(1.0 / d, &ut);
+
+    /* Compute observed radial velocity vector (au/d). */
+    let usr = sxp(DC * (betr - del) / d, &x);
+
+    /* Combine the two to obtain the observed velocity vector. */
+    let v: [f64; 3] = std::array::from_fn(|i| usr[i] + ust[i]);
+
+    /* Cartesian to spherical. */
+    let (a, dec, r, rad, decd, rd) = pv2s(&[pv[0], v]);
+    if r == 0.0 {
+        return Err(-2);
+    }
+
+    /* RA in range 0 to 2pi, proper motions in radians per year,
+       parallax in arcsec and radial velocity in km/s. */
+    Ok(Star::new(anp(a), dec, rad * DJY, decd * DJY, DR2AS / r, rd / KMS2AUD))
+}
+
 /// transform FK5 star data into the Hipparcos system
 pub fn fk52h() {}
 
@@ -0,0 +1,241 @@
+//! Dense time series by Chebyshev interpolation
+//!
+//! For a quantity wanted at many closely spaced epochs, the full series
+//! (nutation, ephemeris) are evaluated only at the Chebyshev nodes of
+//! successive spans, typically a day, and the polynomial through them
+//! is evaluated at each step.  `ChebStream` does this for any function
+//! of the epoch returning a fixed-length vector;  `orientation_stream`
+//! and `pv_stream` give GCRS-to-ITRS matrices and body states.
+//!
+//! A span of one day at degree 12 reproduces the CIP to about 1e-13
+//! radians and the `epv00` Earth to well below its own accuracy, at a
+//! cost of 13 evaluations per span however fine the step.  Fast terms
+//! (the Earth rotation angle) are never interpolated.
+use std::f64::consts::PI;
+
+use crate::consts::DAYSEC;
+use crate::erst::era00;
+use crate::frames::EarthOrientation;
+use crate::math::cos;
+use crate::pnp::{bpn2xy, c2ixys, pnm06a, pom00, s06, sp00};
+use crate::ts::{Epoch, TimeScale};
+use crate::vm::{PvVec, RotMat};
+
+///  Chebyshev approximation of a vector function over a span
+///
+///  ```text
+///     t0,t1   start and end of the span
+///     coef    coefficients, lowest degree first
+///  ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chebyshev<const K: usize> {
+    pub t0: f64,
+    pub t1: f64,
+    pub coef: Vec<[f64; K]>,
+}
+
+impl<const K: usize> Chebyshev<K> {
+    ///  Fit f over t0-t1 by interpolation at the degree+1 Chebyshev
+    ///  nodes (of the first kind).
+    ///
+    ///  Errors from f are passed on.
+    pub fn fit(t0: f64, t1: f64, degree: usize, mut f: impl FnMut(f64) -> Result<[f64; K], i32>)
+               -> Result<Self, i32> {
+        let n = degree + 1;
+        let (mid, half) = ((t0 + t1) / 2.0, (t1 - t0) / 2.0);
+
+        /* Values at the nodes. */
+        let theta = |j: usize| PI * (j as f64 + 0.5) / n as f64;
+        let values = (0..n)
+            .map(|j| f(mid + half * cos(theta(j))))
+            .collect::<Result<Vec<_>, i32>>()?;
+
+        /* Discrete orthogonality:  c_k = 2/n sum f_j cos(k theta_j),
+           halved for k = 0. */
+        let coef = (0..n)
+            .map(|k| {
+                let w = if k == 0 { 1.0 } else { 2.0 } / n as f64;
+                let mut c = [0.0; K];
+                for (j, v) in values.iter().enumerate() {
+                    let t = w * cos(k as f64 * theta(j));
+                    c.iter_mut().zip(v).for_each(|(c, v)| *c += t * v);
+                }
+                c
+            })
+            .collect();
+        Ok(Chebyshev { t0, t1, coef })
+    }
+
+    /// Whether t is within the span.
+    pub fn contains(&self, t: f64) -> bool {
+        t >= self.t0 && t <= self.t1
+    }
+
+    /// The approximation at t (Clenshaw's recurrence).
+    pub fn eval(&self, t: f64) -> [f64; K] {
+        let x = (2.0 * t - self.t0 - self.t1) / (self.t1 - self.t0);
+        let (mut b1, mut b2) = ([0.0; K], [0.0; K]);
+        for c in self.coef.iter().skip(1).rev() {
+            let b0 = std::array::from_fn(|i| 2.0 * x * b1[i] - b2[i] + c[i]);
+            (b1, b2) = (b0, b1);
+        }
+        let c0 = self.coef.first().copied().unwrap_or([0.0; K]);
+        std::array::from_fn(|i| x * b1[i] - b2[i] + c0[i])
+    }
+}
+
+///  Values of a function of the epoch at regular steps, by Chebyshev
+///  interpolation
+///
+///  Given:
+///  ```text
+///     start    Epoch     first epoch, any scale
+///     days     double    length of the series (days)
+///     step     double    interval between epochs (seconds)
+///     f        FnMut     the function, of an epoch in start's scale
+///  ```
+///  Yields (start + i step, f) for i = 0, 1, ... up to start + days,
+///  fitting f afresh whenever a step leaves the current span.  The
+///  span (default 1 day) and degree (default 12) are set with `span`
+///  and `degree`.  An error from f is yielded and ends the series.
+///
+///  f must be smooth over a span, and is also evaluated up to a span
+///  beyond the end of the series.  Panics if step or span is not
+///  positive, as the series would never end, or if days is negative.
+pub struct ChebStream<const K: usize, F> {
+    start: Epoch,
+    step: f64,
+    n: usize,
+    i: usize,
+    span: f64,
+    degree: usize,
+    f: F,
+    seg: Option<Chebyshev<K>>,
+}
+
+impl<const K: usize, F: FnMut(&Epoch) -> Result<[f64; K], i32>> ChebStream<K, F> {
+    pub fn new(start: &Epoch, days: f64, step: f64, f: F) -> Self {
+        assert!(step > 0.0, "ChebStream: step must be positive");
+        assert!(days >= 0.0, "ChebStream: days must not be negative");
+        let step = step / DAYSEC;
+        ChebStream {
+            start: *start,
+            step,
+            n: (days / step + 1e-9).floor() as usize + 1,
+            i: 0,
+            span: 1.0,
+            degree: 12,
+            f,
+            seg: None,
+        }
+    }
+
+    /// Length of the fitted spans (days).
+    pub fn span(mut self, days: f64) -> Self {
+        assert!(days > 0.0, "ChebStream: span must be positive");
+        self.span = days;
+        self
+    }
+
+    /// Degree of the fitted polynomials.
+    pub fn degree(mut self, degree: usize) -> Self {
+        self.degree = degree;
+        self
+    }
+}
+
+impl<const K: usize, F: FnMut(&Epoch) -> Result<[f64; K], i32>> Iterator for ChebStream<K, F> {
+    type Item = Result<(Epoch, [f64; K]), i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i >= self.n {
+            return None;
+        }
+        let t = self.i as f64 * self.step;
+        self.i += 1;
+        let at = |dt: f64| Epoch::new(self.start.scale, self.start.jd + dt);
+
+        /* A new span, starting at this step, if needed. */
+        if !self.seg.as_ref().is_some_and(|s| s.contains(t)) {
+            let f = &mut self.f;
+            match Chebyshev::fit(t, t + self.span, self.degree, |dt| f(&at(dt))) {
+                Ok(s) => self.seg = Some(s),
+                Err(e) => {
+                    self.i = self.n;
+                    return Some(Err(e));
+                }
+            }
+        }
+        let seg = self.seg.as_ref()?;
+        Some(Ok((at(t), seg.eval(t))))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.n - self.i, Some(self.n - self.i))
+    }
+}
+
+/* CIP X,Y (with dX,dY) and CIO locator s. */
+pub(crate) fn xys(epoch: &Epoch, eo: &EarthOrientation) -> Result<[f64; 3], i32> {
+    let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+    let (x, y) = bpn2xy(&pnm06a(tt1, tt2));
+    let (x, y) = (x + eo.dx, y + eo.dy);
+    Ok([x, y, s06(tt1, tt2, x, y)])
+}
+
+///  GCRS to ITRS rotation matrices at regular steps
+///
+///  Given:
+///  ```text
+///     start    Epoch              first epoch (UTC, TAI or TT)
+///     days     double             length of the series (days)
+///     step     double             interval (seconds)
+///     eo       EarthOrientation   UT1-UTC, polar motion, dX,dY
+///  ```
+///  Yields (epoch, rc2t) as `gcrs_to_itrs` would give them.  The CIP
+///  X,Y and the CIO locator s are interpolated (`ChebStream`);  the
+///  Earth rotation angle, s' and polar motion are evaluated at every
+///  step.  The status values are those of the time scale conversions.
+///  Panics if step is not positive or days is negative.
+pub fn orientation_stream(start: &Epoch, days: f64, step: f64, eo: EarthOrientation)
+                          -> impl Iterator<Item = Result<(Epoch, RotMat), i32>> {
+    ChebStream::new(start, days, step, move |t: &Epoch| xys(t, &eo)).map(move |r| {
+        let (epoch, [x, y, s]) = r?;
+        let (tt1, tt2) = epoch.to(TimeScale::TT)?.jd.into();
+        let (ut1, ut2) = epoch.to_ut1(eo.ut1_utc)?.jd.into();
+        let rc2i = c2ixys(x, y, s);
+        let rpom = pom00(eo.xp, eo.yp, sp00(tt1, tt2));
+        Ok((epoch, RotMat::new(rpom) * RotMat::rz(era00(ut1, ut2)) * RotMat::new(rc2i)))
+    })
+}
+
+///  Position and velocity of a body at regular steps
+///
+///  Given:
+///  ```text
+///     start    Epoch     first epoch, any scale
+///     days     double    length of the series (days)
+///     step     double    interval (seconds)
+///     f        FnMut     the ephemeris, of an epoch in start's scale
+///  ```
+///  Yields (epoch, pv), position and velocity being interpolated
+///  separately (`ChebStream`).  For example the Earth from `epv00`:
+///  ```text
+///     pv_stream(&start, 1.0, 60.0, |t| {
+///         let (tt1, tt2) = t.to(TimeScale::TT)?.jd.into();
+///         Ok(PvVec::from(epv00(tt1, tt2).ok_or(1)?.1))
+///     })
+///  ```
+///  Panics if step is not positive or days is negative.
+pub fn pv_stream(start: &Epoch, days: f64, step: f64,
+                 mut f: impl FnMut(&Epoch) -> Result<PvVec, i32>)
+                 -> impl Iterator<Item = Result<(Epoch, PvVec), i32>> {
+    ChebStream::new(start, days, step, move |t: &Epoch| {
+        let pv = f(t)?;
+        Ok([pv.p[0], pv.p[1], pv.p[2], pv.v[0], pv.v[1], pv.v[2]])
+    })
+    .map(|r| {
+        let (epoch, x) = r?;
+        Ok((epoch, PvVec::new([x[0], x[1], x[2]], [x[3], x[4], x[5]])))
+    })
+}
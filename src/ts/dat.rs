@@ -115,6 +115,15 @@
 ///  Called:
 ///     iauCal2jd    Gregorian calendar to JD
 pub fn dat(iy: i32, im: i32, id: i32, fd: f64) -> Result<f64, i32> {
+    match dat_status(iy, im, id, fd)? {
+        (deltat, 0) => Ok(deltat),
+        (_, j) => Err(j),
+    }
+}
+
+/* As dat, with the warning status of the C function (+1 for a pre-UTC
+   year, Delta(AT) then being zero) returned together with the value. */
+pub(crate) fn dat_status(iy: i32, im: i32, id: i32, fd: f64) -> Result<(f64, i32), i32> {
     // Release year for this version of iauDat
     const IYV: i32 = 2023;
 
@@ -214,7 +223,7 @@ pub fn dat(iy: i32, im: i32, id: i32, fd: f64) -> Result<f64, i32> {
 
     // If pre-UTC year, set warning status and give up.
     if iy < CHANGES[0].0 {
-        return Err(1);
+        return Ok((deltat, 1));
     }
 
     let j: i32;
@@ -249,5 +258,5 @@ pub fn dat(iy: i32, im: i32, id: i32, fd: f64) -> Result<f64, i32> {
     deltat = da;
 
     // Return the status.
-    Ok(deltat)
+    Ok((deltat, 0))
 }
@@ -0,0 +1,120 @@
+use crate::consts::{DAYSEC, DJ00, DJY};
+
+///  Approximate Delta T = TT - UT1 for a given decimal year.
+///
+///  Given:
+///  ```text
+///     y        double    decimal year, e.g. 1969.5 (Note 2)
+///  ```
+///  Returned (function value):
+///  ```text
+///              double    Delta T (seconds), APPROXIMATE (Note 1)
+///  ```
+///  Notes:
+///
+///  1) This is a model, not a measurement.  It is meant as a fallback
+///     when no IERS values are available:  for historical dates, for
+///     dates in the future, or offline.  Between 1955 and 2005 it
+///     matches the observed values to better than a second;  the
+///     uncertainty grows to minutes around 1000 AD and to hours in
+///     antiquity, and for the near future the 2005-2050 polynomial
+///     already runs several seconds above the observed values.
+///     Wherever the IERS UT1-UTC is available it must be preferred
+///     (see `Epoch::to_ut1`).
+///
+///  2) The argument is the year as used by Espenak & Meeus, i.e.
+///     y = year + (month - 0.5)/12 for the middle of a month.  A
+///     Julian Date converts with `deltat_approx`.
+///
+///  3) Between -500 and +2150 the piecewise polynomials of Espenak &
+///     Meeus (2006) are used;  they adopt the Morrison & Stephenson
+///     (2004) values and the lunar secular acceleration -25.858
+///     arcsec/cy^2.  Outside that range the long-term parabola of
+///     Morrison & Stephenson, -20 + 32 u^2 with u = (y-1820)/100, is
+///     used.
+///
+///  References:
+///  ```text
+///     Espenak, F. & Meeus, J., 2006, "Five Millennium Canon of Solar
+///     Eclipses: -1999 to +3000", NASA Tech. Pub. 2006-214141
+///
+///     Morrison, L.V. & Stephenson, F.R., 2004, J.Hist.Astron. 35, 327
+///  ```
+pub fn deltat_year(y: f64) -> f64 {
+    /* Long-term parabola. */
+    let lt = |y: f64| {
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    };
+
+    if y < -500.0 {
+        lt(y)
+    } else if y < 500.0 {
+        let u = y / 100.0;
+        10583.6 + u * (-1014.41 + u * (33.78311 + u * (-5.952053
+            + u * (-0.1798452 + u * (0.022174192 + u * 0.0090316521)))))
+    } else if y < 1600.0 {
+        let u = (y - 1000.0) / 100.0;
+        1574.2 + u * (-556.01 + u * (71.23472 + u * (0.319781
+            + u * (-0.8503463 + u * (-0.005050998 + u * 0.0083572073)))))
+    } else if y < 1700.0 {
+        let t = y - 1600.0;
+        120.0 + t * (-0.9808 + t * (-0.01532 + t / 7129.0))
+    } else if y < 1800.0 {
+        let t = y - 1700.0;
+        8.83 + t * (0.1603 + t * (-0.0059285 + t * (0.00013336 - t / 1174000.0)))
+    } else if y < 1860.0 {
+        let t = y - 1800.0;
+        13.72 + t * (-0.332447 + t * (0.0068612 + t * (0.0041116 + t * (-0.00037436
+            + t * (0.0000121272 + t * (-0.0000001699 + t * 0.000000000875))))))
+    } else if y < 1900.0 {
+        let t = y - 1860.0;
+        7.62 + t * (0.5737 + t * (-0.251754 + t * (0.01680668
+            + t * (-0.0004473624 + t / 233174.0))))
+    } else if y < 1920.0 {
+        let t = y - 1900.0;
+        -2.79 + t * (1.494119 + t * (-0.0598939 + t * (0.0061966 - t * 0.000197)))
+    } else if y < 1941.0 {
+        let t = y - 1920.0;
+        21.20 + t * (0.84493 + t * (-0.076100 + t * 0.0020936))
+    } else if y < 1961.0 {
+        let t = y - 1950.0;
+        29.07 + t * (0.407 + t * (-1.0 / 233.0 + t / 2547.0))
+    } else if y < 1986.0 {
+        let t = y - 1975.0;
+        45.45 + t * (1.067 + t * (-1.0 / 260.0 - t / 718.0))
+    } else if y < 2005.0 {
+        let t = y - 2000.0;
+        63.86 + t * (0.3345 + t * (-0.060374 + t * (0.0017275
+            + t * (0.000651814 + t * 0.00002373599))))
+    } else if y < 2050.0 {
+        let t = y - 2000.0;
+        62.92 + t * (0.32217 + t * 0.005589)
+    } else if y < 2150.0 {
+        lt(y) - 0.5628 * (2150.0 - y)
+    } else {
+        lt(y)
+    }
+}
+
+///  Approximate Delta T = TT - UT1 for a given Julian Date.
+///
+///  Given:
+///  ```text
+///     date1,date2   double   TT (or UT1) as a 2-part Julian Date
+///  ```
+///  Returned (function value):
+///  ```text
+///                   double   Delta T (seconds), APPROXIMATE
+///  ```
+///  The model and its limitations are those of `deltat_year`.  The
+///  argument may be either TT or UT1:  the difference is far below the
+///  accuracy of the model.
+pub fn deltat_approx(date1: f64, date2: f64) -> f64 {
+    deltat_year(2000.0 + ((date1 - DJ00) + date2) / DJY)
+}
+
+/* Delta T as a fraction of a day. */
+pub(crate) fn deltat_days(date1: f64, date2: f64) -> f64 {
+    deltat_approx(date1, date2) / DAYSEC
+}
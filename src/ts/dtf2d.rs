@@ -1,6 +1,6 @@
 use crate::{cal::{cal2jd, jd2cal}, consts::DAYSEC};
 
-use super::dat;
+use super::dat_status;
 
 ///  Encode date and time fields into 2-part Julian Date (or in the case
 ///  of UTC a quasi-JD form that includes special provision for leap
@@ -75,6 +75,19 @@ use super::dat;
 ///     iauJd2cal    JD to Gregorian calendar
 pub fn dtf2d(scale: &str, iy: i32, im: i32, id: i32, ihr: i32, imn: i32, sec: f64) 
                                                         -> Result<(f64, f64), i32> {
+    /* A time after the end of the day is accepted;  a dubious year is
+       reported as Err(1). */
+    match dtf2d_status(scale, iy, im, id, ihr, imn, sec)? {
+        (d, j) if j & 1 == 0 => Ok(d),
+        _ => Err(1),
+    }
+}
+
+/* As dtf2d, with the warning status of the C function (+1 dubious
+   year, +2 time after end of day, +3 both) returned together with
+   the date. */
+pub(crate) fn dtf2d_status(scale: &str, iy: i32, im: i32, id: i32, ihr: i32, imn: i32, sec: f64)
+                           -> Result<((f64, f64), i32), i32> {
     /* Today's Julian Day Number. */
     let js = cal2jd(iy, im, id);
     let (mut dj, w) = match js {
@@ -91,26 +104,20 @@ pub fn dtf2d(scale: &str, iy: i32, im: i32, id: i32, ihr: i32, imn: i32, sec: f6
     let (dat0, dat12, dat24);
     let (iy2, im2, id2, w): (i32, i32, i32, f64);
     let dleap;
+    let mut js = 0;
     if scale == "UTC" {
         /* TAI-UTC at 0h today. */
-        match dat(iy, im, id, 0.0) {
-           Ok(d) => dat0 = d,
-           Err(j) => return Err(j),
-        }
+        (dat0, _) = dat_status(iy, im, id, 0.0)?;
+
         /* TAI-UTC at 12h today (to detect drift). */
-        match dat(iy, im, id, 0.5) {
-           Ok(d) => dat12 = d,
-           Err(j) => return Err(j),
-        }
+        (dat12, _) = dat_status(iy, im, id, 0.5)?;
+
         /* TAI-UTC at 0h tomorrow (to detect jumps). */
         (iy2, im2, id2, w) = match jd2cal(dj, 1.5) {
             Ok(v) => v,
             Err(j) => return Err(j),
         };
-        match dat(iy2, im2, id2, 0.0) {
-              Ok(d) => dat24 = d,
-              Err(j) => return Err(j),
-        }
+        (dat24, js) = dat_status(iy2, im2, id2, 0.0)?;
         /* Any sudden change in TAI-UTC between today and tomorrow. */
         dleap = dat24 - (2.0*dat12 - dat0);
 
@@ -121,7 +128,6 @@ pub fn dtf2d(scale: &str, iy: i32, im: i32, id: i32, ihr: i32, imn: i32, sec: f6
         };
     }
     /* Validate the time. */
-    let mut js = 0;
     if ihr >= 0 && ihr <= 23 {
         if imn >= 0 && imn <= 59 {
         if sec >= 0.0 {
@@ -144,5 +150,5 @@ pub fn dtf2d(scale: &str, iy: i32, im: i32, id: i32, ihr: i32, imn: i32, sec: f6
     let time  = ( 60.0 * ( ( 60 * ihr + imn ) as f64 ) + sec ) / day;
 
     /* Return the date and time */
-    Ok((dj, time))
+    Ok(((dj, time), js))
 }
\ No newline at end of file
@@ -0,0 +1,481 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::cal::{cal2jd, jd2cal, Jd};
+use crate::consts::DJM0;
+
+use crate::astro::Site;
+use crate::coords::gd2gc;
+
+use super::{
+    d2dtf_status, deltat_days, dtdb, dtf2d, taitt, taiutc, tcbtdb, tcgtt, tdbtcb, tdbtt, tttai,
+    tttcg, tttdb, utctai, utcut1,
+};
+use crate::math::hypot;
+
+/// Time scale identifiers
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeScale {
+    UTC,
+    TAI,
+    TT,
+    TDB,
+    TCG,
+    TCB,
+    UT1,
+}
+
+impl TimeScale {
+    /// The SOFA scale ID, e.g. "UTC".
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimeScale::UTC => "UTC",
+            TimeScale::TAI => "TAI",
+            TimeScale::TT => "TT",
+            TimeScale::TDB => "TDB",
+            TimeScale::TCG => "TCG",
+            TimeScale::TCB => "TCB",
+            TimeScale::UT1 => "UT1",
+        }
+    }
+
+    /// Scale from its ID (upper case, as in SOFA).
+    pub fn from_name(s: &str) -> Option<Self> {
+        match s {
+            "UTC" => Some(TimeScale::UTC),
+            "TAI" => Some(TimeScale::TAI),
+            "TT" => Some(TimeScale::TT),
+            "TDB" => Some(TimeScale::TDB),
+            "TCG" => Some(TimeScale::TCG),
+            "TCB" => Some(TimeScale::TCB),
+            "UT1" => Some(TimeScale::UT1),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TimeScale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+///  Scale-tagged epoch
+///
+///  A two-part Julian Date together with the time scale it is expressed
+///  in.  For UTC the date is the SOFA quasi-JD (see `dtf2d`), so that a
+///  leap second is represented by the last second of a 86401 s day.
+///
+///  Epochs are read from and written to ISO 8601 strings:
+///  ```text
+///     2024-03-01T12:34:56.789Z           calendar date, UTC
+///     2024-061T12:34:56.789 TT           ordinal date, scale suffix
+///     2024-W09-5 12:34                   week date, space for 'T'
+///     2016-12-31T23:59:60.5Z             leap second
+///     2024-03-01T13:34:56+01:00          UTC with a zone offset
+///  ```
+///  See `Epoch::from_iso` for the accepted forms.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Epoch {
+    pub scale: TimeScale,
+    pub jd: Jd,
+}
+
+/* Status for a malformed string. */
+const ESYNTAX: i32 = -7;
+
+/* Status for a scale conversion that needs data an Epoch does not hold. */
+const ESCALE: i32 = -9;
+
+/* MJD of the Monday starting ISO week 1 of year iy. */
+fn week1_monday(iy: i32) -> Result<i64, i32> {
+    let (_, m4) = cal2jd(iy, 1, 4)?;
+    let m4 = m4 as i64;
+    Ok(m4 - iso_weekday(m4) + 1)
+}
+
+/* ISO day of week (Monday = 1) of an MJD. */
+fn iso_weekday(mjd: i64) -> i64 {
+    (mjd + 2400001).rem_euclid(7) + 1
+}
+
+/* Calendar date from an MJD. */
+fn mjd2cal(mjd: i64) -> Result<(i32, i32, i32), i32> {
+    let (iy, im, id, _) = jd2cal(DJM0, mjd as f64)?;
+    Ok((iy, im, id))
+}
+
+/* Fixed-width unsigned decimal field. */
+fn digits(s: &str, n: usize) -> Result<i32, i32> {
+    if s.len() != n || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ESYNTAX);
+    }
+    s.parse().map_err(|_| ESYNTAX)
+}
+
+/* Year, with an optional sign (required beyond 4 digits). */
+fn year(s: &str) -> Result<i32, i32> {
+    match s.as_bytes().first() {
+        Some(b'+') | Some(b'-') => {
+            let n = s.len() - 1;
+            if n < 4 {
+                return Err(ESYNTAX);
+            }
+            let y = digits(&s[1..], n)?;
+            Ok(if s.starts_with('-') { -y } else { y })
+        }
+        _ => digits(s, 4),
+    }
+}
+
+/* Date part, in any of the three forms, to (iy, im, id). */
+fn parse_date(s: &str) -> Result<(i32, i32, i32), i32> {
+    /* The year runs up to the first '-' after any sign. */
+    let off = if s.starts_with(['+', '-']) { 1 } else { 0 };
+    let k = s[off..].find('-').ok_or(ESYNTAX)? + off;
+    let iy = year(&s[..k])?;
+    let rest = &s[k + 1..];
+
+    if let Some(w) = rest.strip_prefix('W') {
+        /* Week date, YYYY-Www or YYYY-Www-D. */
+        let (ww, wd) = match w.split_once('-') {
+            Some((ww, wd)) => (digits(ww, 2)?, digits(wd, 1)?),
+            None => (digits(w, 2)?, 1),
+        };
+        if !(1..=7).contains(&wd) {
+            return Err(-3);
+        }
+        let mon = week1_monday(iy)?;
+        if ww < 1 || mon + 7 * ww as i64 > week1_monday(iy + 1)? {
+            return Err(-3);
+        }
+        mjd2cal(mon + 7 * (ww as i64 - 1) + (wd as i64 - 1))
+    } else if let Some((mm, dd)) = rest.split_once('-') {
+        /* Calendar date, YYYY-MM-DD. */
+        Ok((iy, digits(mm, 2)?, digits(dd, 2)?))
+    } else {
+        /* Ordinal date, YYYY-DDD. */
+        let ddd = digits(rest, 3)?;
+        let (_, m1) = cal2jd(iy, 1, 1)?;
+        let (jy, jm, jd) = mjd2cal(m1 as i64 + ddd as i64 - 1)?;
+        if ddd < 1 || jy != iy {
+            return Err(-3);
+        }
+        Ok((jy, jm, jd))
+    }
+}
+
+/* Time part, hh:mm[:ss[.fff]], to (ihr, imn, sec). */
+fn parse_time(s: &str) -> Result<(i32, i32, f64), i32> {
+    let mut f = s.splitn(3, ':');
+    let ihr = digits(f.next().ok_or(ESYNTAX)?, 2)?;
+    let imn = digits(f.next().ok_or(ESYNTAX)?, 2)?;
+    let sec = match f.next() {
+        None => 0.0,
+        Some(ss) => {
+            let ss = ss.replace(',', ".");
+            let (w, fr) = ss.split_once('.').unwrap_or((&ss, "0"));
+            digits(w, 2)?;
+            if fr.is_empty() || !fr.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(ESYNTAX);
+            }
+            ss.parse().map_err(|_| ESYNTAX)?
+        }
+    };
+    Ok((ihr, imn, sec))
+}
+
+/* Zone designator, "Z" or +hh:mm / -hh:mm / +hh, to minutes east. */
+fn parse_zone(s: &str) -> Result<i32, i32> {
+    if s == "Z" {
+        return Ok(0);
+    }
+    let sign = match s.as_bytes().first() {
+        Some(b'+') => 1,
+        Some(b'-') => -1,
+        _ => return Err(ESYNTAX),
+    };
+    let (h, m) = match s[1..].split_once(':') {
+        Some((h, m)) => (digits(h, 2)?, digits(m, 2)?),
+        None => (digits(&s[1..], 2)?, 0),
+    };
+    if h > 23 || m > 59 {
+        return Err(ESYNTAX);
+    }
+    Ok(sign * (60 * h + m))
+}
+
+impl Epoch {
+    pub fn new(scale: TimeScale, jd: Jd) -> Self {
+        Epoch { scale, jd }
+    }
+
+    /// Epoch from calendar date and time of day (see `dtf2d`).
+    ///
+    /// Unlike `dtf2d`, a seconds field beyond the end of the minute is
+    /// an error, Err(-6), rather than a warning; in UTC 23:59:60.x is
+    /// accepted on a day that ends with a leap second.
+    pub fn from_calendar(scale: TimeScale, iy: i32, im: i32, id: i32,
+                         ihr: i32, imn: i32, sec: f64) -> Result<Self, i32> {
+        let leap = scale == TimeScale::UTC && ihr == 23 && imn == 59;
+        if sec >= 60.0 && !leap {
+            return Err(-6);
+        }
+        let (d1, d2) = dtf2d(scale.name(), iy, im, id, ihr, imn, sec)?;
+        if d2 >= 1.0 {
+            return Err(-6);
+        }
+        Ok(Epoch { scale, jd: Jd(d1, d2) })
+    }
+
+    /// Calendar date and time of day, to ndp decimal places in the
+    /// seconds (see `d2dtf`): (iy, im, id, [h, m, s, fraction]).  As
+    /// in SOFA, a UTC before 1960 is formatted with TAI-UTC taken as
+    /// zero rather than rejected.
+    pub fn to_calendar(&self, ndp: i32) -> Result<(i32, i32, i32, [i32; 4]), i32> {
+        d2dtf_status(self.scale.name(), ndp, self.jd.0, self.jd.1).map(|(dt, _)| dt)
+    }
+
+    ///  Epoch from an ISO 8601 date and time in a given scale.
+    ///
+    ///  Accepted forms (extended format):
+    ///  ```text
+    ///     date   YYYY-MM-DD | YYYY-DDD | YYYY-Www-D | YYYY-Www
+    ///     time   hh:mm | hh:mm:ss | hh:mm:ss.fff  (',' also accepted)
+    ///     zone   Z | +hh:mm | -hh:mm | +hh | -hh  (UTC only)
+    ///  ```
+    ///  The date may stand alone (0h) or be followed by 'T' or a space
+    ///  and the time, then the zone.  Years outside 0000-9999 carry a
+    ///  sign.  24:00 (or 24:00:00) is the end of the day.
+    ///
+    ///  Status values are those of `dtf2d` (-1 to -6) plus -7 for a
+    ///  string that cannot be parsed.
+    pub fn from_iso(s: &str, scale: TimeScale) -> Result<Self, i32> {
+        let s = s.trim();
+        let (date, time) = match s.find(['T', ' ']) {
+            Some(k) => (&s[..k], Some(&s[k + 1..])),
+            None => (s, None),
+        };
+        let (iy, im, id) = parse_date(date)?;
+
+        let (mut ihr, mut imn, sec, zone) = match time {
+            None => (0, 0, 0.0, 0),
+            Some(t) => {
+                /* Split off the zone designator, if any. */
+                let (t, z) = match t.find(['Z', '+', '-']) {
+                    Some(k) => (&t[..k], Some(&t[k..])),
+                    None => (t, None),
+                };
+                let zone = match z {
+                    Some(z) if scale == TimeScale::UTC => parse_zone(z)?,
+                    Some(_) => return Err(ESYNTAX),
+                    None => 0,
+                };
+                let (h, m, sec) = parse_time(t)?;
+                (h, m, sec, zone)
+            }
+        };
+
+        /* End of day. */
+        let mut carry = 0;
+        if ihr == 24 {
+            if imn != 0 || sec != 0.0 {
+                return Err(-4);
+            }
+            ihr = 0;
+            carry = 1;
+        }
+
+        /* Reject out of range fields before they are moved by the zone. */
+        if !(0..=23).contains(&ihr) {
+            return Err(-4);
+        }
+        if !(0..=59).contains(&imn) {
+            return Err(-5);
+        }
+
+        /* Apply day carry and zone offset to the date, hour and minute. */
+        let (mut iy, mut im, mut id) = (iy, im, id);
+        if carry != 0 || zone != 0 {
+            let m = 60 * ihr + imn - zone;
+            let (_, mjd) = cal2jd(iy, im, id)?;
+            (iy, im, id) = mjd2cal(mjd as i64 + carry + m.div_euclid(1440) as i64)?;
+            ihr = m.rem_euclid(1440) / 60;
+            imn = m.rem_euclid(60);
+        }
+
+        Epoch::from_calendar(scale, iy, im, id, ihr, imn, sec)
+    }
+
+    /// Epoch from an ISO 8601 string carrying its scale, either as a
+    /// zone designator (UTC) or as a trailing scale ID separated by a
+    /// space, e.g. "2024-03-01T12:00:00 TT".  Without either, UTC.
+    pub fn parse(s: &str) -> Result<Self, i32> {
+        let s = s.trim();
+        if let Some((t, id)) = s.rsplit_once(' ')
+            && let Some(scale) = TimeScale::from_name(id)
+        {
+            return Epoch::from_iso(t, scale);
+        }
+        Epoch::from_iso(s, TimeScale::UTC)
+    }
+
+    ///  ISO 8601 calendar date and time, to ndp decimal places in the
+    ///  seconds (ndp <= 0 gives whole seconds, rounded).
+    ///
+    ///  UTC is written with the 'Z' designator and shows a leap second
+    ///  as 23:59:60.  Other scales carry no designator; `Display` adds
+    ///  the scale ID.
+    pub fn to_iso(&self, ndp: i32) -> Result<String, i32> {
+        let ndp = ndp.max(0);
+        let (iy, im, id, ihmsf) = self.to_calendar(ndp)?;
+        let mut s = if (0..=9999).contains(&iy) {
+            format!("{:04}-{:02}-{:02}", iy, im, id)
+        } else {
+            format!("{:+05}-{:02}-{:02}", iy, im, id)
+        };
+        s += &format!("T{:02}:{:02}:{:02}", ihmsf[0], ihmsf[1], ihmsf[2]);
+        if ndp > 0 {
+            s += &format!(".{:0width$}", ihmsf[3], width = ndp as usize);
+        }
+        if self.scale == TimeScale::UTC {
+            s.push('Z');
+        }
+        Ok(s)
+    }
+
+    /// The same instant in another scale.
+    ///
+    /// Conversions among UTC, TAI, TT, TDB, TCG and TCB are supported,
+    /// TDB-TT being the geocentric value from `dtdb`;  for an observer
+    /// on the Earth's surface see `to_topocentric`.  UT1 needs UT1-UTC,
+    /// which an Epoch does not carry (see `to_ut1`), and gives Err(-9).
+    pub fn to(&self, scale: TimeScale) -> Result<Self, i32> {
+        self.convert(scale, |t1, t2| dtdb(t1, t2, 0.0, 0.0, 0.0, 0.0))
+    }
+
+    ///  The same instant in another scale, with TDB-TT evaluated for an
+    ///  observer at the given site rather than at the geocenter.
+    ///
+    ///  The topocentric terms of `dtdb` reach about 2 microseconds; they
+    ///  matter only for TDB and TCB.  The fraction of the day used as
+    ///  UT1 is that of TT (or TDB), which is adequate for these terms.
+    pub fn to_topocentric(&self, scale: TimeScale, site: &Site) -> Result<Self, i32> {
+        /* Distances from the Earth's spin axis and equatorial plane (km). */
+        let mut xyz = [0.0; 3];
+        gd2gc(1, site.elong, site.phi, site.hm, &mut xyz)?;
+        let u = hypot(xyz[0], xyz[1]) / 1e3;
+        let v = xyz[2] / 1e3;
+
+        self.convert(scale, |t1, t2| {
+            let ut = (t1.fract() + t2.fract() + 0.5).rem_euclid(1.0);
+            dtdb(t1, t2, ut, site.elong, u, v)
+        })
+    }
+
+    /* Conversion with TDB-TT (s) supplied by dtr(jd1, jd2). */
+    fn convert(&self, scale: TimeScale, dtr: impl Fn(f64, f64) -> f64) -> Result<Self, i32> {
+        use TimeScale::*;
+        let (d1, d2) = self.jd.into();
+        let jd = match (self.scale, scale) {
+            (a, b) if a == b => (d1, d2),
+            (UTC, TAI) => utctai(d1, d2)?,
+            (TAI, UTC) => taiutc(d1, d2)?,
+            (TAI, TT) => taitt(d1, d2)?,
+            (TT, TAI) => tttai(d1, d2)?,
+            (UTC, TT) => {
+                let (a1, a2) = utctai(d1, d2)?;
+                taitt(a1, a2)?
+            }
+            (TT, UTC) => {
+                let (a1, a2) = tttai(d1, d2)?;
+                taiutc(a1, a2)?
+            }
+            (UT1, _) | (_, UT1) => return Err(ESCALE),
+            _ => {
+                /* Everything else goes by way of TT. */
+                let (t1, t2) = match self.scale {
+                    TT => (d1, d2),
+                    UTC | TAI => self.to(TT)?.jd.into(),
+                    TCG => tcgtt(d1, d2)?,
+                    TDB => tdbtt(d1, d2, dtr(d1, d2))?,
+                    _ => {
+                        let (b1, b2) = tcbtdb(d1, d2)?;
+                        tdbtt(b1, b2, dtr(b1, b2))?
+                    }
+                };
+                match scale {
+                    TT => (t1, t2),
+                    UTC | TAI => Epoch { scale: TT, jd: (t1, t2).into() }.to(scale)?.jd.into(),
+                    TCG => tttcg(t1, t2)?,
+                    TDB => tttdb(t1, t2, dtr(t1, t2))?,
+                    _ => {
+                        let (b1, b2) = tttdb(t1, t2, dtr(t1, t2))?;
+                        tdbtcb(b1, b2)?
+                    }
+                }
+            }
+        };
+        Ok(Epoch { scale, jd: jd.into() })
+    }
+}
+
+impl Epoch {
+    /// UT1, given UT1-UTC (seconds) from the IERS.
+    ///
+    /// The epoch may be in any scale accepted by `to` for UTC.
+    pub fn to_ut1(&self, dut1: f64) -> Result<Self, i32> {
+        if self.scale == TimeScale::UT1 {
+            return Ok(*self);
+        }
+        let (u1, u2) = self.to(TimeScale::UTC)?.jd.into();
+        let ut1 = utcut1(u1, u2, dut1)?;
+        Ok(Epoch { scale: TimeScale::UT1, jd: ut1.into() })
+    }
+
+    ///  Conversion as `to`, but allowing UT1 on either side by way of
+    ///  the APPROXIMATE Delta T model (`deltat_approx`).
+    ///
+    ///  This is the fallback for dates with no IERS data, including
+    ///  those before 1960 when UTC is undefined (give such epochs in TT
+    ///  or UT1).  The result may be in error by seconds for recent
+    ///  dates and by much more in the distant past;  when UT1-UTC is
+    ///  known, use `to_ut1` instead.
+    pub fn to_approx(&self, scale: TimeScale) -> Result<Self, i32> {
+        use TimeScale::*;
+        match (self.scale, scale) {
+            (a, b) if a == b => Ok(*self),
+            (UT1, _) => {
+                let (u1, u2) = self.jd.into();
+                let tt = Epoch { scale: TT, jd: (u1, u2 + deltat_days(u1, u2)).into() };
+                tt.to(scale)
+            }
+            (_, UT1) => {
+                let (t1, t2) = self.to(TT)?.jd.into();
+                Ok(Epoch { scale: UT1, jd: (t1, t2 - deltat_days(t1, t2)).into() })
+            }
+            _ => self.to(scale),
+        }
+    }
+}
+
+/// ISO 8601 to the millisecond, with the scale ID for non-UTC scales.
+impl fmt::Display for Epoch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = self.to_iso(3).map_err(|_| fmt::Error)?;
+        if self.scale == TimeScale::UTC {
+            f.write_str(&s)
+        } else {
+            write!(f, "{} {}", s, self.scale)
+        }
+    }
+}
+
+impl FromStr for Epoch {
+    type Err = i32;
+
+    fn from_str(s: &str) -> Result<Self, i32> {
+        Epoch::parse(s)
+    }
+}
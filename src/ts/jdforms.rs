@@ -0,0 +1,33 @@
+use crate::cal::{jd_forms, Jd};
+
+use super::*;
+
+jd_forms! {
+    date taitt_jd = taitt();
+    date taiut1_jd = taiut1(dta: f64);
+    date taiutc_jd = taiutc();
+    date tcbtdb_jd = tcbtdb();
+    date tcgtt_jd = tcgtt();
+    date tdbtcb_jd = tdbtcb();
+    date tdbtt_jd = tdbtt(dtr: f64);
+    date tttai_jd = tttai();
+    date tttcg_jd = tttcg();
+    date tttdb_jd = tttdb(dtr: f64);
+    date utctai_jd = utctai();
+    date utcut1_jd = utcut1(dut1: f64);
+    value dtdb_jd = dtdb(ut: f64, elong: f64, u: f64, v: f64) -> f64;
+    value deltat_approx_jd = deltat_approx() -> f64;
+}
+
+/// As `d2dtf`, with the date as a `Jd` (or a (date1, date2) pair).
+pub fn d2dtf_jd(scale: &str, ndp: i32, date: impl Into<Jd>)
+                -> Result<(i32, i32, i32, [i32; 4]), i32> {
+    let Jd(d1, d2) = date.into();
+    d2dtf(scale, ndp, d1, d2)
+}
+
+/// As `dtf2d`, returning the date as a `Jd`.
+pub fn dtf2d_jd(scale: &str, iy: i32, im: i32, id: i32, ihr: i32, imn: i32, sec: f64)
+                -> Result<Jd, i32> {
+    dtf2d(scale, iy, im, id, ihr, imn, sec).map(Jd::from)
+}
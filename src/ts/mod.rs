@@ -17,3 +17,42 @@ pub use utcut1::*;
 
 mod taiut1;
 pub use taiut1::*;
+
+mod taiutc;
+pub use taiutc::*;
+
+mod tttai;
+pub use tttai::*;
+
+mod d2dtf;
+pub use d2dtf::*;
+
+mod dtdb;
+pub use dtdb::*;
+
+mod tttdb;
+pub use tttdb::*;
+
+mod tdbtt;
+pub use tdbtt::*;
+
+mod tttcg;
+pub use tttcg::*;
+
+mod tcgtt;
+pub use tcgtt::*;
+
+mod tdbtcb;
+pub use tdbtcb::*;
+
+mod tcbtdb;
+pub use tcbtdb::*;
+
+mod deltat;
+pub use deltat::*;
+
+mod epoch;
+pub use epoch::*;
+
+mod jdforms;
+pub use jdforms::*;
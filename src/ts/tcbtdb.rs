@@ -0,0 +1,55 @@
+use crate::consts::{DAYSEC, DJM0, DJM77, ELB, TDB0, TTMTAI};
+
+///  Time scale transformation:  Barycentric Coordinate Time, TCB, to
+///  Barycentric Dynamical Time, TDB.
+///
+///  This function is part of the International Astronomical Union's
+///  SOFA (Standards of Fundamental Astronomy) software collection.
+///
+///  Status:  canonical.
+///
+///  Given:
+///     tcb1,tcb2  double    TCB as a 2-part Julian Date
+///
+///  Returned:
+///     tdb1,tdb2  double    TDB as a 2-part Julian Date
+///
+///  Returned (function value):
+///                int       status:  0 = OK
+///
+///  Notes:
+///
+///  1) tcb1+tcb2 is Julian Date, apportioned in any convenient way
+///     between the two arguments, for example where tcb1 is the Julian
+///     Day Number and tcb2 is the fraction of a day.  The returned
+///     tdb1,tdb2 follow suit.
+///
+///  2) The 2006 IAU General Assembly introduced a conventional linear
+///     transformation between TDB and TCB (see `tdbtcb`).
+///
+///  3) TDB is essentially the same as Teph, the time argument for the
+///     JPL solar system ephemerides.
+///
+///  Reference:
+///
+///     IAU 2006 Resolution B3
+pub fn tcbtdb(tcb1: f64, tcb2: f64) -> Result<(f64, f64), i32> {
+    // 1977 Jan 1 00:00:32.184 TT, as two-part JD
+    const T77TD: f64 = DJM0 + DJM77;
+    const T77TF: f64 = TTMTAI / DAYSEC;
+
+    // TDB (days) at TAI 1977 Jan 1.0
+    const TDB0D: f64 = TDB0 / DAYSEC;
+
+    // Result, safeguarding precision.
+    let (tdb1, tdb2) = if tcb1.abs() > tcb2.abs() {
+        let d = tcb1 - T77TD;
+        (tcb1, tcb2 + TDB0D - (d + (tcb2 - T77TF)) * ELB)
+    } else {
+        let d = tcb2 - T77TD;
+        (tcb1 + TDB0D - (d + (tcb1 - T77TF)) * ELB, tcb2)
+    };
+
+    // Status (always OK).
+    Ok((tdb1, tdb2))
+}
@@ -0,0 +1,46 @@
+use crate::consts::{DAYSEC, DJM0, DJM77, ELG, TTMTAI};
+
+///  Time scale transformation:  Geocentric Coordinate Time, TCG, to
+///  Terrestrial Time, TT.
+///
+///  This function is part of the International Astronomical Union's
+///  SOFA (Standards of Fundamental Astronomy) software collection.
+///
+///  Status:  canonical.
+///
+///  Given:
+///     tcg1,tcg2  double    TCG as a 2-part Julian Date
+///
+///  Returned:
+///     tt1,tt2    double    TT as a 2-part Julian Date
+///
+///  Returned (function value):
+///                int       status:  0 = OK
+///
+///  Note:
+///
+///     tcg1+tcg2 is Julian Date, apportioned in any convenient way
+///     between the two arguments, for example where tcg1 is the Julian
+///     Day Number and tcg2 is the fraction of a day.  The returned
+///     tt1,tt2 follow suit.
+///
+///  References:
+///
+///     McCarthy, D. D., Petit, G. (eds.), IERS Conventions (2003),
+///     IERS Technical Note No. 32, BKG (2004)
+///
+///     IAU 2000 Resolution B1.9
+pub fn tcgtt(tcg1: f64, tcg2: f64) -> Result<(f64, f64), i32> {
+    // 1977 Jan 1 00:00:32.184 TT, as MJD
+    const T77T: f64 = DJM77 + TTMTAI / DAYSEC;
+
+    // Result, safeguarding precision.
+    let (tt1, tt2) = if tcg1.abs() > tcg2.abs() {
+        (tcg1, tcg2 - ((tcg1 - DJM0) + (tcg2 - T77T)) * ELG)
+    } else {
+        (tcg1 - ((tcg2 - DJM0) + (tcg1 - T77T)) * ELG, tcg2)
+    };
+
+    // Status (always OK).
+    Ok((tt1, tt2))
+}
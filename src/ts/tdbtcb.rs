@@ -0,0 +1,72 @@
+use crate::consts::{DAYSEC, DJM0, DJM77, ELB, TDB0, TTMTAI};
+
+///  Time scale transformation:  Barycentric Dynamical Time, TDB, to
+///  Barycentric Coordinate Time, TCB.
+///
+///  This function is part of the International Astronomical Union's
+///  SOFA (Standards of Fundamental Astronomy) software collection.
+///
+///  Status:  canonical.
+///
+///  Given:
+///     tdb1,tdb2  double    TDB as a 2-part Julian Date
+///
+///  Returned:
+///     tcb1,tcb2  double    TCB as a 2-part Julian Date
+///
+///  Returned (function value):
+///                int       status:  0 = OK
+///
+///  Notes:
+///
+///  1) tdb1+tdb2 is Julian Date, apportioned in any convenient way
+///     between the two arguments, for example where tdb1 is the Julian
+///     Day Number and tdb2 is the fraction of a day.  The returned
+///     tcb1,tcb2 follow suit.
+///
+///  2) The 2006 IAU General Assembly introduced a conventional linear
+///     transformation between TDB and TCB.  This transformation
+///     compensates for the drift between TCB and terrestrial time TT,
+///     and keeps TDB approximately centered on TT.  Because the
+///     relationship between TT and TCB depends on the adopted solar
+///     system ephemeris, the degree of alignment between TDB and TT over
+///     long intervals will vary according to which ephemeris is used.
+///     Former definitions of TDB attempted to avoid this problem by
+///     stipulating that TDB and TT should differ only by periodic
+///     effects.  This is a good description of the nature of the
+///     relationship but eluded precise mathematical formulation.  The
+///     conventional linear relationship adopted in 2006 sidestepped
+///     these difficulties whilst delivering a TDB that in practice was
+///     consistent with values before that date.
+///
+///  3) TDB is essentially the same as Teph, the time argument for the
+///     JPL solar system ephemerides.
+///
+///  Reference:
+///
+///     IAU 2006 Resolution B3
+pub fn tdbtcb(tdb1: f64, tdb2: f64) -> Result<(f64, f64), i32> {
+    // 1977 Jan 1 00:00:32.184 TT, as two-part JD
+    const T77TD: f64 = DJM0 + DJM77;
+    const T77TF: f64 = TTMTAI / DAYSEC;
+
+    // TDB (days) at TAI 1977 Jan 1.0
+    const TDB0D: f64 = TDB0 / DAYSEC;
+
+    // TDB to TCB rate
+    const ELBB: f64 = ELB / (1.0 - ELB);
+
+    // Result, preserving date format but safeguarding precision.
+    let (tcb1, tcb2) = if tdb1.abs() > tdb2.abs() {
+        let d = T77TD - tdb1;
+        let f = tdb2 - TDB0D;
+        (tdb1, f - (d - (f - T77TF)) * ELBB)
+    } else {
+        let d = T77TD - tdb2;
+        let f = tdb1 - TDB0D;
+        (f - (d - (f - T77TF)) * ELBB, tdb2)
+    };
+
+    // Status (always OK).
+    Ok((tcb1, tcb2))
+}
@@ -0,0 +1,53 @@
+use crate::consts::DAYSEC;
+
+///  Time scale transformation:  Barycentric Dynamical Time, TDB, to
+///  Terrestrial Time, TT.
+///
+///  This function is part of the International Astronomical Union's
+///  SOFA (Standards of Fundamental Astronomy) software collection.
+///
+///  Status:  canonical.
+///
+///  Given:
+///     tdb1,tdb2  double    TDB as a 2-part Julian Date
+///     dtr        double    TDB-TT in seconds
+///
+///  Returned:
+///     tt1,tt2    double    TT as a 2-part Julian Date
+///
+///  Returned (function value):
+///                int       status:  0 = OK
+///
+///  Notes:
+///
+///  1) tdb1+tdb2 is Julian Date, apportioned in any convenient way
+///     between the two arguments, for example where tdb1 is the Julian
+///     Day Number and tdb2 is the fraction of a day.  The returned
+///     tt1,tt2 follow suit.
+///
+///  2) The argument dtr represents the quasi-periodic component of the
+///     GR transformation between TT and TCB.  It is dependent upon the
+///     adopted solar-system ephemeris, and can be obtained by numerical
+///     integration, by interrogating a precomputed time ephemeris or by
+///     evaluating a model such as that implemented in the SOFA function
+///     dtdb.   The quantity is dominated by an annual term of 1.7 ms
+///     amplitude.
+///
+///  3) TDB is essentially the same as Teph, the time argument for the
+///     JPL solar system ephemerides.
+///
+///  References:
+///
+///     IAU 2006 Resolution 3
+pub fn tdbtt(tdb1: f64, tdb2: f64, dtr: f64) -> Result<(f64, f64), i32> {
+    // Result, safeguarding precision.
+    let dtrd = dtr / DAYSEC;
+    let (tt1, tt2) = if tdb1.abs() > tdb2.abs() {
+        (tdb1, tdb2 - dtrd)
+    } else {
+        (tdb1 - dtrd, tdb2)
+    };
+
+    // Status (always OK).
+    Ok((tt1, tt2))
+}
@@ -0,0 +1,47 @@
+use crate::consts::{DAYSEC, TTMTAI};
+
+///  Time scale transformation:  Terrestrial Time, TT, to International
+///  Atomic Time, TAI.
+///
+///  This function is part of the International Astronomical Union's
+///  SOFA (Standards of Fundamental Astronomy) software collection.
+///
+///  Status:  canonical.
+///
+///  Given:
+///     tt1,tt2    double    TT as a 2-part Julian Date
+///
+///  Returned:
+///     tai1,tai2  double    TAI as a 2-part Julian Date
+///
+///  Returned (function value):
+///                int       status:  0 = OK
+///
+///  Note:
+///
+///     tt1+tt2 is Julian Date, apportioned in any convenient way between
+///     the two arguments, for example where tt1 is the Julian Day Number
+///     and tt2 is the fraction of a day.  The returned tai1,tai2 follow
+///     suit.
+///
+///  References:
+///
+///     McCarthy, D. D., Petit, G. (eds.), IERS Conventions (2003),
+///     IERS Technical Note No. 32, BKG (2004)
+///
+///     Explanatory Supplement to the Astronomical Almanac,
+///     P. Kenneth Seidelmann (ed), University Science Books (1992)
+pub fn tttai(tt1: f64, tt2: f64) -> Result<(f64, f64), i32> {
+    // TT minus TAI (days).
+    const DTAT: f64 = TTMTAI / DAYSEC;
+
+    // Result, safeguarding precision.
+    let (tai1, tai2) = if tt1.abs() > tt2.abs() {
+        (tt1, tt2 - DTAT)
+    } else {
+        (tt1 - DTAT, tt2)
+    };
+
+    // Status (always OK).
+    Ok((tai1, tai2))
+}
@@ -0,0 +1,49 @@
+use crate::consts::{DAYSEC, DJM0, DJM77, ELG, TTMTAI};
+
+///  Time scale transformation:  Terrestrial Time, TT, to Geocentric
+///  Coordinate Time, TCG.
+///
+///  This function is part of the International Astronomical Union's
+///  SOFA (Standards of Fundamental Astronomy) software collection.
+///
+///  Status:  canonical.
+///
+///  Given:
+///     tt1,tt2    double    TT as a 2-part Julian Date
+///
+///  Returned:
+///     tcg1,tcg2  double    TCG as a 2-part Julian Date
+///
+///  Returned (function value):
+///                int       status:  0 = OK
+///
+///  Note:
+///
+///     tt1+tt2 is Julian Date, apportioned in any convenient way between
+///     the two arguments, for example where tt1 is the Julian Day Number
+///     and tt2 is the fraction of a day.  The returned tcg1,tcg2 follow
+///     suit.
+///
+///  References:
+///
+///     McCarthy, D. D., Petit, G. (eds.), IERS Conventions (2003),
+///     IERS Technical Note No. 32, BKG (2004)
+///
+///     IAU 2000 Resolution B1.9
+pub fn tttcg(tt1: f64, tt2: f64) -> Result<(f64, f64), i32> {
+    // 1977 Jan 1 00:00:32.184 TT, as MJD
+    const T77T: f64 = DJM77 + TTMTAI / DAYSEC;
+
+    // TT to TCG rate
+    const ELGG: f64 = ELG / (1.0 - ELG);
+
+    // Result, safeguarding precision.
+    let (tcg1, tcg2) = if tt1.abs() > tt2.abs() {
+        (tt1, tt2 + ((tt1 - DJM0) + (tt2 - T77T)) * ELGG)
+    } else {
+        (tt1 + ((tt2 - DJM0) + (tt1 - T77T)) * ELGG, tt2)
+    };
+
+    // Status (always OK).
+    Ok((tcg1, tcg2))
+}
@@ -67,6 +67,15 @@ use crate::consts::DAYSEC;
 ///
 ///  Copyright (C) 2023 IAU SOFA Board.  See notes at end.
 pub fn utctai(utc1: f64, utc2: f64) -> Result<(f64, f64), i32> {
+    match utctai_status(utc1, utc2)? {
+        (tai, 0) => Ok(tai),
+        (_, j) => Err(j),
+    }
+}
+
+/* As utctai, with the warning status of the C function (+1 for a
+   dubious year) returned together with the TAI. */
+pub(crate) fn utctai_status(utc1: f64, utc2: f64) -> Result<((f64, f64), i32), i32> {
     let big1: bool;
     let (iy, im, id, iyt, imt, idt): (i32, i32, i32, i32, i32, i32);
     let (u1, u2, mut fd, dat0, dat12, dat24, dlod, dleap, z1, z2, mut a2): (
@@ -103,16 +112,10 @@ pub fn utctai(utc1: f64, utc2: f64) -> Result<(f64, f64), i32> {
         }
         Err(j) => return Err(j),
     }
-    match super::dat(iy, im, id, 0.0) {
-        Ok(d) => dat0 = d,
-        Err(j) => return Err(j),
-    }
+    (dat0, _) = super::dat_status(iy, im, id, 0.0)?;
 
     // Get TAI-UTC at 12h today (to detect drift).
-    match super::dat(iy, im, id, 0.5) {
-        Ok(d) => dat12 = d,
-        Err(j) => return Err(j),
-    }
+    (dat12, _) = super::dat_status(iy, im, id, 0.5)?;
 
     // Get TAI-UTC at 0h tomorrow (to detect jumps).
     let j = jd2cal(u1 + 1.5, u2 - fd);
@@ -125,10 +128,8 @@ pub fn utctai(utc1: f64, utc2: f64) -> Result<(f64, f64), i32> {
         }
     }
 
-    match super::dat(iyt, imt, idt, 0.0) {
-        Ok(d) => dat24 = d,
-        Err(j) => return Err(j),
-    }
+    let j;
+    (dat24, j) = super::dat_status(iyt, imt, idt, 0.0)?;
 
     // Separate TAI-UTC change into per-day (DLOD) and any jump (DLEAP).
     dlod = 2.0 * (dat12 - dat0);
@@ -154,8 +155,8 @@ pub fn utctai(utc1: f64, utc2: f64) -> Result<(f64, f64), i32> {
     a2 += z2;
     a2 += fd + dat0 / DAYSEC;
     if big1 {
-        Ok((u1, a2))
+        Ok(((u1, a2), j))
     } else {
-        Ok((a2, u1))
+        Ok(((a2, u1), j))
     }
 }
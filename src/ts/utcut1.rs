@@ -1,22 +1,36 @@
 use crate::cal::jd2cal;
-use crate::ts::{utctai, taiut1, dat};
+use crate::ts::{utctai_status, taiut1, dat_status};
 
 pub fn utcut1(utc1: f64, utc2: f64, dut1: f64) -> Result<(f64, f64), i32> {
-    let (iy, im, id, w) = match jd2cal(utc1, utc2) {
+    match utcut1_status(utc1, utc2, dut1)? {
+        (ut1, 0) => Ok(ut1),
+        (_, j) => Err(j),
+    }
+}
+
+/* As utcut1, with the warning status of the C function (+1 for a
+   dubious year) returned together with the UT1. */
+pub(crate) fn utcut1_status(utc1: f64, utc2: f64, dut1: f64) -> Result<((f64, f64), i32), i32> {
+    let (iy, im, id, _) = match jd2cal(utc1, utc2) {
         Ok((iy, im, id, fd)) => (iy, im, id, fd),
         Err(_) => return Err(-1),
     };
 
-    let dat = match dat(iy, im, id, 0.0) {
-        Ok(dat) => dat,
+    let (dat, mut js) = match dat_status(iy, im, id, 0.0) {
+        Ok(v) => v,
         Err(_) => return Err(-1),
     };
 
     let dta = dut1 - dat;
 
-    let (tai1, tai2) = match utctai(utc1, utc2) {
-        Ok((tai1, tai2)) => (tai1, tai2),
-        Err(js) => return Err(js),
+    let (tai1, tai2) = match utctai_status(utc1, utc2) {
+        Ok((tai, jw)) => {
+            if jw > 0 {
+                js = jw;
+            }
+            tai
+        }
+        Err(_) => return Err(-1),
     };
 
     let (ut11, ut12) = match taiut1(tai1, tai2, dta) {
@@ -24,5 +38,5 @@ pub fn utcut1(utc1: f64, utc2: f64, dut1: f64) -> Result<(f64, f64), i32> {
         Err(_) => return Err(-1),
     };
 
-    Ok((ut11, ut12))
-}
\ No newline at end of file
+    Ok(((ut11, ut12), js))
+}
@@ -0,0 +1,101 @@
+//! Unit conversions on f64
+//!
+//! The library works in radians and days.  With `Units` in scope an
+//! angle or interval in other units converts where it is written,
+//! instead of by multiplying by DD2R, DAS2R and so on:
+//! ```
+//! use sofars::units::Units;
+//!
+//! let dec = -(12.0 + 31.0 / 60.0).deg();
+//! let pmdec = 595.35.mas();
+//! let dt = 6.0.hours() + 30.0.minutes();
+//! # assert!((dt - 0.2708333333333333).abs() < 1e-15);
+//! # let _ = (dec, pmdec);
+//! ```
+//! Right ascensions and hour angles in hours are `hourangle`;  `hours`
+//! is an interval of time.
+use crate::consts::{DAS2R, DAYSEC, DD2R, DMAS2R, DR2AS, DR2D};
+
+///  Conversions of f64 to radians and days, and back
+pub trait Units {
+    /// Degrees to radians.
+    fn deg(self) -> f64;
+    /// Arcminutes to radians.
+    fn arcmin(self) -> f64;
+    /// Arcseconds to radians.
+    fn arcsec(self) -> f64;
+    /// Milliarcseconds to radians.
+    fn mas(self) -> f64;
+    /// Microarcseconds to radians.
+    fn uas(self) -> f64;
+    /// Hours of angle (right ascension, hour angle) to radians.
+    fn hourangle(self) -> f64;
+    /// Hours of time to days.
+    fn hours(self) -> f64;
+    /// Minutes of time to days.
+    fn minutes(self) -> f64;
+    /// Seconds of time to days.
+    fn seconds(self) -> f64;
+    /// Radians to degrees.
+    fn to_deg(self) -> f64;
+    /// Radians to arcseconds.
+    fn to_arcsec(self) -> f64;
+    /// Radians to hours of angle.
+    fn to_hourangle(self) -> f64;
+    /// Days to seconds of time.
+    fn to_seconds(self) -> f64;
+}
+
+impl Units for f64 {
+    fn deg(self) -> f64 {
+        self * DD2R
+    }
+
+    fn arcmin(self) -> f64 {
+        self * DD2R / 60.0
+    }
+
+    fn arcsec(self) -> f64 {
+        self * DAS2R
+    }
+
+    fn mas(self) -> f64 {
+        self * DMAS2R
+    }
+
+    fn uas(self) -> f64 {
+        self * DMAS2R * 1e-3
+    }
+
+    fn hourangle(self) -> f64 {
+        self * 15.0 * DD2R
+    }
+
+    fn hours(self) -> f64 {
+        self / 24.0
+    }
+
+    fn minutes(self) -> f64 {
+        self / 1440.0
+    }
+
+    fn seconds(self) -> f64 {
+        self / DAYSEC
+    }
+
+    fn to_deg(self) -> f64 {
+        self * DR2D
+    }
+
+    fn to_arcsec(self) -> f64 {
+        self * DR2AS
+    }
+
+    fn to_hourangle(self) -> f64 {
+        self * DR2D / 15.0
+    }
+
+    fn to_seconds(self) -> f64 {
+        self * DAYSEC
+    }
+}
@@ -0,0 +1,159 @@
+//! Checked variants of unvalidated functions
+//!
+//! Several SOFA functions omit validation for efficiency ("the supplied
+//! vectors must be unit vectors", "outlandish input parameters are
+//! silently limited").  The `_checked` functions here verify their
+//! arguments first and return an `InputError` describing the first
+//! offending one, then call the unchecked function unchanged:
+//! ```text
+//!     ld_checked       unit vectors, positive limiter and distance
+//!     ldsun_checked    unit vectors, positive distance
+//!     ab_checked       unit direction, |v| < 1, bm1 in (0,1], s > 0
+//!     refco_checked    physical pressure, temperature, humidity and
+//!                      wavelength (the ranges refco limits to)
+//!     epv00_checked    finite TDB date within the validity range
+//! ```
+//! The argument checks are public, for use ahead of other functions.
+use std::fmt;
+
+use crate::astro;
+use crate::eph;
+
+type Pv = [[f64; 3]; 2];
+
+/// Largest acceptable departure of a unit vector's modulus from 1.
+pub const UNIT_TOLERANCE: f64 = 1e-6;
+
+/// An input argument outside its permitted domain.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InputError {
+    /// NaN or infinite argument.
+    NotFinite { name: &'static str },
+    /// Vector whose modulus is not 1 (within `UNIT_TOLERANCE`).
+    NotUnit { name: &'static str, norm: f64 },
+    /// Argument that must be greater than zero.
+    NotPositive { name: &'static str, value: f64 },
+    /// Argument outside [min, max].
+    OutOfRange { name: &'static str, value: f64, min: f64, max: f64 },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            InputError::NotFinite { name } => write!(f, "{} is not finite", name),
+            InputError::NotUnit { name, norm } => {
+                write!(f, "{} is not a unit vector (modulus {})", name, norm)
+            }
+            InputError::NotPositive { name, value } => {
+                write!(f, "{} must be positive (got {})", name, value)
+            }
+            InputError::OutOfRange { name, value, min, max } => {
+                write!(f, "{} = {} is outside [{}, {}]", name, value, min, max)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Check that x is finite.
+pub fn finite(name: &'static str, x: f64) -> Result<f64, InputError> {
+    if x.is_finite() { Ok(x) } else { Err(InputError::NotFinite { name }) }
+}
+
+/// Check that x is finite and greater than zero.
+pub fn positive(name: &'static str, x: f64) -> Result<f64, InputError> {
+    if finite(name, x)? > 0.0 { Ok(x) } else { Err(InputError::NotPositive { name, value: x }) }
+}
+
+/// Check that x is finite and within [min, max].
+pub fn in_range(name: &'static str, x: f64, min: f64, max: f64) -> Result<f64, InputError> {
+    if (min..=max).contains(&finite(name, x)?) {
+        Ok(x)
+    } else {
+        Err(InputError::OutOfRange { name, value: x, min, max })
+    }
+}
+
+/// Check that v is finite with modulus 1 (within `UNIT_TOLERANCE`).
+pub fn unit_vector(name: &'static str, v: &[f64; 3]) -> Result<(), InputError> {
+    for &x in v {
+        finite(name, x)?;
+    }
+    let norm = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    if (norm - 1.0).abs() <= UNIT_TOLERANCE { Ok(()) } else { Err(InputError::NotUnit { name, norm }) }
+}
+
+/// Check that both parts of a two-part Julian Date are finite.
+pub fn finite_date(date1: f64, date2: f64) -> Result<(), InputError> {
+    finite("date1", date1)?;
+    finite("date2", date2)?;
+    Ok(())
+}
+
+/// `astro::ld` with validated arguments:  p, q and e unit vectors, bm
+/// finite, em and dlim positive.
+pub fn ld_checked(bm: f64, p: &[f64; 3], q: &[f64; 3], e: &[f64; 3],
+                  em: f64, dlim: f64) -> Result<[f64; 3], InputError> {
+    finite("bm", bm)?;
+    unit_vector("p", p)?;
+    unit_vector("q", q)?;
+    unit_vector("e", e)?;
+    positive("em", em)?;
+    positive("dlim", dlim)?;
+    Ok(astro::ld(bm, p, q, e, em, dlim))
+}
+
+/// `astro::ldsun` with validated arguments:  p and e unit vectors, em
+/// positive.
+pub fn ldsun_checked(p: &[f64; 3], e: &[f64; 3], em: f64) -> Result<[f64; 3], InputError> {
+    unit_vector("p", p)?;
+    unit_vector("e", e)?;
+    positive("em", em)?;
+    Ok(astro::ldsun(p, e, em))
+}
+
+/// `astro::ab` with validated arguments:  pnat a unit vector, |v| < 1
+/// (units of c), s positive and bm1 in (0,1].
+pub fn ab_checked(pnat: &[f64; 3], v: &[f64; 3], s: f64, bm1: f64) -> Result<[f64; 3], InputError> {
+    unit_vector("pnat", pnat)?;
+    for &x in v {
+        finite("v", x)?;
+    }
+    let vm = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    if vm >= 1.0 {
+        return Err(InputError::OutOfRange { name: "|v|", value: vm, min: 0.0, max: 1.0 });
+    }
+    positive("s", s)?;
+    positive("bm1", bm1)?;
+    in_range("bm1", bm1, 0.0, 1.0)?;
+    Ok(astro::ab(pnat, v, s, bm1))
+}
+
+/// `astro::refco` with validated arguments:  rather than being silently
+/// limited, values outside the ranges refco accepts are rejected.
+/// ```text
+///     phpa    0 to 10000 hPa
+///     tc      -150 to 200 C
+///     rh      0 to 1
+///     wl      0.1 to 1e6 micrometers
+/// ```
+pub fn refco_checked(phpa: f64, tc: f64, rh: f64, wl: f64) -> Result<(f64, f64), InputError> {
+    in_range("phpa", phpa, 0.0, 10000.0)?;
+    in_range("tc", tc, -150.0, 200.0)?;
+    in_range("rh", rh, 0.0, 1.0)?;
+    in_range("wl", wl, 0.1, 1e6)?;
+    Ok(astro::refco(phpa, tc, rh, wl))
+}
+
+/// `eph::epv00` for a finite TDB date, with the "date outside 1900-2100"
+/// warning reported as an error on the date.
+pub fn epv00_checked(date1: f64, date2: f64) -> Result<(Pv, Pv), InputError> {
+    finite_date(date1, date2)?;
+    eph::epv00(date1, date2).ok_or(InputError::OutOfRange {
+        name: "date",
+        value: date1 + date2,
+        min: 2415020.0,
+        max: 2488070.0,
+    })
+}
@@ -0,0 +1,287 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::{a2af, a2tf};
+use crate::consts::{DAS2R, DD2R, DR2D, DS2R};
+
+/// Unit of the leading sexagesimal field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AngleUnit {
+    /// hours, minutes, seconds of time
+    Hours,
+    /// degrees, arcminutes, arcseconds
+    Degrees,
+}
+
+///  Layout for sexagesimal output
+///
+///  ```text
+///     unit    AngleUnit   hours or degrees
+///     ndp     int         decimal places in the seconds (a2tf/a2af ndp)
+///     sep     [str;3]     text after the first, second and third fields
+///     plus    bool        write '+' for positive angles
+///     width   usize       minimum digits in the leading field
+///  ```
+///  The presets cover the usual forms; the fields are public so any
+///  other layout can be made with struct update syntax, e.g.
+///  `SexFormat { ndp: 1, ..SexFormat::DMS_COLON }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SexFormat {
+    pub unit: AngleUnit,
+    pub ndp: i32,
+    pub sep: [&'static str; 3],
+    pub plus: bool,
+    pub width: usize,
+}
+
+impl SexFormat {
+    /// 12:34:56.789
+    pub const HMS_COLON: SexFormat = SexFormat {
+        unit: AngleUnit::Hours, ndp: 3, sep: [":", ":", ""], plus: false, width: 2,
+    };
+    /// 12 34 56.789
+    pub const HMS_SPACE: SexFormat = SexFormat {
+        unit: AngleUnit::Hours, ndp: 3, sep: [" ", " ", ""], plus: false, width: 2,
+    };
+    /// 12h34m56.789s
+    pub const HMS_LETTERS: SexFormat = SexFormat {
+        unit: AngleUnit::Hours, ndp: 3, sep: ["h", "m", "s"], plus: false, width: 2,
+    };
+    /// +23:45:06.70
+    pub const DMS_COLON: SexFormat = SexFormat {
+        unit: AngleUnit::Degrees, ndp: 2, sep: [":", ":", ""], plus: true, width: 2,
+    };
+    /// +23 45 06.70
+    pub const DMS_SPACE: SexFormat = SexFormat {
+        unit: AngleUnit::Degrees, ndp: 2, sep: [" ", " ", ""], plus: true, width: 2,
+    };
+    /// +23d45m06.70s
+    pub const DMS_LETTERS: SexFormat = SexFormat {
+        unit: AngleUnit::Degrees, ndp: 2, sep: ["d", "m", "s"], plus: true, width: 2,
+    };
+    /// +23°45′06.70″
+    pub const DMS_SYMBOLS: SexFormat = SexFormat {
+        unit: AngleUnit::Degrees, ndp: 2, sep: ["°", "′", "″"], plus: true, width: 2,
+    };
+}
+
+///  Angle with sexagesimal parsing and formatting
+///
+///  A thin wrapper over an angle in radians.  Strings are read by
+///  `Angle::parse` (or `str::parse`) and written by `Angle::format`
+///  using the same rounding as `a2tf` and `a2af`.
+///
+///  Accepted input, with an optional leading sign:
+///  ```text
+///     12h 34m 56.789s    12h34m56.789s    12h34.5m    12.5h
+///     -23:45:06.7        -23 45 06.7      -23d45m06.7s
+///     -23°45'06.7"       -23°45′06.7″     -23.75
+///  ```
+///  The unit of an unmarked string (colons, spaces or a bare number)
+///  is degrees for `parse` and hours for `parse_hours`; a marker on the
+///  first field ('h', 'd' or '°') overrides it.  Only the last field
+///  may have a fractional part.
+///
+///  Status values:
+///  ```text
+///     -1   syntax error
+///      2   minutes field outside 0-59
+///      3   seconds field outside 0-59.999...
+///  ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Angle(pub f64);
+
+impl Angle {
+    pub fn from_radians(rad: f64) -> Self {
+        Angle(rad)
+    }
+
+    pub fn from_degrees(deg: f64) -> Self {
+        Angle(deg * DD2R)
+    }
+
+    pub fn from_hours(h: f64) -> Self {
+        Angle(h * 15.0 * DD2R)
+    }
+
+    pub fn from_arcsec(asec: f64) -> Self {
+        Angle(asec * DAS2R)
+    }
+
+    pub fn radians(&self) -> f64 {
+        self.0
+    }
+
+    pub fn degrees(&self) -> f64 {
+        self.0 * DR2D
+    }
+
+    pub fn hours(&self) -> f64 {
+        self.0 * DR2D / 15.0
+    }
+
+    pub fn arcsec(&self) -> f64 {
+        self.0 / DAS2R
+    }
+
+    /// Parse a sexagesimal or decimal angle, degrees unless marked.
+    pub fn parse(s: &str) -> Result<Self, i32> {
+        parse_sex(s, AngleUnit::Degrees)
+    }
+
+    /// Parse a sexagesimal or decimal angle, hours unless marked.
+    pub fn parse_hours(s: &str) -> Result<Self, i32> {
+        parse_sex(s, AngleUnit::Hours)
+    }
+
+    /// Sign and fields, as from `a2tf` or `a2af`.
+    pub fn to_fields(&self, unit: AngleUnit, ndp: i32) -> (char, [i32; 4]) {
+        match unit {
+            AngleUnit::Hours => a2tf(ndp, self.0),
+            AngleUnit::Degrees => a2af(ndp, self.0),
+        }
+    }
+
+    ///  Format as a sexagesimal string.
+    ///
+    ///  Hours are not normalized:  use `Angle(anp(a))` first for a
+    ///  right ascension.  For ndp < 0 the seconds are rounded as in
+    ///  `a2tf`/`a2af` and written without a fractional part.
+    pub fn format(&self, f: &SexFormat) -> String {
+        let (sign, i) = self.to_fields(f.unit, f.ndp);
+        let mut s = String::new();
+        if sign == '-' {
+            s.push('-');
+        } else if f.plus {
+            s.push('+');
+        }
+        s += &format!("{:0w$}{}{:02}{}{:02}", i[0], f.sep[0], i[1], f.sep[1], i[2],
+                      w = f.width);
+        if f.ndp > 0 {
+            s += &format!(".{:0w$}", i[3], w = f.ndp as usize);
+        }
+        s += f.sep[2];
+        s
+    }
+
+    /// hh:mm:ss.sss... with ndp decimal places.
+    pub fn to_hms(&self, ndp: i32) -> String {
+        self.format(&SexFormat { ndp, ..SexFormat::HMS_COLON })
+    }
+
+    /// +dd:mm:ss.ss... with ndp decimal places.
+    pub fn to_dms(&self, ndp: i32) -> String {
+        self.format(&SexFormat { ndp, ..SexFormat::DMS_COLON })
+    }
+}
+
+/* One field: digits, optional fraction, optional unit marker. */
+struct Field<'a> {
+    num: &'a str,
+    mark: Option<char>,
+}
+
+fn parse_sex(s: &str, default: AngleUnit) -> Result<Angle, i32> {
+    let s = s.trim();
+
+    /* Sign. */
+    let (neg, body) = match s.chars().next() {
+        Some(c @ ('-' | '−')) => (true, &s[c.len_utf8()..]),
+        Some('+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    /* Split into up to three fields. */
+    let mut fields: Vec<Field> = Vec::new();
+    let mut rest = body.trim_start();
+    while !rest.is_empty() {
+        if fields.len() == 3 {
+            return Err(-1);
+        }
+        let n = rest.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(rest.len());
+        if n == 0 {
+            return Err(-1);
+        }
+        let num = &rest[..n];
+        rest = &rest[n..];
+        let mark = rest.chars().next().filter(|c| "hmsd°'\"′″".contains(*c));
+        if let Some(c) = mark {
+            rest = &rest[c.len_utf8()..];
+        }
+        fields.push(Field { num, mark });
+
+        /* Separator. */
+        let t = rest.trim_start();
+        let sep = t.len() != rest.len();
+        rest = t;
+        if let Some(t) = rest.strip_prefix(':') {
+            if mark.is_some() {
+                return Err(-1);
+            }
+            rest = t.trim_start();
+        } else if !sep && mark.is_none() && !rest.is_empty() {
+            return Err(-1);
+        }
+    }
+    if fields.is_empty() {
+        return Err(-1);
+    }
+
+    /* Unit from the first marker. */
+    let unit = match fields[0].mark {
+        Some('h') => AngleUnit::Hours,
+        Some('d') | Some('°') => AngleUnit::Degrees,
+        None if fields.len() == 1 || fields.iter().all(|f| f.mark.is_none()) => default,
+        _ => return Err(-1),
+    };
+    let expect = match unit {
+        AngleUnit::Hours => ["h", "m", "s"],
+        AngleUnit::Degrees => ["d°", "m'′", "s\"″"],
+    };
+
+    /* Values:  only the last field may be fractional. */
+    let last = fields.len() - 1;
+    let mut v = [0.0; 3];
+    for (k, f) in fields.iter().enumerate() {
+        if let Some(c) = f.mark
+            && !expect[k].contains(c)
+        {
+            return Err(-1);
+        }
+        if f.num.contains('.') && k != last {
+            return Err(-1);
+        }
+        if f.num.starts_with('.') || f.num.ends_with('.') {
+            return Err(-1);
+        }
+        v[k] = f.num.parse().map_err(|_| -1)?;
+    }
+    if v[1] >= 60.0 {
+        return Err(2);
+    }
+    if v[2] >= 60.0 {
+        return Err(3);
+    }
+
+    let a = (60.0 * (60.0 * v[0] + v[1]) + v[2]) * match unit {
+        AngleUnit::Hours => DS2R,
+        AngleUnit::Degrees => DAS2R,
+    };
+    Ok(Angle(if neg { -a } else { a }))
+}
+
+/// `{}` gives +dd:mm:ss.ss; the precision, if given, is ndp.
+impl fmt::Display for Angle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ndp = f.precision().map_or(2, |p| p as i32);
+        f.write_str(&self.to_dms(ndp))
+    }
+}
+
+impl FromStr for Angle {
+    type Err = i32;
+
+    fn from_str(s: &str) -> Result<Self, i32> {
+        Angle::parse(s)
+    }
+}
@@ -1,8 +1,7 @@
-use std::ops::Rem;
 use crate::consts::D2PI;
 
-pub fn anp(a: f64) -> f64 {
-    let mut w = a.rem(D2PI);
+pub const fn anp(a: f64) -> f64 {
+    let mut w = a % D2PI;
     if w < 0.0 {
         w += D2PI;
     }
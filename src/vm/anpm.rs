@@ -1,10 +1,9 @@
-use std::ops::Rem;
 use crate::consts::{D2PI, DPI};
 
-pub fn anpm(a: f64) -> f64 {
-    let mut w = a.rem(D2PI);
+pub const fn anpm(a: f64) -> f64 {
+    let mut w = a % D2PI;
     if w.abs() >= DPI {
-        w -= D2PI;
+        w -= if a >= 0.0 { D2PI } else { -D2PI };
     }
     w
 }
\ No newline at end of file
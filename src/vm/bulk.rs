@@ -0,0 +1,157 @@
+//  Batch forms of the vector/matrix functions
+//
+//  Each applies one r-matrix (or conversion) to every element of a
+//  slice.  The loop bodies are written out so that the compiler can
+//  keep the matrix in registers and vectorize across elements; the
+//  results are identical to calling the single-vector function in a
+//  loop.  Input and output slices must have the same length.
+
+use super::RotMat;
+use crate::math::{cos, sin};
+
+#[inline(always)]
+fn rxp1(r: &[[f64; 3]; 3], p: &[f64; 3]) -> [f64; 3] {
+    [
+        r[0][0] * p[0] + r[0][1] * p[1] + r[0][2] * p[2],
+        r[1][0] * p[0] + r[1][1] * p[1] + r[1][2] * p[2],
+        r[2][0] * p[0] + r[2][1] * p[1] + r[2][2] * p[2],
+    ]
+}
+
+#[inline(always)]
+fn trxp1(r: &[[f64; 3]; 3], p: &[f64; 3]) -> [f64; 3] {
+    [
+        r[0][0] * p[0] + r[1][0] * p[1] + r[2][0] * p[2],
+        r[0][1] * p[0] + r[1][1] * p[1] + r[2][1] * p[2],
+        r[0][2] * p[0] + r[1][2] * p[1] + r[2][2] * p[2],
+    ]
+}
+
+///  Multiply many p-vectors by one r-matrix (batch rxp).
+///
+///  Given:
+///  ```text
+///     r        double[3][3]    r-matrix
+///     p        double[n][3]    p-vectors
+///  ```
+///  Returned:
+///  ```text
+///     rp       double[n][3]    r * p[i] for each i
+///  ```
+///  Panics if p and rp differ in length.
+pub fn rxp_many(r: &[[f64; 3]; 3], p: &[[f64; 3]], rp: &mut [[f64; 3]]) {
+    assert_eq!(p.len(), rp.len(), "rxp_many: length mismatch");
+    for (o, v) in rp.iter_mut().zip(p) {
+        *o = rxp1(r, v);
+    }
+}
+
+///  Multiply many p-vectors by one r-matrix, in place.
+pub fn rxp_many_inplace(r: &[[f64; 3]; 3], p: &mut [[f64; 3]]) {
+    for v in p.iter_mut() {
+        *v = rxp1(r, v);
+    }
+}
+
+///  Multiply many p-vectors by the transpose of one r-matrix (batch
+///  trxp).  Panics if p and trp differ in length.
+pub fn trxp_many(r: &[[f64; 3]; 3], p: &[[f64; 3]], trp: &mut [[f64; 3]]) {
+    assert_eq!(p.len(), trp.len(), "trxp_many: length mismatch");
+    for (o, v) in trp.iter_mut().zip(p) {
+        *o = trxp1(r, v);
+    }
+}
+
+///  Multiply many pv-vectors by one r-matrix (batch rxpv).  Panics if
+///  pv and rpv differ in length.
+pub fn rxpv_many(r: &[[f64; 3]; 3], pv: &[[[f64; 3]; 2]], rpv: &mut [[[f64; 3]; 2]]) {
+    assert_eq!(pv.len(), rpv.len(), "rxpv_many: length mismatch");
+    for (o, v) in rpv.iter_mut().zip(pv) {
+        *o = [rxp1(r, &v[0]), rxp1(r, &v[1])];
+    }
+}
+
+///  Spherical to unit vectors (batch s2c).  Panics if the three
+///  slices differ in length.
+pub fn s2c_many(theta: &[f64], phi: &[f64], c: &mut [[f64; 3]]) {
+    assert_eq!(theta.len(), phi.len(), "s2c_many: length mismatch");
+    assert_eq!(theta.len(), c.len(), "s2c_many: length mismatch");
+    for ((o, &t), &f) in c.iter_mut().zip(theta).zip(phi) {
+        let cp = cos(f);
+        *o = [cos(t) * cp, sin(t) * cp, sin(f)];
+    }
+}
+
+///  Vectors to spherical coordinates (batch c2s).  Panics if the three
+///  slices differ in length.
+pub fn c2s_many(p: &[[f64; 3]], theta: &mut [f64], phi: &mut [f64]) {
+    assert_eq!(p.len(), theta.len(), "c2s_many: length mismatch");
+    assert_eq!(p.len(), phi.len(), "c2s_many: length mismatch");
+    for ((v, t), f) in p.iter().zip(theta.iter_mut()).zip(phi.iter_mut()) {
+        (*t, *f) = super::c2s(v);
+    }
+}
+
+impl RotMat {
+    /// Apply to many p-vectors (see `rxp_many`).
+    pub fn apply_many(&self, p: &[[f64; 3]], rp: &mut [[f64; 3]]) {
+        rxp_many(&self.to_array(), p, rp);
+    }
+
+    /// Apply the inverse to many p-vectors (see `trxp_many`).
+    pub fn apply_inverse_many(&self, p: &[[f64; 3]], trp: &mut [[f64; 3]]) {
+        trxp_many(&self.to_array(), p, trp);
+    }
+}
+
+#[cfg(feature = "ndarray")]
+mod nd {
+    use ndarray::{Array2, ArrayView2, ArrayViewMut2, Axis};
+
+    use super::{rxp1, trxp1};
+
+    fn check(ncols: usize, f: &str) {
+        assert_eq!(ncols, 3, "{}: arrays must have shape (n, 3)", f);
+    }
+
+    ///  Multiply the rows of an (n, 3) array by one r-matrix.
+    ///
+    ///  Requires the "ndarray" feature.  Panics unless p has 3 columns.
+    pub fn rxp_array(r: &[[f64; 3]; 3], p: ArrayView2<f64>) -> Array2<f64> {
+        let mut rp = p.to_owned();
+        rxp_array_inplace(r, rp.view_mut());
+        rp
+    }
+
+    ///  Multiply the rows of an (n, 3) array by one r-matrix, in place.
+    ///
+    ///  Requires the "ndarray" feature.  Panics unless p has 3 columns.
+    pub fn rxp_array_inplace(r: &[[f64; 3]; 3], mut p: ArrayViewMut2<f64>) {
+        check(p.ncols(), "rxp_array");
+        for mut row in p.axis_iter_mut(Axis(0)) {
+            let v = rxp1(r, &[row[0], row[1], row[2]]);
+            row[0] = v[0];
+            row[1] = v[1];
+            row[2] = v[2];
+        }
+    }
+
+    ///  Multiply the rows of an (n, 3) array by the transpose of one
+    ///  r-matrix.
+    ///
+    ///  Requires the "ndarray" feature.  Panics unless p has 3 columns.
+    pub fn trxp_array(r: &[[f64; 3]; 3], p: ArrayView2<f64>) -> Array2<f64> {
+        check(p.ncols(), "trxp_array");
+        let mut trp = p.to_owned();
+        for mut row in trp.axis_iter_mut(Axis(0)) {
+            let v = trxp1(r, &[row[0], row[1], row[2]]);
+            row[0] = v[0];
+            row[1] = v[1];
+            row[2] = v[2];
+        }
+        trp
+    }
+}
+
+#[cfg(feature = "ndarray")]
+pub use nd::*;
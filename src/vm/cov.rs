@@ -0,0 +1,77 @@
+use super::{PvVec, RotMat};
+
+/// 3x3 covariance of a position (or any p-vector).
+pub type Cov3 = [[f64; 3]; 3];
+
+/// 6x6 covariance of a pv-vector, position first.
+pub type Cov6 = [[f64; 6]; 6];
+
+/// 6x6 Jacobian of a pv-vector transformation, d(out)/d(in).
+pub type Jacobian6 = [[f64; 6]; 6];
+
+/* a b a^T for square matrices. */
+fn sandwich<const N: usize>(a: &[[f64; N]; N], b: &[[f64; N]; N]) -> [[f64; N]; N] {
+    let mut ab = [[0.0; N]; N];
+    for (abi, ai) in ab.iter_mut().zip(a) {
+        for (j, x) in abi.iter_mut().enumerate() {
+            *x = ai.iter().zip(b).map(|(aik, bk)| aik * bk[j]).sum();
+        }
+    }
+    let mut c = [[0.0; N]; N];
+    for (ci, abi) in c.iter_mut().zip(&ab) {
+        for (x, aj) in ci.iter_mut().zip(a) {
+            *x = abi.iter().zip(aj).map(|(p, q)| p * q).sum();
+        }
+    }
+    c
+}
+
+///  Transform a 6x6 covariance, J C J^T.
+///
+///  j is the Jacobian of the transformation (see `pv_jacobian`).  The
+///  result is symmetric to rounding error.
+pub fn cov6_transform(j: &Jacobian6, c: &Cov6) -> Cov6 {
+    sandwich(j, c)
+}
+
+///  Jacobian of a linear pv-vector transformation
+///
+///  The columns are the images of the six unit pv-vectors, so f must be
+///  linear (a rotation, with or without the rotation-rate term of a
+///  rotating frame, as in `gcrs_to_itrs_pv`).  For an affine f
+///  subtract f(0) first.
+pub fn pv_jacobian(f: impl Fn(&PvVec) -> PvVec) -> Jacobian6 {
+    let mut j = [[0.0; 6]; 6];
+    for k in 0..6 {
+        let mut e = PvVec::default();
+        if k < 3 {
+            e.p[k] = 1.0;
+        } else {
+            e.v[k - 3] = 1.0;
+        }
+        let y = f(&e);
+        for (i, row) in j.iter_mut().enumerate() {
+            row[k] = if i < 3 { y.p[i] } else { y.v[i - 3] };
+        }
+    }
+    j
+}
+
+impl RotMat {
+    /// Rotate a position covariance, r C r^T.
+    pub fn rotate_cov(&self, c: &Cov3) -> Cov3 {
+        sandwich(&self.to_array(), c)
+    }
+
+    /// Rotate a pv-vector covariance by a fixed rotation (as `rotate`).
+    pub fn rotate_cov6(&self, c: &Cov6) -> Cov6 {
+        cov6_transform(&pv_jacobian(|x| x.rotate(self)), c)
+    }
+}
+
+impl PvVec {
+    /// The covariance of `propagate(dt)` for a state of covariance c.
+    pub fn propagate_cov(c: &Cov6, dt: f64) -> Cov6 {
+        cov6_transform(&pv_jacobian(|x| x.propagate(dt)), c)
+    }
+}
@@ -0,0 +1,55 @@
+//! Out-parameter forms of the vm routines
+//!
+//! Before the API was made uniform (references in, values out) these
+//! routines wrote their result through a final `&mut` argument, as in
+//! the C library, and `pm` took its vector by value.  The old forms
+//! are kept here, deprecated.  The original paths now name the new
+//! forms, so code written against the old ones does not compile as it
+//! stands:  importing from here instead (`use sofars::vm::legacy::*;`)
+//! restores the old signatures while the calls are moved over:
+//! ```text
+//!     rxp(&r, &p, &mut rp);      ->   let rp = rxp(&r, &p);
+//!     let m = pm(p);             ->   let m = pm(&p);
+//! ```
+//! The routines that modify their argument in place (`ir`, `zp`, `cp`,
+//! `cr`, `rx`, `ry`, `rz`) are unchanged and are not repeated here.
+
+#[deprecated(note = "use vm::pm, which takes a reference")]
+pub fn pm(p: [f64; 3]) -> f64 {
+    super::pm(&p)
+}
+
+#[deprecated(note = "use vm::q2r, which returns the matrix")]
+pub fn q2r(q: &[f64; 4], r: &mut [[f64; 3]; 3]) {
+    *r = super::q2r(q);
+}
+
+#[deprecated(note = "use vm::rxp, which returns the product")]
+pub fn rxp(r: &[[f64; 3]; 3], p: &[f64; 3], rp: &mut [f64; 3]) {
+    *rp = super::rxp(r, p);
+}
+
+#[deprecated(note = "use vm::rxpv, which returns the product")]
+pub fn rxpv(r: &[[f64; 3]; 3], pv: &[[f64; 3]; 2], rpv: &mut [[f64; 3]; 2]) {
+    *rpv = super::rxpv(r, pv);
+}
+
+#[deprecated(note = "use vm::rxr, which returns the product")]
+pub fn rxr(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3], atb: &mut [[f64; 3]; 3]) {
+    *atb = super::rxr(a, b);
+}
+
+#[deprecated(note = "use vm::tr, which returns the transpose")]
+pub fn tr(r: &[[f64; 3]; 3], rt: &mut [[f64; 3]; 3]) {
+    *rt = super::tr(r);
+}
+
+#[deprecated(note = "use vm::trxp, which returns the product")]
+pub fn trxp(r: &[[f64; 3]; 3], p: &[f64; 3], trp: &mut [f64; 3]) {
+    *trp = super::trxp(r, p);
+}
+
+#[deprecated(note = "use vm::trxpv, which returns the product")]
+pub fn trxpv(r: &[[f64; 3]; 3], pv: &[[f64; 3]; 2], trpv: &mut [[f64; 3]; 2]) {
+    *trpv = super::trxpv(r, pv);
+}
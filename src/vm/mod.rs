@@ -30,6 +30,12 @@ pub use d2tf::*;
 mod ir;
 pub use ir::*;
 
+mod pap;
+pub use pap::*;
+
+mod pas;
+pub use pas::*;
+
 mod pdp;
 pub use pdp::*;
 
@@ -39,6 +45,9 @@ pub use pm::*;
 mod pn;
 pub use pn::*;
 
+mod pv2s;
+pub use pv2s::*;
+
 mod pxp;
 pub use pxp::*;
 
@@ -63,6 +72,18 @@ pub use rz::*;
 mod s2c;
 pub use s2c::*;
 
+mod s2pv;
+pub use s2pv::*;
+
+mod sepp;
+pub use sepp::*;
+
+mod seps;
+pub use seps::*;
+
+mod slerp;
+pub use slerp::*;
+
 mod sxp;
 pub use sxp::*;
 
@@ -80,3 +101,32 @@ pub use trxpv::*;
 
 mod zp;
 pub use zp::*;
+
+mod angle;
+pub use angle::*;
+
+mod wrapped;
+pub use wrapped::*;
+
+mod quat;
+pub use quat::*;
+
+mod mat3;
+pub use mat3::*;
+
+mod rotmat;
+pub use rotmat::*;
+
+mod pvvec;
+pub use pvvec::*;
+
+mod cov;
+pub use cov::*;
+
+mod sphcoord;
+pub use sphcoord::*;
+
+mod bulk;
+pub use bulk::*;
+
+pub mod legacy;
@@ -0,0 +1,78 @@
+use crate::math::atan2;
+
+use super::{pdp, pm, pn, pxp};
+
+///  Position-angle from two p-vectors.
+///
+///  This function is part of the International Astronomical Union's
+///  SOFA (Standards of Fundamental Astronomy) software collection.
+///
+///  Status:  vector/matrix support function.
+///
+///  Given:
+///  ```text
+///     a      double[3]  direction of reference point
+///     b      double[3]  direction of point whose PA is required
+///  ```
+///  Returned (function value):
+///  ```text
+///            double     position angle of b with respect to a (radians)
+///  ```
+///  Notes:
+///
+///  1) The result is the position angle, in radians, of direction b with
+///     respect to direction a.  It is in the range -pi to +pi.  The
+///     sense is such that if b is a small distance "north" of a the
+///     position angle is approximately zero, and if b is a small
+///     distance "east" of a the position angle is approximately +pi/2.
+///
+///  2) The vectors a and b need not be of unit length.
+///
+///  3) Zero is returned if the two directions are the same or if either
+///     vector is null.
+///
+///  4) If vector a is at a pole, the result is ill-defined.
+///
+///  Called:
+///  ```text
+///     iauPn        decompose p-vector into modulus and direction
+///     iauPm        modulus of p-vector
+///     iauPxp       vector product of two p-vectors
+///     iauPmp       p-vector minus p-vector
+///     iauPdp       scalar product of two p-vectors
+///  ```
+pub fn pap(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    /* Modulus and direction of the a vector. */
+    let (am, au) = pn(a);
+
+    /* Distance between the b vector and the a unit vector. */
+    let bm = pm(b);
+
+    let (st, ct) = if am == 0.0 || bm == 0.0 {
+        /* Null vector a or b:  arbitrary result. */
+        (0.0, 1.0)
+    } else {
+        /* The "north" axis tangential from a (arbitrary length). */
+        let eta = [-a[0] * a[2], -a[1] * a[2], a[0] * a[0] + a[1] * a[1]];
+
+        /* The "east" axis tangential from a (same length). */
+        let xi = pxp(&eta, &au);
+
+        /* The vector from a to b. */
+        let a2b = [b[0] - au[0], b[1] - au[1], b[2] - au[2]];
+
+        /* Resolve into components along the north and east axes. */
+        let st = pdp(&a2b, &xi);
+        let ct = pdp(&a2b, &eta);
+
+        /* Deal with degenerate cases. */
+        if st == 0.0 && ct == 0.0 {
+            (st, 1.0)
+        } else {
+            (st, ct)
+        }
+    };
+
+    /* Position angle. */
+    atan2(st, ct)
+}
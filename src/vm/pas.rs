@@ -0,0 +1,38 @@
+use crate::math::{atan2, cos, sin};
+
+///  Position-angle from spherical coordinates.
+///
+///  This function is part of the International Astronomical Union's
+///  SOFA (Standards of Fundamental Astronomy) software collection.
+///
+///  Status:  vector/matrix support function.
+///
+///  Given:
+///  ```text
+///     al     double     longitude of point A (e.g. RA) in radians
+///     ap     double     latitude of point A (e.g. Dec) in radians
+///     bl     double     longitude of point B
+///     bp     double     latitude of point B
+///  ```
+///  Returned (function value):
+///  ```text
+///            double     position angle of B with respect to A
+///  ```
+///  Notes:
+///
+///  1) The result is the bearing (position angle), in radians, of point
+///     B with respect to point A.  It is in the range -pi to +pi.  The
+///     sense is such that if B is a small distance "east" of point A,
+///     the bearing is approximately +pi/2.
+///
+///  2) Zero is returned if the two points are coincident.
+pub fn pas(al: f64, ap: f64, bl: f64, bp: f64) -> f64 {
+    let dl = bl - al;
+    let y = sin(dl) * cos(bp);
+    let x = sin(bp) * cos(ap) - cos(bp) * sin(ap) * cos(dl);
+    if x != 0.0 || y != 0.0 {
+        atan2(y, x)
+    } else {
+        0.0
+    }
+}
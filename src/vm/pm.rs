@@ -1,3 +1,3 @@
-pub fn pm(p: [f64; 3]) -> f64 {
+pub fn pm(p: &[f64; 3]) -> f64 {
     (p[0] * p[0] + p[1] * p[1] + p[2] * p[2]).sqrt()
-}
\ No newline at end of file
+}
@@ -0,0 +1,75 @@
+use crate::math::atan2;
+
+///  Convert position/velocity from Cartesian to spherical coordinates.
+///
+///  This function is part of the International Astronomical Union's
+///  SOFA (Standards of Fundamental Astronomy) software collection.
+///
+///  Status:  vector/matrix support function.
+///
+///  Given:
+///  ```text
+///     pv       double[2][3]  pv-vector
+///  ```
+///  Returned:
+///  ```text
+///     theta    double        longitude angle (radians)
+///     phi      double        latitude angle (radians)
+///     r        double        radial distance
+///     td       double        rate of change of theta
+///     pd       double        rate of change of phi
+///     rd       double        rate of change of r
+///  ```
+///  Notes:
+///
+///  1) If the position part of pv is null, theta, phi, td and pd
+///     are indeterminate.  This is handled by extrapolating the
+///     position through unit time by using the velocity part of
+///     pv.  This moves the origin without changing the direction
+///     of the velocity component.  If the position and velocity
+///     components of pv are both null, zeroes are returned for all
+///     six results.
+///
+///  2) If the position is a pole, theta, td and pd are indeterminate.
+///     In such cases zeroes are returned for all three.
+pub fn pv2s(pv: &[[f64; 3]; 2]) -> (f64, f64, f64, f64, f64, f64) {
+    let [mut x, mut y, mut z] = pv[0];
+    let [xd, yd, zd] = pv[1];
+
+    /* Component of r in XY plane squared. */
+    let mut rxy2 = x * x + y * y;
+
+    /* Modulus squared. */
+    let mut r2 = rxy2 + z * z;
+
+    /* Modulus. */
+    let rtrue = r2.sqrt();
+
+    /* If null vector, move the origin along the direction of movement. */
+    let mut rw = rtrue;
+    if rtrue == 0.0 {
+        x = xd;
+        y = yd;
+        z = zd;
+        rxy2 = x * x + y * y;
+        r2 = rxy2 + z * z;
+        rw = r2.sqrt();
+    }
+
+    /* Position and velocity in spherical coordinates. */
+    let rxy = rxy2.sqrt();
+    let xyp = x * xd + y * yd;
+    let (theta, phi, td, pd) = if rxy2 != 0.0 {
+        (
+            atan2(y, x),
+            atan2(z, rxy),
+            (x * yd - y * xd) / rxy2,
+            (zd * rxy2 - z * xyp) / (r2 * rxy),
+        )
+    } else {
+        (0.0, if z != 0.0 { atan2(z, rxy) } else { 0.0 }, 0.0, 0.0)
+    };
+    let rd = if rw != 0.0 { (xyp + z * zd) / rw } else { 0.0 };
+
+    (theta, phi, rtrue, td, pd, rd)
+}
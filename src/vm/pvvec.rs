@@ -0,0 +1,186 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use super::{pdp, pxp, RotMat};
+use crate::consts::{DAU, DAYSEC};
+
+///  Position/velocity vector
+///
+///  The SOFA pv-vector, [[f64;3];2], as a type with named parts.  The
+///  units are whatever the source uses:  `epv00` returns au and au/day,
+///  `starpv` likewise;  `au_to_km` and `km_to_au` convert between those
+///  and km, km/s.
+///
+///  Arrays convert both ways with `From`, so eph results compose
+///  directly:
+///  ```text
+///     let (h, b) = epv00(tt1, tt2).unwrap();
+///     let pv = PvVec::from(b).rotate(&r).au_to_km();
+///  ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PvVec {
+    pub p: [f64; 3],
+    pub v: [f64; 3],
+}
+
+/* km per au. */
+const AUKM: f64 = DAU / 1e3;
+
+impl PvVec {
+    pub fn new(p: [f64; 3], v: [f64; 3]) -> Self {
+        PvVec { p, v }
+    }
+
+    /// Inner product and its rate of change (as SOFA pvdpv):
+    /// (a.b, d(a.b)/dt).
+    pub fn dot(&self, b: &PvVec) -> (f64, f64) {
+        (pdp(&self.p, &b.p), pdp(&self.p, &b.v) + pdp(&self.v, &b.p))
+    }
+
+    /// Outer product (as SOFA pvxpv):  (a x b, d(a x b)/dt).
+    pub fn cross(&self, b: &PvVec) -> PvVec {
+        let v1 = pxp(&self.p, &b.v);
+        let v2 = pxp(&self.v, &b.p);
+        PvVec {
+            p: pxp(&self.p, &b.p),
+            v: [v1[0] + v2[0], v1[1] + v2[1], v1[2] + v2[2]],
+        }
+    }
+
+    /// Modulus of the position and of the velocity (as SOFA pvm).
+    pub fn modulus(&self) -> (f64, f64) {
+        (pdp(&self.p, &self.p).sqrt(), pdp(&self.v, &self.v).sqrt())
+    }
+
+    /// Scale position and velocity by the same factor.
+    pub fn scale(&self, s: f64) -> PvVec {
+        *self * s
+    }
+
+    /// Scale position and velocity separately (as SOFA s2xpv).
+    pub fn scale2(&self, s1: f64, s2: f64) -> PvVec {
+        PvVec {
+            p: self.p.map(|x| s1 * x),
+            v: self.v.map(|x| s2 * x),
+        }
+    }
+
+    /// Uniform motion over an interval dt in the velocity's time unit
+    /// (as SOFA pvu).
+    pub fn propagate(&self, dt: f64) -> PvVec {
+        PvVec {
+            p: [
+                self.p[0] + dt * self.v[0],
+                self.p[1] + dt * self.v[1],
+                self.p[2] + dt * self.v[2],
+            ],
+            v: self.v,
+        }
+    }
+
+    /// r * pv (as rxpv).
+    pub fn rotate(&self, r: &RotMat) -> PvVec {
+        PvVec {
+            p: r.apply(&self.p),
+            v: r.apply(&self.v),
+        }
+    }
+
+    /// r^T * pv (as trxpv).
+    pub fn rotate_inverse(&self, r: &RotMat) -> PvVec {
+        PvVec {
+            p: r.apply_inverse(&self.p),
+            v: r.apply_inverse(&self.v),
+        }
+    }
+
+    /// au, au/day to km, km/s.
+    pub fn au_to_km(&self) -> PvVec {
+        self.scale2(AUKM, AUKM / DAYSEC)
+    }
+
+    /// km, km/s to au, au/day.
+    pub fn km_to_au(&self) -> PvVec {
+        self.scale2(1.0 / AUKM, DAYSEC / AUKM)
+    }
+
+    /// The SOFA array form.
+    pub fn to_array(&self) -> [[f64; 3]; 2] {
+        [self.p, self.v]
+    }
+}
+
+impl From<[[f64; 3]; 2]> for PvVec {
+    fn from(pv: [[f64; 3]; 2]) -> Self {
+        PvVec { p: pv[0], v: pv[1] }
+    }
+}
+
+impl From<PvVec> for [[f64; 3]; 2] {
+    fn from(pv: PvVec) -> Self {
+        [pv.p, pv.v]
+    }
+}
+
+impl Add for PvVec {
+    type Output = PvVec;
+
+    fn add(self, b: PvVec) -> PvVec {
+        PvVec {
+            p: [self.p[0] + b.p[0], self.p[1] + b.p[1], self.p[2] + b.p[2]],
+            v: [self.v[0] + b.v[0], self.v[1] + b.v[1], self.v[2] + b.v[2]],
+        }
+    }
+}
+
+impl Sub for PvVec {
+    type Output = PvVec;
+
+    fn sub(self, b: PvVec) -> PvVec {
+        self + -b
+    }
+}
+
+impl Neg for PvVec {
+    type Output = PvVec;
+
+    fn neg(self) -> PvVec {
+        self.scale2(-1.0, -1.0)
+    }
+}
+
+impl Mul<f64> for PvVec {
+    type Output = PvVec;
+
+    fn mul(self, s: f64) -> PvVec {
+        self.scale2(s, s)
+    }
+}
+
+impl Mul<PvVec> for f64 {
+    type Output = PvVec;
+
+    fn mul(self, pv: PvVec) -> PvVec {
+        pv * self
+    }
+}
+
+impl AddAssign for PvVec {
+    fn add_assign(&mut self, b: PvVec) {
+        *self = *self + b;
+    }
+}
+
+impl SubAssign for PvVec {
+    fn sub_assign(&mut self, b: PvVec) {
+        *self = *self - b;
+    }
+}
+
+/// Rotation, as rxpv.
+impl Mul<PvVec> for RotMat {
+    type Output = PvVec;
+
+    fn mul(self, pv: PvVec) -> PvVec {
+        pv.rotate(&self)
+    }
+}
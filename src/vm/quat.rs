@@ -0,0 +1,108 @@
+///  Rotation matrix to unit quaternion.
+///
+///  Given:
+///  ```text
+///     r        double[3][3]    r-matrix (proper orthogonal)
+///  ```
+///  Returned (function value):
+///  ```text
+///              double[4]       unit quaternion, w >= 0
+///  ```
+///  Notes:
+///
+///  1) A quaternion is held as [w, x, y, z], scalar first, with the
+///     Hamilton product.  The quaternion and the r-matrix describe the
+///     same rotation when qxp(q, p) == rxp(r, p) for every p-vector p,
+///     and composition follows the matrix order:
+///     q2r(qxq(a, b)) == rxr(q2r(a), q2r(b)).  Users of the scalar-last
+///     ("x y z w") convention need only reorder the components.
+///
+///  2) q and -q represent the same rotation;  the one with w >= 0 is
+///     returned.
+///
+///  3) Shepperd's method is used:  the largest of the four candidate
+///     divisors is chosen, so the result is well conditioned for any
+///     rotation.
+pub fn r2q(r: &[[f64; 3]; 3]) -> [f64; 4] {
+    let tr = r[0][0] + r[1][1] + r[2][2];
+    let q = if tr >= r[0][0] && tr >= r[1][1] && tr >= r[2][2] {
+        let s = 2.0 * (1.0 + tr).sqrt();
+        [0.25 * s, (r[2][1] - r[1][2]) / s, (r[0][2] - r[2][0]) / s, (r[1][0] - r[0][1]) / s]
+    } else if r[0][0] >= r[1][1] && r[0][0] >= r[2][2] {
+        let s = 2.0 * (1.0 + r[0][0] - r[1][1] - r[2][2]).sqrt();
+        [(r[2][1] - r[1][2]) / s, 0.25 * s, (r[0][1] + r[1][0]) / s, (r[0][2] + r[2][0]) / s]
+    } else if r[1][1] >= r[2][2] {
+        let s = 2.0 * (1.0 - r[0][0] + r[1][1] - r[2][2]).sqrt();
+        [(r[0][2] - r[2][0]) / s, (r[0][1] + r[1][0]) / s, 0.25 * s, (r[1][2] + r[2][1]) / s]
+    } else {
+        let s = 2.0 * (1.0 - r[0][0] - r[1][1] + r[2][2]).sqrt();
+        [(r[1][0] - r[0][1]) / s, (r[0][2] + r[2][0]) / s, (r[1][2] + r[2][1]) / s, 0.25 * s]
+    };
+
+    /* Canonical sign, then tidy the norm. */
+    let q = if q[0] < 0.0 { [-q[0], -q[1], -q[2], -q[3]] } else { q };
+    qn(&q)
+}
+
+///  Unit quaternion to rotation matrix.
+///
+///  Given:
+///  ```text
+///     q        double[4]       quaternion [w,x,y,z] (need not be unit)
+///  ```
+///  Returned (function value):
+///  ```text
+///              double[3][3]    r-matrix
+///  ```
+pub fn q2r(q: &[f64; 4]) -> [[f64; 3]; 3] {
+    let [w, x, y, z] = qn(q);
+
+    [
+        [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - w * z), 2.0 * (x * z + w * y)],
+        [2.0 * (x * y + w * z), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - w * x)],
+        [2.0 * (x * z - w * y), 2.0 * (y * z + w * x), 1.0 - 2.0 * (x * x + y * y)],
+    ]
+}
+
+///  Quaternion product a * b (rotation b followed by rotation a, as
+///  for rxr).
+pub fn qxq(a: &[f64; 4], b: &[f64; 4]) -> [f64; 4] {
+    [
+        a[0] * b[0] - a[1] * b[1] - a[2] * b[2] - a[3] * b[3],
+        a[0] * b[1] + a[1] * b[0] + a[2] * b[3] - a[3] * b[2],
+        a[0] * b[2] - a[1] * b[3] + a[2] * b[0] + a[3] * b[1],
+        a[0] * b[3] + a[1] * b[2] - a[2] * b[1] + a[3] * b[0],
+    ]
+}
+
+///  Conjugate, the inverse rotation of a unit quaternion (as for tr).
+pub fn qc(q: &[f64; 4]) -> [f64; 4] {
+    [q[0], -q[1], -q[2], -q[3]]
+}
+
+///  Normalize a quaternion;  the zero quaternion becomes the identity.
+pub fn qn(q: &[f64; 4]) -> [f64; 4] {
+    let m = (q[0] * q[0] + q[1] * q[1] + q[2] * q[2] + q[3] * q[3]).sqrt();
+    if m == 0.0 {
+        [1.0, 0.0, 0.0, 0.0]
+    } else {
+        [q[0] / m, q[1] / m, q[2] / m, q[3] / m]
+    }
+}
+
+///  Rotate a p-vector by a unit quaternion, q p q* (as for rxp).
+pub fn qxp(q: &[f64; 4], p: &[f64; 3]) -> [f64; 3] {
+    let [w, x, y, z] = *q;
+
+    /* t = 2 (v x p), result = p + w t + v x t */
+    let t = [
+        2.0 * (y * p[2] - z * p[1]),
+        2.0 * (z * p[0] - x * p[2]),
+        2.0 * (x * p[1] - y * p[0]),
+    ];
+    [
+        p[0] + w * t[0] + (y * t[2] - z * t[1]),
+        p[1] + w * t[1] + (z * t[0] - x * t[2]),
+        p[2] + w * t[2] + (x * t[1] - y * t[0]),
+    ]
+}
@@ -0,0 +1,201 @@
+use std::ops::{Index, Mul};
+
+use super::{q2r, r2q, Mat3};
+
+///  Rotation matrix
+///
+///  An r-matrix as used throughout SOFA, with composition by `*` in
+///  the same order as `rxr`:  (a * b).apply(p) == a.apply(b.apply(p)).
+///
+///  The constructors follow the SOFA elementary rotations, so that
+///  `RotMat::rz(psi)` is `rz(psi, &mut r)` applied to the identity
+///  matrix, and a chain such as
+///  ```text
+///     ir(r); rz(a, r); ry(b, r); rx(c, r);
+///  ```
+///  is written `RotMat::rx(c) * RotMat::ry(b) * RotMat::rz(a)`.
+///
+///  Arrays produced by the existing functions convert with `From`
+///  (or `RotMat::new`), and `to_array` gives one back for them.  The
+///  matrix is held as a `Mat3`, see `as_mat3`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RotMat {
+    m: Mat3,
+}
+
+impl RotMat {
+    /// Wrap an r-matrix.  No check is made; see `is_orthonormal`.
+    pub fn new(m: [[f64; 3]; 3]) -> Self {
+        RotMat { m: Mat3::from_rows(&m) }
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        RotMat { m: Mat3::IDENTITY }
+    }
+
+    /// Rotation about the x-axis (see `rx`).
+    #[inline]
+    pub fn rx(phi: f64) -> Self {
+        let mut r = Self::identity();
+        r.m.rotate_x(phi);
+        r
+    }
+
+    /// Rotation about the y-axis (see `ry`).
+    #[inline]
+    pub fn ry(theta: f64) -> Self {
+        let mut r = Self::identity();
+        r.m.rotate_y(theta);
+        r
+    }
+
+    /// Rotation about the z-axis (see `rz`).
+    #[inline]
+    pub fn rz(psi: f64) -> Self {
+        let mut r = Self::identity();
+        r.m.rotate_z(psi);
+        r
+    }
+
+    /// From a unit quaternion [w,x,y,z] (see `q2r`).
+    pub fn from_quat(q: &[f64; 4]) -> Self {
+        Self::new(q2r(q))
+    }
+
+    /// As a unit quaternion [w,x,y,z] (see `r2q`).
+    pub fn to_quat(&self) -> [f64; 4] {
+        r2q(&self.to_array())
+    }
+
+    /// The r-matrix as an array.
+    pub fn to_array(&self) -> [[f64; 3]; 3] {
+        self.m.to_rows()
+    }
+
+    /// The matrix, flat.
+    pub fn as_mat3(&self) -> &Mat3 {
+        &self.m
+    }
+
+    /// Transpose, which for a rotation is the inverse.
+    pub fn transpose(&self) -> Self {
+        RotMat { m: self.m.transpose() }
+    }
+
+    /// r * p
+    #[inline]
+    pub fn apply(&self, p: &[f64; 3]) -> [f64; 3] {
+        self.m.mul_vec(p)
+    }
+
+    /// r^T * p, the inverse rotation, without forming the transpose.
+    #[inline]
+    pub fn apply_inverse(&self, p: &[f64; 3]) -> [f64; 3] {
+        self.m.tr_mul_vec(p)
+    }
+
+    /// Determinant (+1 for a proper rotation).
+    pub fn det(&self) -> f64 {
+        let m = &self.m.to_rows();
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// Largest element of |r r^T - I|.
+    pub fn orthonormality_error(&self) -> f64 {
+        let m = &self.m.to_rows();
+        let mut e: f64 = 0.0;
+        for i in 0..3 {
+            for j in 0..3 {
+                let d: f64 = (0..3).map(|k| m[i][k] * m[j][k]).sum();
+                let id = if i == j { 1.0 } else { 0.0 };
+                e = e.max((d - id).abs());
+            }
+        }
+        e
+    }
+
+    /// Whether r r^T = I to within tol and r is proper (det > 0).
+    pub fn is_orthonormal(&self, tol: f64) -> bool {
+        self.orthonormality_error() <= tol && self.det() > 0.0
+    }
+}
+
+impl Default for RotMat {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl From<[[f64; 3]; 3]> for RotMat {
+    fn from(m: [[f64; 3]; 3]) -> Self {
+        Self::new(m)
+    }
+}
+
+impl From<RotMat> for [[f64; 3]; 3] {
+    fn from(r: RotMat) -> Self {
+        r.to_array()
+    }
+}
+
+impl From<Mat3> for RotMat {
+    fn from(m: Mat3) -> Self {
+        RotMat { m }
+    }
+}
+
+impl From<RotMat> for Mat3 {
+    fn from(r: RotMat) -> Self {
+        r.m
+    }
+}
+
+/// Element (row, column).
+impl Index<(usize, usize)> for RotMat {
+    type Output = f64;
+
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        &self.m[(i, j)]
+    }
+}
+
+/// Composition, as rxr.
+impl Mul for RotMat {
+    type Output = RotMat;
+
+    #[inline]
+    fn mul(self, b: RotMat) -> RotMat {
+        RotMat { m: self.m.mul(&b.m) }
+    }
+}
+
+impl Mul<&RotMat> for &RotMat {
+    type Output = RotMat;
+
+    #[inline]
+    fn mul(self, b: &RotMat) -> RotMat {
+        RotMat { m: self.m.mul(&b.m) }
+    }
+}
+
+/// Rotation of a p-vector, as rxp.
+impl Mul<[f64; 3]> for RotMat {
+    type Output = [f64; 3];
+
+    #[inline]
+    fn mul(self, p: [f64; 3]) -> [f64; 3] {
+        self.apply(&p)
+    }
+}
+
+impl Mul<&[f64; 3]> for &RotMat {
+    type Output = [f64; 3];
+
+    #[inline]
+    fn mul(self, p: &[f64; 3]) -> [f64; 3] {
+        self.apply(p)
+    }
+}
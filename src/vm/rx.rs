@@ -1,6 +1,8 @@
+use crate::math::{cos, sin};
+
 pub fn rx(phi: f64, r: &mut [[f64; 3]; 3]) {
-    let s = phi.sin();
-    let c = phi.cos();
+    let s = sin(phi);
+    let c = cos(phi);
 
     let a10 = c * r[1][0] + s * r[2][0];
     let a11 = c * r[1][1] + s * r[2][1];
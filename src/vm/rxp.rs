@@ -1,17 +1,7 @@
-use super::cp;
+use super::Mat3;
 
-pub fn rxp(r: &[[f64; 3]; 3], p: &[f64; 3], rp: &mut [f64; 3]) {
-    let wrp = &mut [0.0; 3];
-
-    // Matrix r * vector p.
-    for j in 0..3 {
-        let mut w = 0.0;
-        for i in 0..3 {
-            w += r[j][i] * p[i];
-        }
-        wrp[j] = w;
-    }
-
-    /* Return the result. */
-    cp(wrp, rp);
-}
\ No newline at end of file
+#[inline]
+pub fn rxp(r: &[[f64; 3]; 3], p: &[f64; 3]) -> [f64; 3] {
+    // Matrix r * vector p, through Mat3.
+    Mat3::from_rows(r).mul_vec(p)
+}
@@ -1,6 +1,8 @@
+use crate::math::{cos, sin};
+
 pub fn ry(theta: f64, r: &mut [[f64; 3]; 3]) {
-    let s = theta.sin();
-    let c = theta.cos();
+    let s = sin(theta);
+    let c = cos(theta);
 
     let a00 = c * r[0][0] - s * r[2][0];
     let a01 = c * r[0][1] - s * r[2][1];
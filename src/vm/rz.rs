@@ -1,6 +1,8 @@
+use crate::math::{cos, sin};
+
 pub fn rz(psi: f64, r: &mut [[f64; 3]; 3]) {
-    let s = psi.sin();
-    let c = psi.cos();
+    let s = sin(psi);
+    let c = cos(psi);
 
     let a00 = c * r[0][0] + s * r[1][0];
     let a01 = c * r[0][1] + s * r[1][1];
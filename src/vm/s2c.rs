@@ -1,4 +1,6 @@
+use crate::math::{cos, sin};
+
 pub fn s2c(theta: f64, phi: f64) -> [f64; 3] {
-    let cp = phi.cos();
-    [theta.cos() * cp, theta.sin() * cp, phi.sin()]
+    let cp = cos(phi);
+    [cos(theta) * cp, sin(theta) * cp, sin(phi)]
 }
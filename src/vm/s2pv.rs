@@ -0,0 +1,36 @@
+use crate::math::sin_cos;
+
+///  Convert position/velocity from spherical to Cartesian coordinates.
+///
+///  This function is part of the International Astronomical Union's
+///  SOFA (Standards of Fundamental Astronomy) software collection.
+///
+///  Status:  vector/matrix support function.
+///
+///  Given:
+///  ```text
+///     theta    double          longitude angle (radians)
+///     phi      double          latitude angle (radians)
+///     r        double          radial distance
+///     td       double          rate of change of theta
+///     pd       double          rate of change of phi
+///     rd       double          rate of change of r
+///  ```
+///  Returned (function value):
+///  ```text
+///              double[2][3]    pv-vector
+///  ```
+pub fn s2pv(theta: f64, phi: f64, r: f64, td: f64, pd: f64, rd: f64) -> [[f64; 3]; 2] {
+    let (st, ct) = sin_cos(theta);
+    let (sp, cp) = sin_cos(phi);
+    let rcp = r * cp;
+    let x = rcp * ct;
+    let y = rcp * st;
+    let rpd = r * pd;
+    let w = rpd * sp - cp * rd;
+
+    [
+        [x, y, r * sp],
+        [-y * td - w * ct, x * td - w * st, rpd * cp + sp * rd],
+    ]
+}
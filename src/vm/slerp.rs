@@ -0,0 +1,57 @@
+use crate::math::{cos, sin};
+
+use super::{pdp, pn, sepp};
+
+///  Great-circle interpolation between two directions
+///
+///  Given:
+///  ```text
+///     a      double[3]    start direction (not necessarily unit length)
+///     b      double[3]    end direction (not necessarily unit length)
+///     t      double       fraction of the arc (0 at a, 1 at b)
+///  ```
+///  Returned (function value):
+///  ```text
+///            double[3]    unit vector
+///  ```
+///  Notes:
+///
+///  1) The result moves at a uniform angular rate along the shorter
+///     great circle arc from a to b.  Values of t outside 0-1 continue
+///     round the same great circle.
+///
+///  2) If a and b are the same direction the result is a (unit
+///     vector).  For opposite directions the great circle is undefined
+///     and the result is not a unit vector.
+pub fn slerp(a: &[f64; 3], b: &[f64; 3], t: f64) -> [f64; 3] {
+    let (_, u) = pn(a);
+    let (_, w) = pn(b);
+
+    /* Unit vector perpendicular to u towards w, and the arc length. */
+    let c = pdp(&u, &w);
+    let (_, v) = pn(&[w[0] - c * u[0], w[1] - c * u[1], w[2] - c * u[2]]);
+    let theta = sepp(&u, &w) * t;
+
+    let (s, c) = (sin(theta), cos(theta));
+    [c * u[0] + s * v[0], c * u[1] + s * v[1], c * u[2] + s * v[2]]
+}
+
+///  Points along the great circle between two directions
+///
+///  Given:
+///  ```text
+///     a      double[3]    start direction
+///     b      double[3]    end direction
+///     n      usize        number of intermediate points
+///  ```
+///  Returned (function value):
+///  ```text
+///            Vec          n unit vectors, equally spaced in angle
+///                         strictly between a and b
+///  ```
+///  The arc is divided into n+1 equal steps (see `slerp`);  for a path
+///  including the end points, add a and b, or use slerp at t = k/(n-1).
+pub fn waypoints(a: &[f64; 3], b: &[f64; 3], n: usize) -> Vec<[f64; 3]> {
+    let h = 1.0 / (n + 1) as f64;
+    (1..=n).map(|k| slerp(a, b, k as f64 * h)).collect()
+}
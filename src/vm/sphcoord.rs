@@ -0,0 +1,106 @@
+use crate::math::{cos, sin};
+
+use super::{anp, c2s, pas, pn, s2c, seps, slerp, waypoints};
+
+///  Direction on the sky as spherical coordinates
+///
+///  ```text
+///     lon     longitude, e.g. RA (radians)
+///     lat     latitude, e.g. Dec (radians)
+///  ```
+///  Any spherical system will do (RA,Dec, ecliptic, galactic, az/alt
+///  with azimuth increasing towards the east);  "north" and "east" are
+///  in the sense of increasing lat and lon.  Converts to and from unit
+///  vectors with `From` (s2c, c2s).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SphCoord {
+    pub lon: f64,
+    pub lat: f64,
+}
+
+impl SphCoord {
+    pub fn new(lon: f64, lat: f64) -> Self {
+        SphCoord { lon, lat }
+    }
+
+    /// From a p-vector, not necessarily of unit length (lon 0-2pi).
+    pub fn from_vector(p: &[f64; 3]) -> Self {
+        let (lon, lat) = c2s(p);
+        SphCoord { lon: anp(lon), lat }
+    }
+
+    /// The unit vector.
+    pub fn to_vector(&self) -> [f64; 3] {
+        s2c(self.lon, self.lat)
+    }
+
+    /// Angular separation (radians, see `seps`).
+    pub fn sep(&self, other: &SphCoord) -> f64 {
+        seps(self.lon, self.lat, other.lon, other.lat)
+    }
+
+    /// Position angle of other with respect to self (radians, +/-pi,
+    /// north through east, see `pas`).
+    pub fn position_angle(&self, other: &SphCoord) -> f64 {
+        pas(self.lon, self.lat, other.lon, other.lat)
+    }
+
+    ///  The point at a given position angle and separation (radians)
+    ///  from self, along a great circle.
+    ///
+    ///  The inverse of `position_angle` and `sep`.  At a pole the
+    ///  position angle is measured from the direction of lon.
+    pub fn offset_by(&self, pa: f64, sep: f64) -> SphCoord {
+        let (sl, cl) = (sin(self.lon), cos(self.lon));
+        let (sb, cb) = (sin(self.lat), cos(self.lat));
+        let (sp, cp) = (sin(pa), cos(pa));
+        let (ss, cs) = (sin(sep), cos(sep));
+
+        /* Unit vectors:  the point, and north and east from it. */
+        let p = [cl * cb, sl * cb, sb];
+        let n = [-cl * sb, -sl * sb, cb];
+        let e = [-sl, cl, 0.0];
+
+        let q = [0, 1, 2].map(|i| p[i] * cs + (n[i] * cp + e[i] * sp) * ss);
+        SphCoord::from_vector(&q)
+    }
+
+    ///  The point half way between self and other on the shorter great
+    ///  circle arc.
+    ///
+    ///  For opposite points, for which the arc is undefined, the result
+    ///  is lon = lat = 0.
+    pub fn midpoint(&self, other: &SphCoord) -> SphCoord {
+        let a = self.to_vector();
+        let b = other.to_vector();
+        let (_, m) = pn(&[a[0] + b[0], a[1] + b[1], a[2] + b[2]]);
+        SphCoord::from_vector(&m)
+    }
+
+    /// The point a fraction t of the way from self to other along the
+    /// great circle (see `slerp`).
+    pub fn slerp(&self, other: &SphCoord, t: f64) -> SphCoord {
+        SphCoord::from_vector(&slerp(&self.to_vector(), &other.to_vector(), t))
+    }
+
+    /// n points strictly between self and other along the great circle
+    /// (see `waypoints`).
+    pub fn waypoints(&self, other: &SphCoord, n: usize) -> Vec<SphCoord> {
+        waypoints(&self.to_vector(), &other.to_vector(), n)
+            .iter()
+            .map(SphCoord::from_vector)
+            .collect()
+    }
+}
+
+impl From<[f64; 3]> for SphCoord {
+    fn from(p: [f64; 3]) -> Self {
+        SphCoord::from_vector(&p)
+    }
+}
+
+impl From<SphCoord> for [f64; 3] {
+    fn from(c: SphCoord) -> Self {
+        c.to_vector()
+    }
+}
@@ -1,11 +1,6 @@
-use super::cr;
+use super::Mat3;
 
-pub fn tr(r: &[[f64; 3]; 3], rt: &mut [[f64; 3]; 3]) {
-    let wm  = &mut [[0.0; 3]; 3];
-    for i in 0..3 {
-        for j in 0..3 {
-            wm[i][j] = r[j][i];
-        }
-    }
-    cr(wm, rt);
-}
\ No newline at end of file
+#[inline]
+pub fn tr(r: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    Mat3::from_rows(r).transpose().to_rows()
+}
@@ -1,7 +1,4 @@
-use super::{rxp, tr};
-
-pub fn trxp(r: &[[f64; 3]; 3], p: &[f64; 3], trp: &mut [f64; 3]) {
-    let tr_ = &mut [[0.0; 3]; 3];
-    tr(r, tr_);
-    rxp(tr_, p, trp);
+pub fn trxp(r: &[[f64; 3]; 3], p: &[f64; 3]) -> [f64; 3] {
+    // Transpose of matrix r * vector p, without forming the transpose.
+    [0, 1, 2].map(|j| r[0][j] * p[0] + r[1][j] * p[1] + r[2][j] * p[2])
 }
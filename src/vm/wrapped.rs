@@ -0,0 +1,185 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::consts::{D2PI, DPI};
+
+use super::{anp, anpm, Angle};
+
+/// Range into which a `WrappedAngle` is normalized
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Wrap {
+    /// 0 <= a < 2pi, as `anp` (right ascension, ERA, GST)
+    #[default]
+    Positive,
+    /// -pi < a <= pi, as `anpm` (hour angle, differences)
+    Signed,
+}
+
+impl Wrap {
+    /// Normalize an angle (radians) into the range.
+    pub fn apply(self, a: f64) -> f64 {
+        match self {
+            /* anp can round up to 2pi for tiny negative angles. */
+            Wrap::Positive => {
+                let w = anp(a);
+                if w >= D2PI { 0.0 } else { w }
+            }
+            /* anpm gives -pi or +pi by the sign of a. */
+            Wrap::Signed => {
+                let w = anpm(a);
+                if w <= -DPI { DPI } else { w }
+            }
+        }
+    }
+}
+
+///  An angle that stays normalized
+///
+///  ```text
+///     radians   the angle, always within the range of wrap
+///     wrap      Wrap::Positive (0-2pi) or Wrap::Signed (+/-pi)
+///  ```
+///  Every operation normalizes the result, so that sums and
+///  differences of, say, sidereal times need no further `anp`.  The
+///  result of a binary operation has the convention of the left
+///  operand;  `diff` gives the shortest signed separation.  For a time
+///  series that should increase steadily (ERA, GST), take the raw
+///  values to `unwrap_continuous`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct WrappedAngle {
+    radians: f64,
+    wrap: Wrap,
+}
+
+impl WrappedAngle {
+    pub fn new(radians: f64, wrap: Wrap) -> Self {
+        WrappedAngle { radians: wrap.apply(radians), wrap }
+    }
+
+    /// In the range 0-2pi.
+    pub fn positive(radians: f64) -> Self {
+        Self::new(radians, Wrap::Positive)
+    }
+
+    /// In the range +/-pi.
+    pub fn signed(radians: f64) -> Self {
+        Self::new(radians, Wrap::Signed)
+    }
+
+    pub fn radians(&self) -> f64 {
+        self.radians
+    }
+
+    pub fn wrap(&self) -> Wrap {
+        self.wrap
+    }
+
+    /// The same angle in the other convention.
+    pub fn with_wrap(&self, wrap: Wrap) -> Self {
+        Self::new(self.radians, wrap)
+    }
+
+    /// Shortest signed separation self - other (radians, +/-pi).
+    pub fn diff(&self, other: &WrappedAngle) -> f64 {
+        Wrap::Signed.apply(self.radians - other.radians)
+    }
+}
+
+impl From<WrappedAngle> for f64 {
+    fn from(a: WrappedAngle) -> Self {
+        a.radians
+    }
+}
+
+impl From<WrappedAngle> for Angle {
+    fn from(a: WrappedAngle) -> Self {
+        Angle(a.radians)
+    }
+}
+
+impl Add for WrappedAngle {
+    type Output = WrappedAngle;
+    fn add(self, rhs: WrappedAngle) -> WrappedAngle {
+        WrappedAngle::new(self.radians + rhs.radians, self.wrap)
+    }
+}
+
+impl Sub for WrappedAngle {
+    type Output = WrappedAngle;
+    fn sub(self, rhs: WrappedAngle) -> WrappedAngle {
+        WrappedAngle::new(self.radians - rhs.radians, self.wrap)
+    }
+}
+
+impl Add<f64> for WrappedAngle {
+    type Output = WrappedAngle;
+    fn add(self, rhs: f64) -> WrappedAngle {
+        WrappedAngle::new(self.radians + rhs, self.wrap)
+    }
+}
+
+impl Sub<f64> for WrappedAngle {
+    type Output = WrappedAngle;
+    fn sub(self, rhs: f64) -> WrappedAngle {
+        WrappedAngle::new(self.radians - rhs, self.wrap)
+    }
+}
+
+impl Mul<f64> for WrappedAngle {
+    type Output = WrappedAngle;
+    fn mul(self, rhs: f64) -> WrappedAngle {
+        WrappedAngle::new(self.radians * rhs, self.wrap)
+    }
+}
+
+impl Neg for WrappedAngle {
+    type Output = WrappedAngle;
+    fn neg(self) -> WrappedAngle {
+        WrappedAngle::new(-self.radians, self.wrap)
+    }
+}
+
+impl AddAssign<f64> for WrappedAngle {
+    fn add_assign(&mut self, rhs: f64) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<f64> for WrappedAngle {
+    fn sub_assign(&mut self, rhs: f64) {
+        *self = *self - rhs;
+    }
+}
+
+///  Removes the 2pi jumps from a series of angles
+///
+///  Each value is moved by a whole number of turns so that it differs
+///  from the previous result by at most pi:
+///  ```text
+///     let mut u = AngleUnwrapper::default();
+///     let era: Vec<f64> = eras.iter().map(|&a| u.push(a)).collect();
+///  ```
+///  The first value is kept as it is.  The steps of the series must be
+///  less than half a turn.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AngleUnwrapper {
+    last: Option<f64>,
+}
+
+impl AngleUnwrapper {
+    /// The next angle (radians), made continuous with the previous.
+    pub fn push(&mut self, a: f64) -> f64 {
+        let w = match self.last {
+            None => a,
+            Some(p) => p + Wrap::Signed.apply(a - p),
+        };
+        self.last = Some(w);
+        w
+    }
+}
+
+///  A series of angles (radians or WrappedAngle) made continuous, see
+///  `AngleUnwrapper`.
+pub fn unwrap_continuous<T: Into<f64>>(angles: impl IntoIterator<Item = T>) -> Vec<f64> {
+    let mut u = AngleUnwrapper::default();
+    angles.into_iter().map(|a| u.push(a.into())).collect()
+}
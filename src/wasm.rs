@@ -0,0 +1,150 @@
+//! JavaScript bindings
+//!
+//! wasm-bindgen wrappers for the high-level API, for use from a web
+//! page or Node.js:
+//! ```text
+//!     Epoch          scale-tagged epoch:  new Epoch("UTC", jd1, jd2),
+//!                    Epoch.parse("2024-03-01T12:00:00Z"), .to("TT") ...
+//!     gcrsToItrs     frame matrices, 9 numbers row by row
+//!     itrsToGcrs
+//!     atco13         ICRS to observed place, the full astrometry chain
+//!     refco          refraction constants
+//!     epv00          Earth position and velocity
+//! ```
+//! Vectors and matrices are returned as Float64Array, errors are thrown
+//! as Error with the SOFA status in the message.
+//!
+//! The "wasm" feature enables the "libm" feature as well, so that
+//! results in the browser are bit-for-bit those of a native build with
+//! "libm".  Nothing here, nor the leap second table used by the time
+//! scale functions, touches the filesystem.  Build with
+//! ```text
+//!     wasm-pack build --target web -- --features wasm
+//! ```
+//! Requires the "wasm" feature.
+use wasm_bindgen::prelude::*;
+
+use crate::astro;
+use crate::eph;
+use crate::frames::{self, EarthOrientation};
+use crate::ts::{self, TimeScale};
+
+fn status(j: i32) -> JsError {
+    JsError::new(&format!("SOFA status {}", j))
+}
+
+fn scale(name: &str) -> Result<TimeScale, JsError> {
+    TimeScale::from_name(name).ok_or_else(|| JsError::new(&format!("unknown time scale {}", name)))
+}
+
+fn flat(m: &[[f64; 3]]) -> Vec<f64> {
+    m.iter().flatten().copied().collect()
+}
+
+/// Scale-tagged epoch (see `ts::Epoch`).
+#[wasm_bindgen(js_name = Epoch)]
+#[derive(Clone, Copy)]
+pub struct JsEpoch(ts::Epoch);
+
+#[wasm_bindgen(js_class = Epoch)]
+impl JsEpoch {
+    #[wasm_bindgen(constructor)]
+    pub fn new(scale_: &str, jd1: f64, jd2: f64) -> Result<JsEpoch, JsError> {
+        Ok(JsEpoch(ts::Epoch::new(scale(scale_)?, (jd1, jd2).into())))
+    }
+
+    /// Epoch from an ISO 8601 string carrying its scale.
+    pub fn parse(s: &str) -> Result<JsEpoch, JsError> {
+        ts::Epoch::parse(s).map(JsEpoch).map_err(status)
+    }
+
+    /// Epoch from an ISO 8601 string in the given scale.
+    #[wasm_bindgen(js_name = fromIso)]
+    pub fn from_iso(s: &str, scale_: &str) -> Result<JsEpoch, JsError> {
+        ts::Epoch::from_iso(s, scale(scale_)?).map(JsEpoch).map_err(status)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn scale(&self) -> String {
+        self.0.scale.name().to_string()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn jd1(&self) -> f64 {
+        self.0.jd.0
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn jd2(&self) -> f64 {
+        self.0.jd.1
+    }
+
+    /// The same instant in another scale.
+    pub fn to(&self, scale_: &str) -> Result<JsEpoch, JsError> {
+        self.0.to(scale(scale_)?).map(JsEpoch).map_err(status)
+    }
+
+    /// UT1, given UT1-UTC (seconds).
+    #[wasm_bindgen(js_name = toUt1)]
+    pub fn to_ut1(&self, dut1: f64) -> Result<JsEpoch, JsError> {
+        self.0.to_ut1(dut1).map(JsEpoch).map_err(status)
+    }
+
+    /// ISO 8601 string to ndp decimal places in the seconds.
+    #[wasm_bindgen(js_name = toIso)]
+    pub fn to_iso(&self, ndp: i32) -> Result<String, JsError> {
+        self.0.to_iso(ndp).map_err(status)
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// GCRS to ITRS matrix, given UT1-UTC (s), polar motion (radians)
+/// and CIP corrections (radians).
+#[wasm_bindgen(js_name = gcrsToItrs)]
+pub fn gcrs_to_itrs(epoch: &JsEpoch, ut1_utc: f64, xp: f64, yp: f64, dx: f64, dy: f64)
+                    -> Result<Vec<f64>, JsError> {
+    let eo = EarthOrientation { ut1_utc, xp, yp, dx, dy };
+    let r = frames::gcrs_to_itrs(&epoch.0, &eo).map_err(status)?;
+    Ok(flat(&r.to_array()))
+}
+
+/// ITRS to GCRS matrix (the transpose of `gcrsToItrs`).
+#[wasm_bindgen(js_name = itrsToGcrs)]
+pub fn itrs_to_gcrs(epoch: &JsEpoch, ut1_utc: f64, xp: f64, yp: f64, dx: f64, dy: f64)
+                    -> Result<Vec<f64>, JsError> {
+    let eo = EarthOrientation { ut1_utc, xp, yp, dx, dy };
+    let r = frames::itrs_to_gcrs(&epoch.0, &eo).map_err(status)?;
+    Ok(flat(&r.to_array()))
+}
+
+/// ICRS catalog place to observed place (see `astro::atco13`):
+/// [aob, zob, hob, dob, rob, eo].
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen]
+pub fn atco13(rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64,
+              utc1: f64, utc2: f64, dut1: f64, elong: f64, phi: f64, hm: f64,
+              xp: f64, yp: f64, phpa: f64, tc: f64, rh: f64, wl: f64) -> Result<Vec<f64>, JsError> {
+    let (aob, zob, hob, dob, rob, eo) = astro::atco13(rc, dc, pr, pd, px, rv, utc1, utc2, dut1,
+                                                      elong, phi, hm, xp, yp, phpa, tc, rh, wl)
+        .map_err(status)?;
+    Ok(vec![aob, zob, hob, dob, rob, eo])
+}
+
+/// Refraction constants [refa, refb] (radians).
+#[wasm_bindgen]
+pub fn refco(phpa: f64, tc: f64, rh: f64, wl: f64) -> Vec<f64> {
+    let (a, b) = astro::refco(phpa, tc, rh, wl);
+    vec![a, b]
+}
+
+/// Earth heliocentric and barycentric position and velocity (au,
+/// au/day) for a TDB date:  [ph, vh, pb, vb], 12 numbers.
+#[wasm_bindgen]
+pub fn epv00(date1: f64, date2: f64) -> Result<Vec<f64>, JsError> {
+    let (h, b) = eph::epv00(date1, date2).ok_or_else(|| status(1))?;
+    Ok(flat(&[h[0], h[1], b[0], b[1]]))
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "rayon")]
+use sofars::astro::{apci13, apco13, atccq, atciq, atioq};
+use sofars::batch::*;
+use sofars::star::Star;
+use sofars::vm::{anpm, RotMat};
+
+fn catalog(n: usize) -> Vec<Star> {
+    (0..n)
+        .map(|i| {
+            let f = i as f64 / n as f64;
+            Star::new(6.2 * f, 1.5 * (2.0 * f - 1.0), 1e-5 * f, -5e-6, 0.1 * f, 55.0 - 20.0 * f)
+        })
+        .collect()
+}
+
+#[test]
+fn test_atciq_par() {
+    let (astrom, _) = apci13(2456165.5, 0.401182685);
+    let astrom = &astrom;
+
+    /* SOFA reference star. */
+    let s = Star::new(2.71, 0.174, 1e-5, 5e-6, 0.1, 55.0);
+    let r = atciq_par(&[s], astrom);
+    assert!((r[0].0 - 2.710121572968696744).abs() < 1e-12, "atciq_par: ri");
+    assert!((r[0].1 - 0.1729371367219539137).abs() < 1e-12, "atciq_par: di");
+
+    /* Identical to the scalar function, in order. */
+    let stars = catalog(1000);
+    let ci = atciq_par(&stars, astrom);
+    let cc = atccq_par(&stars, astrom);
+    for (s, (i, c)) in stars.iter().zip(ci.iter().zip(&cc)) {
+        assert_eq!(*i, atciq(s.ra, s.dec, s.pmr, s.pmd, s.px, s.rv, astrom), "atciq_par");
+        assert_eq!(*c, atccq(s.ra, s.dec, s.pmr, s.pmd, s.px, s.rv, astrom), "atccq_par");
+    }
+}
+
+#[test]
+fn test_atioq_par() {
+    let (astrom, _) = apco13(2456384.5, 0.969254051, 0.1550675, -0.527800806, -1.2345856, 2738.0,
+                             2.47230737e-7, 1.82640464e-6, 731.0, 12.8, 0.59, 0.55).unwrap();
+    let astrom = &astrom;
+
+    let cirs: Vec<(f64, f64)> = (0..500).map(|i| (0.01 * i as f64, -0.7 + 0.002 * i as f64)).collect();
+    let mut out = vec![(0.0, 0.0, 0.0, 0.0, 0.0); cirs.len()];
+    atioq_par_into(&cirs, astrom, &mut out);
+    for (c, o) in cirs.iter().zip(&out) {
+        assert_eq!(*o, atioq(c.0, c.1, astrom), "atioq_par");
+    }
+}
+
+#[test]
+fn test_rotate_par() {
+    let r = RotMat::rz(0.3) * RotMat::rx(0.4);
+    let dirs = [(0.0, 0.0), (1.0, 0.5), (5.0, -1.2)];
+    let fwd = rotate_par(&r, &dirs);
+    let back = rotate_par(&r.transpose(), &fwd);
+    for (a, b) in dirs.iter().zip(&back) {
+        assert!(anpm(a.0 - b.0).abs() < 1e-14 && (a.1 - b.1).abs() < 1e-14, "rotate_par");
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_par_length() {
+    let mut out = [(0.0, 0.0); 2];
+    rotate_par_into(&RotMat::identity(), &[(0.0, 0.0)], &mut out);
+}
@@ -8,4 +8,113 @@ mod tests {
         assert_eq!(jd.0, 2400000.5);
         assert_eq!(jd.1, 52791.0);
     }
+
+    #[test]
+    fn test_jd() {
+        // Normalization to midnight + fraction.
+        let jd = Jd(2451545.0, -1421.3).normalize();
+        assert_eq!(jd.0, 2450123.5);
+        assert!((jd.1 - 0.2).abs() < 1e-9);
+
+        // A microsecond survives arithmetic that a single f64 loses.
+        let t0 = Jd(2460000.5, 0.25);
+        let t1 = t0.add_seconds(1e-6);
+        assert!(((t1 - t0) * 86400.0 - 1e-6).abs() < 1e-11);
+        assert!(t1 > t0);
+
+        // Whole days are carried into the first part.
+        let t2 = t0 + 3.75;
+        assert_eq!(t2.0, 2460004.5);
+        assert!((t2.1 - 0.0).abs() < 1e-15);
+        assert_eq!(Jd(2460004.0, 0.5), t2);
+
+        let (d1, d2): (f64, f64) = Jd::from_mjd(53736.0).into();
+        assert_eq!((d1, d2), (2400000.5, 53736.0));
+
+        let (y, m, d, fd) = Jd(2400000.5, 50123.9999).eval(jd2cal).unwrap();
+        assert_eq!((y, m, d), (1996, 2, 10));
+        assert!((fd - 0.9999).abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_epj() {
+        let epj = epj(2451545.0, -7392.5);
+        assert!((epj - 1979.760438056125941).abs() < 1e-12);
+
+        let (djm0, djm) = epj2jd(1996.8);
+        assert!((djm0 - 2400000.5).abs() < 1e-9);
+        assert!((djm - 50375.7).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_calendar_mode() {
+        use CalendarMode::{Gregorian, Julian};
+        let reform = CalendarMode::REFORM_1582;
+
+        /* The reform:  Thursday 4 October (Julian) then Friday 15 October. */
+        assert_eq!(cal2jd_mode(Julian, 1582, 10, 4).unwrap().1, -100841.0);
+        assert_eq!(cal2jd_mode(Gregorian, 1582, 10, 15).unwrap().1, -100840.0);
+        assert_eq!(cal2jd_mode(reform, 1582, 10, 4).unwrap().1, -100841.0);
+        assert_eq!(cal2jd_mode(reform, 1582, 10, 15).unwrap().1, -100840.0);
+        assert_eq!(cal2jd_mode(reform, 1582, 10, 10), Err(-4));
+        assert_eq!(jd2cal_mode(reform, 2400000.5, -100841.0).unwrap(), (1582, 10, 4, 0.0));
+        assert_eq!(jd2cal_mode(reform, 2299160.5, 0.75).unwrap(), (1582, 10, 15, 0.75));
+
+        /* JD 0 is noon on 1 January 4713 BC (Julian). */
+        let (y, m, d, f) = jd2cal_mode(Julian, 0.0, 0.0).unwrap();
+        assert_eq!((y, m, d), (-4712, 1, 1));
+        assert!((f - 0.5).abs() < 1e-15);
+        let (d0, d1) = cal2jd_mode(reform, -4712, 1, 1).unwrap();
+        assert_eq!(d0 + d1, -0.5);
+
+        /* 1700 was a leap year in Britain but not in Rome. */
+        assert!(CalendarMode::BRITAIN_1752.is_leap(1700));
+        assert!(!reform.is_leap(1700));
+        assert_eq!(cal2jd_mode(reform, 1700, 2, 29), Err(-3));
+        let (_, m1) = cal2jd_mode(CalendarMode::BRITAIN_1752, 1700, 2, 29).unwrap();
+        assert_eq!(jd2cal_mode(reform, 2400000.5, m1).unwrap(), (1700, 3, 11, 0.0));
+        assert_eq!(Julian.days_in_month(1900, 2), 29);
+        assert_eq!(Gregorian.days_in_month(1900, 2), 28);
+        assert_eq!(Gregorian.days_in_month(2000, 4), 30);
+
+        /* Gregorian mode is cal2jd and jd2cal, over their whole range. */
+        for iy in (-4700..3000).step_by(37) {
+            for im in [1, 2, 3, 12] {
+                let a = cal2jd(iy, im, 28).unwrap();
+                assert_eq!(cal2jd_mode(Gregorian, iy, im, 28).unwrap(), a, "cal2jd_mode {} {}", iy, im);
+                assert_eq!(jd2cal_mode(Gregorian, a.0, a.1 + 0.3).unwrap(), jd2cal(a.0, a.1 + 0.3).unwrap());
+            }
+        }
+
+        /* Round trips far beyond them, in both calendars. */
+        for mode in [Gregorian, Julian, reform] {
+            for iy in [-999_999, -100_000, -4801, -1, 0, 1, 1582, 1_000_000] {
+                for (im, id) in [(1, 1), (2, 28), (3, 1), (12, 31)] {
+                    let (d0, d1) = cal2jd_mode(mode, iy, im, id).unwrap();
+                    assert_eq!(jd2cal_mode(mode, d0, d1 + 0.25).unwrap(), (iy, im, id, 0.25),
+                               "{:?} {} {} {}", mode, iy, im, id);
+                }
+            }
+        }
+
+        /* Errors. */
+        assert_eq!(cal2jd_mode(Julian, 1_000_001, 1, 1), Err(-1));
+        assert_eq!(cal2jd_mode(Julian, 2000, 13, 1), Err(-2));
+        assert_eq!(cal2jd_mode(Julian, 2000, 4, 31), Err(-3));
+        assert_eq!(cal2jd_mode(Julian, 2000, 4, 0), Err(-3));
+        assert_eq!(jd2cal_mode(Julian, 4e8, 0.0), Err(-1));
+        assert_eq!(jd2cal_mode(Julian, f64::NAN, 0.0), Err(-1));
+        assert_eq!(jd2cal(f64::NAN, 0.0), Err(-1));
+        assert_eq!(cal2jd(-4800, 1, 1), Err(-1));
+        assert!(cal2jd_mode(Gregorian, -4800, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_jd_forms() {
+        let jd = Jd(2400000.5, 50123.9999);
+        assert_eq!(epb_jd(jd), epb(2400000.5, 50123.9999));
+        assert_eq!(epj_jd((2400000.5, 50123.9999)), epj(2400000.5, 50123.9999));
+        assert_eq!(jd2cal_jd(jd), jd2cal(2400000.5, 50123.9999));
+        assert_eq!(jd2cal_mode_jd(CalendarMode::Julian, jd), jd2cal_mode(CalendarMode::Julian, 2400000.5, 50123.9999));
+    }
 }
@@ -0,0 +1,111 @@
+#![cfg(feature = "capi")]
+use std::ptr::null_mut;
+
+use sofars::astro::IauAstrom;
+use sofars::capi::*;
+
+#[test]
+fn test_capi_time() {
+    let (mut d1, mut d2) = (0.0, 0.0);
+    assert_eq!(unsafe { iauCal2jd(2003, 6, 1, &mut d1, &mut d2) }, 0);
+    assert_eq!((d1, d2), (2400000.5, 52791.0), "iauCal2jd");
+
+    let j = unsafe { iauDtf2d(c"UTC".as_ptr(), 1994, 6, 30, 23, 59, 60.13599, &mut d1, &mut d2) };
+    assert_eq!(j, 0);
+    assert!((d1 + d2 - 2449534.49999).abs() < 1e-6, "iauDtf2d");
+
+    let (mut iy, mut im, mut id, mut ihmsf) = (0, 0, 0, [0; 4]);
+    let j = unsafe { iauD2dtf(c"UTC".as_ptr(), 5, 2400000.5, 49533.99999,
+                              &mut iy, &mut im, &mut id, &mut ihmsf) };
+    assert_eq!(j, 0);
+    assert_eq!((iy, im, id, ihmsf), (1994, 6, 30, [23, 59, 60, 13599]), "iauD2dtf");
+
+    /* Error status passes through; null outputs are skipped. */
+    assert_eq!(unsafe { iauCal2jd(-4800, 1, 1, null_mut(), null_mut()) }, -1);
+    assert_eq!(unsafe { iauUtctai(2453750.5, 0.892100694, null_mut(), null_mut()) }, 0);
+
+    /* A null scale name is taken as not UTC. */
+    let (mut t1, mut t2) = (0.0, 0.0);
+    let j = unsafe { iauDtf2d(std::ptr::null(), 1994, 6, 30, 12, 0, 0.0, &mut d1, &mut d2) };
+    let k = unsafe { iauDtf2d(c"TAI".as_ptr(), 1994, 6, 30, 12, 0, 0.0, &mut t1, &mut t2) };
+    assert_eq!((j, d1, d2), (k, t1, t2), "iauDtf2d null");
+
+    /* Before 1960:  status +1, with the outputs written as in SOFA. */
+    let mut deltat = -1.0;
+    assert_eq!(unsafe { iauDat(1950, 6, 1, 0.0, &mut deltat) }, 1);
+    assert_eq!(deltat, 0.0, "iauDat 1950");
+    assert_eq!(unsafe { iauDat(2003, 6, 1, 1.5, &mut deltat) }, -4);
+    assert_eq!(deltat, 0.0, "iauDat bad fd");
+
+    let j = unsafe { iauUtctai(2433433.5, 0.25, &mut t1, &mut t2) };
+    assert_eq!((j, t1, t2), (1, 2433433.5, 0.25), "iauUtctai 1950");
+    let j = unsafe { iauTaiutc(2433433.5, 0.25, &mut t1, &mut t2) };
+    assert_eq!((j, t1, t2), (1, 2433433.5, 0.25), "iauTaiutc 1950");
+    let j = unsafe { iauUtcut1(2433433.5, 0.25, 0.5, &mut t1, &mut t2) };
+    assert_eq!(j, 1);
+    assert!(((t1 - 2433433.5) + (t2 - 0.25) - 0.5 / 86400.0).abs() < 1e-12, "iauUtcut1 1950");
+
+    let j = unsafe { iauDtf2d(c"UTC".as_ptr(), 1950, 6, 1, 6, 0, 0.0, &mut d1, &mut d2) };
+    assert_eq!((j, d1, d2), (1, 2433433.5, 0.25), "iauDtf2d 1950");
+    let j = unsafe { iauDtf2d(c"UTC".as_ptr(), 1950, 6, 1, 6, 0, 60.5, &mut d1, &mut d2) };
+    assert_eq!(j, 3, "iauDtf2d 1950, 60.5s");
+
+    let j = unsafe { iauD2dtf(c"UTC".as_ptr(), 3, 2433433.5, 0.25,
+                              &mut iy, &mut im, &mut id, &mut ihmsf) };
+    assert_eq!((j, iy, im, id, ihmsf), (1, 1950, 6, 1, [6, 0, 0, 0]), "iauD2dtf 1950");
+}
+
+#[test]
+fn test_capi_refco_pn00() {
+    let (mut refa, mut refb) = (0.0, 0.0);
+    unsafe { iauRefco(800.0, 10.0, 0.9, 0.4, &mut refa, &mut refb) };
+    assert!((refa - 0.2264949956241415009e-3).abs() < 1e-15, "iauRefco refa");
+    assert!((refb - -0.2598658261729343970e-6).abs() < 1e-18, "iauRefco refb");
+
+    let mut epsa = 0.0;
+    let mut r = [[[0.0; 3]; 3]; 5];
+    let [rb, rp, rbp, rn, rbpn] = &mut r;
+    unsafe {
+        iauPn00(2400000.5, 53736.0, -0.9632552291149335877e-5, 0.4063197106621141414e-4,
+                &mut epsa, rb, rp, rbp, rn, rbpn)
+    };
+    assert!((epsa - 0.4090791789404229916).abs() < 1e-12, "iauPn00 epsa");
+    assert!((r[4][0][0] - 0.9999989440499982806).abs() < 1e-12, "iauPn00 rbpn11");
+}
+
+#[test]
+fn test_capi_epv00_astrom() {
+    let (mut pvh, mut pvb) = ([[0.0; 3]; 2], [[0.0; 3]; 2]);
+    assert_eq!(unsafe { iauEpv00(2400000.5, 53411.52501161, &mut pvh, &mut pvb) }, 0);
+    assert!((pvh[0][0] - -0.7757238809297706813).abs() < 1e-14, "iauEpv00 ph(x)");
+    assert!((pvb[1][2] - -0.005404773180966231279).abs() < 1e-15, "iauEpv00 vb(z)");
+
+    /* Outside 1900-2100:  a warning, the vectors still written. */
+    let (mut pvh, mut pvb) = ([[0.0; 3]; 2], [[0.0; 3]; 2]);
+    assert_eq!(unsafe { iauEpv00(2400000.5, 0.0, &mut pvh, &mut pvb) }, 1);
+    assert!(pvh[0].iter().map(|x| x * x).sum::<f64>() > 0.9, "iauEpv00 1858");
+    assert!(pvb[0].iter().map(|x| x * x).sum::<f64>() > 0.9, "iauEpv00 1858");
+
+    /* iauGd2gc zeroes the vector on error. */
+    let mut xyz = [1.0; 3];
+    assert_eq!(unsafe { iauGd2gc(1, 3.1, -0.5, 2500.0, &mut xyz) }, 0);
+    assert!((xyz[0] - -5599000.5577049947).abs() < 1e-7, "iauGd2gc x");
+    assert_eq!(unsafe { iauGd2gc(4, 3.1, -0.5, 2500.0, &mut xyz) }, -1);
+    assert_eq!(xyz, [0.0; 3]);
+
+    let mut astrom = IauAstrom::default();
+    let mut eo = 0.0;
+    unsafe { iauApci13(2456165.5, 0.401182685, &mut astrom, &mut eo) };
+    let (mut ri, mut di) = (0.0, 0.0);
+    unsafe { iauAtciq(2.71, 0.174, 1e-5, 5e-6, 0.1, 55.0, &astrom, &mut ri, &mut di) };
+    assert!((ri - 2.710121572968696744).abs() < 1e-12, "iauAtciq ri");
+    assert!((di - 0.1729371367219539137).abs() < 1e-12, "iauAtciq di");
+
+    /* A dubious year is a warning, the parameters still written. */
+    let mut astrom = IauAstrom::default();
+    let j = unsafe { iauApco13(2433433.5, 0.25, 0.3, -0.5278008060295995734, -1.2345856,
+                               2738.0, 2.47230737e-7, 1.82640464e-6, 731.0, 12.8, 0.59, 0.55,
+                               &mut astrom, &mut eo) };
+    assert_eq!(j, 1);
+    assert!(astrom.eral != 0.0, "iauApco13 1950");
+}
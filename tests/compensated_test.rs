@@ -0,0 +1,50 @@
+#![cfg(feature = "compensated")]
+use sofars::eph::epv00;
+use sofars::erst::gst06a;
+use sofars::pnp::{nut00a, nut06a, s06};
+
+/* The series summed with compensation agree with the SOFA test values
+   at the tolerances of the C test suite. */
+
+#[test]
+fn test_compensated_nut00a() {
+    let (dpsi, deps) = nut00a(2400000.5, 53736.0);
+    assert!((dpsi - -0.9630909107115518431e-5).abs() < 1e-13, "nut00a dpsi");
+    assert!((deps - 0.4063239174001678710e-4).abs() < 1e-13, "nut00a deps");
+
+    let (dpsi, deps) = nut06a(2400000.5, 53736.0);
+    assert!((dpsi - -0.9630912025820308797e-5).abs() < 1e-13, "nut06a dpsi");
+    assert!((deps - 0.4063238496887249798e-4).abs() < 1e-13, "nut06a deps");
+}
+
+#[test]
+fn test_compensated_epv00() {
+    let (pvh, pvb) = epv00(2400000.5, 53411.52501161).unwrap();
+    let h = [
+        [-0.7757238809297706813, 0.5598052241363340596, 0.2426998466481686993],
+        [-0.01091891824147313846, -0.01247187268440845008, -0.005407569418065039061],
+    ];
+    let b = [
+        [-0.7714104440491111971, 0.5598412061824171323, 0.2425996277722452400],
+        [-0.01091874268116823295, -0.01246525461732861538, -0.005404773180966231279],
+    ];
+    for i in 0..3 {
+        assert!((pvh[0][i] - h[0][i]).abs() < 1e-14, "ph[{}]", i);
+        assert!((pvh[1][i] - h[1][i]).abs() < 1e-15, "vh[{}]", i);
+        assert!((pvb[0][i] - b[0][i]).abs() < 1e-14, "pb[{}]", i);
+        assert!((pvb[1][i] - b[1][i]).abs() < 1e-15, "vb[{}]", i);
+    }
+
+    /* Outside 1900-2100 the warning is unchanged. */
+    assert!(epv00(2400000.5, 0.0).is_none());
+}
+
+#[test]
+fn test_compensated_s06() {
+    let s = s06(2400000.5, 53736.0, 0.5791308486706011000e-3, 0.4020579816732961219e-4);
+    assert!((s - -0.1220032213076463117e-7).abs() < 1e-18, "s06");
+
+    /* And through the equation of the origins. */
+    let g = gst06a(2400000.5, 53736.0, 2400000.5, 53736.0);
+    assert!((g - 1.754166137675019159).abs() < 1e-12, "gst06a");
+}
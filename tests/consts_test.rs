@@ -0,0 +1,44 @@
+use sofars::astro::IauLdBody;
+use sofars::consts::{iau2015, Body, ConstantSet, SRS};
+use sofars::eph::Planet;
+
+#[test]
+fn test_constant_set() {
+    let iau = ConstantSet::IAU2015;
+    assert_eq!(ConstantSet::default(), iau);
+    assert!((iau.srs(Body::Sun) - SRS).abs() < 1e-15, "IAU2015: srs");
+    assert_eq!(iau.gm(Body::Earth), iau2015::GM_EARTH);
+    assert_eq!(iau.radius(Body::Jupiter), iau2015::R_JUPITER_EQ);
+
+    /* Sun GM of the two ephemerides (km^3/s^2). */
+    let de405 = ConstantSet::DE405;
+    let de430 = ConstantSet::DE430;
+    assert!((de405.gm(Body::Sun) / 1e9 - 132712440017.987).abs() < 1e-2, "DE405: Sun");
+    assert!((de430.gm(Body::Sun) / 1e9 - 132712440041.9394).abs() < 1e-3, "DE430: Sun");
+
+    /* Earth and Moon add up to the barycenter, in the header ratio. */
+    let emb = de430.gm(Body::Earth) + de430.gm(Body::Moon);
+    assert!((emb / 1e9 - 403503.235502).abs() < 1e-6, "DE430: EMB");
+    let emrat = de430.gm(Body::Earth) / de430.gm(Body::Moon);
+    assert!((emrat - 81.30056907419062).abs() < 1e-9, "DE430: EMRAT");
+
+    /* Sun/Jupiter mass ratio:  the system for the ephemerides, near the
+       IAU 2009 value, and the planet alone for the nominal GM. */
+    assert!((1.0 / iau.mass_ratio(Body::Jupiter) - 1047.5655).abs() < 1e-3, "IAU2015: Jupiter");
+    for set in [de405, de430] {
+        let r = 1.0 / set.mass_ratio(Body::Jupiter);
+        assert!((r - 1047.3486).abs() < 1e-3, "{}: Jupiter {}", set.name, r);
+    }
+
+    /* Overrides. */
+    let gm5 = 2.959122082855911e-4 / 1047.3486;
+    let set = de430.named("test").with_gm_au(Body::Jupiter, gm5).with_radius(Body::Moon, 1.7374e6);
+    assert_eq!(set.name, "test");
+    assert!((set.gm(Body::Jupiter) / de405.gm(Body::Jupiter) - 1.0).abs() < 1e-9, "with_gm_au");
+    assert_eq!(set.radius(Body::Moon), 1.7374e6);
+    assert_eq!(set.gm(Body::Saturn), de430.gm(Body::Saturn));
+
+    let b = IauLdBody::new(&de405, Planet::Jupiter.into(), &[[0.0; 3]; 2], 3e-9);
+    assert!((b.bm - 1.0 / 1047.3486).abs() < 1e-15, "IauLdBody::new: bm");
+    assert_eq!(b.dl, 3e-9);
+}
@@ -0,0 +1,217 @@
+use sofars::consts::{GRS80, WGS72, WGS84};
+use sofars::coords::*;
+
+#[test]
+fn test_gc2gd() {
+    let xyz = [2e6, 3e6, 5.244e6];
+
+    let (e, p, h) = gc2gd(WGS84, &xyz).unwrap();
+    assert!((e - 0.9827937232473290680).abs() < 1e-14, "gc2gd e1");
+    assert!((p - 0.97160184819075459).abs() < 1e-14, "gc2gd p1");
+    assert!((h - 331.4172461426059892).abs() < 1e-8, "gc2gd h1");
+
+    let (e, p, h) = gc2gd(GRS80, &xyz).unwrap();
+    assert!((e - 0.9827937232473290680).abs() < 1e-14, "gc2gd e2");
+    assert!((p - 0.97160184820607853).abs() < 1e-14, "gc2gd p2");
+    assert!((h - 331.41731754844348).abs() < 1e-8, "gc2gd h2");
+
+    let (e, p, h) = gc2gd(WGS72, &xyz).unwrap();
+    assert!((e - 0.9827937232473290680).abs() < 1e-14, "gc2gd e3");
+    assert!((p - 0.9716018181101511937).abs() < 1e-14, "gc2gd p3");
+    assert!((h - 333.2770726130318123).abs() < 1e-8, "gc2gd h3");
+
+    assert_eq!(gc2gd(4, &xyz), Err(-1));
+}
+
+#[test]
+fn test_ellipsoid() {
+    assert_eq!(Ellipsoid::from_id(WGS84), Ok(Ellipsoid::WGS84));
+    assert_eq!(Ellipsoid::new(6378137.0, 1.0), Err(-1));
+    assert_eq!(Ellipsoid::new(0.0, 0.003), Err(-2));
+
+    // Round trip on a user-defined (Clarke 1866) ellipsoid.
+    let clarke = Ellipsoid::new(6378206.4, 1.0 / 294.978698214).unwrap();
+    let xyz = clarke.gd2gc(-1.3, 0.7, 1234.5).unwrap();
+    let (e, p, h) = clarke.gc2gd(&xyz).unwrap();
+    assert!((e - -1.3).abs() < 1e-14, "ellipsoid e");
+    assert!((p - 0.7).abs() < 1e-14, "ellipsoid p");
+    assert!((h - 1234.5).abs() < 1e-8, "ellipsoid h");
+}
+
+#[test]
+fn test_helmert() {
+    let xyz = [4027893.6719, 307045.9064, 4919475.1704];
+
+    // Zero rotation case is a pure translation plus scale.
+    let t = ITRF2020_TO_ITRF2008.at(2020.0);
+    assert!((t.tz - 3.8e-3).abs() < 1e-15, "helmert tz");
+    assert!((t.d - (-0.14e-9)).abs() < 1e-20, "helmert d");
+
+    let x2 = ITRF2020_TO_ITRF2008.apply(&xyz, 2020.0);
+    assert!((x2[0] - (xyz[0] + 0.2e-3 - 0.14e-9 * xyz[0])).abs() < 1e-9, "helmert x");
+    assert!((x2[2] - (xyz[2] + 3.8e-3 - 0.14e-9 * xyz[2])).abs() < 1e-9, "helmert z");
+
+    // Rotation about z moves x into y.
+    let r = Helmert::iers(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+    let p = r.apply(&[6378137.0, 0.0, 0.0]);
+    assert!((p[1] - 6378137.0 * 1e-3 * sofars::consts::DAS2R).abs() < 1e-12, "helmert rz");
+
+    // Inverse undoes the transformation to first order.
+    let back = ITRF2020_TO_ITRF2008.inverse().apply(&x2, 2020.0);
+    for i in 0..3 {
+        assert!((back[i] - xyz[i]).abs() < 1e-9, "helmert inverse");
+    }
+
+    let (_, v) = ITRF2020_TO_ITRF2014.apply_pv(&xyz, &[0.0; 3], 2020.0);
+    assert!((v[2] - 0.2e-3).abs() < 1e-15, "helmert vz");
+}
+
+#[test]
+fn test_icrs2g() {
+    let (dl, db) = icrs2g(5.9338074302227188048671087, -1.1784870613579944551540570);
+    assert!((dl - 5.5850536063818546461558).abs() < 1e-14, "icrs2g: L");
+    assert!((db - -0.7853981633974483096157).abs() < 1e-14, "icrs2g: B");
+
+    let (dr, dd) = g2icrs(5.5850536063818546461558105, -0.7853981633974483096156608);
+    assert!((dr - 5.9338074302227188048671).abs() < 1e-14, "g2icrs: R");
+    assert!((dd - -1.1784870613579944551541).abs() < 1e-14, "g2icrs: D");
+}
+
+#[test]
+fn test_galactic_pm() {
+    /* A pure rotation:  the angular speed is unchanged. */
+    let (dr, dd, pmr, pmd) = (1.2, 0.4, 3e-8, -2e-8);
+    let (dl, db, pml, pmb) = icrs2gpm(dr, dd, pmr, pmd);
+    let speed = |b: f64, pma: f64, pmb: f64| ((pma * b.cos()).powi(2) + pmb * pmb).sqrt();
+    assert!((speed(db, pml, pmb) - speed(dd, pmr, pmd)).abs() < 1e-22, "icrs2gpm: speed");
+    assert_eq!(icrs2g(dr, dd), (dl, db));
+
+    /* Against a central difference over a century. */
+    let (l1, b1) = icrs2g(dr - 50.0 * pmr, dd - 50.0 * pmd);
+    let (l2, b2) = icrs2g(dr + 50.0 * pmr, dd + 50.0 * pmd);
+    assert!(((l2 - l1) / 100.0 - pml).abs() < 1e-16, "icrs2gpm: pml");
+    assert!(((b2 - b1) / 100.0 - pmb).abs() < 1e-16, "icrs2gpm: pmb");
+
+    let (r, d, pr, pd) = g2icrspm(dl, db, pml, pmb);
+    assert!((r - dr).abs() < 1e-14, "g2icrspm: R");
+    assert!((d - dd).abs() < 1e-14, "g2icrspm: D");
+    assert!((pr - pmr).abs() < 1e-20, "g2icrspm: pmr");
+    assert!((pd - pmd).abs() < 1e-20, "g2icrspm: pmd");
+
+    let (sgl, sgb, pmsl, pmsb) = g2sgpm(dl, db, pml, pmb);
+    assert_eq!(g2sg(dl, db), (sgl, sgb));
+    let (l, b, pl, pb) = sg2gpm(sgl, sgb, pmsl, pmsb);
+    assert!((l - dl).abs() < 1e-14, "sg2gpm: L");
+    assert!((b - db).abs() < 1e-14, "sg2gpm: B");
+    assert!((pl - pml).abs() < 1e-20, "sg2gpm: pml");
+    assert!((pb - pmb).abs() < 1e-20, "sg2gpm: pmb");
+}
+
+#[test]
+fn test_g2sg() {
+    use std::f64::consts::FRAC_PI_2;
+    let d2r = std::f64::consts::PI / 180.0;
+
+    /* Supergalactic origin and north pole. */
+    let (sgl, sgb) = g2sg(137.37 * d2r, 0.0);
+    assert!(sgl.abs() < 1e-14 || (sgl - 2.0 * std::f64::consts::PI).abs() < 1e-14, "g2sg: SGL");
+    assert!(sgb.abs() < 1e-14, "g2sg: SGB");
+    let (_, sgb) = g2sg(47.37 * d2r, 6.32 * d2r);
+    assert!((sgb - FRAC_PI_2).abs() < 1e-7, "g2sg: pole");
+
+    let (dl, db) = sg2g(1.0, -0.3);
+    let (sgl, sgb) = g2sg(dl, db);
+    assert!((sgl - 1.0).abs() < 1e-14, "sg2g: SGL");
+    assert!((sgb - -0.3).abs() < 1e-14, "sg2g: SGB");
+}
+
+#[test]
+fn test_uvw() {
+    use sofars::star::Star;
+
+    /* Radial velocity only, towards the Galactic center and pole. */
+    let (ra, dec) = g2icrs(0.0, 0.0);
+    let v = uvw(&Star::new(ra, dec, 0.0, 0.0, 0.1, -20.0)).unwrap();
+    assert!((v[0] - -20.0).abs() < 1e-12, "uvw: U");
+    assert!(v[1].abs() < 1e-12 && v[2].abs() < 1e-12, "uvw: V,W");
+    let (ra, dec) = g2icrs(0.0, std::f64::consts::FRAC_PI_2);
+    let v = uvw(&Star::fixed(ra, dec)).map(|_| ());
+    assert_eq!(v, Err(-1));
+
+    /* 1 arcsec/year at 1 pc is 1 au/year. */
+    let (ra, dec) = g2icrs(0.0, 0.0);
+    let (_, _, pmr, pmd) = g2icrspm(0.0, 0.0, 1.0 / 206264.8062470964, 0.0);
+    let v = uvw(&Star::new(ra, dec, pmr, pmd, 1.0, 0.0)).unwrap();
+    assert!(v[0].abs() < 1e-12, "uvw: U");
+    assert!((v[1] - 4.740470463533348).abs() < 1e-12, "uvw: V");
+    assert!(v[2].abs() < 1e-12, "uvw: W");
+
+    let star = Star::new(0.8, -0.5, -2.1e-7, 3.4e-7, 0.05, 37.5);
+    let v = uvw(&star).unwrap();
+    let s = uvw2star(star.ra, star.dec, star.px, &v).unwrap();
+    assert!((s.pmr - star.pmr).abs() < 1e-20, "uvw2star: pmr");
+    assert!((s.pmd - star.pmd).abs() < 1e-20, "uvw2star: pmd");
+    assert!((s.rv - star.rv).abs() < 1e-12, "uvw2star: rv");
+    assert_eq!(uvw2star(0.8, -0.5, 0.0, &v), Err(-1));
+}
+
+#[test]
+fn test_ecm06() {
+    let rm = ecm06(2456165.5, 0.401182685);
+
+    assert!((rm[0][0] - 0.9999952427708701137).abs() < 1e-14, "ecm06: rm11");
+    assert!((rm[0][1] - -0.2829062057663042347e-2).abs() < 1e-14, "ecm06: rm12");
+    assert!((rm[0][2] - -0.1229163741100017629e-2).abs() < 1e-14, "ecm06: rm13");
+    assert!((rm[1][0] - 0.3084546876908653562e-2).abs() < 1e-14, "ecm06: rm21");
+    assert!((rm[1][1] - 0.9174891871550392514).abs() < 1e-14, "ecm06: rm22");
+    assert!((rm[1][2] - 0.3977487611849338124).abs() < 1e-14, "ecm06: rm23");
+    assert!((rm[2][0] - 0.2488512951527405928e-5).abs() < 1e-14, "ecm06: rm31");
+    assert!((rm[2][1] - -0.3977506604161195467).abs() < 1e-14, "ecm06: rm32");
+    assert!((rm[2][2] - 0.9174935488232863071).abs() < 1e-14, "ecm06: rm33");
+}
+
+#[test]
+fn test_eqec06() {
+    let (dl, db) = eqec06(1234.5, 2440000.5, 1.234, 0.987);
+    assert!((dl - 1.342509918994654619).abs() < 1e-14, "eqec06: dl");
+    assert!((db - 0.5926215259704608132).abs() < 1e-14, "eqec06: db");
+
+    let (dr, dd) = eceq06(2456165.5, 0.401182685, 5.1, -0.9);
+    assert!((dr - 5.533459733613627767).abs() < 1e-14, "eceq06: dr");
+    assert!((dd - -1.246542932554480576).abs() < 1e-14, "eceq06: dd");
+}
+
+#[test]
+fn test_ltecm() {
+    let rm = ltecm(-3000.0);
+
+    assert!((rm[0][0] - 0.3564105644859788825).abs() < 1e-14, "ltecm: rm11");
+    assert!((rm[0][1] - 0.8530575738617682284).abs() < 1e-14, "ltecm: rm12");
+    assert!((rm[0][2] - 0.3811355207795060435).abs() < 1e-14, "ltecm: rm13");
+    assert!((rm[1][0] - -0.9343283469640709942).abs() < 1e-14, "ltecm: rm21");
+    assert!((rm[1][1] - 0.3247830597681745976).abs() < 1e-14, "ltecm: rm22");
+    assert!((rm[1][2] - 0.1467872751535940865).abs() < 1e-14, "ltecm: rm23");
+    assert!((rm[2][0] - 0.1431636191201167793e-2).abs() < 1e-14, "ltecm: rm31");
+    assert!((rm[2][1] - -0.4084222566960599342).abs() < 1e-14, "ltecm: rm32");
+    assert!((rm[2][2] - 0.9127919865189030899).abs() < 1e-14, "ltecm: rm33");
+}
+
+#[test]
+fn test_lteqec() {
+    let (dl, db) = lteqec(-1500.0, 1.234, 0.987);
+    assert!((dl - 0.5039483649047114859).abs() < 1e-14, "lteqec: dl");
+    assert!((db - 0.5848534459726224882).abs() < 1e-14, "lteqec: db");
+
+    let (dr, dd) = lteceq(2500.0, 1.5, 0.6);
+    assert!((dr - 1.275156021861921167).abs() < 1e-14, "lteceq: dr");
+    assert!((dd - 0.9966573543519204791).abs() < 1e-14, "lteceq: dd");
+}
+
+#[test]
+fn test_jd_forms() {
+    use sofars::cal::Jd;
+
+    assert_eq!(ecm06_jd(Jd(1234.5, 2440000.5)), ecm06(1234.5, 2440000.5));
+    assert_eq!(eqec06_jd((1234.5, 2440000.5), 1.234, 0.987), eqec06(1234.5, 2440000.5, 1.234, 0.987));
+    assert_eq!(eceq06_jd((2456165.5, 0.401182685), 5.1, -0.9), eceq06(2456165.5, 0.401182685, 5.1, -0.9));
+}
@@ -1,4 +1,5 @@
 use sofars::eph::*;
+use sofars::vm::pm;
 
 #[test]
 fn test_epv00() {
@@ -24,4 +25,113 @@ fn test_epv00() {
             panic!("Error: {:?}", "epv00() returned None");
         }
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_moon98() {
+    let pv = moon98(2400000.5, 43999.9);
+
+    assert!((pv[0][0] - -0.2601295959971044180e-2).abs() < 1e-11, "x 4");
+    assert!((pv[0][1] - 0.6139750944302742189e-3).abs() < 1e-11, "y 4");
+    assert!((pv[0][2] - 0.2640794528229828909e-3).abs() < 1e-11, "z 4");
+
+    assert!((pv[1][0] - -0.1244321506649895021e-3).abs() < 1e-11, "xd 4");
+    assert!((pv[1][1] - -0.5219076942678119398e-3).abs() < 1e-11, "yd 4");
+    assert!((pv[1][2] - -0.1716132214378462047e-3).abs() < 1e-11, "zd 4");
+}
+
+#[test]
+fn test_oscpv() {
+    use sofars::consts::GK;
+    use sofars::vm::pxp;
+
+    /* Energy and angular momentum for elliptic, parabolic and
+       hyperbolic orbits. */
+    for &e in &[0.0, 0.3, 0.97, 1.0, 1.4] {
+        let el = OscElements { q: 0.8, e, incl: 0.4, node: 1.2, argp: -0.7, tp: 2460000.5 };
+        let h0 = (GK * GK * 0.8 * (1.0 + e)).sqrt();
+        let en0 = -GK * GK * (1.0 - e) / (2.0 * 0.8);
+        for &dt in &[-300.0, -20.0, 0.0, 45.0, 700.0] {
+            let pv = oscpv(&el, 2460000.5, dt).unwrap();
+            let (r, v) = pv.modulus();
+            let en = 0.5 * v * v - GK * GK / r;
+            let h = pxp(&pv.p, &pv.v);
+            let hm = (h[0] * h[0] + h[1] * h[1] + h[2] * h[2]).sqrt();
+            assert!((en - en0).abs() < 1e-15, "oscpv energy e={} dt={}", e, dt);
+            assert!((hm - h0).abs() < 1e-15, "oscpv h e={} dt={}", e, dt);
+
+            /* Velocity is the derivative of position. */
+            let pa = oscpv(&el, 2460000.5, dt - 0.01).unwrap().p;
+            let pb = oscpv(&el, 2460000.5, dt + 0.01).unwrap().p;
+            for k in 0..3 {
+                let vn = (pb[k] - pa[k]) / 0.02;
+                assert!((vn - pv.v[k]).abs() < 1e-9, "oscpv v e={} dt={}", e, dt);
+            }
+        }
+    }
+
+    /* Perihelion. */
+    let el = OscElements { q: 2.1, e: 0.2, incl: 0.1, node: 0.2, argp: 0.3, tp: 2455000.0 };
+    let pv = oscpv(&el, 2455000.0, 0.0).unwrap();
+    assert!((pv.modulus().0 - 2.1).abs() < 1e-15, "oscpv q");
+
+    /* One period. */
+    let a: f64 = 2.1 / 0.8;
+    let per = std::f64::consts::TAU * a.powf(1.5) / GK;
+    let pv1 = oscpv(&el, 2455000.0, 123.0 + per).unwrap();
+    let pv2 = oscpv(&el, 2455000.0, 123.0).unwrap();
+    for (k, x) in pv1.p.iter().enumerate() {
+        assert!((x - pv2.p[k]).abs() < 1e-12, "oscpv period {}", k);
+    }
+
+    /* Continuity through e = 1. */
+    let mut p = [[0.0; 3]; 3];
+    for (i, e) in [1.0 - 1e-7, 1.0, 1.0 + 1e-7].iter().enumerate() {
+        let el = OscElements { q: 0.5, e: *e, ..el };
+        p[i] = oscpv(&el, 2455000.0, 30.0).unwrap().p;
+    }
+    for (k, x) in p[1].iter().enumerate() {
+        assert!((p[0][k] - x).abs() < 1e-7, "oscpv near-parabolic {}", k);
+        assert!((p[2][k] - x).abs() < 1e-7, "oscpv near-hyperbolic {}", k);
+    }
+
+    /* From mean anomaly. */
+    let el2 = OscElements::from_mean_anomaly(2455100.0, a, 0.2, 0.1, 0.2, 0.3, 0.0);
+    assert!((el2.q - 2.1).abs() < 1e-15 && (el2.tp - 2455100.0).abs() < 1e-9, "from_mean_anomaly");
+
+    assert_eq!(oscpv(&OscElements { q: 0.0, ..el }, 2455000.0, 0.0), Err(-1));
+}
+
+#[test]
+fn test_planet_pv() {
+    /* Mercury at J2000, against DE405 to about 1e-4 au. */
+    let pv = planet_pv(Planet::Mercury, 2451545.0, 0.0).unwrap();
+    assert!((pv.p[0] - -0.1301).abs() < 3e-4, "planet_pv x");
+    assert!((pv.p[1] - -0.4006).abs() < 3e-4, "planet_pv y");
+    assert!((pv.p[2] - -0.2005).abs() < 3e-4, "planet_pv z");
+
+    for p in Planet::ALL {
+        /* Between perihelion and aphelion. */
+        let el = planet_elements(p, 2460000.5, 0.0);
+        let a = el.q / (1.0 - el.e);
+        let (r, _) = planet_pv(p, 2460000.5, 0.0).unwrap().modulus();
+        assert!(r >= el.q - 1e-12 && r <= a * (1.0 + el.e) + 1e-12, "planet_pv r {:?}", p);
+
+        /* Velocity is the derivative of position, to the accuracy of
+           the element rates. */
+        let pv = planet_pv(p, 2460000.5, 0.0).unwrap();
+        let pa = planet_pv(p, 2460000.5, -0.5).unwrap().p;
+        let pb = planet_pv(p, 2460000.5, 0.5).unwrap().p;
+        let dv = [0, 1, 2].map(|k| pb[k] - pa[k] - pv.v[k]);
+        assert!(pm(&dv) < 1e-3 * pm(&pv.v), "planet_pv v {:?}", p);
+    }
+}
+
+#[test]
+fn test_jd_forms() {
+    use sofars::cal::Jd;
+
+    assert_eq!(epv00_jd(Jd(2400000.5, 53411.52501161)), epv00(2400000.5, 53411.52501161));
+    assert_eq!(moon98_jd((2400000.5, 43999.9)), moon98(2400000.5, 43999.9));
+    assert_eq!(planet_pv_jd(Planet::Mars, Jd(2460000.5, 0.0)), planet_pv(Planet::Mars, 2460000.5, 0.0));
+}
@@ -0,0 +1,127 @@
+use sofars::erst::{
+    ee06a, era00, era00_grid, era00_grid_into, gmst00, gmst06, gmst06_grid, gmst06_grid_into,
+    gmst82, gst06, gst06a, gst06a_grid,
+};
+use sofars::cal::Jd;
+use sofars::pnp::{obl06, obl80, pnm06a};
+use sofars::vm::{anp, anpm};
+
+#[test]
+fn test_era00() {
+    let era = era00(2400000.5, 54388.0);
+    assert!((era - 0.4022837240028158102).abs() < 1e-12, "iauEra00");
+}
+
+#[test]
+fn test_gmst00() {
+    let theta = gmst00(2400000.5, 53736.0, 2400000.5, 53736.0);
+    assert!((theta - 1.754174972210740592).abs() < 1e-12, "iauGmst00");
+}
+
+#[test]
+fn test_gmst06() {
+    let theta = gmst06(2400000.5, 53736.0, 2400000.5, 53736.0);
+    assert!((theta - 1.754174971870091203).abs() < 1e-12, "iauGmst06");
+}
+
+#[test]
+fn test_gmst82() {
+    let theta = gmst82(2400000.5, 53736.0);
+    assert!((theta - 1.754174981860675096).abs() < 1e-12, "iauGmst82");
+}
+
+#[test]
+fn test_anp_anpm() {
+    assert!((anp(-0.1) - 6.183185307179586477).abs() < 1e-12, "iauAnp");
+    assert!((anpm(-4.0) - 2.283185307179586477).abs() < 1e-12, "iauAnpm");
+    assert!((anpm(4.0) + 2.283185307179586477).abs() < 1e-12, "iauAnpm +");
+}
+
+#[test]
+fn test_const_eval() {
+    /* Tables for fixed epochs, evaluated at compile time. */
+    const EPOCHS: [f64; 3] = [53736.0, 53736.25, 53736.5];
+    const GMST: [f64; 3] = {
+        let mut g = [0.0; 3];
+        let mut i = 0;
+        while i < 3 {
+            g[i] = gmst06(2400000.5, EPOCHS[i], 2400000.5, EPOCHS[i]);
+            i += 1;
+        }
+        g
+    };
+    const ERA: f64 = era00(2400000.5, 54388.0);
+    const EPS80: f64 = obl80(2400000.5, 54388.0);
+    const EPS06: f64 = obl06(2400000.5, 54388.0);
+
+    assert_eq!(GMST[0], gmst06(2400000.5, 53736.0, 2400000.5, 53736.0));
+    assert_eq!(GMST[2], gmst06(2400000.5, 53736.5, 2400000.5, 53736.5));
+    assert_eq!(ERA, era00(2400000.5, 54388.0));
+    assert!((EPS80 - 0.4090751347643816218).abs() < 1e-14, "iauObl80");
+    assert!((EPS06 - 0.4090749229387258204).abs() < 1e-14, "iauObl06");
+}
+
+#[test]
+fn test_gst06a() {
+    let theta = gst06a(2400000.5, 53736.0, 2400000.5, 53736.0);
+    assert!((theta - 1.754166137675019159).abs() < 1e-12, "gst06a");
+
+    let rnpb = pnm06a(2400000.5, 53736.0);
+    let theta = gst06(2400000.5, 53736.0, 2400000.5, 53736.0, &rnpb);
+    assert!((theta - 1.754166137675019159).abs() < 1e-12, "gst06");
+}
+
+#[test]
+fn test_ee06a() {
+    let ee = ee06a(2400000.5, 53736.0);
+    assert!((ee - (-0.8834195072043790156e-5)).abs() < 1e-15, "ee06a");
+}
+
+#[test]
+fn test_grid() {
+    /* One night at 10-minute steps, TT = UT1 + 65.5 s. */
+    let utb: Vec<f64> = (0..100).map(|i| 60218.0 + i as f64 / 144.0).collect();
+    let ttb: Vec<f64> = utb.iter().map(|u| u + 65.5 / 86400.0).collect();
+
+    let era = era00_grid(2400000.5, &utb);
+    let gmst = gmst06_grid(2400000.5, &utb, 2400000.5, &ttb);
+    let gast = gst06a_grid(2400000.5, &utb, 2400000.5, &ttb);
+    for i in 0..utb.len() {
+        assert_eq!(era[i], era00(2400000.5, utb[i]), "era00_grid {}", i);
+        assert_eq!(gmst[i], gmst06(2400000.5, utb[i], 2400000.5, ttb[i]), "gmst06_grid {}", i);
+        let g = gst06a(2400000.5, utb[i], 2400000.5, ttb[i]);
+        assert!((gast[i] - g).abs() < 1e-12, "gst06a_grid {}", i);
+    }
+
+    /* Dense and unsorted, through the Chebyshev fits of the EO;  also
+       the larger part second. */
+    let utb: Vec<f64> = (0..5000).map(|i| ((i * 7919) % 5000) as f64 / 1000.0).collect();
+    let gast = gst06a_grid(2460218.5, &utb, 2460218.5, &utb);
+    for (i, &u) in utb.iter().enumerate().step_by(97) {
+        let g = gst06a(2460218.5, u, 2460218.5, u);
+        assert!((gast[i] - g).abs() < 1e-12, "gst06a_grid dense {}", i);
+    }
+    let mut era = vec![0.0; 3];
+    era00_grid_into(0.25, &[2451545.0, 2460000.5, 0.0], &mut era);
+    assert_eq!(era[0], era00(0.25, 2451545.0));
+    assert_eq!(era[1], era00(2460000.5, 0.25));
+    assert_eq!(era[2], era00(0.25, 0.0));
+    assert!(gst06a_grid(2451545.0, &[], 2451545.0, &[]).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "length mismatch")]
+fn test_grid_length() {
+    let mut gmst = vec![0.0; 2];
+    gmst06_grid_into(2400000.5, &[0.0, 0.5], 2400000.5, &[0.0], &mut gmst);
+}
+
+#[test]
+fn test_jd_forms() {
+    let ut = Jd(2400000.5, 53736.0);
+    assert_eq!(sofars::erst::era00_jd(ut), era00(2400000.5, 53736.0));
+    assert_eq!(sofars::erst::gmst06_jd(ut, ut), gmst06(2400000.5, 53736.0, 2400000.5, 53736.0));
+    assert_eq!(sofars::erst::gst06a_jd(ut, (2400000.5, 53736.0)), gst06a(2400000.5, 53736.0, 2400000.5, 53736.0));
+    let rnpb = pnm06a(2400000.5, 53736.0);
+    assert_eq!(sofars::erst::gst06_jd(ut, ut, &rnpb), gst06(2400000.5, 53736.0, 2400000.5, 53736.0, &rnpb));
+}
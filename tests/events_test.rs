@@ -0,0 +1,89 @@
+use sofars::astro::{sun_altaz, Site};
+use sofars::consts::DD2R;
+use sofars::events::*;
+use sofars::frames::EarthOrientation;
+use sofars::ts::{Epoch, TimeScale};
+
+fn hours(start: &Epoch, e: &Epoch) -> f64 {
+    (e.jd - start.jd) * 24.0
+}
+
+#[test]
+fn test_sun_events() {
+    /* Greenwich, 2024 March 20:  sunrise 06:02, transit 12:07, sunset
+       18:14 UTC. */
+    let site = Site::new(0.0, 51.4769 * DD2R, 46.0);
+    let eo = EarthOrientation::default();
+    let start = Epoch::from_calendar(TimeScale::UTC, 2024, 3, 20, 0, 0, 0.0).unwrap();
+    let opts = EventOptions::with_altitude(ALT_SUN);
+
+    let rst = rise_set_transit(&Target::Sun, &site, &eo, &start, &opts).unwrap();
+    let (rise, set, transit) = (rst.rise.unwrap(), rst.set.unwrap(), rst.transit.unwrap());
+    assert!((hours(&start, &rise) - (6.0 + 2.0 / 60.0)).abs() < 1.0 / 60.0, "sun: rise");
+    assert!((hours(&start, &transit) - (12.0 + 7.0 / 60.0)).abs() < 1.0 / 60.0, "sun: transit");
+    assert!((hours(&start, &set) - (18.0 + 14.0 / 60.0)).abs() < 1.0 / 60.0, "sun: set");
+
+    /* The altitude at the events is the threshold. */
+    let (_, el) = sun_altaz(&rise, &site, &eo).unwrap();
+    assert!((el - ALT_SUN).abs() < 1e-6, "sun: rise altitude");
+    let (ha, _) = hour_angle_altitude(&Target::Sun, &site, &eo, &transit).unwrap();
+    assert!(ha.abs() < 1e-6, "sun: transit hour angle");
+
+    /* Twilight comes before sunrise. */
+    let rst = rise_set_transit(&Target::Sun, &site, &eo, &start,
+                               &EventOptions::with_altitude(ALT_CIVIL)).unwrap();
+    let dawn = hours(&start, &rst.rise.unwrap());
+    assert!(dawn < hours(&start, &rise) - 0.4, "sun: civil dawn");
+}
+
+#[test]
+fn test_fixed_events() {
+    let site = Site::new(-1.2, 0.6, 0.0);
+    let eo = EarthOrientation::default();
+    let start = Epoch::new(TimeScale::TT, (2460000.5, 0.0).into());
+    let opts = EventOptions::default();
+
+    /* Events alternate rise, transit, set over several days. */
+    let target = Target::Fixed { ra: 1.0, dec: 0.2 };
+    let ev = find_events(&target, &site, &eo, &start, 3.0, &opts).unwrap();
+    assert!(ev.len() >= 8 && ev.len() <= 10, "fixed: count");
+    for w in ev.windows(2) {
+        let next = match w[0].kind {
+            EventKind::Rise => EventKind::Transit,
+            EventKind::Transit => EventKind::Set,
+            EventKind::Set => EventKind::Rise,
+        };
+        assert_eq!(w[1].kind, next, "fixed: order");
+        assert!(w[1].epoch.jd - w[0].epoch.jd > 0.1, "fixed: spacing");
+    }
+    for e in &ev {
+        let (ha, el) = hour_angle_altitude(&target, &site, &eo, &e.epoch).unwrap();
+        match e.kind {
+            EventKind::Transit => assert!(ha.abs() < 1e-6, "fixed: transit"),
+            _ => assert!((el - ALT_STAR).abs() < 1e-6, "fixed: rise/set"),
+        }
+    }
+
+    /* The same through an ephemeris callback. */
+    let eph = |_: &Epoch| Ok((1.0, 0.2));
+    let ev2 = find_events(&Target::Ephemeris(&eph), &site, &eo, &start, 3.0, &opts).unwrap();
+    assert_eq!(ev, ev2, "ephemeris");
+
+    /* Circumpolar:  transits only. */
+    let ev = find_events(&Target::Fixed { ra: 1.0, dec: 1.4 }, &site, &eo, &start, 2.0, &opts).unwrap();
+    assert_eq!(ev.len(), 2, "circumpolar");
+    assert!(ev.iter().all(|e| e.kind == EventKind::Transit), "circumpolar: kind");
+
+    /* An empty span. */
+    for days in [0.0, -1.0, f64::NAN] {
+        assert!(find_events(&target, &site, &eo, &start, days, &opts).unwrap().is_empty(), "span {}", days);
+    }
+
+    /* Refraction raises the target:  earlier rise at 10 deg. */
+    let mut opts = EventOptions::with_altitude(10.0 * DD2R);
+    let r0 = rise_set_transit(&target, &site, &eo, &start, &opts).unwrap().rise.unwrap();
+    opts.refraction = Some((2.8e-4, -3e-7));
+    let r1 = rise_set_transit(&target, &site, &eo, &start, &opts).unwrap().rise.unwrap();
+    let dt = (r0.jd - r1.jd) * 86400.0;
+    assert!(dt > 10.0 && dt < 60.0, "refraction");
+}
@@ -0,0 +1,512 @@
+use sofars::cal::Jd;
+use sofars::consts::{DAS2R, DMAS2R};
+use sofars::erst::{ee06a, era00, gmst06, gst06a};
+use sofars::fundargs::{faf03, faom03};
+use sofars::coords::{eceq06, eqec06, lteqec};
+use sofars::frames::{
+    ecliptic_to_icrs_lonlat, ecliptic_to_icrs_pv, era_at, gast_at, gcrs_to_itrs, gcrs_to_itrs_pv,
+    gcrs_to_itrs_pv_cov, gcrs_to_teme, gmst_at, icrs_to_ecliptic, icrs_to_ecliptic_lonlat,
+    icrs_to_ecliptic_pv, itrs_to_gcrs, itrs_to_gcrs_pv, itrs_to_gcrs_pv_cov, itrs_to_teme,
+    itrs_to_teme_pv, itrs_to_teme_pv_cov, solid_tide, solid_tide_at, teme_to_gcrs, teme_to_itrs,
+    teme_to_itrs_pv, teme_to_itrs_pv_cov, CioEquinox, CipCorrectionProvider, EarthOrientation,
+    Ecliptic, EopEntry, EopProvider, EopTable, FcnAmplitude, FcnModel, TidalEop, TidalEopTerm,
+    ZeroEop, OMEGA_EARTH,
+};
+use sofars::pnp::{c2i06a, c2i06a_cip, c2t06a_cip, eo06a, pnm06a};
+use sofars::ts::{taitt, utctai, utcut1, Epoch, TimeScale};
+use sofars::vm::{anp, c2s, PvVec, RotMat};
+
+fn eop() -> EarthOrientation {
+    EarthOrientation::from_iers(-0.1332, 0.0349282, 0.4833163, 0.1725, -0.2650)
+}
+
+#[test]
+fn test_eop_units() {
+    let eo = eop();
+    assert!((eo.xp - 0.0349282 * DAS2R).abs() < 1e-20);
+    assert!((eo.dy - (-0.2650 * DMAS2R)).abs() < 1e-20);
+    assert_eq!(EarthOrientation::default().ut1_utc, 0.0);
+}
+
+#[test]
+fn test_gcrs_to_itrs() {
+    let eo = eop();
+    let epoch = Epoch::from_calendar(TimeScale::UTC, 2007, 4, 5, 12, 0, 0.0).unwrap();
+
+    /* The same thing done by hand. */
+    let (u1, u2) = epoch.jd.into();
+    let (a1, a2) = utctai(u1, u2).unwrap();
+    let (t1, t2) = taitt(a1, a2).unwrap();
+    let (b1, b2) = utcut1(u1, u2, eo.ut1_utc).unwrap();
+    let rc2t = c2t06a_cip(t1, t2, b1, b2, eo.xp, eo.yp, eo.dx, eo.dy);
+
+    let r = gcrs_to_itrs(&epoch, &eo).unwrap();
+    for (i, row) in rc2t.iter().enumerate() {
+        for (j, x) in row.iter().enumerate() {
+            assert!((r[(i, j)] - x).abs() < 1e-15, "gcrs_to_itrs {}{}", i + 1, j + 1);
+        }
+    }
+
+    let t = itrs_to_gcrs(&epoch, &eo).unwrap();
+    assert_eq!(t, r.transpose());
+
+    /* The same instant given in TT. */
+    let tt = epoch.to(TimeScale::TT).unwrap();
+    let r2 = gcrs_to_itrs(&tt, &eo).unwrap();
+    assert!((r2[(0, 1)] - r[(0, 1)]).abs() < 1e-15, "gcrs_to_itrs TT");
+}
+
+#[test]
+fn test_gcrs_to_itrs_pv() {
+    let eo = eop();
+    let epoch = Epoch::from_calendar(TimeScale::UTC, 2020, 3, 1, 6, 30, 0.0).unwrap();
+
+    /* A station at rest on the equator, metres. */
+    let sta = PvVec::new([6378137.0, 0.0, 0.0], [0.0; 3]);
+    let g = itrs_to_gcrs_pv(&epoch, &eo, &sta).unwrap();
+    let (r, v) = g.modulus();
+    assert!((r - 6378137.0).abs() < 1e-6, "itrs_to_gcrs_pv r");
+    assert!((v - OMEGA_EARTH * 6378137.0).abs() < 1e-2, "itrs_to_gcrs_pv v");
+    assert!((v - 465.1).abs() < 0.1, "itrs_to_gcrs_pv 465 m/s");
+
+    /* Round trip gives the station back at rest. */
+    let b = gcrs_to_itrs_pv(&epoch, &eo, &g).unwrap();
+    for k in 0..3 {
+        assert!((b.p[k] - sta.p[k]).abs() < 1e-6, "gcrs_to_itrs_pv p{}", k);
+        assert!(b.v[k].abs() < 1e-9, "gcrs_to_itrs_pv v{}", k);
+    }
+
+    /* Positions agree with the matrix form. */
+    let p = gcrs_to_itrs(&epoch, &eo).unwrap().apply(&g.p);
+    for (k, x) in p.iter().enumerate() {
+        assert!((x - b.p[k]).abs() < 1e-6, "gcrs_to_itrs_pv matrix {}", k);
+    }
+}
+
+/* Vallado et al. 2006, AIAA 2006-6753, TEME example. */
+fn vallado() -> (Epoch, EarthOrientation, PvVec) {
+    let epoch = Epoch::from_calendar(TimeScale::UTC, 2004, 4, 6, 7, 51, 28.386009).unwrap();
+    let eo = EarthOrientation::from_iers(-0.4399619, -0.140682, 0.333309, 0.0, 0.0);
+    let teme = PvVec::new(
+        [5094.18016210, 6127.64465950, 6380.34453270],
+        [-4.746131487, 0.785818041, 5.531931288],
+    );
+    (epoch, eo, teme)
+}
+
+#[test]
+fn test_teme_to_itrs() {
+    let (epoch, eo, teme) = vallado();
+
+    let itrs = teme_to_itrs_pv(&epoch, &eo, &teme).unwrap();
+    let p = [-1033.4793830, 7901.2952754, 6380.3565958];
+    let v = [-3.225636520, -2.872451450, 5.531924446];
+    for k in 0..3 {
+        assert!((itrs.p[k] - p[k]).abs() < 2e-5, "teme_to_itrs_pv p{}", k);
+        assert!((itrs.v[k] - v[k]).abs() < 1e-7, "teme_to_itrs_pv v{}", k);
+    }
+
+    let back = itrs_to_teme_pv(&epoch, &eo, &itrs).unwrap();
+    for k in 0..3 {
+        assert!((back.p[k] - teme.p[k]).abs() < 1e-9, "itrs_to_teme_pv p{}", k);
+        assert!((back.v[k] - teme.v[k]).abs() < 1e-12, "itrs_to_teme_pv v{}", k);
+    }
+
+    let r = teme_to_itrs(&epoch, &eo).unwrap();
+    assert_eq!(itrs_to_teme(&epoch, &eo).unwrap(), r.transpose());
+}
+
+#[test]
+fn test_teme_to_gcrs() {
+    let (epoch, eo, teme) = vallado();
+
+    /* With zero offsets the equation of the equinoxes is ee06a. */
+    let (tt1, tt2) = epoch.to(TimeScale::TT).unwrap().jd.into();
+    let r0 = RotMat::rz(ee06a(tt1, tt2)) * RotMat::new(pnm06a(tt1, tt2));
+    let r = gcrs_to_teme(&epoch, &EarthOrientation::default()).unwrap();
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((r[(i, j)] - r0[(i, j)]).abs() < 1e-15, "gcrs_to_teme {}{}", i, j);
+        }
+    }
+
+    /* Vallado's J2000 position (IAU 1976/1980 with corrections). */
+    let g = teme.rotate(&teme_to_gcrs(&epoch, &eo).unwrap());
+    let p = [5102.508958, 6123.011401, 6378.136928];
+    for (k, x) in p.iter().enumerate() {
+        assert!((g.p[k] - x).abs() < 2e-3, "teme_to_gcrs p{}", k);
+    }
+
+    /* Both routes to the ITRS agree to a few cm. */
+    let a = teme_to_itrs(&epoch, &eo).unwrap().apply(&teme.p);
+    let b = gcrs_to_itrs(&epoch, &eo).unwrap().apply(&g.p);
+    for k in 0..3 {
+        assert!((a[k] - b[k]).abs() < 1e-3, "teme routes {}", k);
+    }
+}
+
+#[test]
+fn test_cio_equinox() {
+    let (tt1, tt2) = (2400000.5, 53736.0);
+    let (ut1, ut2) = (2400000.5, 53736.0);
+    let eqc = CioEquinox::new(tt1, tt2);
+
+    assert!((eqc.eo() - eo06a(tt1, tt2)).abs() < 1e-15, "eo");
+    assert!((eqc.gst(era00(ut1, ut2)) - gst06a(ut1, ut2, tt1, tt2)).abs() < 1e-15, "gst");
+    assert!((eqc.era(eqc.gst(1.0)) - 1.0).abs() < 1e-15, "era");
+    let r = eqc.rc2i();
+    for (i, row) in c2i06a(tt1, tt2).iter().enumerate() {
+        for (j, x) in row.iter().enumerate() {
+            assert!((r[(i, j)] - x).abs() < 1e-15, "rc2i {}{}", i + 1, j + 1);
+        }
+    }
+
+    /* The same direction in both systems. */
+    let p = [0.3, -0.8, 0.52];
+    let (ra_cirs, _) = c2s(&(eqc.rc2i() * p));
+    let (ra_eq, _) = c2s(&(eqc.rnpb() * p));
+    assert!((eqc.cirs_to_true_equinox(ra_cirs) - anp(ra_eq)).abs() < 1e-14, "cirs_to_true_equinox");
+    assert!((eqc.true_equinox_to_cirs(ra_eq) - anp(ra_cirs)).abs() < 1e-14, "true_equinox_to_cirs");
+
+    /* With celestial pole offsets. */
+    let eo = eop();
+    let epoch = Epoch::from_calendar(TimeScale::TT, 2007, 4, 5, 12, 0, 0.0).unwrap();
+    let (t1, t2) = epoch.jd.into();
+    let eqc = CioEquinox::from_epoch(&epoch, &eo).unwrap();
+    let r = eqc.rc2i();
+    for (i, row) in c2i06a_cip(t1, t2, eo.dx, eo.dy).iter().enumerate() {
+        for (j, x) in row.iter().enumerate() {
+            assert!((r[(i, j)] - x).abs() < 1e-12, "rc2i (dX,dY) {}{}", i + 1, j + 1);
+        }
+    }
+    assert!((eqc.eo() - eo06a(t1, t2)).abs() < 1e-12, "eo (dX,dY)");
+}
+
+#[test]
+fn test_solid_tide() {
+    let re: f64 = 6378136.6;
+    let xsta = [re, 0.0, 0.0];
+
+    /* Moon overhead at the equator, Sun above the north pole. */
+    let (rm, rs) = (3.844e8, 1.496e11);
+    let fac2m = 0.0123000371 * re * (re / rm).powi(3);
+    let fac2s = 332946.0482 * re * (re / rs).powi(3);
+    let utc = (2454934.5, 0.0);
+    let d = solid_tide(&xsta, &[0.0, 0.0, rs], &[rm, 0.0, 0.0], utc.0, utc.1).unwrap();
+
+    /* Step 2 does not depend on the Sun and Moon:  remove it with the
+       two at a great distance, leaving Step 1. */
+    let far = solid_tide(&xsta, &[0.0, 0.0, 1e30], &[1e30, 0.0, 0.0], utc.0, utc.1).unwrap();
+    let d = [0, 1, 2].map(|i| d[i] - far[i]);
+
+    /* Radial:  h2 (latitude dependent) and h3 under the Moon, -h2/2
+       at 90 degrees from the Sun. */
+    let h2 = 0.6078 + 0.0003;
+    let dr = fac2m * h2 + fac2m * (re / rm) * 0.292 - fac2s * h2 / 2.0;
+    assert!((d[0] - dr).abs() < 1e-12, "solid_tide: radial");
+    assert!((d[0] - 0.1696).abs() < 1e-4, "solid_tide: size");
+
+    /* East:  the out-of-phase semidiurnal term;  north:  degree 3. */
+    assert!((d[1] - 0.00105 * fac2m).abs() < 1e-12, "solid_tide: east");
+    assert!((d[2] + 0.0225 * fac2s * re / rs).abs() < 1e-12, "solid_tide: north");
+
+    /* Step 2 at the equator:  no diurnal radial term, leaving the
+       long-period one (under 0.6 mm), and about a millimeter at most
+       north, mostly K1. */
+    assert!(far[0] != 0.0 && far[0].abs() < 6e-4, "solid_tide: step 2 radial");
+    assert!(far[2].abs() < 1.5e-3, "solid_tide: step 2 north");
+
+    /* The test cases of the IERS routine DEHANTTIDEINEL (2009, 2012
+       and 2015, 0h UTC), to 0.5 mm. */
+    let cases = [
+        ([4075578.385, 931852.890, 4801570.154],
+         [137859926952.015, 54228127881.4350, 23509422341.6960],
+         [-179996231.920342, -312468450.131567, -169288918.592160],
+         2454934.5, [0.07700420357108125891, 0.06304056321824967613, 0.05516568152597246810]),
+        ([1112189.660, -4842955.026, 3985352.284],
+         [-54537460436.2357, 130244288385.279, 56463429031.5996],
+         [300396716.912, 243238281.451, 120548075.939],
+         2456121.5, [-0.02036831479592075833, 0.05658254776225972449, -0.07597679676871742227]),
+        ([1112200.5696, -4842957.8511, 3985345.9122],
+         [100210282451.6279, 103055630398.3160, 56855096480.4475],
+         [369817604.4348, 1897917.5258, 120804980.8284],
+         2457218.5, [0.00509570869172363845, 0.0828663025983528700, -0.0636634925404189617]),
+    ];
+    for (xsta, xsun, xmon, utc1, dx) in cases {
+        let d = solid_tide(&xsta, &xsun, &xmon, utc1, 0.0).unwrap();
+        for i in 0..3 {
+            assert!((d[i] - dx[i]).abs() < 5e-4, "solid_tide: DEHANTTIDEINEL {} {}", utc1, i);
+        }
+    }
+
+    /* At an epoch:  a few decimeters at most. */
+    let xsta = [4075539.5, 931735.3, 4801629.4];
+    let epoch = Epoch::from_calendar(TimeScale::UTC, 2009, 4, 13, 0, 0, 0.0).unwrap();
+    let d = solid_tide_at(&xsta, &epoch, &EarthOrientation::default()).unwrap();
+    let r = (d[0] * d[0] + d[1] * d[1] + d[2] * d[2]).sqrt();
+    assert!(r > 0.01 && r < 0.45, "solid_tide_at");
+}
+
+#[test]
+fn test_tidal_eop() {
+    let eo = eop();
+    let epoch = Epoch::from_calendar(TimeScale::UTC, 2007, 4, 5, 12, 0, 0.0).unwrap();
+
+    /* No terms, no change. */
+    assert_eq!(eo.with_tides(&epoch, &TidalEop::default()).unwrap(), eo);
+
+    /* One term, with the argument of O1. */
+    let o1 = TidalEopTerm {
+        arg: [1, 0, 0, -2, 0, -2],
+        xsin: 48.82,
+        xcos: 132.91,
+        ysin: -132.90,
+        ycos: 48.82,
+        utsin: 16.020,
+        utcos: -12.069,
+    };
+    let tides = TidalEop::new(vec![o1]);
+    let eot = eo.with_tides(&epoch, &tides).unwrap();
+
+    let (tt1, tt2) = epoch.to(TimeScale::TT).unwrap().jd.into();
+    let (ut1, ut2) = epoch.to_ut1(eo.ut1_utc).unwrap().jd.into();
+    let t = ((tt1 - 2451545.0) + tt2) / 36525.0;
+    let a = gmst06(ut1, ut2, tt1, tt2) + std::f64::consts::PI - 2.0 * faf03(t) - 2.0 * faom03(t);
+    let (s, c) = a.sin_cos();
+    assert!((eot.xp - eo.xp - (48.82 * s + 132.91 * c) * DMAS2R * 1e-3).abs() < 1e-18, "xp");
+    assert!((eot.yp - eo.yp - (-132.90 * s + 48.82 * c) * DMAS2R * 1e-3).abs() < 1e-18, "yp");
+    assert!((eot.ut1_utc - eo.ut1_utc - (16.020 * s - 12.069 * c) * 1e-6).abs() < 1e-15, "ut1");
+
+    /* Libration, against the test cases of the IERS routines PMSDNUT2
+       and UTLIBR (microarcsec, microsec), which take GMST from UT1. */
+    let lib = TidalEop::libration();
+    let uas = DMAS2R * 1e-3;
+    let (dx, dy, dut) = lib.corrections_at(2400000.5, 54335.0, 2400000.5, 54335.0);
+    assert!((dx / uas - 24.83144238273364834).abs() < 1e-5, "libration x");
+    assert!((dy / uas - -14.09240692041837661).abs() < 1e-5, "libration y");
+    assert!(dut.abs() < 3e-6, "libration ut1");
+    let (_, _, dut) = lib.corrections_at(2400000.5, 44239.1, 2400000.5, 44239.1);
+    assert!((dut * 1e6 - 2.441143834386761746).abs() < 1e-6, "libration ut1 1980");
+    let (_, _, dut) = lib.corrections_at(2400000.5, 55227.4, 2400000.5, 55227.4);
+    assert!((dut * 1e6 - -2.655705844335680244).abs() < 1e-6, "libration ut1 2010");
+    assert_eq!((eot.dx, eot.dy), (eo.dx, eo.dy));
+}
+
+#[test]
+fn test_eop_provider() {
+    let eo = eop();
+    let epoch = Epoch::from_calendar(TimeScale::UTC, 2007, 4, 5, 12, 0, 0.0).unwrap();
+    assert_eq!(eo.eop(&epoch).unwrap(), eo);
+    assert_eq!(ZeroEop.eop(&epoch).unwrap(), EarthOrientation::default());
+    let f = |_: &Epoch| eo;
+    assert_eq!(f.eop(&epoch).unwrap(), eo);
+    let dynp: &dyn EopProvider = &ZeroEop;
+    assert_eq!(gcrs_to_itrs(&epoch, dynp).unwrap(), gcrs_to_itrs(&epoch, &EarthOrientation::default()).unwrap());
+
+    /* Sidereal times. */
+    let (tt1, tt2) = epoch.to(TimeScale::TT).unwrap().jd.into();
+    let (ut1, ut2) = epoch.to_ut1(eo.ut1_utc).unwrap().jd.into();
+    assert_eq!(era_at(&epoch, &eo).unwrap(), era00(ut1, ut2));
+    assert_eq!(gmst_at(&epoch, &eo).unwrap(), gmst06(ut1, ut2, tt1, tt2));
+    let gast = gast_at(&epoch, &EarthOrientation { dx: 0.0, dy: 0.0, ..eo }).unwrap();
+    assert!((gast - gst06a(ut1, ut2, tt1, tt2)).abs() < 1e-15, "gast_at");
+    let cio = CioEquinox::from_epoch(&epoch, &eo).unwrap();
+    assert!((gast_at(&epoch, &eo).unwrap() - cio.gst(era00(ut1, ut2))).abs() < 1e-15, "gast_at dX,dY");
+}
+
+#[test]
+fn test_eop_table() {
+    /* Daily values either side of the 2008 December 31 leap second
+       (MJD 54832 is 2009 January 1), UT1-UTC jumping by +1 s. */
+    let e = |mjd: f64, ut1_utc: f64, xp: f64| EopEntry {
+        mjd,
+        eo: EarthOrientation::from_iers(ut1_utc, xp, 0.3, 0.1, -0.2),
+    };
+    let table = EopTable::new(vec![
+        e(54832.0, 0.2907, 0.0204),
+        e(54829.0, -0.7061, 0.0189),
+        e(54830.0, -0.7072, 0.0194),
+        e(54831.0, -0.7082, 0.0199),
+        e(54833.0, 0.2896, 0.0210),
+        e(54834.0, 0.2884, 0.0216),
+        e(54835.0, 0.2872, 0.0222),
+    ]);
+    assert_eq!(table.span(), Some((54829.0, 54835.0)));
+    assert_eq!(table.entries()[3].mjd, 54832.0);
+    let at = |mjd: f64| Epoch::new(TimeScale::UTC, Jd::from_mjd(mjd));
+
+    /* Exact at the nodes. */
+    let eo = table.eop(&at(54830.0)).unwrap();
+    assert!((eo.ut1_utc + 0.7072).abs() < 1e-12, "node ut1_utc");
+    assert!((eo.xp - 0.0194 * DAS2R).abs() < 1e-18, "node xp");
+    assert!((eo.dy - (-0.2 * DMAS2R)).abs() < 1e-20, "node dy");
+
+    /* Across the leap second UT1 is smooth:  halfway through 31 Dec
+       UT1-UTC is near -0.709, not the mean of the two days. */
+    let eo = table.eop(&at(54831.5)).unwrap();
+    assert!((eo.ut1_utc + 0.70875).abs() < 1e-4, "leap second: {}", eo.ut1_utc);
+    let eo = table.eop(&at(54833.5)).unwrap();
+    assert!((eo.ut1_utc - 0.2890).abs() < 1e-4, "after: {}", eo.ut1_utc);
+    assert!(eo.xp > 0.0210 * DAS2R && eo.xp < 0.0216 * DAS2R, "xp");
+
+    /* The same instant in TT. */
+    let tt = at(54833.5).to(TimeScale::TT).unwrap();
+    assert!((table.eop(&tt).unwrap().ut1_utc - eo.ut1_utc).abs() < 1e-12, "TT");
+
+    /* Outside the table, and empty. */
+    assert_eq!(table.eop(&at(54828.0)), Err(-10));
+    assert_eq!(EopTable::default().eop(&at(54831.0)), Err(-10));
+
+    /* A later bulletin replacing a day. */
+    let mut t2 = table.clone();
+    t2.push(e(54835.0, 0.2870, 0.0222));
+    t2.push(e(54836.0, 0.2860, 0.0228));
+    assert_eq!(t2.entries().len(), 8);
+    assert!((t2.eop(&at(54835.0)).unwrap().ut1_utc - 0.2870).abs() < 1e-12, "push");
+
+    /* Tides are added after the interpolation. */
+    let tides = TidalEop::new(vec![TidalEopTerm {
+        arg: [1, 0, 0, -2, 0, -2],
+        xsin: 48.82, xcos: 132.91, ysin: -132.90, ycos: 48.82, utsin: 16.020, utcos: -12.069,
+    }]);
+    let epoch = at(54832.25);
+    let eot = table.clone().with_tides(tides.clone()).eop(&epoch).unwrap();
+    assert_eq!(eot, table.eop(&epoch).unwrap().with_tides(&epoch, &tides).unwrap());
+
+    /* As a provider of the terrestrial transformations. */
+    let r = gcrs_to_itrs(&epoch, &table).unwrap();
+    assert_eq!(r, gcrs_to_itrs(&epoch, &table.eop(&epoch).unwrap()).unwrap());
+}
+
+#[test]
+fn test_fcn_model() {
+    let a = |mjd, xc, xs| FcnAmplitude { mjd, xc, xs };
+    let fcn = FcnModel::new(vec![a(52000.0, -100.0, 200.0), a(51000.0, 50.0, -80.0)]);
+    assert_eq!(fcn.amplitudes()[0].mjd, 51000.0);
+
+    /* Phase zero at J2000.0, a quarter period later. */
+    let (x, y) = fcn.xy(51544.5);
+    let (xc, xs) = (50.0 - 150.0 * 544.5 / 1000.0, -80.0 + 280.0 * 544.5 / 1000.0);
+    assert!((x - xc * DMAS2R * 1e-3).abs() < 1e-20, "x");
+    assert!((y - xs * DMAS2R * 1e-3).abs() < 1e-20, "y");
+    let (x, y) = fcn.xy(51544.5 + FcnModel::PERIOD / 4.0);
+    let f = (544.5 + FcnModel::PERIOD / 4.0) / 1000.0;
+    let (xc, xs) = (50.0 - 150.0 * f, -80.0 + 280.0 * f);
+    assert!((x + xs * DMAS2R * 1e-3).abs() < 1e-18, "x/4");
+    assert!((y - xc * DMAS2R * 1e-3).abs() < 1e-18, "y/4");
+
+    /* Held outside the table, updated by push. */
+    let (x, y) = fcn.xy(60000.0);
+    let r = (x * x + y * y).sqrt() / DMAS2R / 1e-3;
+    assert!((r - (100.0f64.powi(2) + 200.0f64.powi(2)).sqrt()).abs() < 1e-9, "hold");
+    let mut fcn2 = fcn.clone();
+    fcn2.push(a(53000.0, 0.0, 0.0));
+    assert_eq!(fcn2.xy(60000.0), (0.0, 0.0));
+
+    /* As a provider. */
+    let epoch = Epoch::from_calendar(TimeScale::TT, 2001, 5, 1, 0, 0, 0.0).unwrap();
+    let (t1, t2) = epoch.jd.into();
+    let eo = eop().with_cip_corrections(&epoch, &fcn).unwrap();
+    assert_eq!((eo.dx, eo.dy), fcn.cip_corrections(t1, t2));
+    assert_eq!(eo.ut1_utc, eop().ut1_utc);
+    let p: &dyn CipCorrectionProvider = &eop();
+    assert_eq!(p.cip_corrections(t1, t2), (eop().dx, eop().dy));
+
+    /* The built-in table, against the test case of FCNNUT
+       (microarcseconds). */
+    let fcn = FcnModel::lambert();
+    assert_eq!(fcn.amplitudes().len(), 30);
+    let (x, y) = fcn.xy(54790.0);
+    let uas = DMAS2R * 1e-3;
+    assert!((x / uas - -176.8012290066270680).abs() < 1e-9, "lambert x");
+    assert!((y / uas - -93.51855308903639647).abs() < 1e-9, "lambert y");
+}
+
+#[test]
+fn test_pv_cov() {
+    let eo = eop();
+    let epoch = Epoch::from_calendar(TimeScale::UTC, 2007, 4, 5, 12, 0, 0.0).unwrap();
+    let pv = PvVec::new([7000e3, -1200e3, 300e3], [1.2e3, 6.5e3, 3.1e3]);
+
+    /* A correlated covariance, m and m/s. */
+    let mut cov = [[0.0; 6]; 6];
+    for (i, v) in [100.0, 400.0, 25.0, 1e-2, 4e-2, 1e-3].iter().enumerate() {
+        cov[i][i] = *v;
+    }
+    cov[0][3] = 0.5;
+    cov[3][0] = 0.5;
+
+    let (pvt, ct) = gcrs_to_itrs_pv_cov(&epoch, &eo, &pv, &cov).unwrap();
+    assert_eq!(pvt, gcrs_to_itrs_pv(&epoch, &eo, &pv).unwrap());
+
+    /* The position block is r C r^T. */
+    let r = gcrs_to_itrs(&epoch, &eo).unwrap();
+    let cp = r.rotate_cov(&[[100.0, 0.0, 0.0], [0.0, 400.0, 0.0], [0.0, 0.0, 25.0]]);
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((ct[i][j] - cp[i][j]).abs() < 1e-10, "position block");
+        }
+    }
+
+    /* The rotation-rate term:  velocity variance grows by omega^2
+       times the position variance across the axis, about 3e-6. */
+    let tv = ct[3][3] + ct[4][4] + ct[5][5];
+    assert!(tv > 5.1e-2 && tv < 5.1e-2 + 3e-6, "velocity trace {}", tv);
+
+    /* And back. */
+    let (pvg, cg) = itrs_to_gcrs_pv_cov(&epoch, &eo, &pvt, &ct).unwrap();
+    for i in 0..3 {
+        assert!((pvg.p[i] - pv.p[i]).abs() < 1e-6 && (pvg.v[i] - pv.v[i]).abs() < 1e-9, "pv");
+    }
+    for i in 0..6 {
+        for j in 0..6 {
+            assert!((cg[i][j] - cov[i][j]).abs() < 1e-9, "round trip {} {}", i, j);
+        }
+    }
+
+    /* TEME, likewise. */
+    let (pvm, cm) = itrs_to_teme_pv_cov(&epoch, &eo, &pvt, &ct).unwrap();
+    let (pvt2, ct2) = teme_to_itrs_pv_cov(&epoch, &eo, &pvm, &cm).unwrap();
+    assert!((pvt2.p[0] - pvt.p[0]).abs() < 1e-6, "teme pv");
+    for i in 0..6 {
+        for j in 0..6 {
+            assert!((ct2[i][j] - ct[i][j]).abs() < 1e-9, "teme round trip {} {}", i, j);
+        }
+    }
+}
+
+#[test]
+fn test_ecliptic() {
+    let epoch = Epoch::new(TimeScale::TT, sofars::cal::Jd(2456165.5, 0.401182685));
+
+    /* Of date:  as eqec06 and eceq06. */
+    let (dl, db) = icrs_to_ecliptic_lonlat(Ecliptic::OfDate, &epoch, 1.234, 0.987).unwrap();
+    let (l, b) = eqec06(2456165.5, 0.401182685, 1.234, 0.987);
+    assert!((dl - l).abs() < 1e-15 && (db - b).abs() < 1e-15, "OfDate");
+    let (dr, dd) = ecliptic_to_icrs_lonlat(Ecliptic::OfDate, &epoch, dl, db).unwrap();
+    let (r, d) = eceq06(2456165.5, 0.401182685, dl, db);
+    assert!((dr - r).abs() < 1e-15 && (dd - d).abs() < 1e-15, "OfDate inverse");
+    assert!((dr - 1.234).abs() < 1e-14 && (dd - 0.987).abs() < 1e-14, "OfDate round trip");
+
+    /* Long term:  as lteqec, and near the IAU 2006 model today. */
+    let (ll, lb) = icrs_to_ecliptic_lonlat(Ecliptic::LongTerm, &epoch, 1.234, 0.987).unwrap();
+    let (l, b) = lteqec(sofars::cal::epj(2456165.5, 0.401182685), 1.234, 0.987);
+    assert!((ll - l).abs() < 1e-15 && (lb - b).abs() < 1e-15, "LongTerm");
+    assert!((ll - dl).abs() < 100e-6 * DAS2R && (lb - db).abs() < 100e-6 * DAS2R, "LongTerm vs OfDate");
+
+    /* J2000:  the obliquity of J2000, whatever the epoch. */
+    let r = icrs_to_ecliptic(Ecliptic::J2000, &epoch).unwrap();
+    let r0 = icrs_to_ecliptic(Ecliptic::J2000, &Epoch::new(TimeScale::TT, sofars::cal::Jd::j2000())).unwrap();
+    assert_eq!(r, r0);
+    let (_, b) = c2s(&r.apply(&[0.0, 0.0, 1.0]));
+    assert!((b - (std::f64::consts::FRAC_PI_2 - 84381.406 * DAS2R)).abs() < 1e-7, "J2000 obliquity");
+
+    /* Position/velocity round trip. */
+    let pv = PvVec::new([1.2, -0.3, 0.1], [0.001, 0.015, -0.002]);
+    let e = icrs_to_ecliptic_pv(Ecliptic::J2000, &epoch, &pv).unwrap();
+    assert!((e.modulus().0 - pv.modulus().0).abs() < 1e-14, "pv");
+    let q = ecliptic_to_icrs_pv(Ecliptic::J2000, &epoch, &e).unwrap();
+    for i in 0..3 {
+        assert!((q.p[i] - pv.p[i]).abs() < 1e-15 && (q.v[i] - pv.v[i]).abs() < 1e-17, "pv round trip");
+    }
+}
@@ -0,0 +1,26 @@
+use sofars::fundargs::*;
+use sofars::pnp::{nut00a, nut00a_with, nut06a, nut06a_with, s06, s06_with};
+
+#[test]
+fn test_fane03() {
+    assert!((fane03(0.80) - 2.079343830860413523).abs() < 1e-12, "iauFane03");
+}
+
+#[test]
+fn test_epoch_cache() {
+    let mut fa = EpochCache::new(2400000.5, 53736.0);
+    assert_eq!(fa.date(), (2400000.5, 53736.0));
+    assert_eq!(fa.l, fal03(fa.t));
+    assert_eq!(fa.pa, fapa03(fa.t));
+
+    /* Same results as the date forms. */
+    assert_eq!(nut00a_with(&fa), nut00a(2400000.5, 53736.0), "nut00a_with");
+    assert_eq!(nut06a_with(&fa), nut06a(2400000.5, 53736.0), "nut06a_with");
+    let (x, y) = (0.5791308486706011000e-3, 0.4020579816732961219e-4);
+    assert_eq!(s06_with(&fa, x, y), s06(2400000.5, 53736.0, x, y), "s06_with");
+
+    /* Recomputed only when the date changes. */
+    assert!(!fa.update(2400000.5, 53736.0));
+    assert!(fa.update(2400000.5, 53737.0));
+    assert_eq!(fa, EpochCache::new(2400000.5, 53737.0));
+}
@@ -0,0 +1,107 @@
+use sofars::gnomic::*;
+use sofars::vm::{c2s, s2c};
+
+#[test]
+fn test_tpors() {
+    let (n, az1, bz1, az2, bz2) = tpors(-0.03, 0.07, 1.3, 1.5);
+
+    assert!((az1 - 1.736621577783208748).abs() < 1e-13, "tpors az1");
+    assert!((bz1 - 1.436736561844090323).abs() < 1e-13, "tpors bz1");
+    assert!((az2 - 4.004971075806584490).abs() < 1e-13, "tpors az2");
+    assert!((bz2 - 1.565084088476417917).abs() < 1e-13, "tpors bz2");
+    assert_eq!(n, 2, "tpors n");
+}
+
+#[test]
+fn test_tporv() {
+    let v = s2c(1.3, 1.5);
+    let (n, vz1, vz2) = tporv(-0.03, 0.07, &v);
+    let (a1, b1) = c2s(&vz1);
+    let (a2, b2) = c2s(&vz2);
+
+    assert!((sofars::vm::anp(a1) - 1.736621577783208748).abs() < 1e-13, "tporv az1");
+    assert!((b1 - 1.436736561844090323).abs() < 1e-13, "tporv bz1");
+    assert!((sofars::vm::anp(a2) - 4.004971075806584490).abs() < 1e-13, "tporv az2");
+    assert!((b2 - 1.565084088476417917).abs() < 1e-13, "tporv bz2");
+    assert_eq!(n, 2, "tporv n");
+}
+
+#[test]
+fn test_tpsts() {
+    let (ra, dec) = tpsts(-0.03, 0.07, 2.3, 1.5);
+
+    assert!((ra - 0.7596127167359629775).abs() < 1e-14, "tpsts ra");
+    assert!((dec - 1.540864645109263028).abs() < 1e-13, "tpsts dec");
+}
+
+#[test]
+fn test_tpstv() {
+    let v0 = s2c(2.3, 1.5);
+    let v = tpstv(-0.03, 0.07, &v0);
+    let (ra, dec) = c2s(&v);
+
+    assert!((sofars::vm::anp(ra) - 0.7596127167359629775).abs() < 1e-14, "tpstv ra");
+    assert!((dec - 1.540864645109263028).abs() < 1e-13, "tpstv dec");
+}
+
+#[test]
+fn test_tpxes() {
+    let (xi, eta) = tpxes(1.3, 1.55, 2.3, 1.5).unwrap();
+
+    assert!((xi - (-0.01753200983236980595)).abs() < 1e-15, "tpxes xi");
+    assert!((eta - 0.05962940005778712891).abs() < 1e-15, "tpxes eta");
+
+    assert_eq!(tpxes(1.3, -1.55, 2.3, 1.5), Err(3), "tpxes antistar");
+}
+
+#[test]
+fn test_tpxev() {
+    let v = s2c(1.3, 1.55);
+    let v0 = s2c(2.3, 1.5);
+    let (xi, eta) = tpxev(&v, &v0).unwrap();
+
+    assert!((xi - (-0.01753200983236980595)).abs() < 1e-15, "tpxev xi");
+    assert!((eta - 0.05962940005778712891).abs() < 1e-15, "tpxev eta");
+}
+
+#[test]
+fn test_platefit() {
+    /* Synthetic plate:  1"/pixel, rotated, slightly skewed. */
+    let pc = PlateConstants {
+        a: 4.8e-6, b: -0.3e-6, c: -0.004, d: 0.35e-6, e: 4.9e-6, f: 0.002,
+    };
+    let xy: Vec<[f64; 2]> = (0..20)
+        .map(|i| [(i * 97 % 2048) as f64 + 0.5, (i * 211 % 2048) as f64 - 0.25])
+        .collect();
+    let mut xieta: Vec<[f64; 2]> =
+        xy.iter().map(|p| { let (x, e) = pc.apply(p[0], p[1]); [x, e] }).collect();
+
+    let sol = platefit(&xy, &xieta).unwrap();
+    assert!((sol.pc.a - pc.a).abs() < 1e-18, "platefit a");
+    assert!((sol.pc.e - pc.e).abs() < 1e-18, "platefit e");
+    assert!((sol.pc.c - pc.c).abs() < 1e-14, "platefit c");
+    assert!((sol.pc.f - pc.f).abs() < 1e-14, "platefit f");
+    assert!(sol.rms < 1e-15, "platefit rms");
+
+    /* One bad star shows in the residuals. */
+    xieta[7][0] += 1e-5;
+    let sol = platefit(&xy, &xieta).unwrap();
+    assert_eq!(sol.residuals.len(), 20);
+    let worst = sol.residuals.iter().map(|r| r[0].abs()).fold(0.0, f64::max);
+    assert_eq!(worst, sol.residuals[7][0].abs(), "platefit outlier");
+
+    /* Inverse. */
+    let (x, y) = sol.pc.invert(sol.pc.apply(100.0, 200.0).0, sol.pc.apply(100.0, 200.0).1)
+        .unwrap();
+    assert!((x - 100.0).abs() < 1e-9 && (y - 200.0).abs() < 1e-9, "invert");
+
+    /* With the projection. */
+    let (a0, b0) = (1.2, 0.4);
+    let (xi, eta) = tpxes(1.201, 0.399, a0, b0).unwrap();
+    let (a, b) = tpsts(xi, eta, a0, b0);
+    assert!((a - 1.201).abs() < 1e-14 && (b - 0.399).abs() < 1e-14, "tpxes/tpsts");
+
+    assert_eq!(platefit(&xy[..2], &xieta[..2]), Err(-1));
+    let line = [[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]];
+    assert_eq!(platefit(&line, &xieta[..3]), Err(-2));
+}
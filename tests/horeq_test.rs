@@ -0,0 +1,24 @@
+use sofars::horeq::*;
+
+#[test]
+fn test_ae2hd() {
+    let (h, d) = ae2hd(5.5, 1.1, 0.7);
+
+    assert!((h - 0.5933291115507309663).abs() < 1e-14, "ae2hd h");
+    assert!((d - 0.9613934761647817620).abs() < 1e-14, "ae2hd d");
+}
+
+#[test]
+fn test_hd2ae() {
+    let (a, e) = hd2ae(1.1, 1.2, 0.3);
+
+    assert!((a - 5.916889243730066194).abs() < 1e-13, "hd2ae a");
+    assert!((e - 0.4472186304990486228).abs() < 1e-14, "hd2ae e");
+}
+
+#[test]
+fn test_hd2pa() {
+    let q = hd2pa(1.1, 1.2, 0.3);
+
+    assert!((q - 1.906227428001995580).abs() < 1e-13, "hd2pa");
+}
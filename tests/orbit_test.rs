@@ -0,0 +1,107 @@
+use sofars::astro::oscplace;
+use sofars::consts::GK;
+use sofars::orbit::{kepler_uv, oscpv, pv2osc, pvplace, OscElements, GMS};
+use sofars::vm::PvVec;
+
+fn close(a: &PvVec, b: &PvVec, tp: f64, tv: f64, what: &str) {
+    for k in 0..3 {
+        assert!((a.p[k] - b.p[k]).abs() < tp, "{}: p[{}] {} {}", what, k, a.p[k], b.p[k]);
+        assert!((a.v[k] - b.v[k]).abs() < tv, "{}: v[{}] {} {}", what, k, a.v[k], b.v[k]);
+    }
+}
+
+#[test]
+fn test_kepler_uv() {
+    /* Against oscpv, for each kind of orbit and both directions. */
+    for &e in &[0.0, 0.3, 0.97, 1.0, 1.4, 8.0] {
+        let el = OscElements { q: 0.8, e, incl: 0.4, node: 1.2, argp: -0.7, tp: 2460000.5 };
+        let pv0 = oscpv(&el, 2460000.5, 10.0).unwrap();
+        for &dt in &[-300.0, -20.0, 0.0, 1e-6, 45.0, 700.0] {
+            let pv = kepler_uv(&pv0, dt, GMS).unwrap();
+            let pvx = oscpv(&el, 2460000.5, 10.0 + dt).unwrap();
+            close(&pv, &pvx, 1e-12, 1e-14, &format!("kepler_uv e={} dt={}", e, dt));
+        }
+    }
+
+    /* Many revolutions. */
+    let el = OscElements { q: 1.0, e: 0.1, incl: 0.1, node: 0.2, argp: 0.3, tp: 2451545.0 };
+    let pv0 = oscpv(&el, 2451545.0, 0.0).unwrap();
+    let pv = kepler_uv(&pv0, 36525.0 * 3.0 + 17.0, GMS).unwrap();
+    let pvx = oscpv(&el, 2451545.0, 36525.0 * 3.0 + 17.0).unwrap();
+    close(&pv, &pvx, 1e-10, 1e-12, "kepler_uv revolutions");
+
+    /* Forwards and back;  another central body and units. */
+    let pv0 = PvVec::new([7000.0, 0.0, 100.0], [0.0, 7.5, 1.0]);
+    let pv1 = kepler_uv(&pv0, 5400.0, 398600.4418).unwrap();
+    let pv2 = kepler_uv(&pv1, -5400.0, 398600.4418).unwrap();
+    close(&pv2, &pv0, 1e-8, 1e-11, "kepler_uv round trip");
+
+    assert_eq!(kepler_uv(&pv0, 1.0, 0.0), Err(-1));
+    assert_eq!(kepler_uv(&PvVec::new([0.0; 3], [1.0, 0.0, 0.0]), 1.0, GMS), Err(-1));
+}
+
+#[test]
+fn test_pv2osc() {
+    for &e in &[0.0, 0.3, 0.97, 1.0, 1.4] {
+        let el = OscElements { q: 0.8, e, incl: 0.4, node: 1.2, argp: 5.5, tp: 2460000.5 };
+        for &dt in &[-300.0, -20.0, 0.0, 45.0] {
+            let pv = oscpv(&el, 2460000.5, dt).unwrap();
+            let el2 = pv2osc(&pv, 2460000.5, dt).unwrap();
+            assert!((el2.q - el.q).abs() < 1e-13, "pv2osc q e={} dt={}", e, dt);
+            assert!((el2.e - el.e).abs() < 1e-12, "pv2osc e e={} dt={}", e, dt);
+            assert!((el2.incl - el.incl).abs() < 1e-13, "pv2osc incl e={} dt={}", e, dt);
+            assert!((el2.node - el.node).abs() < 1e-13, "pv2osc node e={} dt={}", e, dt);
+            if e > 0.0 {
+                assert!((el2.argp - el.argp).abs() < 1e-9, "pv2osc argp e={} dt={}", e, dt);
+                /* The nearest perihelion, which may be a period earlier. */
+                let mut dtp = el2.tp - el.tp;
+                if e < 1.0 {
+                    let per = std::f64::consts::TAU * (el.q / (1.0 - e)).powf(1.5) / GK;
+                    dtp -= per * (dtp / per).round();
+                }
+                assert!(dtp.abs() < 1e-6, "pv2osc tp e={} dt={}", e, dt);
+            }
+
+            /* Whatever the angles, the same state (to the resolution of
+               tp as a JD, 5e-10 days). */
+            let pv2 = oscpv(&el2, 2460000.5, dt).unwrap();
+            close(&pv2, &pv, 1e-11, 1e-13, &format!("pv2osc e={} dt={}", e, dt));
+        }
+    }
+
+    /* In the ecliptic, and retrograde. */
+    for incl in [0.0, std::f64::consts::PI, 2.5] {
+        let el = OscElements { q: 1.5, e: 0.2, incl, node: 0.7, argp: 0.4, tp: 2455000.0 };
+        let pv = oscpv(&el, 2455000.0, 100.0).unwrap();
+        let el2 = pv2osc(&pv, 2455000.0, 100.0).unwrap();
+        assert!((el2.incl - incl).abs() < 1e-12, "pv2osc incl {}", incl);
+        close(&oscpv(&el2, 2455000.0, 100.0).unwrap(), &pv, 1e-11, 1e-13, "pv2osc plane");
+    }
+
+    assert_eq!(pv2osc(&PvVec::new([1.0, 0.0, 0.0], [0.01, 0.0, 0.0]), 2455000.0, 0.0), Err(-1));
+}
+
+#[test]
+fn test_pvplace() {
+    let el = OscElements { q: 2.2, e: 0.15, incl: 0.2, node: 1.0, argp: 2.0, tp: 2460100.5 };
+    let pv = oscpv(&el, 2460000.5, 0.0).unwrap();
+    let a = pvplace(&pv, 2460000.5, 0.0, 2460030.5, 0.0).unwrap();
+    let b = oscplace(&el, 2460030.5, 0.0).unwrap();
+    assert!((a.ra - b.ra).abs() < 1e-11, "pvplace ra");
+    assert!((a.dec - b.dec).abs() < 1e-11, "pvplace dec");
+    assert!((a.delta - b.delta).abs() < 1e-12, "pvplace delta");
+}
+
+#[test]
+fn test_jd_forms() {
+    use sofars::cal::Jd;
+    use sofars::orbit::{pv2osc_jd, pvplace_jd};
+
+    let el = OscElements { q: 2.2, e: 0.15, incl: 0.2, node: 1.0, argp: 2.0, tp: 2460100.5 };
+    let pv = oscpv(&el, 2460000.5, 0.0).unwrap();
+    assert_eq!(pv2osc_jd(&pv, Jd(2460000.5, 0.0)), pv2osc(&pv, 2460000.5, 0.0));
+    assert_eq!(
+        pvplace_jd(&pv, (2460000.5, 0.0), Jd(2460030.5, 0.0)),
+        pvplace(&pv, 2460000.5, 0.0, 2460030.5, 0.0)
+    );
+}
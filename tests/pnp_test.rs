@@ -1,4 +1,9 @@
-use sofars::pnp::{c2ixys, nut00a, nut06a, pnm00a, pnm06a, s06};
+use sofars::consts::DMAS2R;
+use sofars::pnp::{
+    bpn2xy, c2i06a, c2i06a_cip, c2ixys, c2t06a, c2t06a_cip, eo06a, fw2xy, ltp, ltpb, ltpecl, ltpequ, nut00a, nut06a, pnm00a, pnm06a,
+    pmat06, pn00, pn00_mat3, pnm06a_cip, s06, FwAngles, PrecNut,
+};
+use sofars::cal::Jd;
 
 #[test]
 fn test_c2ixys() {
@@ -6,8 +11,7 @@ fn test_c2ixys() {
     let y = 0.4020579816732961219e-4;
     let s = -0.1220040848472271978e-7;
 
-    let rc2i = &mut [[0.0; 3]; 3];
-    c2ixys(x, y, s, rc2i);
+    let rc2i = c2ixys(x, y, s);
 
     assert!((rc2i[0][0] - 0.9999998323037157138).abs() < 1e-12, "c2ixys 11");
     assert!((rc2i[0][1] - 0.5581984869168499149e-9).abs() < 1e-12, "c2ixys 12");
@@ -40,8 +44,7 @@ fn test_nut00a() {
 
 #[test]
 fn test_pnm00a() {
-    let rbpn = &mut [[0.0; 3]; 3];
-    pnm00a(2400000.5, 50123.9999, rbpn);
+    let rbpn = pnm00a(2400000.5, 50123.9999);
 
     assert!((rbpn[0][0] - 0.9999995832793134257).abs() < 1e-12, "pnm00a 11");
     assert!((rbpn[0][1] - 0.8372384254137809439e-3).abs() < 1e-14, "pnm00a 12");
@@ -81,4 +84,207 @@ fn test_s06() {
     let s = s06(2400000.5, 53736.0, x, y);
 
     assert!((s - -0.1220032213076463117e-7).abs() < 1e-18, "s06");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_pnm06a_quat() {
+    let rbpn = pnm06a(2400000.5, 50123.9999);
+    let q = sofars::vm::r2q(&rbpn);
+    let r = sofars::vm::q2r(&q);
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((r[i][j] - rbpn[i][j]).abs() < 1e-15, "pnm06a quaternion");
+        }
+    }
+}
+
+#[test]
+fn test_c2t06a() {
+    let rc2t = c2t06a(2400000.5, 53736.0, 2400000.5, 53736.0, 2.55060238e-7, 1.860359247e-6);
+
+    assert!((rc2t[0][0] - -0.1810332128305897282).abs() < 1e-12, "c2t06a 11");
+    assert!((rc2t[0][1] - 0.9834769806938592296).abs() < 1e-12, "c2t06a 12");
+    assert!((rc2t[0][2] - 0.6555550962998436505e-4).abs() < 1e-12, "c2t06a 13");
+    assert!((rc2t[1][0] - -0.9834768134136214897).abs() < 1e-12, "c2t06a 21");
+    assert!((rc2t[1][1] - -0.1810332203649130832).abs() < 1e-12, "c2t06a 22");
+    assert!((rc2t[1][2] - 0.5749800844905594110e-3).abs() < 1e-12, "c2t06a 23");
+    assert!((rc2t[2][0] - 0.5773474024748545878e-3).abs() < 1e-12, "c2t06a 31");
+    assert!((rc2t[2][1] - 0.3961816829632690581e-4).abs() < 1e-12, "c2t06a 32");
+    assert!((rc2t[2][2] - 0.9999998325501747785).abs() < 1e-12, "c2t06a 33");
+}
+
+#[test]
+fn test_cip_corrections() {
+    let (d1, d2) = (2400000.5, 53736.0);
+    let (dx, dy) = (0.1750 * DMAS2R, -0.2259 * DMAS2R);
+
+    /* Zero offsets reproduce the model. */
+    assert_eq!(c2i06a_cip(d1, d2, 0.0, 0.0), c2i06a(d1, d2));
+    assert_eq!(c2t06a_cip(d1, d2, d1, d2, 1e-7, 2e-6, 0.0, 0.0), c2t06a(d1, d2, d1, d2, 1e-7, 2e-6));
+    let rm = pnm06a(d1, d2);
+    let r0 = pnm06a_cip(d1, d2, 0.0, 0.0);
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((r0[i][j] - rm[i][j]).abs() < 1e-15, "pnm06a_cip zero");
+        }
+    }
+
+    /* The corrected matrices carry the corrected CIP. */
+    let (x, y) = bpn2xy(&rm);
+    for r in [c2i06a_cip(d1, d2, dx, dy), pnm06a_cip(d1, d2, dx, dy)] {
+        let (xc, yc) = bpn2xy(&r);
+        assert!((xc - x - dx).abs() < 1e-15, "corrected X");
+        assert!((yc - y - dy).abs() < 1e-15, "corrected Y");
+    }
+    let rc2t = c2t06a_cip(d1, d2, d1, d2, 0.0, 0.0, dx, dy);
+    let rc2t0 = c2t06a(d1, d2, d1, d2, 0.0, 0.0);
+    assert!((rc2t[2][0] - rc2t0[2][0] - dx).abs() < 1e-15, "c2t06a_cip X");
+    assert!((rc2t[2][1] - rc2t0[2][1] - dy).abs() < 1e-15, "c2t06a_cip Y");
+}
+
+#[test]
+fn test_ltp() {
+    let rp = ltp(1666.666);
+
+    assert!((rp[0][0] - 0.9967044141159213819).abs() < 1e-14, "ltp 11");
+    assert!((rp[0][1] - 0.7437801893193210840e-1).abs() < 1e-14, "ltp 12");
+    assert!((rp[0][2] - 0.3237624409345603401e-1).abs() < 1e-14, "ltp 13");
+    assert!((rp[1][0] - -0.7437802731819618167e-1).abs() < 1e-14, "ltp 21");
+    assert!((rp[1][1] - 0.9972293894454533070).abs() < 1e-14, "ltp 22");
+    assert!((rp[1][2] - -0.1205768842723593346e-2).abs() < 1e-14, "ltp 23");
+    assert!((rp[2][0] - -0.3237622482766575399e-1).abs() < 1e-14, "ltp 31");
+    assert!((rp[2][1] - -0.1206286039697609008e-2).abs() < 1e-14, "ltp 32");
+    assert!((rp[2][2] - 0.9994750246704010914).abs() < 1e-14, "ltp 33");
+}
+
+#[test]
+fn test_ltpb() {
+    let rpb = ltpb(1666.666);
+
+    assert!((rpb[0][0] - 0.9967044167723271851).abs() < 1e-14, "ltpb 11");
+    assert!((rpb[0][1] - 0.7437794731203340345e-1).abs() < 1e-14, "ltpb 12");
+    assert!((rpb[0][2] - 0.3237632684841625547e-1).abs() < 1e-14, "ltpb 13");
+    assert!((rpb[1][0] - -0.7437795663437177152e-1).abs() < 1e-14, "ltpb 21");
+    assert!((rpb[1][1] - 0.9972293947500013666).abs() < 1e-14, "ltpb 22");
+    assert!((rpb[1][2] - -0.1205741865911243235e-2).abs() < 1e-14, "ltpb 23");
+    assert!((rpb[2][0] - -0.3237630543224664992e-1).abs() < 1e-14, "ltpb 31");
+    assert!((rpb[2][1] - -0.1206316791076485295e-2).abs() < 1e-14, "ltpb 32");
+    assert!((rpb[2][2] - 0.9994750220222438819).abs() < 1e-14, "ltpb 33");
+}
+
+#[test]
+fn test_ltpecl() {
+    let vec = ltpecl(-1500.0);
+
+    assert!((vec[0] - 0.4768625676477096525e-3).abs() < 1e-14, "ltpecl 1");
+    assert!((vec[1] - -0.4052259533091875112).abs() < 1e-14, "ltpecl 2");
+    assert!((vec[2] - 0.9142164401096448012).abs() < 1e-14, "ltpecl 3");
+}
+
+#[test]
+fn test_ltpequ() {
+    let veq = ltpequ(-2500.0);
+
+    assert!((veq[0] - -0.3586652560237326659).abs() < 1e-14, "ltpequ 1");
+    assert!((veq[1] - -0.1996978910771128475).abs() < 1e-14, "ltpequ 2");
+    assert!((veq[2] - 0.9118552442250819624).abs() < 1e-14, "ltpequ 3");
+}
+
+#[test]
+fn test_fw2xy() {
+    let gamb = -0.2243387670997992368e-5;
+    let phib = 0.4091014602391312982;
+    let psi = -0.9501954178013015092e-3;
+    let eps = 0.4091014316587367472;
+
+    let (x, y) = fw2xy(gamb, phib, psi, eps);
+
+    assert!((x - -0.3779734957034082790e-3).abs() < 1e-14, "fw2xy x");
+    assert!((y - -0.1924880848087615651e-6).abs() < 1e-14, "fw2xy y");
+}
+
+#[test]
+fn test_fw_angles() {
+    let (date1, date2) = (2400000.5, 50123.9999);
+
+    /* With IAU 2000A nutation the matrix is pnm06a. */
+    let (dpsi, deps) = nut06a(date1, date2);
+    let fw = FwAngles::iau2006(date1, date2).with_nutation(dpsi, deps);
+    let rbpn = pnm06a(date1, date2);
+    let r = fw.matrix().to_array();
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((r[i][j] - rbpn[i][j]).abs() < 1e-15, "FwAngles: matrix");
+        }
+    }
+
+    /* Frame bias alone. */
+    let rb = FwAngles::bias().matrix().to_array();
+    assert!((rb[0][1] - -0.7078368960971557145e-7).abs() < 1e-14, "FwAngles: bias 12");
+    assert!((rb[2][0] - -0.8056214211620056792e-7).abs() < 1e-14, "FwAngles: bias 31");
+
+    /* Pole offsets move the CIP. */
+    let (dx, dy) = (0.1750 * DMAS2R, -0.2259 * DMAS2R);
+    let (x0, y0) = fw.xy();
+    let (x, y) = fw.with_cip_offsets(dx, dy).xy();
+    assert!((x - x0 - dx).abs() < 1e-15, "FwAngles: dX");
+    assert!((y - y0 - dy).abs() < 1e-15, "FwAngles: dY");
+}
+
+#[test]
+fn test_eo06a() {
+    let eo = eo06a(2400000.5, 53736.0);
+
+    assert!((eo - -0.1332882371941833644e-2).abs() < 1e-15, "eo06a");
+}
+
+#[test]
+fn test_pmat06() {
+    let rbp = pmat06(2400000.5, 50123.9999);
+
+    assert!((rbp[0][0] - 0.9999995505176007047).abs() < 1e-12, "pmat06: 11");
+    assert!((rbp[0][1] - 0.8695404617348208406e-3).abs() < 1e-14, "pmat06: 12");
+    assert!((rbp[0][2] - 0.3779735201865589104e-3).abs() < 1e-14, "pmat06: 13");
+
+    assert!((rbp[1][0] - -0.8695404723772031414e-3).abs() < 1e-14, "pmat06: 21");
+    assert!((rbp[1][1] - 0.9999996219496027161).abs() < 1e-12, "pmat06: 22");
+    assert!((rbp[1][2] - -0.1361752497080270143e-6).abs() < 1e-14, "pmat06: 23");
+
+    assert!((rbp[2][0] - -0.3779734957034082790e-3).abs() < 1e-14, "pmat06: 31");
+    assert!((rbp[2][1] - -0.1924880848087615651e-6).abs() < 1e-14, "pmat06: 32");
+    assert!((rbp[2][2] - 0.9999999285679971958).abs() < 1e-12, "pmat06: 33");
+}
+
+#[test]
+fn test_jd_forms() {
+    let tt = Jd(2400000.5, 50123.9999);
+    assert_eq!(sofars::pnp::pnm06a_jd(tt), pnm06a(2400000.5, 50123.9999));
+    assert_eq!(sofars::pnp::nut06a_jd((2400000.5, 53736.0)), nut06a(2400000.5, 53736.0));
+    assert_eq!(
+        sofars::pnp::c2t06a_jd(Jd(2400000.5, 53736.0), Jd(2400000.5, 53736.0), 2.55060238e-7, 1.860359247e-6),
+        c2t06a(2400000.5, 53736.0, 2400000.5, 53736.0, 2.55060238e-7, 1.860359247e-6)
+    );
+    assert_eq!(
+        sofars::pnp::c2t06a_cip_jd(Jd(2400000.5, 53736.0), Jd(2400000.5, 53736.0), 2.55060238e-7, 1.860359247e-6, 1e-9, -1e-9),
+        c2t06a_cip(2400000.5, 53736.0, 2400000.5, 53736.0, 2.55060238e-7, 1.860359247e-6, 1e-9, -1e-9)
+    );
+    assert_eq!(
+        sofars::pnp::pn00_mat3_jd(tt, -0.9632552291149335877e-5, 0.4063197106621141414e-4),
+        pn00_mat3(2400000.5, 50123.9999, -0.9632552291149335877e-5, 0.4063197106621141414e-4)
+    );
+}
+
+#[test]
+fn test_pn00_mat3() {
+    /* With the IAU 2000A nutation, pn00 gives the pnm00a matrix. */
+    let (dpsi, deps) = nut00a(2400000.5, 50123.9999);
+    let pn = pn00(2400000.5, 50123.9999, dpsi, deps);
+    assert!((pn.rbpn[0][0] - 0.9999995832793134257).abs() < 1e-12, "pn00: rbpn11");
+    assert!((pn.rbpn[0][1] - 0.8372384254137809439e-3).abs() < 1e-14, "pn00: rbpn12");
+    assert!((pn.rbpn[2][2] - 0.9999999329094390695).abs() < 1e-12, "pn00: rbpn33");
+
+    /* The Mat3 form carries the same matrices. */
+    let pm = pn00_mat3(2400000.5, 50123.9999, dpsi, deps);
+    assert_eq!(pm.rbpn[(0, 1)], pn.rbpn[0][1]);
+    assert_eq!(PrecNut::from(pm), pn);
+}
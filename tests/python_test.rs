@@ -0,0 +1,36 @@
+#![cfg(feature = "python")]
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyModule};
+
+#[test]
+fn test_python_module() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let m = PyModule::new(py, "sofars").unwrap();
+        sofars::python::sofars(&m).unwrap();
+        let locals = PyDict::new(py);
+        locals.set_item("s", m).unwrap();
+        py.run(c"
+e = s.Epoch.parse('2016-12-31T23:59:60.5Z')
+assert e.scale == 'UTC'
+assert e.to('TT').to_iso(3) == '2017-01-01T00:01:08.684'
+assert str(e.to('TT')) == '2017-01-01T00:01:08.684 TT'
+
+eo = s.EarthOrientation.from_iers(0.1, 0.05, 0.3)
+m = s.gcrs_to_itrs(e, eo)
+t = s.itrs_to_gcrs(e, eo)
+assert abs(sum(m[0][k] * t[k][0] for k in range(3)) - 1.0) < 1e-15
+
+refa, refb = s.refco(800.0, 10.0, 0.9, 0.4)
+assert abs(refa - 0.2264949956241415009e-3) < 1e-15
+(ph, vh), (pb, vb) = s.epv00(2400000.5, 53411.52501161)
+assert abs(ph[0] - -0.7757238809297706813) < 1e-14
+
+try:
+    s.Epoch.parse('2024-3-01')
+    assert False
+except ValueError:
+    pass
+", Some(&locals), None).unwrap();
+    });
+}
@@ -0,0 +1,84 @@
+use sofars::star::*;
+use sofars::vm::PvVec;
+
+#[test]
+fn test_starpv() {
+    let (pv, j) = starpv(0.01686756, -1.093989828, -1.78323516e-5, 2.336024047e-6, 0.74723, -21.6);
+
+    assert!((pv[0][0] - 126668.5912743160601).abs() < 1e-10, "starpv: 11");
+    assert!((pv[0][1] - 2136.792716839935195).abs() < 1e-12, "starpv: 12");
+    assert!((pv[0][2] - -245251.2339876830091).abs() < 1e-10, "starpv: 13");
+
+    assert!((pv[1][0] - -0.4051854008955659551e-2).abs() < 1e-13, "starpv: 21");
+    assert!((pv[1][1] - -0.6253919754414777970e-2).abs() < 1e-15, "starpv: 22");
+    assert!((pv[1][2] - 0.1189353714588109341e-1).abs() < 1e-13, "starpv: 23");
+
+    assert_eq!(j, 0);
+
+    let (_, j) = starpv(0.01686756, -1.093989828, 0.0, 0.0, 0.0, -21.6);
+    assert_eq!(j, 1);
+}
+
+#[test]
+fn test_pvstar() {
+    /* The starpv test result. */
+    let pv = [
+        [126668.5912743160601, 2136.792716839935195, -245251.2339876830091],
+        [-0.4051854008955659551e-2, -0.6253919754414777970e-2, 0.1189353714588109341e-1],
+    ];
+
+    let s = pvstar(&pv).unwrap();
+    assert!((s.ra - 0.1686756e-1).abs() < 1e-12, "pvstar: ra");
+    assert!((s.dec - -1.093989828).abs() < 1e-12, "pvstar: dec");
+    assert!((s.pmr - -1.78323516e-5).abs() < 1e-16, "pvstar: pmr");
+    assert!((s.pmd - 2.336024047e-6).abs() < 1e-16, "pvstar: pmd");
+    assert!((s.px - 0.74723).abs() < 1e-14, "pvstar: px");
+    assert!((s.rv - -21.6).abs() < 1e-9, "pvstar: rv");
+
+    assert_eq!(pvstar(&[[0.0; 3], pv[1]]), Err(-2));
+    assert_eq!(pvstar(&[pv[0], [1e3, 0.0, 0.0]]), Err(-1));
+}
+
+#[test]
+fn test_star_pv() {
+    let star = Star::new(0.01686756, -1.093989828, -1.78323516e-5, 2.336024047e-6, 0.74723, -21.6);
+    let close = |a: &Star, b: &Star| {
+        (a.ra - b.ra).abs() < 1e-12
+            && (a.dec - b.dec).abs() < 1e-12
+            && (a.pmr - b.pmr).abs() < 1e-16
+            && (a.pmd - b.pmd).abs() < 1e-16
+            && (a.px - b.px).abs() < 1e-12
+            && (a.rv - b.rv).abs() < 1e-9
+    };
+
+    /* The default is starpv. */
+    let (pv, j) = star.to_pv(&StarPvOptions::default());
+    assert_eq!(j, 0);
+    assert_eq!(pv.to_array(), starpv(star.ra, star.dec, star.pmr, star.pmd, star.px, star.rv).0);
+
+    /* Round trips. */
+    for rv in [RvConvention::Kinematic, RvConvention::Barycentric] {
+        for relativistic in [true, false] {
+            let opts = StarPvOptions { rv, relativistic };
+            let (pv, j) = star.to_pv(&opts);
+            assert_eq!(j, 0);
+            let s = Star::from_pv(&pv, &opts).unwrap();
+            assert!(close(&s, &star), "round trip {:?}: {:?}", opts, s);
+        }
+    }
+
+    /* Geometric:  the radial velocity is the rate of change of distance. */
+    let (pv, _) = star.to_pv(&StarPvOptions::GEOMETRIC);
+    let (r, rd) = (pv.modulus().0, pv.dot(&pv).1 / 2.0);
+    assert!((rd / r * 149597870.7 / 86400.0 - star.rv).abs() < 1e-9, "geometric: rv");
+
+    /* Barycentric cz:  the transverse Doppler effect adds v_t^2/2c. */
+    let fast = Star::new(1.0, 0.5, 2e-5, -3e-5, 0.5, 0.0);
+    let opts = StarPvOptions { rv: RvConvention::Barycentric, relativistic: false };
+    let (pv, _) = fast.to_pv(&opts);
+    let k = Star::from_pv(&pv, &StarPvOptions::GEOMETRIC).unwrap();
+    let vt = (2e-5 * 0.5f64.cos()).hypot(3e-5) * 206264.8062470964 / 0.5 * 4.740470463533348;
+    assert!((k.rv - -vt * vt / 2.0 / 299792.458).abs() < 1e-9, "barycentric: rv");
+
+    assert_eq!(Star::from_pv(&PvVec::default(), &StarPvOptions::GEOMETRIC), Err(-2));
+}
@@ -0,0 +1,101 @@
+use sofars::eph::epv00;
+use sofars::frames::{gcrs_to_itrs, EarthOrientation};
+use sofars::stream::{orientation_stream, pv_stream, ChebStream, Chebyshev};
+use sofars::ts::{Epoch, TimeScale};
+use sofars::vm::PvVec;
+
+#[test]
+fn test_chebyshev() {
+    /* A cubic is reproduced exactly at degree 3. */
+    let f = |t: f64| [t * t * t - 2.0 * t + 1.0, 3.0 * t];
+    let c = Chebyshev::fit(-1.0, 2.0, 3, |t| Ok(f(t))).unwrap();
+    assert_eq!(c.coef.len(), 4);
+    for t in [-1.0, -0.3, 0.0, 0.7, 2.0] {
+        let (v, w) = (c.eval(t), f(t));
+        assert!((v[0] - w[0]).abs() < 1e-13 && (v[1] - w[1]).abs() < 1e-13, "eval {}", t);
+    }
+    assert!(c.contains(2.0) && !c.contains(2.1));
+    assert_eq!(Chebyshev::<1>::fit(0.0, 1.0, 3, |_| Err(-7)), Err(-7));
+}
+
+#[test]
+fn test_cheb_stream() {
+    let start = Epoch::from_calendar(TimeScale::TT, 2020, 3, 1, 0, 0, 0.0).unwrap();
+
+    /* 0, 6, ... 48 hours:  9 epochs, two spans of 3 nodes. */
+    let mut calls = 0;
+    let s = ChebStream::new(&start, 2.0, 21600.0, |t: &Epoch| {
+        calls += 1;
+        Ok([t.jd - start.jd])
+    })
+    .degree(2);
+    assert_eq!(s.size_hint(), (9, Some(9)));
+    let v: Vec<_> = s.map(|r| r.unwrap()).collect();
+    assert_eq!(v.len(), 9);
+    for (e, x) in &v {
+        assert!((x[0] - (e.jd - start.jd)).abs() < 1e-12, "linear");
+    }
+    assert!((v[8].0.jd - start.jd - 2.0).abs() < 1e-12, "end");
+    assert_eq!(calls, 6);
+
+    /* Errors end the series. */
+    let mut s = ChebStream::new(&start, 2.0, 3600.0, |_: &Epoch| Err::<[f64; 1], i32>(3));
+    assert_eq!(s.next().map(|r| r.is_err()), Some(true));
+    assert!(s.next().is_none());
+}
+
+#[test]
+#[should_panic(expected = "step must be positive")]
+fn test_cheb_stream_step() {
+    let start = Epoch::from_calendar(TimeScale::TT, 2020, 3, 1, 0, 0, 0.0).unwrap();
+    let _ = ChebStream::new(&start, 1.0, 0.0, |_: &Epoch| Ok([0.0]));
+}
+
+#[test]
+#[should_panic(expected = "span must be positive")]
+fn test_cheb_stream_span() {
+    let start = Epoch::from_calendar(TimeScale::TT, 2020, 3, 1, 0, 0, 0.0).unwrap();
+    let _ = ChebStream::new(&start, 1.0, 60.0, |_: &Epoch| Ok([0.0])).span(0.0);
+}
+
+#[test]
+#[should_panic(expected = "days must not be negative")]
+fn test_cheb_stream_days() {
+    let start = Epoch::from_calendar(TimeScale::TT, 2020, 3, 1, 0, 0, 0.0).unwrap();
+    let _ = ChebStream::new(&start, -1.0, 60.0, |_: &Epoch| Ok([0.0]));
+}
+
+#[test]
+fn test_orientation_stream() {
+    let start = Epoch::from_calendar(TimeScale::UTC, 2021, 6, 30, 12, 0, 0.0).unwrap();
+    let eo = EarthOrientation::from_iers(-0.18, 0.1, 0.45, 0.2, -0.1);
+    let mut n = 0;
+    for r in orientation_stream(&start, 2.5, 3700.0, eo) {
+        let (epoch, r) = r.unwrap();
+        let r0 = gcrs_to_itrs(&epoch, &eo).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((r[(i, j)] - r0[(i, j)]).abs() < 1e-13, "rc2t {} {}", n, i);
+            }
+        }
+        n += 1;
+    }
+    assert_eq!(n, 59);
+}
+
+#[test]
+fn test_pv_stream() {
+    let start = Epoch::from_calendar(TimeScale::TT, 2010, 1, 1, 0, 0, 0.0).unwrap();
+    let earth = |t: &Epoch| {
+        let (tt1, tt2) = t.jd.into();
+        Ok(PvVec::from(epv00(tt1, tt2).ok_or(1)?.1))
+    };
+    for r in pv_stream(&start, 3.0, 5000.0, earth) {
+        let (epoch, pv) = r.unwrap();
+        let pv0 = earth(&epoch).unwrap();
+        for i in 0..3 {
+            assert!((pv.p[i] - pv0.p[i]).abs() < 1e-12, "p");
+            assert!((pv.v[i] - pv0.v[i]).abs() < 1e-13, "v");
+        }
+    }
+}
@@ -11,4 +11,205 @@ fn test_dat() {
 
     deltat = ts::dat(2017, 9, 1, 0.0).unwrap();
     assert!((deltat - 37.0).abs() < 1e-12, "iauDat d3");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_d2dtf() {
+    let (iy, im, id, ihmsf) = ts::d2dtf("UTC", 5, 2400000.5, 49533.99999).unwrap();
+    assert_eq!((iy, im, id), (1994, 6, 30), "iauD2dtf date");
+    assert_eq!(ihmsf, [23, 59, 60, 13599], "iauD2dtf time");
+
+    /* Before UTC:  a warning, as from dat. */
+    assert_eq!(ts::d2dtf("UTC", 3, 2433433.5, 0.25), Err(1));
+    assert_eq!(ts::d2dtf("TAI", 3, 2433433.5, 0.25).unwrap(), (1950, 6, 1, [6, 0, 0, 0]));
+}
+
+#[test]
+fn test_taiutc() {
+    let (u1, u2) = ts::taiutc(2453750.5, 0.892482639).unwrap();
+    assert!((u1 - 2453750.5).abs() < 1e-6, "iauTaiutc u1");
+    assert!((u2 - 0.8921006945555555556).abs() < 1e-12, "iauTaiutc u2");
+}
+
+#[test]
+fn test_tttai() {
+    let (a1, a2) = ts::tttai(2453750.5, 0.892482639).unwrap();
+    assert!((a1 - 2453750.5).abs() < 1e-6, "iauTttai a1");
+    assert!((a2 - 0.892110139).abs() < 1e-12, "iauTttai a2");
+}
+
+#[test]
+fn test_epoch_iso() {
+    use ts::{Epoch, TimeScale};
+
+    /* Calendar, ordinal and week forms of the same instant. */
+    let e = Epoch::parse("2024-03-01T12:34:56.789Z").unwrap();
+    assert_eq!(e.scale, TimeScale::UTC);
+    let o = Epoch::from_iso("2024-061T12:34:56.789", TimeScale::UTC).unwrap();
+    let w = Epoch::from_iso("2024-W09-5T12:34:56.789", TimeScale::UTC).unwrap();
+    assert!((e.jd - o.jd).abs() < 1e-12, "ordinal");
+    assert!((e.jd - w.jd).abs() < 1e-12, "week");
+    assert_eq!(e.to_iso(3).unwrap(), "2024-03-01T12:34:56.789Z");
+    assert_eq!(Epoch::from_iso("2020-W53-7", TimeScale::TT).unwrap().to_iso(0).unwrap(),
+               "2021-01-03T00:00:00");
+    assert!(Epoch::from_iso("2021-W53-1", TimeScale::TT).is_err(), "no week 53");
+
+    /* Zone offset and end of day. */
+    let z = Epoch::parse("2024-03-01T13:34:56.789+01:00").unwrap();
+    assert!((z.jd - e.jd).abs() < 1e-12, "offset");
+    let m = Epoch::parse("2024-02-29T24:00").unwrap();
+    assert_eq!(m.to_iso(0).unwrap(), "2024-03-01T00:00:00Z");
+    assert_eq!(Epoch::from_iso("2024-03-01T12:00Z", TimeScale::TT), Err(-7));
+
+    /* Leap second round trip. */
+    let l = Epoch::parse("2016-12-31T23:59:60.5Z").unwrap();
+    assert_eq!(l.to_iso(1).unwrap(), "2016-12-31T23:59:60.5Z");
+    assert_eq!(Epoch::parse("2016-12-30T23:59:60.5Z"), Err(-6));
+    assert_eq!(Epoch::parse("2016-12-31T12:00:60Z"), Err(-6));
+
+    /* Scale suffix, Display and conversion. */
+    let t = l.to(TimeScale::TT).unwrap();
+    assert_eq!(t.to_string(), "2017-01-01T00:01:08.684 TT");
+    let b: Epoch = t.to_string().parse().unwrap();
+    assert_eq!(b.scale, TimeScale::TT);
+    assert!((b.jd - t.jd).abs() * 86400.0 < 1e-6, "round trip");
+    assert_eq!(t.to(TimeScale::UTC).unwrap().to_iso(1).unwrap(), "2016-12-31T23:59:60.5Z");
+    assert_eq!(t.to(TimeScale::UT1), Err(-9));
+
+    /* Extended years and syntax errors. */
+    let x = Epoch::from_iso("-0500-03-01T00:00", TimeScale::TT).unwrap();
+    assert_eq!(x.to_iso(0).unwrap(), "-0500-03-01T00:00:00");
+
+    /* UTC before 1960 is formatted with TAI-UTC = 0, as in SOFA. */
+    let u = Epoch::new(TimeScale::UTC, sofars::cal::Jd(2433433.5, 0.25));
+    assert_eq!(u.to_iso(3).unwrap(), "1950-06-01T06:00:00.000Z");
+    assert_eq!(Epoch::parse("2024-3-01"), Err(-7));
+    assert_eq!(Epoch::parse("2024-03-01T12:3"), Err(-7));
+}
+
+#[test]
+fn test_deltat() {
+    use ts::{deltat_approx, deltat_year};
+
+    assert!((deltat_year(2000.0) - 63.86).abs() < 1e-12, "deltat 2000");
+    assert!((deltat_year(1900.0) - -2.79).abs() < 1e-12, "deltat 1900");
+    assert!((deltat_year(1000.0) - 1574.2).abs() < 1e-12, "deltat 1000");
+    assert!((deltat_year(0.0) - 10583.6).abs() < 1e-12, "deltat 0");
+    assert!((deltat_year(-1000.0) - 25427.68).abs() < 1e-9, "deltat -1000");
+    assert!((deltat_year(2200.0) - 442.08).abs() < 1e-9, "deltat 2200");
+
+    /* Continuous at the joins, to the model's own mismatch. */
+    for y in [1600.0, 1700.0, 1800.0, 1860.0, 1900.0, 1920.0, 1941.0, 1961.0, 1986.0, 2005.0, 2050.0] {
+        let d = deltat_year(y - 1e-9) - deltat_year(y);
+        assert!(d.abs() < 1.0, "deltat join {} {}", y, d);
+    }
+
+    /* Observed:  1980.0 50.54 s, 1995.0 60.79 s. */
+    assert!((deltat_approx(2444239.5, 0.0) - 50.54).abs() < 0.5, "deltat 1980");
+    assert!((deltat_approx(2449718.5, 0.0) - 60.79).abs() < 0.5, "deltat 1995");
+}
+
+#[test]
+fn test_epoch_approx() {
+    use sofars::frames::EarthOrientation;
+    use ts::{deltat_approx, Epoch, TimeScale};
+
+    /* 1900, before UTC. */
+    let tt = Epoch::from_calendar(TimeScale::TT, 1900, 1, 1, 0, 0, 0.0).unwrap();
+    assert!(tt.to(TimeScale::UT1).is_err());
+    let ut1 = tt.to_approx(TimeScale::UT1).unwrap();
+    let (d1, d2): (f64, f64) = ut1.jd.into();
+    let (t1, t2): (f64, f64) = tt.jd.into();
+    assert!((((t1 - d1) + (t2 - d2)) * 86400.0 - deltat_approx(t1, t2)).abs() < 1e-3, "to_approx");
+
+    /* And back. */
+    let back = ut1.to_approx(TimeScale::TT).unwrap();
+    let (b1, b2): (f64, f64) = back.jd.into();
+    assert!(((b1 - t1) + (b2 - t2)).abs() * 86400.0 < 1e-3, "to_approx back");
+
+    /* Modern epoch:  within a second of the IERS value (+0.3554 s). */
+    let utc = Epoch::from_calendar(TimeScale::UTC, 2000, 1, 1, 12, 0, 0.0).unwrap();
+    let eo = EarthOrientation::from_deltat(&utc).unwrap();
+    assert!((eo.ut1_utc - 0.3554).abs() < 1.0, "from_deltat");
+}
+
+#[test]
+fn test_dtdb() {
+    /* SOFA -0.1280368005936998991e-2; leading terms only, see Note 6. */
+    let dt = ts::dtdb(2448939.5, 0.123, 0.76543, 5.0123, 5525.242, 3190.0);
+    assert!((dt - -0.1280368005936998991e-2).abs() < 1e-7, "iauDtdb");
+}
+
+#[test]
+fn test_tdb_tcg_tcb() {
+    let (a, b) = ts::tttdb(2453750.5, 0.892855139, -0.000201).unwrap();
+    assert!((a - 2453750.5).abs() < 1e-6, "iauTttdb tdb1");
+    assert!((b - 0.8928551366736111111).abs() < 1e-12, "iauTttdb tdb2");
+
+    let (a, b) = ts::tdbtt(2453750.5, 0.892855137, -0.000201).unwrap();
+    assert!((a - 2453750.5).abs() < 1e-6, "iauTdbtt tt1");
+    assert!((b - 0.8928551393263888889).abs() < 1e-12, "iauTdbtt tt2");
+
+    let (a, b) = ts::tttcg(2453750.5, 0.892482639).unwrap();
+    assert!((a - 2453750.5).abs() < 1e-6, "iauTttcg tcg1");
+    assert!((b - 0.8924900312508587113).abs() < 1e-12, "iauTttcg tcg2");
+
+    let (a, b) = ts::tcgtt(2453750.5, 0.892862531).unwrap();
+    assert!((a - 2453750.5).abs() < 1e-6, "iauTcgtt tt1");
+    assert!((b - 0.8928551387488816828).abs() < 1e-12, "iauTcgtt tt2");
+
+    let (a, b) = ts::tdbtcb(2453750.5, 0.892855137).unwrap();
+    assert!((a - 2453750.5).abs() < 1e-6, "iauTdbtcb tcb1");
+    assert!((b - 0.8930195997253656716).abs() < 1e-12, "iauTdbtcb tcb2");
+
+    let (a, b) = ts::tcbtdb(2453750.5, 0.893019599).unwrap();
+    assert!((a - 2453750.5).abs() < 1e-6, "iauTcbtdb tdb1");
+    assert!((b - 0.8928551362746343397).abs() < 1e-12, "iauTcbtdb tdb2");
+}
+
+#[test]
+fn test_epoch_tdb() {
+    use sofars::astro::Site;
+    use ts::{Epoch, TimeScale};
+
+    let utc = Epoch::from_calendar(TimeScale::UTC, 2006, 1, 15, 21, 24, 37.5).unwrap();
+    let tt = utc.to(TimeScale::TT).unwrap();
+
+    /* TDB-TT within the 1.7 ms annual term, and back again. */
+    let tdb = utc.to(TimeScale::TDB).unwrap();
+    assert!((tdb.jd - tt.jd).abs() * 86400.0 < 1.7e-3, "TDB-TT");
+    let back = tdb.to(TimeScale::UTC).unwrap();
+    assert!((back.jd - utc.jd).abs() * 86400.0 < 1e-9, "TDB round trip");
+
+    /* TCB and TCG via TT, and round trips. */
+    let tcb = tt.to(TimeScale::TCB).unwrap();
+    let tcg = tcb.to(TimeScale::TCG).unwrap();
+    assert!((tcg.to(TimeScale::TT).unwrap().jd - tt.jd).abs() * 86400.0 < 1e-9, "TCG");
+    assert!((tcb.to(TimeScale::TT).unwrap().jd - tt.jd).abs() * 86400.0 < 1e-9, "TCB");
+    assert!(((tcg.jd - tt.jd).abs() * 86400.0 - 0.6).abs() < 0.1, "TCG-TT 2006");
+
+    /* Topocentric terms are microseconds. */
+    let site = Site::new(-2.0, 0.5, 2000.0);
+    let topo = tt.to_topocentric(TimeScale::TDB, &site).unwrap();
+    let d = (topo.jd - tdb.jd).abs() * 86400.0;
+    assert!(d > 1e-8 && d < 3e-6, "topocentric");
+}
+
+#[test]
+fn test_jd_forms() {
+    use sofars::cal::Jd;
+
+    let (d1, d2) = ts::tttai(2453750.5, 0.892482639).unwrap();
+    assert_eq!(ts::tttai_jd(Jd(2453750.5, 0.892482639)).unwrap(), Jd(d1, d2));
+    assert_eq!(ts::tttai_jd((2453750.5, 0.892482639)).unwrap(), Jd(d1, d2));
+
+    let (d1, d2) = ts::utcut1(2453750.5, 0.892100694, 0.3341).unwrap();
+    assert_eq!(ts::utcut1_jd(Jd(2453750.5, 0.892100694), 0.3341).unwrap(), Jd(d1, d2));
+
+    let dtdb = ts::dtdb(2448939.5, 0.123, 0.76543, 5.0123, 5525.242, 3190.0);
+    assert_eq!(ts::dtdb_jd(Jd(2448939.5, 0.123), 0.76543, 5.0123, 5525.242, 3190.0), dtdb);
+
+    let jd = ts::dtf2d_jd("UTC", 1994, 6, 30, 23, 59, 60.13599).unwrap();
+    assert_eq!((jd.0, jd.1), ts::dtf2d("UTC", 1994, 6, 30, 23, 59, 60.13599).unwrap());
+    assert_eq!(ts::d2dtf_jd("UTC", 5, jd).unwrap(), ts::d2dtf("UTC", 5, jd.0, jd.1).unwrap());
+}
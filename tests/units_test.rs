@@ -0,0 +1,34 @@
+use sofars::consts::{DAS2R, DD2R, DPI};
+use sofars::prelude::*;
+use sofars::vm::tf2a;
+
+#[test]
+fn test_units() {
+    assert_eq!(3.5.deg(), 3.5 * DD2R);
+    assert_eq!(1.2.arcsec(), 1.2 * DAS2R);
+    assert!((30.0.arcmin() - 0.5.deg()).abs() < 1e-17);
+    assert!((1000.0.mas() - 1.0.arcsec()).abs() < 1e-20);
+    assert!((1000.0.uas() - 1.0.mas()).abs() < 1e-23);
+    assert!((12.0.hourangle() - DPI).abs() < 1e-15);
+    assert_eq!(tf2a(' ', 14, 34, 16.81183).unwrap(), (14.0 + 34.0 / 60.0 + 16.81183 / 3600.0).hourangle());
+
+    assert_eq!(12.0.hours(), 0.5);
+    assert_eq!(90.0.minutes(), 0.0625);
+    assert_eq!(43200.0.seconds(), 0.5);
+
+    assert!((DPI.to_deg() - 180.0).abs() < 1e-13);
+    assert!((1.5.arcsec().to_arcsec() - 1.5).abs() < 1e-13);
+    assert!((DPI.to_hourangle() - 12.0).abs() < 1e-14);
+    assert_eq!(0.25.to_seconds(), 21600.0);
+}
+
+#[test]
+fn test_prelude() {
+    let epoch = Epoch::from_calendar(TimeScale::UTC, 2024, 6, 20, 12, 0, 0.0).unwrap();
+    let later = Epoch::new(TimeScale::UTC, epoch.jd + 6.0.hours());
+    assert!(((later.jd - epoch.jd) - 0.25).abs() < 1e-12);
+
+    let a = WrappedAngle::positive(370.0.deg());
+    assert!((a.radians() - 10.0.deg()).abs() < 1e-15);
+    assert!((Angle::from_degrees(42.0).radians() - 42.0.deg()).abs() < 1e-16);
+}
@@ -0,0 +1,58 @@
+use sofars::astro::{ld, refco};
+use sofars::validate::*;
+
+#[test]
+fn test_ld_checked() {
+    let bm = 0.00028574;
+    let p = [-0.763276255, -0.608633767, -0.216735543];
+    let q = [-0.763276255, -0.608633767, -0.216735543];
+    let e = [0.76700421, 0.605629598, 0.211937094];
+    let em = 8.91276983;
+    let dlim = 3e-10;
+
+    let p1 = ld_checked(bm, &p, &q, &e, em, dlim).unwrap();
+    assert_eq!(p1, ld(bm, &p, &q, &e, em, dlim), "ld_checked: p1");
+
+    let r = ld_checked(bm, &p, &q.map(|x| x * 2.0), &e, em, dlim);
+    assert!(matches!(r, Err(InputError::NotUnit { name: "q", .. })), "ld_checked: q");
+    let r = ld_checked(bm, &p, &q, &e, em, 0.0);
+    assert_eq!(r, Err(InputError::NotPositive { name: "dlim", value: 0.0 }), "ld_checked: dlim");
+    let r = ld_checked(f64::NAN, &p, &q, &e, em, dlim);
+    assert_eq!(r, Err(InputError::NotFinite { name: "bm" }), "ld_checked: bm");
+}
+
+#[test]
+fn test_ldsun_ab_checked() {
+    let p = [-0.763276255, -0.608633767, -0.216735543];
+    let e = [-0.973644023, -0.20925523, -0.0907169552];
+    assert!(ldsun_checked(&p, &e, 0.999809214).is_ok(), "ldsun_checked");
+    assert!(ldsun_checked(&p, &e, -1.0).is_err(), "ldsun_checked: em");
+
+    let pnat = [-0.76321968546737951, -0.60869453983060384, -0.21676408580639883];
+    let v = [2.1044018893653786e-5, -8.9108923304429319e-5, -3.8633714797716569e-5];
+    let ppr = ab_checked(&pnat, &v, 0.99980921395708788, 0.99999999506209258).unwrap();
+    assert!((ppr[0] - -0.7631631094219556269).abs() < 1e-12, "ab_checked: ppr[0]");
+    let r = ab_checked(&pnat, &[0.8, 0.8, 0.0], 1.0, 0.5);
+    assert!(matches!(r, Err(InputError::OutOfRange { name: "|v|", .. })), "ab_checked: v");
+    assert!(ab_checked(&pnat, &v, 1.0, 1.5).is_err(), "ab_checked: bm1");
+}
+
+#[test]
+fn test_refco_checked() {
+    let r = refco_checked(800.0, 10.0, 0.9, 0.4).unwrap();
+    assert_eq!(r, refco(800.0, 10.0, 0.9, 0.4), "refco_checked");
+
+    let r = refco_checked(800.0, 10.0, 90.0, 0.4);
+    assert_eq!(r, Err(InputError::OutOfRange { name: "rh", value: 90.0, min: 0.0, max: 1.0 }),
+               "refco_checked: rh");
+    assert_eq!(r.unwrap_err().to_string(), "rh = 90 is outside [0, 1]", "refco_checked: message");
+    assert!(refco_checked(-5.0, 10.0, 0.9, 0.4).is_err(), "refco_checked: phpa");
+}
+
+#[test]
+fn test_epv00_checked() {
+    assert!(epv00_checked(2400000.5, 53411.52501161).is_ok(), "epv00_checked");
+    assert!(epv00_checked(2400000.5, f64::INFINITY).is_err(), "epv00_checked: inf");
+    let r = epv00_checked(2400000.5, 0.0);
+    assert!(matches!(r, Err(InputError::OutOfRange { name: "date", .. })), "epv00_checked: range");
+}
@@ -42,9 +42,7 @@ fn test_tr() {
         [3.0, 4.0, 5.0],
     ];
 
-    let rt = &mut [[0.0; 3]; 3];
-
-    tr(r, rt);
+    let rt = tr(r);
 
     assert!((rt[0][0] - 2.0).abs() < 1e-12, "tr 11");
     assert!((rt[0][1] - 3.0).abs() < 1e-12, "tr 12");
@@ -69,9 +67,7 @@ fn test_rxp() {
 
     let p = [0.2, 1.5, 0.1];
 
-    let mut rp = [0.0; 3];
-
-    rxp(&r, &p, &mut rp);
+    let rp = rxp(&r, &p);
 
     assert!((rp[0] - 5.1).abs() < 1e-12, "rxp 1");
     assert!((rp[1] - 3.9).abs() < 1e-12, "rxp 2");
@@ -89,9 +85,7 @@ fn test_rxpv() {
         [0.2, 1.5, 0.1],
         [1.5, 0.2, 0.1],
     ];
-    let mut rpv = [[0.0; 3]; 2];
-
-    rxpv(&r, &pv, &mut rpv);
+    let rpv = rxpv(&r, &pv);
 
     assert!((rpv[0][0] - 5.1).abs() < 1e-12);
     assert!((rpv[1][0] - 3.8).abs() < 1e-12);
@@ -116,9 +110,7 @@ fn test_trxpv() {
         [1.5, 0.2, 0.1],
     ];
 
-    let mut trpv = [[0.0; 3]; 2];
-
-    trxpv(&r, &pv, &mut trpv);
+    let trpv = trxpv(&r, &pv);
 
     assert!((trpv[0][0] - 5.2).abs() < 1e-12, "trxpv p1");
     assert!((trpv[0][1] - 4.0).abs() < 1e-12, "trxpv p1");
@@ -127,4 +119,498 @@ fn test_trxpv() {
     assert!((trpv[1][0] - 3.9).abs() < 1e-12, "trxpv v1");
     assert!((trpv[1][1] - 5.3).abs() < 1e-12, "trxpv v2");
     assert!((trpv[1][2] - 4.1).abs() < 1e-12, "trxpv v3");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_angle() {
+    /* Hours, the three spellings. */
+    let a = Angle::parse("12h 34m 56.789s").unwrap();
+    assert!((a.hours() - (12.0 + 34.0 / 60.0 + 56.789 / 3600.0)).abs() < 1e-12, "hms");
+    assert_eq!(Angle::parse("12h34m56.789s").unwrap(), a);
+    assert!((Angle::parse_hours("12:34:56.789").unwrap().0 - a.0).abs() < 1e-15, "colon");
+    assert!((Angle::parse("12.5h").unwrap().hours() - 12.5).abs() < 1e-12, "decimal h");
+
+    /* Degrees. */
+    let d = Angle::parse("-23:45:06.7").unwrap();
+    assert!((d.degrees() + (23.0 + 45.0 / 60.0 + 6.7 / 3600.0)).abs() < 1e-12, "dms");
+    for s in ["-23 45 06.7", "-23d45m06.7s", "-23°45'06.7\"", "−23°45′06.7″"] {
+        assert!((s.parse::<Angle>().unwrap().0 - d.0).abs() < 1e-15, "{}", s);
+    }
+    assert!((Angle::parse("-23:45.5").unwrap().degrees() + 23.0 + 45.5 / 60.0).abs() < 1e-12,
+            "fractional minutes");
+    assert!((Angle::parse("+10:30").unwrap().degrees() - 10.5).abs() < 1e-12, "dm");
+
+    /* Errors. */
+    assert_eq!(Angle::parse("12:60:00"), Err(2));
+    assert_eq!(Angle::parse("12:34:60"), Err(3));
+    assert_eq!(Angle::parse("12.5:34:00"), Err(-1));
+    assert_eq!(Angle::parse("12h34'00\""), Err(-1));
+    assert_eq!(Angle::parse("12 34m"), Err(-1));
+    assert_eq!(Angle::parse("abc"), Err(-1));
+    assert_eq!(Angle::parse(""), Err(-1));
+
+    /* Formatting, agreeing with a2tf and a2af. */
+    let r = Angle(2.345);
+    assert_eq!(r.to_dms(4), "+134:21:30.9706");
+    assert_eq!(Angle(-3.01234).to_hms(4), "-11:30:22.6484");
+    assert_eq!(d.format(&SexFormat::DMS_SYMBOLS), "-23°45′06.70″");
+    assert_eq!(a.format(&SexFormat::HMS_LETTERS), "12h34m56.789s");
+    assert_eq!(a.format(&SexFormat { ndp: 0, sep: [" ", " ", ""], ..SexFormat::HMS_COLON }),
+               "12 34 57");
+    assert_eq!(format!("{:.1}", d), "-23:45:06.7");
+    assert_eq!(Angle::parse(&d.to_string()).unwrap().to_dms(2), d.to_dms(2));
+}
+
+#[test]
+fn test_quat() {
+    /* A frame rotation about z is an active rotation by -psi. */
+    let psi = 0.3456789;
+    let mut r = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    rz(psi, &mut r);
+    let q = r2q(&r);
+    assert!((q[0] - (psi / 2.0).cos()).abs() < 1e-15, "r2q w");
+    assert!(q[1].abs() < 1e-15 && q[2].abs() < 1e-15, "r2q xy");
+    assert!((q[3] + (psi / 2.0).sin()).abs() < 1e-15, "r2q z");
+
+    /* Round trip and vector rotation on a general matrix. */
+    let mut a = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    rx(2.9, &mut a);
+    ry(-1.1, &mut a);
+    rz(0.4, &mut a);
+    let qa = r2q(&a);
+    assert!(qa[0] >= 0.0, "r2q sign");
+    let b = q2r(&qa);
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((a[i][j] - b[i][j]).abs() < 1e-15, "q2r");
+        }
+    }
+    let p = [0.3, -1.2, 2.5];
+    let rp = rxp(&a, &p);
+    let qp = qxp(&qa, &p);
+    for i in 0..3 {
+        assert!((rp[i] - qp[i]).abs() < 1e-14, "qxp");
+    }
+
+    /* Composition and inverse follow rxr and tr. */
+    let ab = rxr(&a, &r);
+    let c = q2r(&qxq(&qa, &q));
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((ab[i][j] - c[i][j]).abs() < 1e-15, "qxq");
+        }
+    }
+    let back = qxp(&qc(&qa), &qp);
+    for i in 0..3 {
+        assert!((back[i] - p[i]).abs() < 1e-14, "qc");
+    }
+}
+
+#[test]
+fn test_rotmat() {
+    /* Constructors agree with the elementary rotations applied in turn. */
+    let mut m = [[0.0; 3]; 3];
+    ir(&mut m);
+    rz(0.4, &mut m);
+    ry(-1.1, &mut m);
+    rx(2.9, &mut m);
+    let r = RotMat::rx(2.9) * RotMat::ry(-1.1) * RotMat::rz(0.4);
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((r[(i, j)] - m[i][j]).abs() < 1e-15, "RotMat chain");
+        }
+    }
+    assert_eq!(RotMat::from(m).to_array(), m);
+
+    /* Apply and inverse apply. */
+    let p = [0.3, -1.2, 2.5];
+    let rp = rxp(&m, &p);
+    let q = r.apply(&p);
+    let back = r.apply_inverse(&q);
+    for i in 0..3 {
+        assert!((q[i] - rp[i]).abs() < 1e-15, "apply");
+        assert!((back[i] - p[i]).abs() < 1e-14, "apply_inverse");
+    }
+    assert_eq!(r * p, q);
+    assert_eq!(r.transpose().apply(&q), r.apply_inverse(&q));
+
+    /* Orthonormality. */
+    assert!(r.is_orthonormal(1e-14), "orthonormal");
+    assert!((r.det() - 1.0).abs() < 1e-14, "det");
+    assert!(!RotMat::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]])
+        .is_orthonormal(1e-14), "improper");
+    assert!(!RotMat::new([[1.001, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]])
+        .is_orthonormal(1e-6), "scaled");
+
+    /* Quaternion round trip. */
+    let rq = RotMat::from_quat(&r.to_quat());
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((rq[(i, j)] - r[(i, j)]).abs() < 1e-15, "quaternion");
+        }
+    }
+}
+
+#[test]
+fn test_pvvec() {
+    let a = PvVec::from([[2.0, 2.0, 3.0], [6.0, 0.0, 4.0]]);
+    let b = PvVec::new([1.0, 3.0, 4.0], [0.5, 2.0, 6.0]);
+
+    /* pvdpv */
+    let (adb, dadb) = a.dot(&b);
+    assert!((adb - 20.0).abs() < 1e-12, "pvdpv 1");
+    assert!((dadb - 45.0).abs() < 1e-12, "pvdpv 2");
+
+    /* pvxpv, checked against a finite difference. */
+    let axb = a.cross(&b);
+    assert_eq!(axb.p, [-1.0, -5.0, 4.0]);
+    let h = 1e-6;
+    let fd = a.propagate(h).cross(&b.propagate(h)).p;
+    for (i, f) in fd.iter().enumerate() {
+        assert!(((f - axb.p[i]) / h - axb.v[i]).abs() < 1e-4, "pvxpv v");
+    }
+
+    /* pvm */
+    let (r, s) = PvVec::from([[0.3, 1.2, -2.5], [0.45, -0.25, 1.1]]).modulus();
+    assert!((r - 2.789265136196270604).abs() < 1e-12, "pvm r");
+    assert!((s - 1.214495780149111922).abs() < 1e-12, "pvm s");
+
+    /* pvu */
+    let pv = PvVec::new([126668.5912743160734, 2136.792716839935565, -245251.2339876830229],
+                        [-0.4051854035740713039e-2, -0.6253919754866175788e-2,
+                         0.1189353719774107615e-1]);
+    let u = pv.propagate(2920.0);
+    assert!((u.p[0] - 126656.7598605317105).abs() < 1e-6, "pvu p1");
+    assert!((u.p[1] - 2118.531271155726332).abs() < 1e-8, "pvu p2");
+    assert!((u.p[2] + 245216.5048590656190).abs() < 1e-6, "pvu p3");
+    assert_eq!(u.v, pv.v);
+
+    /* Arithmetic and units. */
+    assert_eq!(a + b - b, a);
+    assert_eq!(2.0 * a, a.scale(2.0));
+    let km = PvVec::new([1.0, 0.0, 0.0], [1.0, 0.0, 0.0]).au_to_km();
+    assert!((km.p[0] - 149597870.7).abs() < 1e-6, "au to km");
+    assert!((km.v[0] - 149597870.7 / 86400.0).abs() < 1e-9, "au/d to km/s");
+    let back = km.km_to_au();
+    assert!((back.p[0] - 1.0).abs() < 1e-15 && (back.v[0] - 1.0).abs() < 1e-15, "km to au");
+
+    /* Rotation, as rxpv. */
+    let rm = RotMat::rz(0.7) * RotMat::rx(-0.2);
+    let rpv = rxpv(&rm.to_array(), &a.to_array());
+    assert_eq!(a.rotate(&rm), PvVec::from(rpv));
+    assert_eq!(rm * a, PvVec::from(rpv));
+    let c = a.rotate(&rm).rotate_inverse(&rm);
+    for i in 0..3 {
+        assert!((c.p[i] - a.p[i]).abs() < 1e-14 && (c.v[i] - a.v[i]).abs() < 1e-14, "trxpv");
+    }
+}
+
+#[test]
+fn test_rxp_many() {
+    let r = (RotMat::rz(0.7) * RotMat::ry(0.3) * RotMat::rx(-0.2)).to_array();
+    let p: Vec<[f64; 3]> = (0..100)
+        .map(|i| s2c(0.1 * i as f64, 0.013 * i as f64 - 0.6))
+        .collect();
+
+    let mut rp = vec![[0.0; 3]; p.len()];
+    rxp_many(&r, &p, &mut rp);
+    let mut back = vec![[0.0; 3]; p.len()];
+    trxp_many(&r, &rp, &mut back);
+    let mut q = p.clone();
+    rxp_many_inplace(&r, &mut q);
+    for i in 0..p.len() {
+        let w = rxp(&r, &p[i]);
+        for k in 0..3 {
+            assert!((rp[i][k] - w[k]).abs() < 1e-15, "rxp_many");
+            assert!((back[i][k] - p[i][k]).abs() < 1e-15, "trxp_many");
+        }
+        assert_eq!(q[i], rp[i], "rxp_many_inplace");
+    }
+
+    /* pv-vectors and spherical conversions. */
+    let pv: Vec<[[f64; 3]; 2]> = p.iter().map(|v| [*v, sxp(2.0, v)]).collect();
+    let mut rpv = vec![[[0.0; 3]; 2]; pv.len()];
+    rxpv_many(&r, &pv, &mut rpv);
+    assert_eq!(rpv[17][0], rp[17]);
+
+    let (mut th, mut ph) = (vec![0.0; p.len()], vec![0.0; p.len()]);
+    c2s_many(&p, &mut th, &mut ph);
+    let mut c = vec![[0.0; 3]; p.len()];
+    s2c_many(&th, &ph, &mut c);
+    for i in 0..p.len() {
+        assert_eq!(c2s(&p[i]), (th[i], ph[i]), "c2s_many");
+        for k in 0..3 {
+            assert!((c[i][k] - p[i][k]).abs() < 1e-15, "s2c_many");
+        }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn test_rxp_array() {
+    let r = RotMat::rz(0.7) * RotMat::rx(-0.2);
+    let p = ndarray::Array2::from_shape_fn((5, 3), |(i, k)| (i * 3 + k) as f64 - 4.0);
+    let rp = rxp_array(&r.to_array(), p.view());
+    for i in 0..5 {
+        let w = r.apply(&[p[[i, 0]], p[[i, 1]], p[[i, 2]]]);
+        for k in 0..3 {
+            assert!((rp[[i, k]] - w[k]).abs() < 1e-14, "rxp_array");
+        }
+    }
+    let back = trxp_array(&r.to_array(), rp.view());
+    assert!((back - &p).iter().all(|d| d.abs() < 1e-13), "trxp_array");
+}
+
+#[test]
+fn test_sepp() {
+    let a = [1.0, 0.1, 0.2];
+    let b = [-3.0, 1e-3, 0.2];
+
+    assert!((sepp(&a, &b) - 2.860391919024660768).abs() < 1e-12, "sepp");
+}
+
+#[test]
+fn test_seps() {
+    let s = seps(1.0, 0.1, 0.2, -3.0);
+
+    assert!((s - 2.346722016996998842).abs() < 1e-14, "seps");
+}
+
+#[test]
+fn test_pap() {
+    let a = [1.0, 0.1, 0.2];
+    let b = [-3.0, 1e-3, 0.2];
+
+    assert!((pap(&a, &b) - 0.3671514267841113674).abs() < 1e-12, "pap");
+}
+
+#[test]
+fn test_pas() {
+    let p = pas(1.0, 0.1, 0.2, -1.0);
+
+    assert!((p - -2.724544922932270424).abs() < 1e-12, "pas");
+}
+
+#[test]
+fn test_sphcoord() {
+    let a = SphCoord::new(1.0, 0.1);
+    let b = SphCoord::new(0.2, -1.0);
+
+    assert!((a.sep(&b) - seps(1.0, 0.1, 0.2, -1.0)).abs() < 1e-15, "sep");
+    assert!((a.position_angle(&b) - -2.724544922932270424).abs() < 1e-12, "position_angle");
+    assert!((a.position_angle(&b) - pap(&a.to_vector(), &b.to_vector())).abs() < 1e-12, "pap");
+
+    /* Offset is the inverse of separation and position angle. */
+    let c = a.offset_by(a.position_angle(&b), a.sep(&b));
+    assert!(c.sep(&b) < 1e-14, "offset_by");
+    let c = a.offset_by(0.3, 0.01);
+    assert!((a.sep(&c) - 0.01).abs() < 1e-14, "offset_by: sep");
+    assert!((a.position_angle(&c) - 0.3).abs() < 1e-12, "offset_by: pa");
+    let n = a.offset_by(0.0, 0.2);
+    assert!((n.lon - 1.0).abs() < 1e-15 && (n.lat - 0.3).abs() < 1e-15, "offset_by: north");
+
+    /* Midpoint. */
+    let m = a.midpoint(&b);
+    assert!((a.sep(&m) - a.sep(&b) / 2.0).abs() < 1e-14, "midpoint: a");
+    assert!((b.sep(&m) - a.sep(&b) / 2.0).abs() < 1e-14, "midpoint: b");
+
+    /* Unit vectors. */
+    let p: [f64; 3] = a.into();
+    assert_eq!(p, s2c(1.0, 0.1));
+    let d = SphCoord::from([-2.0, -1.0, 0.5]);
+    assert!((d.lon - (std::f64::consts::PI + 0.5_f64.atan())).abs() < 1e-15, "from_vector: lon");
+    assert!((d.lat - (0.5 / 5.0_f64.sqrt()).atan()).abs() < 1e-15, "from_vector: lat");
+}
+
+#[test]
+fn test_slerp() {
+    let a = [1.0, 0.1, 0.2];
+    let b = [-3.0, 1e-3, 0.2];
+    let (_, au) = pn(&a);
+    let (_, bu) = pn(&b);
+    let theta = sepp(&a, &b);
+
+    /* End points, and uniform rate along the arc. */
+    let p = slerp(&a, &b, 0.0);
+    assert!(sepp(&p, &au) < 1e-15 && (pm(&p) - 1.0).abs() < 1e-15, "slerp: 0");
+    assert!(sepp(&slerp(&a, &b, 1.0), &bu) < 1e-15, "slerp: 1");
+    let p = slerp(&a, &b, 0.3);
+    assert!((sepp(&a, &p) - 0.3 * theta).abs() < 1e-14, "slerp: a");
+    assert!((sepp(&p, &b) - 0.7 * theta).abs() < 1e-14, "slerp: b");
+    assert!(pdp(&p, &pxp(&a, &b)).abs() < 1e-15, "slerp: plane");
+
+    /* Coincident directions. */
+    assert_eq!(slerp(&a, &a, 0.5), au);
+
+    /* Waypoints. */
+    let w = waypoints(&a, &b, 4);
+    assert_eq!(w.len(), 4);
+    for (k, p) in w.iter().enumerate() {
+        assert!((sepp(&a, p) - (k + 1) as f64 * theta / 5.0).abs() < 1e-14, "waypoints");
+    }
+
+    let c = SphCoord::new(1.0, 0.1);
+    let d = SphCoord::new(0.2, -1.0);
+    assert!(c.slerp(&d, 0.5).sep(&c.midpoint(&d)) < 1e-15, "SphCoord::slerp");
+    let w = c.waypoints(&d, 9);
+    assert!((w[4].sep(&c) - c.sep(&d) / 2.0).abs() < 1e-14, "SphCoord::waypoints");
+}
+
+#[test]
+fn test_wrapped_angle() {
+    use std::f64::consts::PI;
+
+    /* Normalization, both conventions. */
+    let a = WrappedAngle::positive(-0.5);
+    assert!((a.radians() - (2.0 * PI - 0.5)).abs() < 1e-15, "positive");
+    assert_eq!(WrappedAngle::positive(-1e-20).radians(), 0.0);
+    assert_eq!(WrappedAngle::signed(PI).radians(), PI);
+    assert_eq!(WrappedAngle::signed(-PI).radians(), PI);
+    assert!((WrappedAngle::signed(3.0 * PI / 2.0).radians() + PI / 2.0).abs() < 1e-15, "signed");
+
+    /* Arithmetic keeps the left operand's convention. */
+    let b = WrappedAngle::positive(6.0) + WrappedAngle::signed(1.0);
+    assert_eq!(b.wrap(), Wrap::Positive);
+    assert!((b.radians() - (7.0 - 2.0 * PI)).abs() < 1e-15, "add");
+    let c = WrappedAngle::signed(3.0) + 0.5;
+    assert!((c.radians() - (3.5 - 2.0 * PI)).abs() < 1e-15, "add f64");
+    let mut d = WrappedAngle::positive(0.25);
+    d -= 0.5;
+    assert!((d.radians() - (2.0 * PI - 0.25)).abs() < 1e-15, "sub_assign");
+    assert!(((-d).radians() - 0.25).abs() < 1e-15, "neg");
+    assert!(((d * 2.0).radians() - (2.0 * PI - 0.5)).abs() < 1e-14, "mul");
+    assert!((d.with_wrap(Wrap::Signed).radians() + 0.25).abs() < 1e-15, "with_wrap");
+
+    /* Shortest separation across 0. */
+    let (e, f) = (WrappedAngle::positive(0.1), WrappedAngle::positive(6.2));
+    assert!((e.diff(&f) - (0.1 - 6.2 + 2.0 * PI)).abs() < 1e-15, "diff");
+    assert!((f.diff(&e) + (0.1 - 6.2 + 2.0 * PI)).abs() < 1e-15, "diff");
+    assert_eq!(f64::from(e), 0.1);
+    assert_eq!(Angle::from(e), Angle(0.1));
+
+    /* A series that passes 2pi twice. */
+    let raw: Vec<WrappedAngle> = (0..40).map(|i| WrappedAngle::positive(i as f64 * 0.4)).collect();
+    let u = unwrap_continuous(raw.iter().copied());
+    for (i, x) in u.iter().enumerate() {
+        assert!((x - i as f64 * 0.4).abs() < 1e-13, "unwrap {}", i);
+    }
+    let u = unwrap_continuous([3.0, -3.0, 3.0]);
+    assert!((u[1] - (2.0 * PI - 3.0)).abs() < 1e-15 && (u[2] - 3.0).abs() < 1e-15, "unwrap");
+}
+
+#[test]
+fn test_cov() {
+    /* A quarter turn about z exchanges the x and y variances. */
+    let c = [[4.0, 1.0, 0.0], [1.0, 9.0, 0.0], [0.0, 0.0, 1.0]];
+    let r = RotMat::rz(std::f64::consts::FRAC_PI_2);
+    let d = r.rotate_cov(&c);
+    assert!((d[0][0] - 9.0).abs() < 1e-14 && (d[1][1] - 4.0).abs() < 1e-14, "rotate_cov");
+    assert!((d[0][1] + 1.0).abs() < 1e-14 && (d[1][0] + 1.0).abs() < 1e-14, "rotate_cov");
+
+    /* 6x6, position and velocity blocks alike. */
+    let mut c6 = [[0.0; 6]; 6];
+    for (i, row) in c6.iter_mut().enumerate() {
+        row[i] = (i + 1) as f64;
+    }
+    let d6 = r.rotate_cov6(&c6);
+    assert!((d6[0][0] - 2.0).abs() < 1e-14 && (d6[3][3] - 5.0).abs() < 1e-14, "rotate_cov6");
+    assert!(d6[0][3].abs() < 1e-14, "rotate_cov6");
+
+    /* Uniform motion:  var(p) grows by dt^2 var(v). */
+    let e = PvVec::propagate_cov(&c6, 10.0);
+    assert!((e[0][0] - (1.0 + 100.0 * 4.0)).abs() < 1e-12, "propagate_cov");
+    assert!((e[0][3] - 40.0).abs() < 1e-12 && (e[3][0] - 40.0).abs() < 1e-12, "propagate_cov");
+    assert_eq!(e[3][3], 4.0);
+
+    /* The Jacobian of a rotation. */
+    let j = pv_jacobian(|x| x.rotate(&r));
+    assert!((j[1][0] + 1.0).abs() < 1e-15 && (j[4][3] + 1.0).abs() < 1e-15, "pv_jacobian");
+    assert_eq!(j[0][3], 0.0);
+    assert_eq!(cov6_transform(&j, &c6), d6);
+}
+
+#[test]
+fn test_mat3() {
+    /* Rotations and products agree with the r-matrix functions. */
+    let mut a = [[0.0; 3]; 3];
+    ir(&mut a);
+    rz(0.4, &mut a);
+    ry(-1.1, &mut a);
+    rx(2.9, &mut a);
+    let mut ma = Mat3::IDENTITY;
+    ma.rotate_z(0.4);
+    ma.rotate_y(-1.1);
+    ma.rotate_x(2.9);
+    assert_eq!(ma.to_rows(), a);
+    assert_eq!(Mat3::from(a), ma);
+
+    let b = [[0.2, -0.7, 1.3], [0.5, 0.1, -0.4], [-1.9, 0.8, 0.6]];
+    let ab = rxr(&a, &b);
+    let mb = Mat3::from_rows(&b);
+    assert_eq!(ma.mul(&mb).to_rows(), ab);
+    assert_eq!(&ma * &mb, ma.mul(&mb));
+
+    let bt = tr(&b);
+    assert_eq!(mb.transpose().to_rows(), bt);
+    assert_eq!(mb[(2, 0)], -1.9);
+
+    let p = [0.3, -1.2, 2.5];
+    let (bp, btp) = (rxp(&b, &p), trxp(&b, &p));
+    assert_eq!(mb.mul_vec(&p), bp);
+    assert_eq!(&mb * &p, bp);
+    assert_eq!(mb.tr_mul_vec(&p), btp);
+
+    /* RotMat is held as a Mat3. */
+    let r = RotMat::from(ma);
+    assert_eq!(r.as_mat3(), &ma);
+    assert_eq!(Mat3::from(r).to_rows(), r.to_array());
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_legacy() {
+    let r = [[2.0, 3.0, 2.0], [3.0, 2.0, 3.0], [3.0, 4.0, 5.0]];
+    let p = [0.2, 1.5, 0.1];
+
+    let mut rp = [0.0; 3];
+    legacy::rxp(&r, &p, &mut rp);
+    assert_eq!(rp, rxp(&r, &p), "legacy::rxp");
+
+    let mut rt = [[0.0; 3]; 3];
+    legacy::tr(&r, &mut rt);
+    assert_eq!(rt, tr(&r), "legacy::tr");
+
+    let mut rr = [[0.0; 3]; 3];
+    legacy::rxr(&r, &rt, &mut rr);
+    assert_eq!(rr, rxr(&r, &rt), "legacy::rxr");
+    assert_eq!(legacy::pm(p), pm(&p), "legacy::pm");
+}
+
+#[test]
+fn test_s2pv() {
+    let pv = s2pv(-3.21, 0.123, 0.456, -7.8e-6, 9.01e-6, -1.23e-5);
+
+    assert!((pv[0][0] - -0.4514964673880165228).abs() < 1e-12, "s2pv: x");
+    assert!((pv[0][1] - 0.0309339427734258688).abs() < 1e-12, "s2pv: y");
+    assert!((pv[0][2] - 0.0559466810510877933).abs() < 1e-12, "s2pv: z");
+
+    assert!((pv[1][0] - 0.1292270850663260170e-4).abs() < 1e-16, "s2pv: vx");
+    assert!((pv[1][1] - 0.2652814182060691422e-5).abs() < 1e-16, "s2pv: vy");
+    assert!((pv[1][2] - 0.2568431853930292259e-5).abs() < 1e-16, "s2pv: vz");
+}
+
+#[test]
+fn test_pv2s() {
+    let pv = [
+        [-0.4514964673880165, 0.03093394277342585, 0.05594668105108779],
+        [1.292270850663260e-5, 2.652814182060692e-6, 2.568431853930293e-6],
+    ];
+
+    let (theta, phi, r, td, pd, rd) = pv2s(&pv);
+    assert!((theta - 3.073185307179586515).abs() < 1e-12, "pv2s: theta");
+    assert!((phi - 0.1229999999999999992).abs() < 1e-12, "pv2s: phi");
+    assert!((r - 0.4559999999999999757).abs() < 1e-12, "pv2s: r");
+    assert!((td - -0.7800000000000000364e-5).abs() < 1e-16, "pv2s: td");
+    assert!((pd - 0.9010000000000001639e-5).abs() < 1e-16, "pv2s: pd");
+    assert!((rd - -0.1229999999999999832e-4).abs() < 1e-16, "pv2s: rd");
+}
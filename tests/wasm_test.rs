@@ -0,0 +1,35 @@
+#![cfg(feature = "wasm")]
+use sofars::wasm::*;
+
+#[test]
+fn test_wasm_epoch() {
+    let e = JsEpoch::parse("2016-12-31T23:59:60.5Z").unwrap();
+    assert_eq!(e.scale(), "UTC");
+    let t = e.to("TT").unwrap();
+    assert_eq!(t.to_js_string(), "2017-01-01T00:01:08.684 TT");
+    assert_eq!(JsEpoch::new("TT", t.jd1(), t.jd2()).unwrap().to_iso(3).unwrap(), "2017-01-01T00:01:08.684");
+
+    /* Row-major matrices, one the transpose of the other. */
+    let m = gcrs_to_itrs(&t, 0.1, 1e-6, 2e-6, 0.0, 0.0).unwrap();
+    let w = itrs_to_gcrs(&t, 0.1, 1e-6, 2e-6, 0.0, 0.0).unwrap();
+    assert_eq!(m.len(), 9);
+    assert_eq!(m[1], w[3]);
+}
+
+#[test]
+fn test_wasm_astrometry() {
+    let r = refco(800.0, 10.0, 0.9, 0.4);
+    assert!((r[0] - 0.2264949956241415009e-3).abs() < 1e-15, "refco");
+
+    let pv = epv00(2400000.5, 53411.52501161).unwrap();
+    assert_eq!(pv.len(), 12);
+    assert!((pv[0] - -0.7757238809297706813).abs() < 1e-14, "epv00 ph(x)");
+    assert!((pv[11] - -0.005404773180966231279).abs() < 1e-15, "epv00 vb(z)");
+
+    /* SOFA t_atco13. */
+    let o = atco13(2.71, 0.174, 1e-5, 5e-6, 0.1, 55.0, 2456384.5, 0.969254051, 0.1550675,
+                   -0.527800806, -1.2345856, 2738.0, 2.47230737e-7, 1.82640464e-6,
+                   731.0, 12.8, 0.59, 0.55).unwrap();
+    assert!((o[0] - 0.9251774485485515207e-1).abs() < 1e-12, "atco13 aob");
+    assert!((o[1] - 1.407661405256499357).abs() < 1e-12, "atco13 zob");
+}